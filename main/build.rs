@@ -19,4 +19,153 @@ fn main() {
                 .expect("Codegen failed.");
         }
     }
+
+    #[cfg(feature = "unicode-tables")]
+    unicode_tables::generate();
+}
+
+/// Generates the character tables used by the tokenizers (`src/tokenizer/unicode_tables.rs`) from
+/// the Unicode Character Database. The directory containing the UCD files (`UnicodeData.txt` and
+/// `ReadMe.txt`) is given by the `UCD_DIR` environment variable, for example:
+///
+/// `UCD_DIR=path/to/ucd cargo build --features unicode-tables`
+#[cfg(feature = "unicode-tables")]
+mod unicode_tables {
+    use std::env;
+    use std::fmt::Write;
+    use std::fs;
+    use std::path::Path;
+
+    const OUT_FILE_NAME: &str = "src/tokenizer/unicode_tables.rs";
+
+    /// Characters considered whitespace by the tokenizers in addition to the `Zs` category
+    const ADDITIONAL_WHITESPACE_CHARS: [u32; 3] = [0x0009, 0x000A, 0x000D];
+
+    pub fn generate() {
+        println!("cargo:rerun-if-env-changed=UCD_DIR");
+        let ucd_dir = env::var("UCD_DIR").expect(
+            "The UCD_DIR environment variable must point to the Unicode Character Database",
+        );
+        let ucd_dir = Path::new(&ucd_dir);
+        println!("cargo:rerun-if-changed={}", ucd_dir.display());
+
+        let readme =
+            fs::read_to_string(ucd_dir.join("ReadMe.txt")).expect("Cannot read ReadMe.txt");
+        let version = unicode_version(&readme).expect("Unicode version not found in ReadMe.txt");
+        let unicode_data = fs::read_to_string(ucd_dir.join("UnicodeData.txt"))
+            .expect("Cannot read UnicodeData.txt");
+        let categories = general_categories(&unicode_data);
+
+        let select = |predicate: &dyn Fn(&str) -> bool| -> Vec<u32> {
+            categories
+                .iter()
+                .filter(|(_, category)| predicate(category))
+                .map(|(code_point, _)| *code_point)
+                .collect()
+        };
+        let mut whitespace = select(&|category| category == "Zs");
+        whitespace.extend_from_slice(&ADDITIONAL_WHITESPACE_CHARS);
+        whitespace.sort_unstable();
+
+        let mut output = String::new();
+        writeln!(
+            output,
+            "// This file is generated by build.rs from the Unicode Character Database, do not edit it\n\
+             // manually. To regenerate it, run:\n\
+             //     UCD_DIR=path/to/ucd cargo build --features unicode-tables\n\
+             //\n\
+             // Unicode version: {}.{}.{}\n\
+             \n\
+             /// Version of the Unicode Character Database the tables are generated from\n\
+             pub const UNICODE_VERSION: (u8, u8, u8) = ({}, {}, {});",
+            version.0, version.1, version.2, version.0, version.1, version.2
+        )
+        .unwrap();
+        write_table(
+            &mut output,
+            "Whitespace characters: `Zs` category, tab, line feed and carriage return",
+            "WHITESPACE_CHARS",
+            &whitespace,
+        );
+        write_table(
+            &mut output,
+            "Punctuation characters: `P` categories (`Pc`, `Pd`, `Pe`, `Pf`, `Pi`, `Po` and `Ps`)",
+            "PUNCTUATION_TABLE",
+            &select(&|category| category.starts_with('P')),
+        );
+        write_table(
+            &mut output,
+            "Control characters: `Cc` and `Cf` categories",
+            "CONTROL_TABLE",
+            &select(&|category| category == "Cc" || category == "Cf"),
+        );
+        write_table(
+            &mut output,
+            "Accent markers (non-spacing marks): `Mn` category",
+            "ACCENT_MARKERS_TABLE",
+            &select(&|category| category == "Mn"),
+        );
+
+        if fs::read_to_string(OUT_FILE_NAME).ok().as_deref() != Some(output.as_str()) {
+            fs::write(OUT_FILE_NAME, output).expect("Cannot write the unicode tables");
+        }
+    }
+
+    /// Extracts the first `Version X.Y.Z` occurrence of the UCD ReadMe
+    fn unicode_version(readme: &str) -> Option<(u8, u8, u8)> {
+        let start = readme.find("Version ")? + "Version ".len();
+        let mut parts = readme[start..]
+            .split(|character: char| !character.is_ascii_digit() && character != '.')
+            .next()?
+            .split('.')
+            .map(|part| part.parse::<u8>().ok());
+        Some((parts.next()??, parts.next()??, parts.next()??))
+    }
+
+    /// Reads the general category of each code point listed in `UnicodeData.txt`, expanding the
+    /// ranges given as `<..., First>` and `<..., Last>` entries.
+    fn general_categories(unicode_data: &str) -> Vec<(u32, String)> {
+        let mut categories = Vec::new();
+        let mut range_start = None;
+        for line in unicode_data.lines().filter(|line| !line.trim().is_empty()) {
+            let fields: Vec<&str> = line.split(';').collect();
+            assert!(fields.len() > 2, "Invalid UnicodeData.txt line: {}", line);
+            let code_point = u32::from_str_radix(fields[0], 16)
+                .unwrap_or_else(|_| panic!("Invalid code point: {}", fields[0]));
+            let category = fields[2].to_owned();
+            if fields[1].ends_with(", First>") {
+                range_start = Some(code_point);
+            } else if fields[1].ends_with(", Last>") {
+                let start = range_start
+                    .take()
+                    .unwrap_or_else(|| panic!("Range end without start: {}", line));
+                for code_point in start..=code_point {
+                    categories.push((code_point, category.clone()));
+                }
+            } else {
+                categories.push((code_point, category));
+            }
+        }
+        categories
+    }
+
+    fn write_table(output: &mut String, doc: &str, name: &str, code_points: &[u32]) {
+        writeln!(
+            output,
+            "\n/// {}\npub const {}: [u32; {}] = [",
+            doc,
+            name,
+            code_points.len()
+        )
+        .unwrap();
+        for chunk in code_points.chunks(10) {
+            let line = chunk
+                .iter()
+                .map(|code_point| format!("0x{:04X},", code_point))
+                .collect::<Vec<String>>()
+                .join(" ");
+            writeln!(output, "    {}", line).unwrap();
+        }
+        writeln!(output, "];").unwrap();
+    }
 }
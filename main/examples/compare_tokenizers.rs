@@ -0,0 +1,125 @@
+// Copyright 2019 Guillaume Becquin
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//     http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Compares the encodings of a corpus by two tokenizers (for example two versions of a vocabulary,
+//! or two model families) and prints the divergence statistics and the first mismatches.
+//!
+//! Usage:
+//! ```text
+//! cargo run --example compare_tokenizers -- <tokenizer_1> <tokenizer_2> <corpus> [max_len] [max_mismatches]
+//! ```
+//! Each tokenizer is given as `<family>,<vocab>` for the `bert` and `sentencepiece` families, and
+//! as `<family>,<vocab>,<merges>` for the `gpt2`, `roberta` and `deberta` families (for example
+//! `bert,path/to/vocab.txt` or `roberta,path/to/vocab.json,path/to/merges.txt`). The corpus file
+//! contains one sample per line. BERT vocabularies are read with lower casing and accent stripping
+//! enabled, the other tokenizers are cased and do not add a prefix space.
+
+use anyhow::{anyhow, bail};
+use rust_tokenizers::adapters::{compare_tokenizers, TokenizerDiffReport};
+use rust_tokenizers::tokenizer::{
+    BertTokenizer, DeBERTaTokenizer, Gpt2Tokenizer, RobertaTokenizer, SentencePieceTokenizer,
+};
+use std::env;
+use std::fs;
+
+#[allow(clippy::large_enum_variant)]
+enum LoadedTokenizer {
+    Bert(BertTokenizer),
+    SentencePiece(SentencePieceTokenizer),
+    Gpt2(Gpt2Tokenizer),
+    Roberta(RobertaTokenizer),
+    DeBERTa(DeBERTaTokenizer),
+}
+
+/// Runs an expression with the tokenizer wrapped by a `LoadedTokenizer` bound to `$name`
+macro_rules! with_tokenizer {
+    ($tokenizer:expr, $name:ident => $body:expr) => {
+        match $tokenizer {
+            LoadedTokenizer::Bert($name) => $body,
+            LoadedTokenizer::SentencePiece($name) => $body,
+            LoadedTokenizer::Gpt2($name) => $body,
+            LoadedTokenizer::Roberta($name) => $body,
+            LoadedTokenizer::DeBERTa($name) => $body,
+        }
+    };
+}
+
+fn load_tokenizer(spec: &str) -> anyhow::Result<LoadedTokenizer> {
+    let parts: Vec<&str> = spec.split(',').collect();
+    Ok(match parts.as_slice() {
+        ["bert", vocab] => LoadedTokenizer::Bert(BertTokenizer::from_file(vocab, true, true)?),
+        ["sentencepiece", vocab] => {
+            LoadedTokenizer::SentencePiece(SentencePieceTokenizer::from_file(vocab, false)?)
+        }
+        ["gpt2", vocab, merges] => {
+            LoadedTokenizer::Gpt2(Gpt2Tokenizer::from_file(vocab, merges, false, false)?)
+        }
+        ["roberta", vocab, merges] => {
+            LoadedTokenizer::Roberta(RobertaTokenizer::from_file(vocab, merges, false, false)?)
+        }
+        ["deberta", vocab, merges] => {
+            LoadedTokenizer::DeBERTa(DeBERTaTokenizer::from_file(vocab, merges, false)?)
+        }
+        _ => return Err(anyhow!("unsupported tokenizer specification: {spec}")),
+    })
+}
+
+fn print_report(report: &TokenizerDiffReport) {
+    println!("samples compared: {}", report.num_samples);
+    println!(
+        "tokens: {} (first tokenizer), {} (second tokenizer)",
+        report.num_tokens_1, report.num_tokens_2
+    );
+    println!(
+        "id mismatches: {} ({:.2}%)",
+        report.num_id_mismatches,
+        100.0 * report.id_mismatch_rate()
+    );
+    println!(
+        "offset mismatches: {} ({:.2}%)",
+        report.num_offset_mismatches,
+        100.0 * report.offset_mismatch_rate()
+    );
+    for mismatch in &report.mismatches {
+        println!();
+        println!(
+            "sample {} (first divergence at token {}): {}",
+            mismatch.sample_index, mismatch.first_divergence, mismatch.text
+        );
+        println!("  ids 1:     {:?}", mismatch.token_ids_1);
+        println!("  ids 2:     {:?}", mismatch.token_ids_2);
+        println!("  offsets 1: {:?}", mismatch.offsets_1);
+        println!("  offsets 2: {:?}", mismatch.offsets_2);
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.len() < 3 {
+        bail!(
+            "usage: compare_tokenizers <family>,<vocab>[,<merges>] <family>,<vocab>[,<merges>] <corpus> [max_len] [max_mismatches]"
+        );
+    }
+    let tokenizer_1 = load_tokenizer(&args[0])?;
+    let tokenizer_2 = load_tokenizer(&args[1])?;
+    let max_len = args.get(3).map_or(Ok(512), |value| value.parse())?;
+    let max_mismatches = args.get(4).map_or(Ok(10), |value| value.parse())?;
+    let corpus = fs::read_to_string(&args[2])?;
+    let samples = corpus.lines().filter(|line| !line.trim().is_empty());
+
+    let report = with_tokenizer!(&tokenizer_1, tokenizer_1 => {
+        with_tokenizer!(&tokenizer_2, tokenizer_2 => {
+            compare_tokenizers(tokenizer_1, tokenizer_2, samples, max_len, max_mismatches)?
+        })
+    });
+    print_report(&report);
+    Ok(())
+}
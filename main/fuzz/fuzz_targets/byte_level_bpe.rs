@@ -0,0 +1,12 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use rust_tokenizers_fuzz::{fuzz_tokenizer, gpt2_tokenizer, roberta_tokenizer, split_input};
+
+fuzz_target!(|data: &[u8]| {
+    if let Some((flags, payload)) = split_input(data) {
+        if let Ok(text) = std::str::from_utf8(payload) {
+            fuzz_tokenizer(&gpt2_tokenizer(&flags), text, &flags);
+            fuzz_tokenizer(&roberta_tokenizer(&flags), text, &flags);
+        }
+    }
+});
@@ -0,0 +1,34 @@
+#![no_main]
+//! Feeds arbitrary (possibly invalid UTF-8) byte sequences to the lossy byte-level entry points,
+//! checking that the byte offsets returned stay within the input buffer.
+use libfuzzer_sys::fuzz_target;
+use rust_tokenizers::tokenizer::Tokenizer;
+use rust_tokenizers_fuzz::{
+    albert_tokenizer, bert_tokenizer, check_tokenized_input, check_tokens_with_offsets,
+    roberta_tokenizer, split_input,
+};
+
+fuzz_target!(|data: &[u8]| {
+    if let Some((flags, payload)) = split_input(data) {
+        let bert_tokenizer = bert_tokenizer(&flags);
+        check_tokens_with_offsets(&bert_tokenizer.tokenize_bytes(payload), payload.len());
+        let roberta_tokenizer = roberta_tokenizer(&flags);
+        check_tokens_with_offsets(&roberta_tokenizer.tokenize_bytes(payload), payload.len());
+        let albert_tokenizer = albert_tokenizer(&flags);
+        check_tokens_with_offsets(&albert_tokenizer.tokenize_bytes(payload), payload.len());
+
+        let (bytes_1, bytes_2) = match payload.iter().position(|&byte| byte == b'\t') {
+            Some(position) => (&payload[..position], Some(&payload[position + 1..])),
+            None => (payload, None),
+        };
+        if let Ok(tokenized_input) = bert_tokenizer.encode_bytes(
+            bytes_1,
+            bytes_2,
+            usize::MAX,
+            &flags.truncation_strategy,
+            0,
+        ) {
+            check_tokenized_input(&tokenized_input, payload.len());
+        }
+    }
+});
@@ -0,0 +1,11 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use rust_tokenizers_fuzz::{fuzz_tokenizer, openai_gpt_tokenizer, split_input};
+
+fuzz_target!(|data: &[u8]| {
+    if let Some((flags, payload)) = split_input(data) {
+        if let Ok(text) = std::str::from_utf8(payload) {
+            fuzz_tokenizer(&openai_gpt_tokenizer(&flags), text, &flags);
+        }
+    }
+});
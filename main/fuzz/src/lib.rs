@@ -0,0 +1,343 @@
+//! Shared utilities for the `rust_tokenizers` fuzz targets.
+//!
+//! The fuzz targets do not rely on pretrained resources: small vocabularies and models are built
+//! in memory so that the targets exercise the pre-tokenization, normalization and offset
+//! arithmetic paths of each tokenizer family without requiring network access.
+//!
+//! The first byte of every fuzzing input is used as a set of flags for the tokenizer options
+//! (see `Flags`), the remaining bytes being the text to tokenize.
+use rust_tokenizers::tokenizer::{
+    AlbertTokenizer, BertTokenizer, Gpt2Tokenizer, OpenAiGptTokenizer, RobertaTokenizer, Tokenizer,
+    TruncationStrategy,
+};
+use rust_tokenizers::vocab::{
+    AlbertVocab, BertVocab, BpePairVocab, Gpt2Vocab, OpenAiGptVocab, RobertaVocab,
+    SentencePieceModel, SpecialTokenMap, TrieNode, Vocab,
+};
+use rust_tokenizers::{Offset, TokenizedInput, TokensWithOffsets};
+use std::collections::HashMap;
+
+/// Tokenizer options derived from the first byte of a fuzzing input
+pub struct Flags {
+    pub lower_case: bool,
+    pub strip_accents: bool,
+    pub add_prefix_space: bool,
+    pub truncation_strategy: TruncationStrategy,
+    pub max_len: usize,
+}
+
+/// Splits a fuzzing input into the tokenizer options and the remaining payload.
+pub fn split_input(data: &[u8]) -> Option<(Flags, &[u8])> {
+    let (&flags, payload) = data.split_first()?;
+    let truncation_strategy = match (flags >> 3) & 0b11 {
+        0 => TruncationStrategy::LongestFirst,
+        1 => TruncationStrategy::OnlyFirst,
+        2 => TruncationStrategy::OnlySecond,
+        _ => TruncationStrategy::DoNotTruncate,
+    };
+    Some((
+        Flags {
+            lower_case: flags & 0b1 != 0,
+            strip_accents: flags & 0b10 != 0,
+            add_prefix_space: flags & 0b100 != 0,
+            truncation_strategy,
+            max_len: (flags >> 5) as usize * 4,
+        },
+        payload,
+    ))
+}
+
+/// Splits a text in two at the first tab character, allowing to fuzz sentence pairs.
+pub fn split_pair(text: &str) -> (&str, Option<&str>) {
+    match text.split_once('\t') {
+        Some((text_1, text_2)) => (text_1, Some(text_2)),
+        None => (text, None),
+    }
+}
+
+/// Checks that the offsets of tokens refer to valid positions of the input.
+///
+/// # Parameters
+/// - tokens (`&TokensWithOffsets`): tokenization output to validate
+/// - input_length (`usize`): length of the input, in the unit of the offsets (characters or bytes)
+pub fn check_tokens_with_offsets(tokens: &TokensWithOffsets, input_length: usize) {
+    assert_eq!(tokens.tokens.len(), tokens.offsets.len());
+    assert_eq!(tokens.tokens.len(), tokens.reference_offsets.len());
+    assert_eq!(tokens.tokens.len(), tokens.masks.len());
+    for offset in tokens.offsets.iter().flatten() {
+        check_offset(offset, input_length);
+    }
+    for reference_offsets in &tokens.reference_offsets {
+        for &position in reference_offsets {
+            assert!(
+                (position as usize) < input_length.max(1),
+                "reference offset {} out of bounds for input of length {}",
+                position,
+                input_length
+            );
+        }
+    }
+}
+
+/// Checks that the offsets of an encoded input refer to valid positions of the inputs and that
+/// all the per-token fields are aligned.
+///
+/// # Parameters
+/// - tokenized_input (`&TokenizedInput`): encoding output to validate
+/// - input_length (`usize`): length of the longest input, in the unit of the offsets
+pub fn check_tokenized_input(tokenized_input: &TokenizedInput, input_length: usize) {
+    let length = tokenized_input.token_ids.len();
+    assert_eq!(tokenized_input.segment_ids.len(), length);
+    assert_eq!(tokenized_input.special_tokens_mask.len(), length);
+    assert_eq!(tokenized_input.token_offsets.len(), length);
+    assert_eq!(tokenized_input.reference_offsets.len(), length);
+    assert_eq!(tokenized_input.mask.len(), length);
+    for offset in tokenized_input.token_offsets.iter().flatten() {
+        check_offset(offset, input_length);
+    }
+}
+
+fn check_offset(offset: &Offset, input_length: usize) {
+    assert!(
+        offset.begin <= offset.end,
+        "invalid offset {:?} (begin after end)",
+        offset
+    );
+    assert!(
+        offset.end as usize <= input_length,
+        "offset {:?} out of bounds for input of length {}",
+        offset,
+        input_length
+    );
+}
+
+/// Tokenizes and encodes a text (split in a sentence pair at the first tab character), checking
+/// the validity of the offsets returned and that the decoding of the output does not panic.
+pub fn fuzz_tokenizer<T: Vocab, U: Tokenizer<T>>(tokenizer: &U, text: &str, flags: &Flags) {
+    let char_count = text.chars().count();
+    check_tokens_with_offsets(&tokenizer.tokenize_with_offsets(text), char_count);
+
+    let (text_1, text_2) = split_pair(text);
+    if let Ok(tokenized_input) = tokenizer.try_encode(
+        text_1,
+        text_2,
+        flags.max_len,
+        &flags.truncation_strategy,
+        flags.max_len / 4,
+    ) {
+        let input_length = text_1
+            .chars()
+            .count()
+            .max(text_2.map_or(0, |text| text.chars().count()));
+        check_tokenized_input(&tokenized_input, input_length);
+        let _ = tokenizer.decode(&tokenized_input.token_ids, false, true);
+        let _ = tokenizer.decode_with_offsets(&tokenized_input.token_ids, true, true);
+    }
+}
+
+fn string_map(entries: &[&str]) -> HashMap<String, i64> {
+    entries
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| (entry.to_string(), index as i64))
+        .collect()
+}
+
+fn merges_map(entries: &[(&str, &str)]) -> BpePairVocab {
+    let values = entries
+        .iter()
+        .enumerate()
+        .map(|(index, (left, right))| ((left.to_string(), right.to_string()), index as i64))
+        .collect();
+    BpePairVocab { values }
+}
+
+fn special_token_map(
+    unk_token: &str,
+    pad_token: Option<&str>,
+    bos_token: Option<&str>,
+    sep_token: Option<&str>,
+    cls_token: Option<&str>,
+    eos_token: Option<&str>,
+    mask_token: Option<&str>,
+) -> SpecialTokenMap {
+    SpecialTokenMap {
+        unk_token: unk_token.to_string(),
+        pad_token: pad_token.map(str::to_string),
+        bos_token: bos_token.map(str::to_string),
+        sep_token: sep_token.map(str::to_string),
+        cls_token: cls_token.map(str::to_string),
+        eos_token: eos_token.map(str::to_string),
+        mask_token: mask_token.map(str::to_string),
+        additional_special_tokens: None,
+    }
+}
+
+/// Builds a WordPiece tokenizer with a toy vocabulary
+pub fn bert_tokenizer(flags: &Flags) -> BertTokenizer {
+    let vocab = BertVocab::from_values_and_special_token_map(
+        string_map(&[
+            "[UNK]", "[PAD]", "[CLS]", "[SEP]", "[MASK]", "hello", "world", "!", "中", "华", "人",
+            "una", "##ffa", "##ble", "a", "##a", "é", "##é",
+        ]),
+        special_token_map(
+            "[UNK]",
+            Some("[PAD]"),
+            None,
+            Some("[SEP]"),
+            Some("[CLS]"),
+            None,
+            Some("[MASK]"),
+        ),
+    )
+    .unwrap();
+    BertTokenizer::from_existing_vocab(vocab, flags.lower_case, flags.strip_accents)
+}
+
+const BYTE_LEVEL_BPE_MERGES: [(&str, &str); 10] = [
+    ("Ġ", "t"),
+    ("Ġ", "n"),
+    ("e", "e"),
+    ("Ġt", "he"),
+    ("h", "e"),
+    ("t", "h"),
+    ("t", "he"),
+    ("Ġ", "e"),
+    ("Ġe", "a"),
+    ("Ġea", "r"),
+];
+
+/// Builds a GPT2 (byte-level BPE) tokenizer with a toy vocabulary and merges
+pub fn gpt2_tokenizer(flags: &Flags) -> Gpt2Tokenizer {
+    let vocab = Gpt2Vocab::from_values_and_special_token_map(
+        string_map(&["<|endoftext|>", "t", "h", "n", "the", "Ġ", "Ġear", "th"]),
+        special_token_map(
+            "<|endoftext|>",
+            None,
+            Some("<|endoftext|>"),
+            None,
+            None,
+            Some("<|endoftext|>"),
+            None,
+        ),
+    )
+    .unwrap();
+    Gpt2Tokenizer::from_existing_vocab_and_merges(
+        vocab,
+        merges_map(&BYTE_LEVEL_BPE_MERGES),
+        flags.lower_case,
+        flags.add_prefix_space,
+    )
+}
+
+/// Builds a RoBERTa (byte-level BPE) tokenizer with a toy vocabulary and merges
+pub fn roberta_tokenizer(flags: &Flags) -> RobertaTokenizer {
+    let vocab = RobertaVocab::from_values_and_special_token_map(
+        string_map(&[
+            "<unk>", "<s>", "</s>", "<pad>", "<mask>", "t", "h", "n", "Ġthe", "Ġ", "Ġear", "th",
+        ]),
+        special_token_map(
+            "<unk>",
+            Some("<pad>"),
+            Some("<s>"),
+            Some("</s>"),
+            Some("<s>"),
+            Some("</s>"),
+            Some("<mask>"),
+        ),
+    )
+    .unwrap();
+    RobertaTokenizer::from_existing_vocab_and_merges(
+        vocab,
+        merges_map(&BYTE_LEVEL_BPE_MERGES),
+        flags.lower_case,
+        flags.add_prefix_space,
+    )
+}
+
+/// Builds an OpenAI GPT (character-level BPE) tokenizer with a toy vocabulary and merges
+pub fn openai_gpt_tokenizer(flags: &Flags) -> OpenAiGptTokenizer {
+    let vocab = OpenAiGptVocab::from_values_and_special_token_map(
+        string_map(&[
+            "<unk>", "t", "h", "a</w>", "n", "the", "o</w>", "the</w>", "rth</w>", "ea",
+        ]),
+        special_token_map("<unk>", None, None, None, None, None, None),
+    )
+    .unwrap();
+    let merges = merges_map(&[
+        ("r", "th</w>"),
+        ("t", "he</w>"),
+        ("h", "e"),
+        ("t", "h</w>"),
+        ("t", "h"),
+        ("th", "e</w>"),
+        ("e", "a"),
+    ]);
+    OpenAiGptTokenizer::from_existing_vocab_and_merges(vocab, merges, flags.lower_case)
+}
+
+const SENTENCE_PIECE_VOCAB: [(&str, f32); 17] = [
+    ("<unk>", 0.0),
+    ("<pad>", 0.0),
+    ("[CLS]", 0.0),
+    ("[SEP]", 0.0),
+    ("[MASK]", 0.0),
+    ("▁", -2.0),
+    ("▁the", -3.0),
+    ("▁t", -4.0),
+    ("he", -4.5),
+    ("h", -5.0),
+    ("e", -5.0),
+    ("t", -5.0),
+    ("a", -5.0),
+    ("▁ear", -6.0),
+    ("th", -6.5),
+    ("é", -7.0),
+    ("中", -7.0),
+];
+
+fn insert_piece(root: &mut TrieNode, piece: &str, score: f32, index: i64) {
+    let mut node = root;
+    for character in piece.chars() {
+        let text = format!("{}{}", node.text, character);
+        node = node
+            .children
+            .entry(character)
+            .or_insert_with(|| TrieNode::new(text));
+    }
+    node.end = true;
+    node.score = score;
+    node.index = index;
+}
+
+/// Builds an ALBERT (SentencePiece unigram) tokenizer with a toy vocabulary and model
+pub fn albert_tokenizer(flags: &Flags) -> AlbertTokenizer {
+    let mut root = TrieNode::new(String::new());
+    for (index, (piece, score)) in SENTENCE_PIECE_VOCAB.iter().enumerate() {
+        insert_piece(&mut root, piece, *score, index as i64);
+    }
+    let model = SentencePieceModel::new(root);
+    let vocab = AlbertVocab::from_values_and_special_token_map(
+        SENTENCE_PIECE_VOCAB
+            .iter()
+            .enumerate()
+            .map(|(index, (piece, _))| (piece.to_string(), index as i64))
+            .collect(),
+        special_token_map(
+            "<unk>",
+            Some("<pad>"),
+            Some("[CLS]"),
+            Some("[SEP]"),
+            Some("[CLS]"),
+            Some("[SEP]"),
+            Some("[MASK]"),
+        ),
+    )
+    .unwrap();
+    AlbertTokenizer::from_existing_vocab_and_model(
+        vocab,
+        model,
+        flags.lower_case,
+        flags.strip_accents,
+    )
+}
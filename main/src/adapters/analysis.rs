@@ -0,0 +1,619 @@
+// Copyright 2019 Guillaume Becquin
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//     http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::error::TokenizerError;
+use crate::tokenizer::tokenization_utils::ratio;
+use crate::tokenizer::{Tokenizer, TruncationStrategy};
+use crate::vocab::Vocab;
+use crate::{Mask, Offset, TokenizedInput};
+use std::collections::HashMap;
+
+/// # Vocabulary coverage report
+/// Coverage of a corpus by the vocabulary of a tokenizer, returned by `analyze_coverage`. Words
+/// are sequences of tokens made of a token followed by its `Mask::Continuation` sub-tokens
+/// (special tokens are ignored).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CoverageReport {
+    /// Number of samples analyzed
+    pub num_samples: usize,
+
+    /// Number of words in the corpus
+    pub num_words: usize,
+
+    /// Number of tokens in the corpus
+    pub num_tokens: usize,
+
+    /// Number of words containing at least one unknown token
+    pub num_unknown_words: usize,
+
+    /// Number of tokens mapped to the unknown token
+    pub num_unknown_tokens: usize,
+
+    /// Most frequent strings producing unknown tokens and their number of occurrences, sorted by
+    /// decreasing frequency
+    pub unknown_strings: Vec<(String, usize)>,
+}
+
+impl CoverageReport {
+    /// Returns the share of words containing an unknown token
+    pub fn oov_rate(&self) -> f64 {
+        ratio(self.num_unknown_words as u64, self.num_words as u64)
+    }
+
+    /// Returns the share of tokens mapped to the unknown token
+    pub fn unknown_token_rate(&self) -> f64 {
+        ratio(self.num_unknown_tokens as u64, self.num_tokens as u64)
+    }
+
+    /// Returns the subword fertility (average number of tokens per word)
+    pub fn fertility(&self) -> f64 {
+        ratio(self.num_tokens as u64, self.num_words as u64)
+    }
+}
+
+/// Runs a corpus through a tokenizer and reports the coverage of the corpus by its vocabulary: rate
+/// of out-of-vocabulary words, subword fertility and the most frequent strings producing unknown
+/// tokens. This helps selecting a vocabulary or deciding if it should be adapted to a domain.
+///
+///  # Arguments
+/// - tokenizer: tokenizer to analyze
+/// - corpus: iterator over the samples (string-like) of the corpus
+/// - max_unknown_strings (`usize`): maximum number of unknown-producing strings to report
+///
+///  # Returns
+/// - `CoverageReport` with the coverage statistics of the corpus
+///
+///  # Example
+///
+/// ```no_run
+/// # fn main() -> anyhow::Result<()> {
+/// use rust_tokenizers::adapters::analyze_coverage;
+/// use rust_tokenizers::tokenizer::BertTokenizer;
+/// let tokenizer = BertTokenizer::from_file("path/to/vocab", true, true)?;
+/// let corpus = ["The patient was given acetaminophen.", "No adverse reaction."];
+/// let report = analyze_coverage(&tokenizer, corpus.iter(), 20);
+/// println!(
+///     "OOV rate: {}, fertility: {}, most frequent unknown strings: {:?}",
+///     report.oov_rate(),
+///     report.fertility(),
+///     report.unknown_strings
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn analyze_coverage<T, V, I, S>(
+    tokenizer: &T,
+    corpus: I,
+    max_unknown_strings: usize,
+) -> CoverageReport
+where
+    T: Tokenizer<V>,
+    V: Vocab,
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let unknown_id = tokenizer
+        .vocab()
+        .token_to_id(tokenizer.vocab().get_unknown_value());
+    let mut report = CoverageReport::default();
+    let mut unknown_strings: HashMap<String, usize> = HashMap::new();
+    for sample in corpus {
+        let sample = sample.as_ref();
+        let char_positions = sample
+            .char_indices()
+            .map(|(position, _)| position)
+            .chain(std::iter::once(sample.len()))
+            .collect::<Vec<usize>>();
+        let tokens = tokenizer.tokenize_with_offsets(sample);
+        let token_ids = tokenizer.convert_tokens_to_ids(&tokens.tokens);
+        report.num_samples += 1;
+
+        let mut words: Vec<(Option<Offset>, Vec<usize>)> = Vec::new();
+        for (position, mask) in tokens.masks.iter().enumerate() {
+            match (mask, words.last_mut()) {
+                (Mask::Special, _) => continue,
+                (Mask::Continuation, Some((word_offset, word_tokens)))
+                    if word_tokens.last() == Some(&(position - 1)) =>
+                {
+                    *word_offset = match (*word_offset, tokens.offsets[position]) {
+                        (Some(word), Some(token)) => {
+                            Some(Offset::new(word.begin, word.end.max(token.end)))
+                        }
+                        (word, token) => word.or(token),
+                    };
+                    word_tokens.push(position);
+                }
+                _ => words.push((tokens.offsets[position], vec![position])),
+            }
+        }
+
+        for (word_offset, word_tokens) in words {
+            let num_unknown_tokens = word_tokens
+                .iter()
+                .filter(|&&position| token_ids[position] == unknown_id)
+                .count();
+            report.num_words += 1;
+            report.num_tokens += word_tokens.len();
+            report.num_unknown_tokens += num_unknown_tokens;
+            if num_unknown_tokens > 0 {
+                report.num_unknown_words += 1;
+                let word = word_offset
+                    .and_then(|offset| {
+                        let begin = *char_positions.get(offset.begin as usize)?;
+                        let end = *char_positions.get(offset.end as usize)?;
+                        sample.get(begin..end)
+                    })
+                    .map(str::to_owned)
+                    .unwrap_or_else(|| {
+                        word_tokens
+                            .iter()
+                            .map(|&position| tokens.tokens[position].as_str())
+                            .collect()
+                    });
+                *unknown_strings.entry(word).or_insert(0) += 1;
+            }
+        }
+    }
+    let mut unknown_strings = unknown_strings
+        .into_iter()
+        .collect::<Vec<(String, usize)>>();
+    unknown_strings.sort_by(|(string_1, count_1), (string_2, count_2)| {
+        count_2.cmp(count_1).then_with(|| string_1.cmp(string_2))
+    });
+    unknown_strings.truncate(max_unknown_strings);
+    report.unknown_strings = unknown_strings;
+    report
+}
+
+/// # Mismatch between two tokenizers
+/// Encodings of a sample of the corpus for which the outputs of the tokenizers compared by
+/// `compare_tokenizers` differ
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenizerMismatch {
+    /// Index of the sample in the corpus
+    pub sample_index: usize,
+
+    /// Text of the sample
+    pub text: String,
+
+    /// Position of the first token for which the ids or offsets differ
+    pub first_divergence: usize,
+
+    /// Token ids produced by the first tokenizer
+    pub token_ids_1: Vec<i64>,
+
+    /// Token ids produced by the second tokenizer
+    pub token_ids_2: Vec<i64>,
+
+    /// Token offsets produced by the first tokenizer
+    pub offsets_1: Vec<Option<Offset>>,
+
+    /// Token offsets produced by the second tokenizer
+    pub offsets_2: Vec<Option<Offset>>,
+}
+
+/// # Tokenizer comparison report
+/// Divergence between the encodings of a corpus by two tokenizers, returned by `compare_tokenizers`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TokenizerDiffReport {
+    /// Number of samples compared
+    pub num_samples: usize,
+
+    /// Number of samples encoded with different token ids
+    pub num_id_mismatches: usize,
+
+    /// Number of samples encoded with the same token ids but different offsets
+    pub num_offset_mismatches: usize,
+
+    /// Number of tokens produced by the first tokenizer
+    pub num_tokens_1: usize,
+
+    /// Number of tokens produced by the second tokenizer
+    pub num_tokens_2: usize,
+
+    /// First mismatching samples, in corpus order
+    pub mismatches: Vec<TokenizerMismatch>,
+}
+
+impl TokenizerDiffReport {
+    /// Returns the share of samples encoded with different token ids
+    pub fn id_mismatch_rate(&self) -> f64 {
+        ratio(self.num_id_mismatches as u64, self.num_samples as u64)
+    }
+
+    /// Returns the share of samples encoded with the same token ids but different offsets
+    pub fn offset_mismatch_rate(&self) -> f64 {
+        ratio(self.num_offset_mismatches as u64, self.num_samples as u64)
+    }
+
+    /// Returns `true` if both tokenizers produced identical ids and offsets for all samples
+    pub fn is_identical(&self) -> bool {
+        self.num_id_mismatches == 0 && self.num_offset_mismatches == 0
+    }
+}
+
+/// Encodes a corpus with two tokenizers and reports where their outputs diverge: number of
+/// samples with different token ids or offsets and the first mismatching samples. This is useful
+/// to validate a migration between tokenizer implementations or vocabulary versions.
+///
+///  # Arguments
+/// - tokenizer_1: first tokenizer
+/// - tokenizer_2: second tokenizer
+/// - corpus: iterator over the samples (string-like) of the corpus
+/// - max_len (`usize`): maximum length of the encodings (longer samples are truncated)
+/// - max_mismatches (`usize`): maximum number of mismatching samples to report
+///
+///  # Returns
+/// - `TokenizerDiffReport` with the divergence statistics of the corpus, or an error if a sample
+///   can not be encoded by one of the tokenizers
+///
+///  # Example
+///
+/// ```no_run
+/// # fn main() -> anyhow::Result<()> {
+/// use rust_tokenizers::adapters::compare_tokenizers;
+/// use rust_tokenizers::tokenizer::BertTokenizer;
+/// let tokenizer_1 = BertTokenizer::from_file("path/to/vocab", true, true)?;
+/// let tokenizer_2 = BertTokenizer::from_file("path/to/new/vocab", true, true)?;
+/// let corpus = ["The patient was given acetaminophen.", "No adverse reaction."];
+/// let report = compare_tokenizers(&tokenizer_1, &tokenizer_2, corpus.iter(), 512, 10)?;
+/// println!(
+///     "{} / {} samples differ, first mismatches: {:?}",
+///     report.num_id_mismatches, report.num_samples, report.mismatches
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn compare_tokenizers<T1, V1, T2, V2, I, S>(
+    tokenizer_1: &T1,
+    tokenizer_2: &T2,
+    corpus: I,
+    max_len: usize,
+    max_mismatches: usize,
+) -> Result<TokenizerDiffReport, TokenizerError>
+where
+    T1: Tokenizer<V1>,
+    V1: Vocab,
+    T2: Tokenizer<V2>,
+    V2: Vocab,
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut report = TokenizerDiffReport::default();
+    for (sample_index, sample) in corpus.into_iter().enumerate() {
+        let sample = sample.as_ref();
+        let encoded_1 =
+            tokenizer_1.try_encode(sample, None, max_len, &TruncationStrategy::LongestFirst, 0)?;
+        let encoded_2 =
+            tokenizer_2.try_encode(sample, None, max_len, &TruncationStrategy::LongestFirst, 0)?;
+        report.num_samples += 1;
+        report.num_tokens_1 += encoded_1.token_ids.len();
+        report.num_tokens_2 += encoded_2.token_ids.len();
+
+        if encoded_1.token_ids != encoded_2.token_ids {
+            report.num_id_mismatches += 1;
+        } else if encoded_1.token_offsets != encoded_2.token_offsets {
+            report.num_offset_mismatches += 1;
+        } else {
+            continue;
+        }
+        if report.mismatches.len() < max_mismatches {
+            let first_divergence = encoded_1
+                .token_ids
+                .iter()
+                .zip(encoded_1.token_offsets.iter())
+                .zip(
+                    encoded_2
+                        .token_ids
+                        .iter()
+                        .zip(encoded_2.token_offsets.iter()),
+                )
+                .position(|(token_1, token_2)| token_1 != token_2)
+                .unwrap_or_else(|| encoded_1.token_ids.len().min(encoded_2.token_ids.len()));
+            report.mismatches.push(TokenizerMismatch {
+                sample_index,
+                text: sample.to_owned(),
+                first_divergence,
+                token_ids_1: encoded_1.token_ids,
+                token_ids_2: encoded_2.token_ids,
+                offsets_1: encoded_1.token_offsets,
+                offsets_2: encoded_2.token_offsets,
+            });
+        }
+    }
+    Ok(report)
+}
+
+/// # Token alignment between two tokenizations
+/// Many-to-many mapping between the tokens of two tokenizations of the same text, obtained from
+/// the overlap of their offsets. Tokens without offsets (e.g. special tokens) are not aligned.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TokenAlignment {
+    /// For each token of the first tokenization, indices of the overlapping tokens of the second one
+    pub tokens_1_to_2: Vec<Vec<usize>>,
+
+    /// For each token of the second tokenization, indices of the overlapping tokens of the first one
+    pub tokens_2_to_1: Vec<Vec<usize>>,
+}
+
+impl TokenAlignment {
+    /// Aligns two tokenizations of the same text given their token offsets. Two tokens are aligned
+    /// if their character spans overlap; tokens with an empty span or without offsets are left
+    /// unaligned.
+    ///
+    ///  # Arguments
+    /// - offsets_1: token offsets of the first tokenization
+    /// - offsets_2: token offsets of the second tokenization
+    ///
+    ///  # Returns
+    /// - `TokenAlignment` mapping the token indices of each tokenization to the other one
+    ///
+    ///  # Example
+    ///
+    /// ```
+    /// use rust_tokenizers::adapters::TokenAlignment;
+    /// use rust_tokenizers::Offset;
+    /// let offsets_1 = [Some(Offset::new(0, 9))];
+    /// let offsets_2 = [Some(Offset::new(0, 3)), Some(Offset::new(3, 9)), None];
+    /// let alignment = TokenAlignment::from_offsets(&offsets_1, &offsets_2);
+    /// assert_eq!(alignment.tokens_1_to_2, vec![vec![0, 1]]);
+    /// assert_eq!(alignment.tokens_2_to_1, vec![vec![0], vec![0], vec![]]);
+    /// ```
+    pub fn from_offsets(offsets_1: &[Option<Offset>], offsets_2: &[Option<Offset>]) -> Self {
+        let mut tokens_1_to_2 = vec![Vec::new(); offsets_1.len()];
+        let mut tokens_2_to_1 = vec![Vec::new(); offsets_2.len()];
+        for (index_1, offset_1) in offsets_1.iter().enumerate() {
+            let offset_1 = match offset_1 {
+                Some(offset) if offset.begin < offset.end => offset,
+                _ => continue,
+            };
+            for (index_2, offset_2) in offsets_2.iter().enumerate() {
+                if let Some(offset_2) = offset_2 {
+                    if offset_1.begin < offset_2.end && offset_2.begin < offset_1.end {
+                        tokens_1_to_2[index_1].push(index_2);
+                        tokens_2_to_1[index_2].push(index_1);
+                    }
+                }
+            }
+        }
+        TokenAlignment {
+            tokens_1_to_2,
+            tokens_2_to_1,
+        }
+    }
+}
+
+/// Encodes a text with two tokenizers (e.g. a teacher and a student model with different
+/// vocabularies) and aligns the resulting tokens via their offsets. The alignment indices refer to
+/// the positions in the encodings, including special tokens, so that they can directly be used to
+/// map the model outputs of one tokenizer to the other for distillation.
+///
+///  # Arguments
+/// - tokenizer_1: first tokenizer
+/// - tokenizer_2: second tokenizer
+/// - text: text to encode
+/// - max_len (`usize`): maximum length of the encodings (longer texts are truncated)
+///
+///  # Returns
+/// - Encodings of the text by the first and second tokenizer and the `TokenAlignment` between
+///   them, or an error if the text can not be encoded by one of the tokenizers
+///
+///  # Example
+///
+/// ```no_run
+/// # fn main() -> anyhow::Result<()> {
+/// use rust_tokenizers::adapters::align_tokenizers;
+/// use rust_tokenizers::tokenizer::{BertTokenizer, RobertaTokenizer};
+/// let teacher_tokenizer = RobertaTokenizer::from_file("path/to/vocab", "path/to/merges", false, true)?;
+/// let student_tokenizer = BertTokenizer::from_file("path/to/vocab", true, true)?;
+/// let (teacher_encoding, student_encoding, alignment) = align_tokenizers(
+///     &teacher_tokenizer,
+///     &student_tokenizer,
+///     "The patient was given acetaminophen.",
+///     512,
+/// )?;
+/// for (teacher_position, student_positions) in alignment.tokens_1_to_2.iter().enumerate() {
+///     println!(
+///         "{} -> {:?}",
+///         teacher_encoding.token_ids[teacher_position], student_positions
+///     );
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn align_tokenizers<T1, V1, T2, V2>(
+    tokenizer_1: &T1,
+    tokenizer_2: &T2,
+    text: &str,
+    max_len: usize,
+) -> Result<(TokenizedInput, TokenizedInput, TokenAlignment), TokenizerError>
+where
+    T1: Tokenizer<V1>,
+    V1: Vocab,
+    T2: Tokenizer<V2>,
+    V2: Vocab,
+{
+    let encoded_1 =
+        tokenizer_1.try_encode(text, None, max_len, &TruncationStrategy::LongestFirst, 0)?;
+    let encoded_2 =
+        tokenizer_2.try_encode(text, None, max_len, &TruncationStrategy::LongestFirst, 0)?;
+    let alignment =
+        TokenAlignment::from_offsets(&encoded_1.token_offsets, &encoded_2.token_offsets);
+    Ok((encoded_1, encoded_2, alignment))
+}
+
+//==============================
+// Unit tests
+//==============================
+#[cfg(test)]
+mod tests {
+    extern crate anyhow;
+
+    use super::*;
+    use crate::adapters::tests::generate_test_vocab;
+    use crate::tokenizer::{BaseTokenizer, BertTokenizer};
+    use crate::vocab::BertVocab;
+
+    #[test]
+    fn test_analyze_coverage() {
+        //        Given
+        let tokenizer = BertTokenizer::from_existing_vocab(generate_test_vocab(), true, true);
+        let corpus = [
+            "Hello unaffable world!",
+            "Foo hello [CLS] foo foo",
+            "unaffablex",
+            "",
+        ];
+
+        //        When
+        let report = analyze_coverage(&tokenizer, corpus.iter(), 5);
+        let truncated_report = analyze_coverage(&tokenizer, corpus.iter(), 1);
+
+        //        Then
+        assert_eq!(report.num_samples, 4);
+        assert_eq!(report.num_words, 9);
+        assert_eq!(report.num_tokens, 11);
+        assert_eq!(report.num_unknown_words, 4);
+        assert_eq!(report.num_unknown_tokens, 4);
+        assert_eq!(
+            report.unknown_strings,
+            vec![
+                ("foo".to_owned(), 2),
+                ("Foo".to_owned(), 1),
+                ("unaffablex".to_owned(), 1)
+            ]
+        );
+        assert_eq!(report.oov_rate(), 4.0 / 9.0);
+        assert_eq!(report.unknown_token_rate(), 4.0 / 11.0);
+        assert_eq!(report.fertility(), 11.0 / 9.0);
+        assert_eq!(
+            truncated_report.unknown_strings,
+            vec![("foo".to_owned(), 2)]
+        );
+    }
+
+    #[test]
+    fn test_compare_tokenizers() -> anyhow::Result<()> {
+        //        Given
+        let uncased_tokenizer =
+            BertTokenizer::from_existing_vocab(generate_test_vocab(), true, true);
+        let cased_tokenizer =
+            BertTokenizer::from_existing_vocab(generate_test_vocab(), false, false);
+        let corpus = ["hello world!", "Hello world!", "unaffable", "World hello"];
+
+        //        When
+        let report =
+            compare_tokenizers(&uncased_tokenizer, &cased_tokenizer, corpus.iter(), 128, 1)?;
+        let identical_report = compare_tokenizers(
+            &uncased_tokenizer,
+            &uncased_tokenizer,
+            corpus.iter(),
+            128,
+            1,
+        )?;
+
+        //        Then
+        assert_eq!(report.num_samples, 4);
+        assert_eq!(report.num_id_mismatches, 2);
+        assert_eq!(report.num_offset_mismatches, 0);
+        assert_eq!(report.num_tokens_1, 19);
+        assert_eq!(report.num_tokens_2, 19);
+        assert_eq!(report.id_mismatch_rate(), 0.5);
+        assert!(!report.is_identical());
+        assert_eq!(
+            report.mismatches,
+            vec![TokenizerMismatch {
+                sample_index: 1,
+                text: "Hello world!".to_owned(),
+                first_divergence: 1,
+                token_ids_1: vec![4, 0, 1, 3, 5],
+                token_ids_2: vec![4, 2, 1, 3, 5],
+                offsets_1: vec![
+                    None,
+                    Some(Offset::new(0, 5)),
+                    Some(Offset::new(6, 11)),
+                    Some(Offset::new(11, 12)),
+                    None
+                ],
+                offsets_2: vec![
+                    None,
+                    Some(Offset::new(0, 5)),
+                    Some(Offset::new(6, 11)),
+                    Some(Offset::new(11, 12)),
+                    None
+                ],
+            }]
+        );
+        assert!(identical_report.is_identical());
+        assert!(identical_report.mismatches.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_align_tokenizers() -> anyhow::Result<()> {
+        //        Given
+        let bert_tokenizer: BertTokenizer =
+            BertTokenizer::from_existing_vocab(generate_test_vocab(), true, true);
+        let base_tokenizer: BaseTokenizer<BertVocab> =
+            BaseTokenizer::from_existing_vocab(generate_test_vocab(), true, true);
+
+        //        When
+        let (bert_encoding, base_encoding, alignment) = align_tokenizers(
+            &bert_tokenizer,
+            &base_tokenizer,
+            "hello unaffable world!",
+            128,
+        )?;
+
+        //        Then
+        assert_eq!(bert_encoding.token_ids, vec![4, 0, 8, 9, 10, 1, 3, 5]);
+        assert_eq!(base_encoding.token_ids, vec![0, 2, 1, 3]);
+        assert_eq!(
+            alignment.tokens_1_to_2,
+            vec![
+                vec![],
+                vec![0],
+                vec![1],
+                vec![1],
+                vec![1],
+                vec![2],
+                vec![3],
+                vec![]
+            ]
+        );
+        assert_eq!(
+            alignment.tokens_2_to_1,
+            vec![vec![1], vec![2, 3, 4], vec![5], vec![6]]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_token_alignment_from_offsets() {
+        //        Given
+        let offsets_1 = vec![
+            Some(Offset::new(0, 4)),
+            Some(Offset::new(4, 4)),
+            Some(Offset::new(4, 8)),
+        ];
+        let offsets_2 = vec![None, Some(Offset::new(0, 2)), Some(Offset::new(2, 6)), None];
+
+        //        When
+        let alignment = TokenAlignment::from_offsets(&offsets_1, &offsets_2);
+
+        //        Then
+        assert_eq!(alignment.tokens_1_to_2, vec![vec![1, 2], vec![], vec![2]]);
+        assert_eq!(
+            alignment.tokens_2_to_1,
+            vec![vec![], vec![0], vec![0, 2], vec![]]
+        );
+    }
+}
@@ -0,0 +1,1033 @@
+// Copyright 2019 Guillaume Becquin
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//     http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::adapters::{encode_long_document, DocumentWindow};
+use crate::error::TokenizerError;
+use crate::error::TokenizerError::ValueError;
+use crate::tokenizer::{MultiThreadedTokenizer, TruncationStrategy};
+use crate::vocab::Vocab;
+use crate::TokenizedInput;
+use rayon::prelude::*;
+use serde_json::Value;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Lines};
+use std::path::{Path, PathBuf};
+
+/// # Sentiment analysis label
+/// Enum to represent a binary sentiment (positive or negative). An additional variant is available for
+/// enums which have not yet been assigned.
+#[derive(Debug)]
+pub enum Label {
+    Positive,
+    Negative,
+    Unassigned,
+}
+
+/// # SST2 sample
+/// Contains a placeholder for up to 2 sentences (the SST2 dataset only contains one per example) and
+/// a label
+#[derive(Debug)]
+pub struct Example {
+    pub sentence_1: String,
+    pub sentence_2: String,
+    pub label: Label,
+}
+
+impl Example {
+    fn new(sentence_1: &str, sentence_2: &str, label: &str) -> Result<Self, TokenizerError> {
+        Ok(Example {
+            sentence_1: String::from(sentence_1),
+            sentence_2: String::from(sentence_2),
+            label: match label {
+                "0" => Ok(Label::Negative),
+                "1" => Ok(Label::Positive),
+                _ => Err(ValueError("invalid label class (must be 0 or 1)".into())),
+            }?,
+        })
+    }
+
+    /// Creates a new `Example` from an unlabbeled string.
+    ///
+    ///  # Arguments
+    /// - sentence (`&str`): sentence string
+    ///
+    ///  # Returns
+    /// - `Example` containing the example with an unassigned label
+    pub fn new_from_string(sentence: &str) -> Self {
+        Example {
+            sentence_1: String::from(sentence),
+            sentence_2: String::from(""),
+            label: Label::Unassigned,
+        }
+    }
+
+    /// Creates a new `Example` from a sentence pair and a label `&str`.
+    ///
+    ///  # Arguments
+    /// - sentence_1 (`&str`): first sentence string
+    /// - sentence_2 (`&str`): second sentence string
+    ///
+    ///  # Returns
+    /// - `Example` containing the example with two sentences and an unassigned label
+    pub fn new_from_strings(sentence_1: &str, sentence_2: &str) -> Self {
+        Example {
+            sentence_1: String::from(sentence_1),
+            sentence_2: String::from(sentence_2),
+            label: Label::Unassigned,
+        }
+    }
+}
+
+/// Reads a SST2 dataset file and returns a vector of SST2 examples
+///
+///  # Arguments
+/// - path (`&str`): path to the SST2 file
+/// - sep (`u8`): separator for CSV parsing (default is a `\t` for SST2 dataset files)
+///
+///  # Returns
+/// - `Result<Vec<Example>, TokenizerError>` containing the examples with their corresponding label
+pub fn read_sst2(path: &str, sep: u8) -> Result<Vec<Example>, TokenizerError> {
+    let mut examples: Vec<Example> = Vec::new();
+    let f = File::open(path).map_err(|e| TokenizerError::file_not_found(path, e))?;
+
+    let mut rdr = csv::ReaderBuilder::new()
+        .has_headers(true)
+        .delimiter(sep)
+        .flexible(false)
+        .from_reader(f);
+
+    for result in rdr.records() {
+        let record = result.map_err(|e| TokenizerError::io_error(path, e))?;
+        let example = Example::new(&record[0], "", &record[1])?;
+        examples.push(example);
+    }
+    Ok(examples)
+}
+
+/// # Delimited file configuration
+/// Layout of a delimited (CSV, TSV...) classification dataset file. The default configuration
+/// matches the SST2 layout (tab-separated, with headers, text in the first column and label in the
+/// second column).
+#[derive(Debug, Clone)]
+pub struct DelimitedFileConfig {
+    /// Field delimiter (e.g. `b','` for CSV or `b'\t'` for TSV files)
+    pub delimiter: u8,
+    /// Flag indicating if the first line of the file is a header and should be skipped
+    pub has_headers: bool,
+    /// Index of the column containing the text
+    pub text_column: usize,
+    /// Index of the column containing the optional second text
+    pub text_pair_column: Option<usize>,
+    /// Index of the column containing the optional label
+    pub label_column: Option<usize>,
+}
+
+impl Default for DelimitedFileConfig {
+    fn default() -> Self {
+        DelimitedFileConfig {
+            delimiter: b'\t',
+            has_headers: true,
+            text_column: 0,
+            text_pair_column: None,
+            label_column: Some(1),
+        }
+    }
+}
+
+/// Reads a delimited classification dataset file (CSV, TSV...) and returns a vector of records.
+/// Labels are read as strings (or `None` if no label column is configured).
+///
+///  # Arguments
+/// - path: path to the dataset file
+/// - config (`&DelimitedFileConfig`): layout of the dataset file
+///
+///  # Returns
+/// - `Result<Vec<TextRecord>, TokenizerError>` containing the records with their corresponding label
+///
+///  # Example
+///
+/// ```no_run
+/// use rust_tokenizers::adapters::{read_delimited_file, DelimitedFileConfig};
+/// let config = DelimitedFileConfig {
+///     delimiter: b',',
+///     has_headers: true,
+///     text_column: 1,
+///     text_pair_column: Some(2),
+///     label_column: Some(0),
+/// };
+/// let records = read_delimited_file("path/to/dataset.csv", &config);
+/// ```
+pub fn read_delimited_file<P: AsRef<Path>>(
+    path: P,
+    config: &DelimitedFileConfig,
+) -> Result<Vec<TextRecord>, TokenizerError> {
+    let f = File::open(&path).map_err(|e| TokenizerError::file_not_found(&path, e))?;
+
+    let mut rdr = csv::ReaderBuilder::new()
+        .has_headers(config.has_headers)
+        .delimiter(config.delimiter)
+        .flexible(false)
+        .from_reader(f);
+
+    let mut records: Vec<TextRecord> = Vec::new();
+    for result in rdr.records() {
+        let record = result.map_err(|e| TokenizerError::io_error(&path, e))?;
+        let get_column = |column: usize| {
+            record.get(column).ok_or_else(|| {
+                ValueError(format!(
+                    "column {} not found in record at line {}",
+                    column,
+                    record.position().map_or(0, |position| position.line())
+                ))
+            })
+        };
+        records.push(TextRecord {
+            text: get_column(config.text_column)?.to_string(),
+            text_pair: config
+                .text_pair_column
+                .map(get_column)
+                .transpose()?
+                .map(str::to_string),
+            label: config
+                .label_column
+                .map(get_column)
+                .transpose()?
+                .map(str::to_string),
+        });
+    }
+    Ok(records)
+}
+
+/// # Text record
+/// Generic dataset record containing a text, an optional second text (e.g. for sentence pair
+/// classification) and an optional label.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextRecord {
+    pub text: String,
+    pub text_pair: Option<String>,
+    pub label: Option<String>,
+}
+
+/// # JSON Lines records iterator
+/// Lazily reads a JSON Lines file (one JSON object per line) and extracts a `TextRecord` from
+/// each line. Empty lines are skipped.
+pub struct JsonlRecords {
+    path: PathBuf,
+    lines: Lines<BufReader<File>>,
+    line_number: usize,
+    text_field: String,
+    text_pair_field: Option<String>,
+    label_field: Option<String>,
+}
+
+impl JsonlRecords {
+    /// Creates a new `JsonlRecords` iterator over a JSON Lines file.
+    ///
+    ///  # Arguments
+    /// - path: path to the JSON Lines file
+    /// - text_field (`&str`): name of the field containing the text
+    /// - text_pair_field (`Option<&str>`): name of the field containing the optional second text
+    /// - label_field (`Option<&str>`): name of the field containing the optional label
+    ///
+    ///  # Returns
+    /// - `Result<JsonlRecords, TokenizerError>` iterator over the records of the file
+    pub fn new<P: AsRef<Path>>(
+        path: P,
+        text_field: &str,
+        text_pair_field: Option<&str>,
+        label_field: Option<&str>,
+    ) -> Result<Self, TokenizerError> {
+        let f = File::open(&path).map_err(|e| TokenizerError::file_not_found(&path, e))?;
+        Ok(JsonlRecords {
+            path: path.as_ref().to_path_buf(),
+            lines: BufReader::new(f).lines(),
+            line_number: 0,
+            text_field: text_field.to_string(),
+            text_pair_field: text_pair_field.map(str::to_string),
+            label_field: label_field.map(str::to_string),
+        })
+    }
+
+    fn parse_record(&self, line: &str) -> Result<TextRecord, TokenizerError> {
+        let value: Value = serde_json::from_str(line).map_err(|e| {
+            ValueError(format!(
+                "invalid JSON record at line {}: {}",
+                self.line_number, e
+            ))
+        })?;
+        let get_field = |field: &str| -> Result<String, TokenizerError> {
+            match value.get(field) {
+                Some(Value::String(text)) => Ok(text.clone()),
+                Some(Value::Null) | None => Err(ValueError(format!(
+                    "missing field {} at line {}",
+                    field, self.line_number
+                ))),
+                Some(other) => Ok(other.to_string()),
+            }
+        };
+        Ok(TextRecord {
+            text: get_field(&self.text_field)?,
+            text_pair: self.text_pair_field.as_deref().map(get_field).transpose()?,
+            label: self.label_field.as_deref().map(get_field).transpose()?,
+        })
+    }
+}
+
+impl Iterator for JsonlRecords {
+    type Item = Result<TextRecord, TokenizerError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let line = match self.lines.next()? {
+                Ok(line) => line,
+                Err(e) => return Some(Err(TokenizerError::io_error(&self.path, e))),
+            };
+            self.line_number += 1;
+            if !line.trim().is_empty() {
+                return Some(self.parse_record(&line));
+            }
+        }
+    }
+}
+
+/// Reads a JSON Lines dataset file and returns a vector of text records. Non-string field values
+/// (e.g. integer labels) are converted to their JSON string representation.
+///
+///  # Arguments
+/// - path: path to the JSON Lines file
+/// - text_field (`&str`): name of the field containing the text
+/// - text_pair_field (`Option<&str>`): name of the field containing the optional second text
+/// - label_field (`Option<&str>`): name of the field containing the optional label
+///
+///  # Returns
+/// - `Result<Vec<TextRecord>, TokenizerError>` containing the records read from the file
+///
+///  # Example
+///
+/// ```no_run
+/// use rust_tokenizers::adapters::read_jsonl;
+/// let records = read_jsonl("path/to/dataset.jsonl", "sentence", None, Some("label"));
+/// ```
+pub fn read_jsonl<P: AsRef<Path>>(
+    path: P,
+    text_field: &str,
+    text_pair_field: Option<&str>,
+    label_field: Option<&str>,
+) -> Result<Vec<TextRecord>, TokenizerError> {
+    JsonlRecords::new(path, text_field, text_pair_field, label_field)?.collect()
+}
+
+/// # Parquet records iterator
+/// Streams `TextRecord`s out of a Parquet file, reading only the requested columns. Rows are
+/// decoded lazily one row group at a time, allowing large corpus shards to be fed to
+/// `encode_record_batches` without loading them in memory. Non-string values (e.g. integer labels)
+/// are converted to their string representation. Requires the `parquet` feature.
+#[cfg(feature = "parquet")]
+pub struct ParquetRecords {
+    path: PathBuf,
+    rows: parquet::record::reader::RowIter<'static>,
+    row_number: usize,
+    text_field: String,
+    text_pair_field: Option<String>,
+    label_field: Option<String>,
+}
+
+#[cfg(feature = "parquet")]
+impl ParquetRecords {
+    /// Creates a new `ParquetRecords` iterator over a Parquet file.
+    ///
+    ///  # Arguments
+    /// - path: path to the Parquet file
+    /// - text_field (`&str`): name of the column containing the text
+    /// - text_pair_field (`Option<&str>`): name of the column containing the optional second text
+    /// - label_field (`Option<&str>`): name of the column containing the optional label
+    ///
+    ///  # Returns
+    /// - `Result<ParquetRecords, TokenizerError>` iterator over the records of the file
+    pub fn new<P: AsRef<Path>>(
+        path: P,
+        text_field: &str,
+        text_pair_field: Option<&str>,
+        label_field: Option<&str>,
+    ) -> Result<Self, TokenizerError> {
+        use parquet::file::reader::{FileReader, SerializedFileReader};
+        use parquet::record::reader::RowIter;
+        use parquet::schema::types::Type;
+        use std::sync::Arc;
+
+        let f = File::open(&path).map_err(|e| TokenizerError::file_not_found(&path, e))?;
+        let reader =
+            SerializedFileReader::new(f).map_err(|e| TokenizerError::io_error(&path, e))?;
+
+        let columns: Vec<&str> = std::iter::once(text_field)
+            .chain(text_pair_field)
+            .chain(label_field)
+            .collect();
+        let schema = reader.metadata().file_metadata().schema();
+        for column in columns.iter() {
+            if !schema
+                .get_fields()
+                .iter()
+                .any(|field| field.name() == *column)
+            {
+                return Err(ValueError(format!(
+                    "column {} not found in {}",
+                    column,
+                    path.as_ref().display()
+                )));
+            }
+        }
+        let projected_fields = schema
+            .get_fields()
+            .iter()
+            .filter(|field| columns.contains(&field.name()))
+            .map(Arc::clone)
+            .collect();
+        let projection = Type::group_type_builder(schema.name())
+            .with_fields(projected_fields)
+            .build()
+            .map_err(|e| TokenizerError::io_error(&path, e))?;
+
+        let reader: Box<dyn FileReader> = Box::new(reader);
+        Ok(ParquetRecords {
+            path: path.as_ref().to_path_buf(),
+            rows: RowIter::from_file_into(reader)
+                .project(Some(projection))
+                .map_err(|e| TokenizerError::io_error(&path, e))?,
+            row_number: 0,
+            text_field: text_field.to_string(),
+            text_pair_field: text_pair_field.map(str::to_string),
+            label_field: label_field.map(str::to_string),
+        })
+    }
+
+    fn parse_record(&self, row: &parquet::record::Row) -> Result<TextRecord, TokenizerError> {
+        use parquet::record::Field;
+
+        let get_field = |column: &str| -> Result<String, TokenizerError> {
+            match row.get_column_iter().find(|(name, _)| *name == column) {
+                Some((_, Field::Str(text))) => Ok(text.clone()),
+                Some((_, Field::Null)) | None => Err(ValueError(format!(
+                    "missing value for column {} at row {}",
+                    column, self.row_number
+                ))),
+                Some((_, other)) => Ok(other.to_string()),
+            }
+        };
+        Ok(TextRecord {
+            text: get_field(&self.text_field)?,
+            text_pair: self.text_pair_field.as_deref().map(get_field).transpose()?,
+            label: self.label_field.as_deref().map(get_field).transpose()?,
+        })
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl Iterator for ParquetRecords {
+    type Item = Result<TextRecord, TokenizerError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let row = match self.rows.next()? {
+            Ok(row) => row,
+            Err(e) => return Some(Err(TokenizerError::io_error(&self.path, e))),
+        };
+        self.row_number += 1;
+        Some(self.parse_record(&row))
+    }
+}
+
+/// Reads a Parquet dataset file and returns a vector of text records. For large files, prefer
+/// streaming the records with `ParquetRecords`. Requires the `parquet` feature.
+///
+///  # Arguments
+/// - path: path to the Parquet file
+/// - text_field (`&str`): name of the column containing the text
+/// - text_pair_field (`Option<&str>`): name of the column containing the optional second text
+/// - label_field (`Option<&str>`): name of the column containing the optional label
+///
+///  # Returns
+/// - `Result<Vec<TextRecord>, TokenizerError>` containing the records read from the file
+///
+///  # Example
+///
+/// ```no_run
+/// use rust_tokenizers::adapters::read_parquet;
+/// let records = read_parquet("path/to/shard.parquet", "text", None, None);
+/// ```
+#[cfg(feature = "parquet")]
+pub fn read_parquet<P: AsRef<Path>>(
+    path: P,
+    text_field: &str,
+    text_pair_field: Option<&str>,
+    label_field: Option<&str>,
+) -> Result<Vec<TextRecord>, TokenizerError> {
+    ParquetRecords::new(path, text_field, text_pair_field, label_field)?.collect()
+}
+
+/// Encodes a slice of text records with a multi-threaded tokenizer. Records with a second text are
+/// encoded as sentence pairs.
+///
+///  # Arguments
+/// - tokenizer: tokenizer used for the encoding
+/// - records (`&[TextRecord]`): records to encode
+/// - max_len (`usize`): maximum combined sequence length
+/// - truncation_strategy (`&TruncationStrategy`): strategy to follow for the truncation, if required
+/// - stride (`usize`): amount of tokens to shift the input by if truncation is required
+///
+///  # Returns
+/// - `Vec<TokenizedInput>` containing the encoding output for each record
+pub fn encode_records<T, V>(
+    tokenizer: &T,
+    records: &[TextRecord],
+    max_len: usize,
+    truncation_strategy: &TruncationStrategy,
+    stride: usize,
+) -> Vec<TokenizedInput>
+where
+    T: MultiThreadedTokenizer<V>,
+    V: Vocab,
+{
+    records
+        .par_iter()
+        .map(|record| {
+            tokenizer.encode(
+                &record.text,
+                record.text_pair.as_deref(),
+                max_len,
+                truncation_strategy,
+                stride,
+            )
+        })
+        .collect()
+}
+
+/// Encodes a slice of texts with a multi-threaded tokenizer, carrying the metadata (e.g. a document
+/// id) attached to each text through to its encoding output.
+///
+///  # Arguments
+/// - tokenizer: tokenizer used for the encoding
+/// - inputs (`&[(S, M)]`): texts to encode with their metadata
+/// - max_len (`usize`): maximum sequence length
+/// - truncation_strategy (`&TruncationStrategy`): strategy to follow for the truncation, if required
+/// - stride (`usize`): amount of tokens to shift the input by if truncation is required
+///
+///  # Returns
+/// - `Vec<(M, TokenizedInput)>` containing the metadata and encoding output for each text
+///
+///  # Example
+///
+/// ```no_run
+/// # fn main() -> anyhow::Result<()> {
+/// use rust_tokenizers::adapters::encode_list_with_metadata;
+/// use rust_tokenizers::tokenizer::{BertTokenizer, TruncationStrategy};
+/// let tokenizer = BertTokenizer::from_file("path/to/vocab", true, true)?;
+/// let inputs = [("First document", "doc-1"), ("Second document", "doc-2")];
+/// let encodings =
+///     encode_list_with_metadata(&tokenizer, &inputs, 128, &TruncationStrategy::LongestFirst, 0);
+/// for (document_id, encoding) in encodings {
+///     println!("{document_id}: {:?}", encoding.token_ids);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn encode_list_with_metadata<T, V, S, M>(
+    tokenizer: &T,
+    inputs: &[(S, M)],
+    max_len: usize,
+    truncation_strategy: &TruncationStrategy,
+    stride: usize,
+) -> Vec<(M, TokenizedInput)>
+where
+    T: MultiThreadedTokenizer<V>,
+    V: Vocab,
+    S: AsRef<str> + Sync,
+    M: Clone + Send + Sync,
+{
+    inputs
+        .par_iter()
+        .map(|(text, metadata)| {
+            (
+                metadata.clone(),
+                tokenizer.encode(text.as_ref(), None, max_len, truncation_strategy, stride),
+            )
+        })
+        .collect()
+}
+
+/// Encodes a slice of texts with a multi-threaded tokenizer, carrying the metadata attached to each
+/// text through to its encoding output. In contrast with `encode_list_with_metadata`, this returns an
+/// error instead of panicking if any of the texts can not be encoded (for example an input exceeding
+/// `max_len` with `TruncationStrategy::DoNotTruncate`).
+///
+///  # Arguments
+/// - tokenizer: tokenizer used for the encoding
+/// - inputs (`&[(S, M)]`): texts to encode with their metadata
+/// - max_len (`usize`): maximum sequence length
+/// - truncation_strategy (`&TruncationStrategy`): strategy to follow for the truncation, if required
+/// - stride (`usize`): amount of tokens to shift the input by if truncation is required
+///
+///  # Returns
+/// - `Result<Vec<(M, TokenizedInput)>, TokenizerError>` containing the metadata and encoding output
+///   for each text
+///
+///  # Example
+///
+/// ```no_run
+/// # fn main() -> anyhow::Result<()> {
+/// use rust_tokenizers::adapters::try_encode_list_with_metadata;
+/// use rust_tokenizers::tokenizer::{BertTokenizer, TruncationStrategy};
+/// let tokenizer = BertTokenizer::from_file("path/to/vocab", true, true)?;
+/// let inputs = [("First document", "doc-1"), ("Second document", "doc-2")];
+/// let encodings = try_encode_list_with_metadata(
+///     &tokenizer,
+///     &inputs,
+///     128,
+///     &TruncationStrategy::DoNotTruncate,
+///     0,
+/// )?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn try_encode_list_with_metadata<T, V, S, M>(
+    tokenizer: &T,
+    inputs: &[(S, M)],
+    max_len: usize,
+    truncation_strategy: &TruncationStrategy,
+    stride: usize,
+) -> Result<Vec<(M, TokenizedInput)>, TokenizerError>
+where
+    T: MultiThreadedTokenizer<V>,
+    V: Vocab,
+    S: AsRef<str> + Sync,
+    M: Clone + Send + Sync,
+{
+    inputs
+        .par_iter()
+        .map(|(text, metadata)| {
+            Ok((
+                metadata.clone(),
+                tokenizer.try_encode(text.as_ref(), None, max_len, truncation_strategy, stride)?,
+            ))
+        })
+        .collect()
+}
+
+/// Encodes a slice of documents of arbitrary length with a multi-threaded tokenizer, splitting each
+/// document into windows (see `encode_long_document`). The metadata attached to each document (e.g.
+/// a document id) is carried through to all of its windows.
+///
+///  # Arguments
+/// - tokenizer: tokenizer used for the encoding
+/// - inputs (`&[(S, M)]`): documents to encode with their metadata
+/// - max_len (`usize`): maximum length of each window, including the special tokens
+/// - overlap (`usize`): maximum number of tokens shared by consecutive windows
+///
+///  # Returns
+/// - `Result<Vec<(M, DocumentWindow)>, TokenizerError>` containing the metadata and windows of all
+///   documents, in the order of the documents
+///
+///  # Example
+///
+/// ```no_run
+/// # fn main() -> anyhow::Result<()> {
+/// use rust_tokenizers::adapters::encode_documents_with_metadata;
+/// use rust_tokenizers::tokenizer::BertTokenizer;
+/// let tokenizer = BertTokenizer::from_file("path/to/vocab", true, true)?;
+/// let inputs = [("A long first document...", 1), ("A long second document...", 2)];
+/// let windows = encode_documents_with_metadata(&tokenizer, &inputs, 512, 128)?;
+/// for (document_id, window) in windows {
+///     println!("{document_id}: {:?}", window.offset);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn encode_documents_with_metadata<T, V, S, M>(
+    tokenizer: &T,
+    inputs: &[(S, M)],
+    max_len: usize,
+    overlap: usize,
+) -> Result<Vec<(M, DocumentWindow)>, TokenizerError>
+where
+    T: MultiThreadedTokenizer<V>,
+    V: Vocab,
+    S: AsRef<str> + Sync,
+    M: Clone + Send + Sync,
+{
+    let windows = inputs
+        .par_iter()
+        .map(|(text, metadata)| {
+            Ok(
+                encode_long_document(tokenizer, text.as_ref(), max_len, overlap)?
+                    .into_iter()
+                    .map(|window| (metadata.clone(), window))
+                    .collect::<Vec<(M, DocumentWindow)>>(),
+            )
+        })
+        .collect::<Result<Vec<Vec<(M, DocumentWindow)>>, TokenizerError>>()?;
+    Ok(windows.into_iter().flatten().collect())
+}
+
+/// Streams text records through a multi-threaded tokenizer by batches of `batch_size` records.
+/// Each batch is read from the record iterator (e.g. a `JsonlRecords` iterator) and encoded in
+/// parallel with `Tokenizer::try_encode`, avoiding loading the entire dataset in memory. Errors are
+/// reported per record: a record that can not be read or encoded does not affect the other records
+/// of its batch, and the iteration can continue after an error.
+///
+///  # Arguments
+/// - tokenizer: tokenizer used for the encoding
+/// - records: iterator over the records to encode
+/// - batch_size (`usize`): number of records per batch
+/// - max_len (`usize`): maximum combined sequence length
+/// - truncation_strategy (`&TruncationStrategy`): strategy to follow for the truncation, if required
+/// - stride (`usize`): amount of tokens to shift the input by if truncation is required
+///
+///  # Returns
+/// - Iterator over the batches, containing for each record either the record and its encoding
+///   output, or the error raised while reading or encoding it
+///
+///  # Example
+///
+/// ```no_run
+/// # fn main() -> anyhow::Result<()> {
+/// use rust_tokenizers::adapters::{encode_record_batches, JsonlRecords};
+/// use rust_tokenizers::tokenizer::{BertTokenizer, TruncationStrategy};
+/// let tokenizer = BertTokenizer::from_file("path/to/vocab", true, true)?;
+/// let records = JsonlRecords::new("path/to/dataset.jsonl", "sentence", None, Some("label"))?;
+/// for batch in encode_record_batches(
+///     &tokenizer,
+///     records,
+///     256,
+///     128,
+///     &TruncationStrategy::LongestFirst,
+///     0,
+/// ) {
+///     for result in batch {
+///         let (record, encoding) = result?;
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn encode_record_batches<'a, T, V, I>(
+    tokenizer: &'a T,
+    records: I,
+    batch_size: usize,
+    max_len: usize,
+    truncation_strategy: &'a TruncationStrategy,
+    stride: usize,
+) -> impl Iterator<Item = Vec<Result<(TextRecord, TokenizedInput), TokenizerError>>> + 'a
+where
+    T: MultiThreadedTokenizer<V>,
+    V: Vocab,
+    I: IntoIterator<Item = Result<TextRecord, TokenizerError>>,
+    I::IntoIter: 'a,
+{
+    let mut records = records.into_iter();
+    let batch_size = batch_size.max(1);
+    std::iter::from_fn(move || {
+        let batch = records
+            .by_ref()
+            .take(batch_size)
+            .collect::<Vec<Result<TextRecord, TokenizerError>>>();
+        if batch.is_empty() {
+            return None;
+        }
+        Some(
+            batch
+                .into_par_iter()
+                .map(|record| {
+                    let record = record?;
+                    let encoding = tokenizer.try_encode(
+                        &record.text,
+                        record.text_pair.as_deref(),
+                        max_len,
+                        truncation_strategy,
+                        stride,
+                    )?;
+                    Ok((record, encoding))
+                })
+                .collect(),
+        )
+    })
+}
+
+//==============================
+// Unit tests
+//==============================
+#[cfg(test)]
+mod tests {
+    extern crate anyhow;
+
+    use super::*;
+    use crate::adapters::tests::generate_test_vocab;
+    use crate::tokenizer::BertTokenizer;
+    use std::io::Write;
+    use std::ops::Range;
+
+    #[test]
+    fn test_read_jsonl() -> anyhow::Result<()> {
+        //        Given
+        let mut dataset_file = tempfile::NamedTempFile::new()?;
+        write!(
+            dataset_file,
+            "{{\"sentence\": \"hello world\", \"label\": 1}}\n\n{{\"sentence\": \"bye\", \"label\": \"neg\"}}\n"
+        )?;
+        let path = dataset_file.into_temp_path();
+
+        //        When
+        let records = read_jsonl(&path, "sentence", None, Some("label"))?;
+
+        //        Then
+        assert_eq!(
+            records,
+            vec![
+                TextRecord {
+                    text: "hello world".to_string(),
+                    text_pair: None,
+                    label: Some("1".to_string()),
+                },
+                TextRecord {
+                    text: "bye".to_string(),
+                    text_pair: None,
+                    label: Some("neg".to_string()),
+                },
+            ]
+        );
+        assert!(read_jsonl(&path, "text", None, None).is_err());
+        drop(path);
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode_record_batches() {
+        //        Given
+        let tokenizer: BertTokenizer =
+            BertTokenizer::from_existing_vocab(generate_test_vocab(), true, true);
+        let record = |text: &str| TextRecord {
+            text: text.to_string(),
+            text_pair: None,
+            label: None,
+        };
+        let records = vec![
+            Ok(record("hello world")),
+            Err(TokenizerError::ValueError("invalid record".to_string())),
+            Ok(record("hello hello hello world")),
+            Ok(record("world !")),
+        ];
+
+        //        When
+        let batches = encode_record_batches(
+            &tokenizer,
+            records,
+            2,
+            5,
+            &TruncationStrategy::DoNotTruncate,
+            0,
+        )
+        .collect::<Vec<_>>();
+
+        //        Then
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[0].len(), 2);
+        assert_eq!(batches[1].len(), 2);
+        let (first_record, first_encoding) = batches[0][0].as_ref().unwrap();
+        assert_eq!(first_record.text, "hello world");
+        assert_eq!(first_encoding.token_ids, vec![4, 0, 1, 5]);
+        assert!(matches!(batches[0][1], Err(TokenizerError::ValueError(_))));
+        assert!(matches!(batches[1][0], Err(TokenizerError::ValueError(_))));
+        let (last_record, last_encoding) = batches[1][1].as_ref().unwrap();
+        assert_eq!(last_record.text, "world !");
+        assert_eq!(last_encoding.token_ids, vec![4, 1, 3, 5]);
+    }
+
+    #[test]
+    fn test_encode_with_metadata() -> anyhow::Result<()> {
+        //        Given
+        let tokenizer = BertTokenizer::from_existing_vocab(generate_test_vocab(), true, true);
+        let inputs = [
+            ("hello world unaffable hello world !", "doc-1"),
+            ("hello !", "doc-2"),
+        ];
+
+        //        When
+        let encodings =
+            encode_list_with_metadata(&tokenizer, &inputs, 4, &TruncationStrategy::LongestFirst, 0);
+        let windows = encode_documents_with_metadata(&tokenizer, &inputs, 6, 1)?;
+
+        //        Then
+        assert_eq!(
+            encodings
+                .iter()
+                .map(|(metadata, encoding)| (*metadata, encoding.token_ids.clone()))
+                .collect::<Vec<(&str, Vec<i64>)>>(),
+            vec![("doc-1", vec![4, 0, 1, 5]), ("doc-2", vec![4, 0, 3, 5])]
+        );
+        assert_eq!(
+            windows
+                .iter()
+                .map(|(metadata, window)| (*metadata, window.token_range.clone()))
+                .collect::<Vec<(&str, Range<usize>)>>(),
+            vec![
+                ("doc-1", 0..2),
+                ("doc-1", 1..5),
+                ("doc-1", 5..8),
+                ("doc-2", 0..2)
+            ]
+        );
+        assert_eq!(
+            try_encode_list_with_metadata(
+                &tokenizer,
+                &inputs,
+                4,
+                &TruncationStrategy::LongestFirst,
+                0
+            )?,
+            encodings
+        );
+        assert!(try_encode_list_with_metadata(
+            &tokenizer,
+            &inputs,
+            4,
+            &TruncationStrategy::DoNotTruncate,
+            0
+        )
+        .is_err());
+        assert!(encode_documents_with_metadata(&tokenizer, &inputs, 3, 1).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_delimited_file() -> anyhow::Result<()> {
+        //        Given
+        let mut dataset_file = tempfile::NamedTempFile::new()?;
+        write!(
+            dataset_file,
+            "label,premise,hypothesis\nentailment,A man sleeps.,A person rests.\ncontradiction,\"Hi, there\",Bye\n"
+        )?;
+        let path = dataset_file.into_temp_path();
+        let config = DelimitedFileConfig {
+            delimiter: b',',
+            has_headers: true,
+            text_column: 1,
+            text_pair_column: Some(2),
+            label_column: Some(0),
+        };
+
+        //        When
+        let records = read_delimited_file(&path, &config)?;
+
+        //        Then
+        assert_eq!(
+            records,
+            vec![
+                TextRecord {
+                    text: "A man sleeps.".to_string(),
+                    text_pair: Some("A person rests.".to_string()),
+                    label: Some("entailment".to_string()),
+                },
+                TextRecord {
+                    text: "Hi, there".to_string(),
+                    text_pair: Some("Bye".to_string()),
+                    label: Some("contradiction".to_string()),
+                },
+            ]
+        );
+        let invalid_config = DelimitedFileConfig {
+            label_column: Some(3),
+            ..config
+        };
+        assert!(read_delimited_file(&path, &invalid_config).is_err());
+        drop(path);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_sst2_missing_file() {
+        //        Given
+        let path = "path/to/missing/sst2.tsv";
+
+        //        When
+        let result = read_sst2(path, b'\t');
+
+        //        Then
+        assert!(matches!(result, Err(TokenizerError::FileNotFound { .. })));
+    }
+
+    #[cfg(feature = "parquet")]
+    #[test]
+    fn test_read_parquet() -> anyhow::Result<()> {
+        use parquet::data_type::{ByteArray, ByteArrayType, Int64Type};
+        use parquet::file::writer::SerializedFileWriter;
+        use parquet::schema::parser::parse_message_type;
+        use std::sync::Arc;
+
+        //        Given
+        let dataset_file = tempfile::NamedTempFile::new()?;
+        let schema = Arc::new(parse_message_type(
+            "message schema {
+                REQUIRED BINARY id (UTF8);
+                REQUIRED BINARY text (UTF8);
+                REQUIRED INT64 label;
+            }",
+        )?);
+        let mut writer =
+            SerializedFileWriter::new(dataset_file.reopen()?, schema, Default::default())?;
+        let mut row_group = writer.next_row_group()?;
+        let mut column = row_group.next_column()?.unwrap();
+        column.typed::<ByteArrayType>().write_batch(
+            &[ByteArray::from("a"), ByteArray::from("b")],
+            None,
+            None,
+        )?;
+        column.close()?;
+        let mut column = row_group.next_column()?.unwrap();
+        column.typed::<ByteArrayType>().write_batch(
+            &[
+                ByteArray::from("hello world"),
+                ByteArray::from("unaffable !"),
+            ],
+            None,
+            None,
+        )?;
+        column.close()?;
+        let mut column = row_group.next_column()?.unwrap();
+        column
+            .typed::<Int64Type>()
+            .write_batch(&[1, 0], None, None)?;
+        column.close()?;
+        row_group.close()?;
+        writer.close()?;
+        let path = dataset_file.into_temp_path();
+
+        //        When
+        let records = read_parquet(&path, "text", None, Some("label"))?;
+
+        //        Then
+        assert_eq!(
+            records,
+            vec![
+                TextRecord {
+                    text: "hello world".to_string(),
+                    text_pair: None,
+                    label: Some("1".to_string()),
+                },
+                TextRecord {
+                    text: "unaffable !".to_string(),
+                    text_pair: None,
+                    label: Some("0".to_string()),
+                },
+            ]
+        );
+        assert!(read_parquet(&path, "sentence", None, None).is_err());
+        drop(path);
+        Ok(())
+    }
+}
@@ -0,0 +1,537 @@
+// Copyright 2019 Guillaume Becquin
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//     http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::error::TokenizerError;
+use crate::error::TokenizerError::ValueError;
+use crate::tokenizer::{MultiThreadedTokenizer, Tokenizer, TruncationStrategy};
+use crate::vocab::Vocab;
+use crate::{ConsolidatedTokenIterator, Mask, OffsetSize, TokenTrait, TokenizedInput};
+use rayon::prelude::*;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+/// # CoNLL sentence
+/// Sentence read from a CoNLL-style file, containing the words and their respective tags
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConllSentence {
+    pub words: Vec<String>,
+    pub labels: Vec<String>,
+}
+
+/// # Label alignment strategy
+/// Indicates how word-level labels are expanded to the sub-tokens of each word
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LabelAlignment {
+    /// Only the first sub-token of each word is labelled, the following sub-tokens are not
+    FirstSubToken,
+    /// All sub-tokens of a word are assigned the word label
+    AllSubTokens,
+}
+
+/// Label id of the tokens that do not carry a label (special tokens or sub-tokens ignored by the
+/// `LabelAlignment` strategy), ignored by the loss of most training frameworks
+pub const IGNORE_INDEX: i64 = -100;
+
+/// # Labelled tokenized input
+/// Encoding output with labels aligned to the tokens. Tokens that do not carry a label (special
+/// tokens or sub-tokens ignored by the `LabelAlignment` strategy) are registered as None.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LabeledTokenizedInput {
+    pub tokenized_input: TokenizedInput,
+    pub labels: Vec<Option<String>>,
+}
+
+/// Reads a CoNLL-style file (one word per line with its tag in the last whitespace-separated
+/// column, sentences separated by empty lines). `-DOCSTART-` lines are skipped.
+///
+///  # Arguments
+/// - path: path to the CoNLL file
+///
+///  # Returns
+/// - `Result<Vec<ConllSentence>, TokenizerError>` containing the sentences read from the file
+///
+///  # Example
+///
+/// ```no_run
+/// use rust_tokenizers::adapters::read_conll;
+/// let sentences = read_conll("path/to/train.conll");
+/// ```
+pub fn read_conll<P: AsRef<Path>>(path: P) -> Result<Vec<ConllSentence>, TokenizerError> {
+    let f = File::open(&path).map_err(|e| TokenizerError::file_not_found(&path, e))?;
+    let mut sentences = Vec::new();
+    let mut words = Vec::new();
+    let mut labels = Vec::new();
+    for (line_index, line) in BufReader::new(f).lines().enumerate() {
+        let line = line.map_err(|e| TokenizerError::io_error(&path, e))?;
+        let fields = line.split_whitespace().collect::<Vec<&str>>();
+        if fields.is_empty() {
+            if !words.is_empty() {
+                sentences.push(ConllSentence {
+                    words: std::mem::take(&mut words),
+                    labels: std::mem::take(&mut labels),
+                });
+            }
+            continue;
+        }
+        if fields[0] == "-DOCSTART-" {
+            continue;
+        }
+        if fields.len() < 2 {
+            return Err(ValueError(format!(
+                "missing tag at line {}: {}",
+                line_index + 1,
+                line
+            )));
+        }
+        words.push(fields[0].to_string());
+        labels.push(fields[fields.len() - 1].to_string());
+    }
+    if !words.is_empty() {
+        sentences.push(ConllSentence { words, labels });
+    }
+    Ok(sentences)
+}
+
+/// Encodes a sentence made of pre-split words and aligns the word-level labels with the resulting
+/// tokens. The words are joined with a single space before tokenization. The tokens are grouped
+/// with `TokenizedInput::word_spans`, and each group is assigned to the word containing the start
+/// of its offset.
+///
+///  # Arguments
+/// - tokenizer: tokenizer used for the encoding
+/// - words (`&[S]`): words of the sentence
+/// - labels (`&[L]`): labels for each word (must have the same length as `words`)
+/// - max_len (`usize`): maximum sequence length
+/// - truncation_strategy (`&TruncationStrategy`): strategy to follow for the truncation, if required
+/// - label_alignment (`LabelAlignment`): strategy to expand the word labels to their sub-tokens
+///
+///  # Returns
+/// - `Result<LabeledTokenizedInput, TokenizerError>` encoding output with aligned labels, or an
+///   error if the number of words and labels do not match or if the sentence can not be encoded
+pub fn encode_with_word_labels<T, V, S, L>(
+    tokenizer: &T,
+    words: &[S],
+    labels: &[L],
+    max_len: usize,
+    truncation_strategy: &TruncationStrategy,
+    label_alignment: LabelAlignment,
+) -> Result<LabeledTokenizedInput, TokenizerError>
+where
+    T: Tokenizer<V>,
+    V: Vocab,
+    S: AsRef<str>,
+    L: AsRef<str>,
+{
+    if words.len() != labels.len() {
+        return Err(ValueError(format!(
+            "number of words ({}) and labels ({}) do not match",
+            words.len(),
+            labels.len()
+        )));
+    }
+    let mut text = String::new();
+    let mut word_ends: Vec<OffsetSize> = Vec::with_capacity(words.len());
+    for word in words {
+        if !text.is_empty() {
+            text.push(' ');
+        }
+        text.push_str(word.as_ref());
+        word_ends.push(text.chars().count() as OffsetSize);
+    }
+    let tokenized_input = tokenizer.try_encode(&text, None, max_len, truncation_strategy, 0)?;
+
+    let mut aligned_labels = vec![None; tokenized_input.token_ids.len()];
+    let mut previous_word: Option<usize> = None;
+    for word_span in tokenized_input.word_spans() {
+        let word_index = match word_span.offset {
+            Some(offset) => word_ends.partition_point(|&end| end <= offset.begin),
+            None => continue,
+        };
+        let label = match labels.get(word_index) {
+            Some(label) => label.as_ref(),
+            None => continue,
+        };
+        for position in word_span.tokens {
+            let is_first_sub_token = previous_word != Some(word_index);
+            previous_word = Some(word_index);
+            if label_alignment == LabelAlignment::AllSubTokens || is_first_sub_token {
+                aligned_labels[position] = Some(label.to_string());
+            }
+        }
+    }
+    Ok(LabeledTokenizedInput {
+        tokenized_input,
+        labels: aligned_labels,
+    })
+}
+
+/// Expands word-level labels to the sub-tokens of a tokenized sequence. Words are the groups of
+/// sub-tokens returned by the `ConsolidatedTokenIterator` (a token followed by its
+/// `Mask::Continuation` sub-tokens), special tokens (`Mask::Special`) do not carry a label.
+///
+///  # Arguments
+/// - tokens (`&[T]`): tokens (`Token` or `TokenRef`) of the sequence
+/// - word_labels (`&[L]`): labels for each word of the sequence
+/// - label_alignment (`LabelAlignment`): strategy to expand the word labels to their sub-tokens
+///
+///  # Returns
+/// - `Result<Vec<Option<L>>, TokenizerError>` label of each token (None for the tokens without
+///   label), or an error if the number of words and labels do not match
+///
+///  # Example
+///
+/// ```no_run
+/// # fn main() -> anyhow::Result<()> {
+/// use rust_tokenizers::adapters::{align_word_labels, LabelAlignment};
+/// use rust_tokenizers::tokenizer::{BertTokenizer, Tokenizer};
+/// use rust_tokenizers::{OffsetSize, TokenRef};
+/// let tokenizer = BertTokenizer::from_file("path/to/vocab", true, true)?;
+/// let text = "John lives in Paris";
+/// let offsets = (0..text.chars().count() as OffsetSize).collect::<Vec<OffsetSize>>();
+/// let tokens = tokenizer.tokenize_to_tokens(TokenRef::new(text, &offsets));
+/// let labels = align_word_labels(
+///     &tokens,
+///     &["B-PER", "O", "O", "B-LOC"],
+///     LabelAlignment::FirstSubToken,
+/// )?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn align_word_labels<T, L>(
+    tokens: &[T],
+    word_labels: &[L],
+    label_alignment: LabelAlignment,
+) -> Result<Vec<Option<L>>, TokenizerError>
+where
+    T: TokenTrait,
+    L: Clone,
+{
+    let mut aligned_labels = Vec::with_capacity(tokens.len());
+    let mut word_labels_iter = word_labels.iter();
+    for word in ConsolidatedTokenIterator::new(tokens) {
+        if word.iter().all(|token| token.mask() == Mask::Special) {
+            aligned_labels.extend(word.iter().map(|_| None));
+            continue;
+        }
+        let label = word_labels_iter.next().ok_or_else(|| {
+            ValueError(format!(
+                "number of labels ({}) lower than the number of words",
+                word_labels.len()
+            ))
+        })?;
+        for (sub_token_index, token) in word.iter().enumerate() {
+            aligned_labels.push(match (token.mask(), label_alignment) {
+                (Mask::Special, _) => None,
+                (_, LabelAlignment::FirstSubToken) if sub_token_index > 0 => None,
+                _ => Some(label.clone()),
+            });
+        }
+    }
+    if word_labels_iter.next().is_some() {
+        return Err(ValueError(format!(
+            "number of labels ({}) greater than the number of words",
+            word_labels.len()
+        )));
+    }
+    Ok(aligned_labels)
+}
+
+/// Expands word-level label ids to the sub-tokens of a tokenized sequence, following the same rules
+/// as `align_word_labels`. Tokens without label are assigned `IGNORE_INDEX` (-100).
+///
+///  # Arguments
+/// - tokens (`&[T]`): tokens (`Token` or `TokenRef`) of the sequence
+/// - word_label_ids (`&[i64]`): label ids for each word of the sequence
+/// - label_alignment (`LabelAlignment`): strategy to expand the word labels to their sub-tokens
+///
+///  # Returns
+/// - `Result<Vec<i64>, TokenizerError>` label id of each token, or an error if the number of words
+///   and labels do not match
+pub fn align_word_label_ids<T>(
+    tokens: &[T],
+    word_label_ids: &[i64],
+    label_alignment: LabelAlignment,
+) -> Result<Vec<i64>, TokenizerError>
+where
+    T: TokenTrait,
+{
+    Ok(align_word_labels(tokens, word_label_ids, label_alignment)?
+        .into_iter()
+        .map(|label_id| label_id.unwrap_or(IGNORE_INDEX))
+        .collect())
+}
+
+/// Encodes CoNLL sentences with a multi-threaded tokenizer, expanding the word labels to the
+/// sub-tokens following the `LabelAlignment` strategy provided.
+///
+///  # Arguments
+/// - tokenizer: tokenizer used for the encoding
+/// - sentences (`&[ConllSentence]`): sentences to encode
+/// - max_len (`usize`): maximum sequence length
+/// - truncation_strategy (`&TruncationStrategy`): strategy to follow for the truncation, if required
+/// - label_alignment (`LabelAlignment`): strategy to expand the word labels to their sub-tokens
+///
+///  # Returns
+/// - `Result<Vec<LabeledTokenizedInput>, TokenizerError>` encoding output with aligned labels for each sentence
+///
+///  # Example
+///
+/// ```no_run
+/// # fn main() -> anyhow::Result<()> {
+/// use rust_tokenizers::adapters::{encode_conll_sentences, read_conll, LabelAlignment};
+/// use rust_tokenizers::tokenizer::{BertTokenizer, TruncationStrategy};
+/// let tokenizer = BertTokenizer::from_file("path/to/vocab", false, false)?;
+/// let sentences = read_conll("path/to/train.conll")?;
+/// let encoded_sentences = encode_conll_sentences(
+///     &tokenizer,
+///     &sentences,
+///     128,
+///     &TruncationStrategy::LongestFirst,
+///     LabelAlignment::FirstSubToken,
+/// )?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn encode_conll_sentences<T, V>(
+    tokenizer: &T,
+    sentences: &[ConllSentence],
+    max_len: usize,
+    truncation_strategy: &TruncationStrategy,
+    label_alignment: LabelAlignment,
+) -> Result<Vec<LabeledTokenizedInput>, TokenizerError>
+where
+    T: MultiThreadedTokenizer<V>,
+    V: Vocab,
+{
+    sentences
+        .par_iter()
+        .map(|sentence| {
+            encode_with_word_labels(
+                tokenizer,
+                &sentence.words,
+                &sentence.labels,
+                max_len,
+                truncation_strategy,
+                label_alignment,
+            )
+        })
+        .collect()
+}
+
+//==============================
+// Unit tests
+//==============================
+#[cfg(test)]
+mod tests {
+    extern crate anyhow;
+
+    use super::*;
+    use crate::adapters::tests::generate_test_vocab;
+    use crate::tokenizer::BertTokenizer;
+    use crate::TokenRef;
+    use std::io::Write;
+
+    #[test]
+    fn test_read_and_encode_conll() -> anyhow::Result<()> {
+        //        Given
+        let mut dataset_file = tempfile::NamedTempFile::new()?;
+        write!(
+            dataset_file,
+            "-DOCSTART- O\n\nHello NNP O\nunaffable JJ B-MISC\nworld NN O\n\n! . O\n"
+        )?;
+        let path = dataset_file.into_temp_path();
+        let tokenizer = BertTokenizer::from_existing_vocab(generate_test_vocab(), true, true);
+
+        //        When
+        let sentences = read_conll(&path)?;
+        let first_sub_token = encode_conll_sentences(
+            &tokenizer,
+            &sentences,
+            128,
+            &TruncationStrategy::LongestFirst,
+            LabelAlignment::FirstSubToken,
+        )?;
+        let all_sub_tokens = encode_conll_sentences(
+            &tokenizer,
+            &sentences,
+            128,
+            &TruncationStrategy::LongestFirst,
+            LabelAlignment::AllSubTokens,
+        )?;
+
+        //        Then
+        assert_eq!(sentences.len(), 2);
+        assert_eq!(sentences[0].words, vec!["Hello", "unaffable", "world"]);
+        assert_eq!(sentences[0].labels, vec!["O", "B-MISC", "O"]);
+        assert_eq!(
+            first_sub_token[0].tokenized_input.token_ids,
+            vec![4, 0, 8, 9, 10, 1, 5]
+        );
+        assert_eq!(
+            first_sub_token[0].labels,
+            vec![
+                None,
+                Some("O".to_string()),
+                Some("B-MISC".to_string()),
+                None,
+                None,
+                Some("O".to_string()),
+                None
+            ]
+        );
+        assert_eq!(
+            all_sub_tokens[0].labels,
+            vec![
+                None,
+                Some("O".to_string()),
+                Some("B-MISC".to_string()),
+                Some("B-MISC".to_string()),
+                Some("B-MISC".to_string()),
+                Some("O".to_string()),
+                None
+            ]
+        );
+        assert_eq!(
+            all_sub_tokens[1].labels,
+            vec![None, Some("O".to_string()), None]
+        );
+        drop(path);
+        Ok(())
+    }
+
+    #[test]
+    fn test_align_word_labels() -> anyhow::Result<()> {
+        //        Given
+        let tokenizer = BertTokenizer::from_existing_vocab(generate_test_vocab(), true, true);
+        let text = "hello unaffable [MASK] world";
+        let offsets = (0..text.chars().count() as OffsetSize).collect::<Vec<OffsetSize>>();
+        let tokens = tokenizer.tokenize_to_tokens(TokenRef::new(text, &offsets));
+
+        //        When
+        let first_sub_token_labels =
+            align_word_labels(&tokens, &["A", "B", "C"], LabelAlignment::FirstSubToken)?;
+        let all_sub_tokens_labels =
+            align_word_labels(&tokens, &["A", "B", "C"], LabelAlignment::AllSubTokens)?;
+        let label_ids = align_word_label_ids(&tokens, &[1, 2, 3], LabelAlignment::FirstSubToken)?;
+
+        //        Then
+        assert_eq!(
+            tokens.iter().map(|token| token.mask).collect::<Vec<Mask>>(),
+            vec![
+                Mask::None,
+                Mask::Begin,
+                Mask::Continuation,
+                Mask::Continuation,
+                Mask::Special,
+                Mask::None
+            ]
+        );
+        assert_eq!(
+            first_sub_token_labels,
+            vec![Some("A"), Some("B"), None, None, None, Some("C")]
+        );
+        assert_eq!(
+            all_sub_tokens_labels,
+            vec![Some("A"), Some("B"), Some("B"), Some("B"), None, Some("C")]
+        );
+        assert_eq!(label_ids, vec![1, 2, -100, -100, -100, 3]);
+        assert!(align_word_labels(&tokens, &["A", "B"], LabelAlignment::FirstSubToken).is_err());
+        assert!(align_word_labels(
+            &tokens,
+            &["A", "B", "C", "D"],
+            LabelAlignment::FirstSubToken
+        )
+        .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode_with_word_labels() -> anyhow::Result<()> {
+        //        Given
+        let tokenizer = BertTokenizer::from_existing_vocab(generate_test_vocab(), true, true);
+        let words = ["hello", "unaffable", "world!"];
+        let labels = ["A", "B", "C"];
+
+        //        When
+        let first_sub_token = encode_with_word_labels(
+            &tokenizer,
+            &words,
+            &labels,
+            128,
+            &TruncationStrategy::LongestFirst,
+            LabelAlignment::FirstSubToken,
+        )?;
+        let all_sub_tokens = encode_with_word_labels(
+            &tokenizer,
+            &words,
+            &labels,
+            128,
+            &TruncationStrategy::LongestFirst,
+            LabelAlignment::AllSubTokens,
+        )?;
+
+        //        Then
+        assert_eq!(
+            first_sub_token.tokenized_input.token_ids,
+            vec![4, 0, 8, 9, 10, 1, 3, 5]
+        );
+        let expected: Vec<Option<String>> = vec![
+            None,
+            Some("A"),
+            Some("B"),
+            None,
+            None,
+            Some("C"),
+            None,
+            None,
+        ]
+        .into_iter()
+        .map(|label| label.map(str::to_string))
+        .collect();
+        assert_eq!(first_sub_token.labels, expected);
+        let expected: Vec<Option<String>> = vec![
+            None,
+            Some("A"),
+            Some("B"),
+            Some("B"),
+            Some("B"),
+            Some("C"),
+            Some("C"),
+            None,
+        ]
+        .into_iter()
+        .map(|label| label.map(str::to_string))
+        .collect();
+        assert_eq!(all_sub_tokens.labels, expected);
+        assert!(encode_with_word_labels(
+            &tokenizer,
+            &words,
+            &labels[..2],
+            128,
+            &TruncationStrategy::LongestFirst,
+            LabelAlignment::FirstSubToken,
+        )
+        .is_err());
+        assert!(matches!(
+            encode_with_word_labels(
+                &tokenizer,
+                &words,
+                &labels,
+                3,
+                &TruncationStrategy::DoNotTruncate,
+                LabelAlignment::FirstSubToken,
+            ),
+            Err(TokenizerError::ValueError(_))
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+// Copyright 2019 Guillaume Becquin
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//     http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Adapter helpers to load datasets
+//! While this crate does not aim at providing built-in support for loading dataset, it exposes
+//! a few adapters for testing and benchmarking purposes (e.g. for SST2 or delimited file sentence
+//! classification, generic JSON Lines or Parquet datasets, CoNLL-style token classification files
+//! or SQuAD-style question answering examples), as well as builders for masked language model
+//! and next sentence prediction training examples, a windowed encoding of long documents, a
+//! vocabulary coverage analysis of a corpus, a comparison of the outputs of two tokenizers and an
+//! offset-based alignment of their tokens.
+//! Parquet support requires the `parquet` feature.
+
+mod analysis;
+mod datasets;
+mod labels;
+mod pretraining;
+mod qa;
+
+pub use analysis::{
+    align_tokenizers, analyze_coverage, compare_tokenizers, CoverageReport, TokenAlignment,
+    TokenizerDiffReport, TokenizerMismatch,
+};
+pub use datasets::{
+    encode_documents_with_metadata, encode_list_with_metadata, encode_record_batches,
+    encode_records, read_delimited_file, read_jsonl, read_sst2, try_encode_list_with_metadata,
+    DelimitedFileConfig, Example, JsonlRecords, Label, TextRecord,
+};
+#[cfg(feature = "parquet")]
+pub use datasets::{read_parquet, ParquetRecords};
+pub use labels::{
+    align_word_label_ids, align_word_labels, encode_conll_sentences, encode_with_word_labels,
+    read_conll, ConllSentence, LabelAlignment, LabeledTokenizedInput, IGNORE_INDEX,
+};
+pub use pretraining::{
+    build_nsp_examples, MlmConfig, MlmExample, MlmExampleBuilder, NspExample, MLM_IGNORE_INDEX,
+};
+pub use qa::{build_qa_features, encode_long_document, DocumentWindow, QaExample, QaFeature};
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use crate::vocab::base_vocab::{swap_key_values, SpecialTokenMap};
+    use crate::vocab::BertVocab;
+    use std::collections::HashMap;
+
+    pub(crate) fn generate_test_vocab() -> BertVocab {
+        let values: HashMap<String, i64> = [
+            ("hello".to_owned(), 0),
+            ("world".to_owned(), 1),
+            ("[UNK]".to_owned(), 2),
+            ("!".to_owned(), 3),
+            ("[CLS]".to_owned(), 4),
+            ("[SEP]".to_owned(), 5),
+            ("[MASK]".to_owned(), 6),
+            ("[PAD]".to_owned(), 7),
+            ("una".to_owned(), 8),
+            ("##ffa".to_owned(), 9),
+            ("##ble".to_owned(), 10),
+        ]
+        .iter()
+        .cloned()
+        .collect();
+
+        let special_token_map = SpecialTokenMap {
+            unk_token: "[UNK]".to_string(),
+            pad_token: Some("[PAD]".to_string()),
+            bos_token: None,
+            sep_token: Some("[SEP]".to_string()),
+            cls_token: Some("[CLS]".to_string()),
+            eos_token: None,
+            mask_token: Some("[MASK]".to_string()),
+            additional_special_tokens: None,
+        };
+
+        let special_values: HashMap<String, i64> = [
+            ("[UNK]".to_owned(), 2),
+            ("[CLS]".to_owned(), 4),
+            ("[SEP]".to_owned(), 5),
+            ("[MASK]".to_owned(), 6),
+            ("[PAD]".to_owned(), 7),
+        ]
+        .iter()
+        .cloned()
+        .collect();
+
+        let indices = swap_key_values(&values);
+        let special_indices = swap_key_values(&special_values);
+
+        BertVocab {
+            values,
+            indices,
+            special_token_map,
+            special_values,
+            special_indices,
+        }
+    }
+}
@@ -0,0 +1,609 @@
+// Copyright 2019 Guillaume Becquin
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//     http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::adapters::IGNORE_INDEX;
+use crate::error::TokenizerError;
+use crate::error::TokenizerError::ValueError;
+use crate::tokenizer::{Tokenizer, TruncationStrategy};
+use crate::vocab::Vocab;
+use crate::TokenizedInput;
+use rand::seq::{IteratorRandom, SliceRandom};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::sync::Mutex;
+
+/// Label of the tokens that are not selected for prediction in masked language model examples
+/// (ignored by the loss of most training frameworks)
+pub const MLM_IGNORE_INDEX: i64 = IGNORE_INDEX;
+
+/// # Masked language model configuration
+/// Masking probabilities used to build masked language model examples. The defaults follow the
+/// BERT pre-training procedure: 15% of the tokens are selected for prediction, of which 80% are
+/// replaced by the mask token, 10% by a random token and 10% are left unchanged.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MlmConfig {
+    /// Probability for a token to be selected for prediction
+    pub mask_probability: f64,
+    /// Probability for a selected token to be replaced by the mask token
+    pub mask_token_probability: f64,
+    /// Probability for a selected token to be replaced by a random (non-special) token. Selected
+    /// tokens that are neither replaced by the mask token nor by a random token are left unchanged.
+    pub random_token_probability: f64,
+    /// Select whole words for prediction: a token and its following `Mask::Continuation`
+    /// sub-tokens are always selected together
+    pub whole_word_masking: bool,
+}
+
+impl Default for MlmConfig {
+    fn default() -> Self {
+        MlmConfig {
+            mask_probability: 0.15,
+            mask_token_probability: 0.8,
+            random_token_probability: 0.1,
+            whole_word_masking: false,
+        }
+    }
+}
+
+/// # Masked language model example
+/// Training example for masked language models, built from a `TokenizedInput`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MlmExample {
+    /// Token ids after masking
+    pub input_ids: Vec<i64>,
+    /// Original token ids at the positions selected for prediction, `MLM_IGNORE_INDEX` elsewhere.
+    /// This vector has the same length as `input_ids`.
+    pub labels: Vec<i64>,
+    /// Positions of the tokens selected for prediction, in increasing order
+    pub masked_positions: Vec<usize>,
+}
+
+/// # Masked language model example builder
+/// Builds masked language model training examples from encoded inputs. Special tokens are never
+/// selected for prediction. The random number generator can be provided for each call (`build`),
+/// created from a seed (`build_with_seed`), or taken from the builder instance
+/// (`build_with_default_rng`, seeded with `set_seed`) for reproducible examples.
+#[derive(Debug)]
+pub struct MlmExampleBuilder {
+    config: MlmConfig,
+    mask_token_id: i64,
+    random_token_ids: Vec<i64>,
+    rng: Mutex<ChaCha8Rng>,
+}
+
+impl Clone for MlmExampleBuilder {
+    /// Clones the builder, including the current state of its default random number generator
+    fn clone(&self) -> Self {
+        MlmExampleBuilder {
+            config: self.config,
+            mask_token_id: self.mask_token_id,
+            random_token_ids: self.random_token_ids.clone(),
+            rng: Mutex::new(self.rng.lock().unwrap().clone()),
+        }
+    }
+}
+
+impl MlmExampleBuilder {
+    /// Creates a new masked language model example builder. Random replacements are drawn from the
+    /// non-special tokens of the vocabulary.
+    ///
+    ///  # Arguments
+    /// - vocab: vocabulary of the tokenizer used to encode the inputs
+    /// - mask_token (`&str`): mask token (must be a special token of the vocabulary)
+    /// - config (`MlmConfig`): masking probabilities
+    ///
+    ///  # Returns
+    /// - `Result<MlmExampleBuilder, TokenizerError>` builder, or an error if the mask token is not
+    ///   a special token of the vocabulary or if the probabilities are not valid
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    /// # fn main() -> anyhow::Result<()> {
+    /// use rust_tokenizers::adapters::{MlmConfig, MlmExampleBuilder};
+    /// use rust_tokenizers::tokenizer::{BertTokenizer, Tokenizer, TruncationStrategy};
+    /// use rand::SeedableRng;
+    /// use rand_chacha::ChaCha8Rng;
+    /// let tokenizer = BertTokenizer::from_file("path/to/vocab", true, true)?;
+    /// let builder = MlmExampleBuilder::new(
+    ///     tokenizer.vocab(),
+    ///     tokenizer.vocab().get_mask_value(),
+    ///     MlmConfig::default(),
+    /// )?;
+    ///
+    /// let mut rng = ChaCha8Rng::seed_from_u64(42);
+    /// let tokenized_input = tokenizer.encode(
+    ///     "Hello, world!",
+    ///     None,
+    ///     128,
+    ///     &TruncationStrategy::LongestFirst,
+    ///     0,
+    /// );
+    /// let example = builder.build(&tokenized_input, &mut rng);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new<V: Vocab>(
+        vocab: &V,
+        mask_token: &str,
+        config: MlmConfig,
+    ) -> Result<MlmExampleBuilder, TokenizerError> {
+        let probabilities = [
+            config.mask_probability,
+            config.mask_token_probability,
+            config.random_token_probability,
+        ];
+        if probabilities
+            .iter()
+            .any(|probability| !(0.0..=1.0).contains(probability))
+            || config.mask_token_probability + config.random_token_probability > 1.0
+        {
+            return Err(ValueError(format!(
+                "invalid masking probabilities: {config:?}"
+            )));
+        }
+        let mask_token_id = *vocab.special_values().get(mask_token).ok_or_else(|| {
+            TokenizerError::SpecialTokenNotFound(format!(
+                "The mask token {mask_token} could not be found in the special tokens"
+            ))
+        })?;
+        let mut random_token_ids = vocab
+            .values()
+            .values()
+            .filter(|id| !vocab.special_indices().contains_key(id))
+            .cloned()
+            .collect::<Vec<i64>>();
+        random_token_ids.sort_unstable();
+        random_token_ids.dedup();
+        Ok(MlmExampleBuilder {
+            config,
+            mask_token_id,
+            random_token_ids,
+            rng: Mutex::new(ChaCha8Rng::from_entropy()),
+        })
+    }
+
+    /// Seeds the default random number generator of the builder, used by `build_with_default_rng`.
+    /// The default generator is seeded from the operating system entropy on creation.
+    ///
+    ///  # Arguments
+    /// - seed (`u64`): seed of the random number generator
+    pub fn set_seed(&mut self, seed: u64) {
+        self.rng = Mutex::new(ChaCha8Rng::seed_from_u64(seed));
+    }
+
+    /// Builds a masked language model example from an encoded input. The number of tokens selected
+    /// for prediction is the rounded product of the number of non-special tokens with the mask
+    /// probability (at least one token if the mask probability is positive).
+    ///
+    ///  # Arguments
+    /// - tokenized_input (`&TokenizedInput`): encoded input
+    /// - rng: random number generator used for the selection and replacement of the tokens
+    ///
+    ///  # Returns
+    /// - `MlmExample` with the masked input ids, the labels and the masked positions
+    pub fn build<R: Rng + ?Sized>(
+        &self,
+        tokenized_input: &TokenizedInput,
+        rng: &mut R,
+    ) -> MlmExample {
+        let mut words: Vec<Vec<usize>> = Vec::new();
+        for word_span in tokenized_input.word_spans() {
+            let positions = word_span
+                .tokens
+                .filter(|&position| tokenized_input.special_tokens_mask.get(position) != Some(&1));
+            if self.config.whole_word_masking {
+                words.push(positions.collect());
+            } else {
+                words.extend(positions.map(|position| vec![position]));
+            }
+        }
+        words.retain(|word| !word.is_empty());
+
+        let num_tokens = words.iter().map(Vec::len).sum::<usize>();
+        let num_to_predict = if self.config.mask_probability > 0.0 && num_tokens > 0 {
+            ((num_tokens as f64 * self.config.mask_probability).round() as usize).max(1)
+        } else {
+            0
+        };
+        words.shuffle(rng);
+        let mut masked_positions = Vec::with_capacity(num_to_predict);
+        for word in words {
+            if masked_positions.len() >= num_to_predict {
+                break;
+            }
+            if masked_positions.len() + word.len() <= num_to_predict {
+                masked_positions.extend(word);
+            }
+        }
+        masked_positions.sort_unstable();
+
+        let mut input_ids = tokenized_input.token_ids.clone();
+        let mut labels = vec![MLM_IGNORE_INDEX; input_ids.len()];
+        for &position in masked_positions.iter() {
+            labels[position] = input_ids[position];
+            let draw = rng.gen::<f64>();
+            if draw < self.config.mask_token_probability {
+                input_ids[position] = self.mask_token_id;
+            } else if draw
+                < self.config.mask_token_probability + self.config.random_token_probability
+            {
+                if let Some(token_id) = self.random_token_ids.choose(rng) {
+                    input_ids[position] = *token_id;
+                }
+            }
+        }
+        MlmExample {
+            input_ids,
+            labels,
+            masked_positions,
+        }
+    }
+
+    /// Builds a masked language model example with a random number generator seeded with the
+    /// provided seed: the same input and seed always produce the same example, across platforms.
+    /// The generator is a `ChaCha8Rng`: examples are stable for the `rand` (0.8) and `rand_chacha`
+    /// (0.3) versions used by this crate, and may change when these dependencies are upgraded.
+    ///
+    ///  # Arguments
+    /// - tokenized_input (`&TokenizedInput`): encoded input
+    /// - seed (`u64`): seed of the random number generator
+    ///
+    ///  # Returns
+    /// - `MlmExample` with the masked input ids, the labels and the masked positions
+    pub fn build_with_seed(&self, tokenized_input: &TokenizedInput, seed: u64) -> MlmExample {
+        self.build(tokenized_input, &mut ChaCha8Rng::seed_from_u64(seed))
+    }
+
+    /// Builds a masked language model example with the default random number generator of the
+    /// builder. The generator is shared by the threads using the builder: examples built from a
+    /// seeded builder are reproducible if they are built in a deterministic order.
+    ///
+    ///  # Arguments
+    /// - tokenized_input (`&TokenizedInput`): encoded input
+    ///
+    ///  # Returns
+    /// - `MlmExample` with the masked input ids, the labels and the masked positions
+    pub fn build_with_default_rng(&self, tokenized_input: &TokenizedInput) -> MlmExample {
+        self.build(tokenized_input, &mut *self.rng.lock().unwrap())
+    }
+}
+
+/// # Next sentence prediction example
+/// Encoded sentence pair with a flag indicating if the second sentence follows the first one in
+/// the source document
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NspExample {
+    pub tokenized_input: TokenizedInput,
+    pub is_next: bool,
+}
+
+/// Builds BERT-style next sentence prediction examples from a collection of documents (each made
+/// of a sequence of sentences). An example is created for each pair of consecutive sentences. With
+/// probability `negative_probability`, the second sentence is replaced by a sentence sampled from
+/// another document and the example is labelled as a negative (`is_next` set to false). If no
+/// other document contains sentences, only positive examples are generated. The sentence pairs
+/// are encoded with `try_encode_pair_list`.
+///
+///  # Arguments
+/// - tokenizer: tokenizer used for the encoding
+/// - documents (`&[D]`): documents, each containing a sequence of sentences
+/// - negative_probability (`f64`): probability for an example to be a negative example (0.5 for BERT)
+/// - max_len (`usize`): maximum sequence length
+/// - truncation_strategy (`&TruncationStrategy`): strategy to follow for the truncation, if required
+/// - rng: random number generator used for the sampling of the negative examples
+///
+///  # Returns
+/// - `Result<Vec<NspExample>, TokenizerError>` encoded examples, or an error if the negative
+///   probability is not valid or if a sentence pair can not be encoded
+///
+///  # Example
+///
+/// ```no_run
+/// # fn main() -> anyhow::Result<()> {
+/// use rust_tokenizers::adapters::build_nsp_examples;
+/// use rust_tokenizers::tokenizer::{BertTokenizer, TruncationStrategy};
+/// use rand::SeedableRng;
+/// use rand_chacha::ChaCha8Rng;
+/// let tokenizer = BertTokenizer::from_file("path/to/vocab", true, true)?;
+/// let documents = vec![
+///     vec!["The cat sat on the mat.", "It fell asleep."],
+///     vec!["Paris is the capital of France.", "It is a large city."],
+/// ];
+/// let mut rng = ChaCha8Rng::seed_from_u64(42);
+/// let examples = build_nsp_examples(
+///     &tokenizer,
+///     &documents,
+///     0.5,
+///     128,
+///     &TruncationStrategy::LongestFirst,
+///     &mut rng,
+/// )?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn build_nsp_examples<T, V, D, S, R>(
+    tokenizer: &T,
+    documents: &[D],
+    negative_probability: f64,
+    max_len: usize,
+    truncation_strategy: &TruncationStrategy,
+    rng: &mut R,
+) -> Result<Vec<NspExample>, TokenizerError>
+where
+    T: Tokenizer<V>,
+    V: Vocab,
+    D: AsRef<[S]>,
+    S: AsRef<str>,
+    R: Rng + ?Sized,
+{
+    if !(0.0..=1.0).contains(&negative_probability) {
+        return Err(ValueError(format!(
+            "invalid negative probability: {negative_probability}"
+        )));
+    }
+    let non_empty_documents = documents
+        .iter()
+        .enumerate()
+        .filter(|(_, document)| !document.as_ref().is_empty())
+        .map(|(document_index, _)| document_index)
+        .collect::<Vec<usize>>();
+
+    let mut pairs = Vec::new();
+    let mut labels = Vec::new();
+    for (document_index, document) in documents.iter().enumerate() {
+        for sentences in document.as_ref().windows(2) {
+            let negative_document = if rng.gen::<f64>() < negative_probability {
+                non_empty_documents
+                    .iter()
+                    .filter(|&&other_index| other_index != document_index)
+                    .choose(rng)
+            } else {
+                None
+            };
+            let second_sentence = match negative_document {
+                Some(&other_index) => documents[other_index].as_ref().choose(rng).unwrap(),
+                None => &sentences[1],
+            };
+            pairs.push((sentences[0].as_ref(), second_sentence.as_ref()));
+            labels.push(negative_document.is_none());
+        }
+    }
+    Ok(tokenizer
+        .try_encode_pair_list(&pairs, max_len, truncation_strategy, 0)?
+        .into_iter()
+        .zip(labels)
+        .map(|(tokenized_input, is_next)| NspExample {
+            tokenized_input,
+            is_next,
+        })
+        .collect())
+}
+
+//==============================
+// Unit tests
+//==============================
+#[cfg(test)]
+mod tests {
+    extern crate anyhow;
+
+    use super::*;
+    use crate::adapters::tests::generate_test_vocab;
+    use crate::tokenizer::BertTokenizer;
+
+    #[test]
+    fn test_mlm_example_builder() -> anyhow::Result<()> {
+        //        Given
+        let vocab = generate_test_vocab();
+        let tokenizer = BertTokenizer::from_existing_vocab(vocab.clone(), true, true);
+        let tokenized_input = tokenizer.encode(
+            "hello unaffable world !",
+            None,
+            128,
+            &TruncationStrategy::LongestFirst,
+            0,
+        );
+        let mask_all = MlmConfig {
+            mask_probability: 1.0,
+            mask_token_probability: 1.0,
+            random_token_probability: 0.0,
+            whole_word_masking: false,
+        };
+        let random_all = MlmConfig {
+            mask_token_probability: 0.0,
+            random_token_probability: 1.0,
+            ..mask_all
+        };
+        let whole_words = MlmConfig {
+            mask_probability: 0.5,
+            whole_word_masking: true,
+            ..mask_all
+        };
+        let mut rng = ChaCha8Rng::seed_from_u64(42);
+
+        //        When
+        let masked =
+            MlmExampleBuilder::new(&vocab, "[MASK]", mask_all)?.build(&tokenized_input, &mut rng);
+        let randomized =
+            MlmExampleBuilder::new(&vocab, "[MASK]", random_all)?.build(&tokenized_input, &mut rng);
+        let whole_word_builder = MlmExampleBuilder::new(&vocab, "[MASK]", whole_words)?;
+        let whole_word_examples = (0..20)
+            .map(|_| whole_word_builder.build(&tokenized_input, &mut rng))
+            .collect::<Vec<MlmExample>>();
+
+        //        Then
+        assert_eq!(tokenized_input.token_ids, vec![4, 0, 8, 9, 10, 1, 3, 5]);
+        assert_eq!(masked.input_ids, vec![4, 6, 6, 6, 6, 6, 6, 5]);
+        assert_eq!(masked.labels, vec![-100, 0, 8, 9, 10, 1, 3, -100]);
+        assert_eq!(masked.masked_positions, vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(randomized.masked_positions, vec![1, 2, 3, 4, 5, 6]);
+        assert!(randomized.input_ids[1..7]
+            .iter()
+            .all(|token_id| ![2, 4, 5, 6, 7].contains(token_id)));
+        for example in whole_word_examples.iter() {
+            assert_eq!(example.masked_positions.len(), 3);
+            assert!(
+                example.masked_positions == vec![2, 3, 4]
+                    || example
+                        .masked_positions
+                        .iter()
+                        .all(|position| *position < 2 || *position > 4)
+            );
+        }
+        assert!(MlmExampleBuilder::new(&vocab, "[CLS]", mask_all).is_ok());
+        assert!(MlmExampleBuilder::new(&vocab, "<mask>", mask_all).is_err());
+        assert!(MlmExampleBuilder::new(
+            &vocab,
+            "[MASK]",
+            MlmConfig {
+                mask_token_probability: 0.8,
+                random_token_probability: 0.3,
+                ..mask_all
+            }
+        )
+        .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_mlm_example_builder_seeding() -> anyhow::Result<()> {
+        //        Given
+        let vocab = generate_test_vocab();
+        let tokenizer = BertTokenizer::from_existing_vocab(vocab.clone(), true, true);
+        let tokenized_input = tokenizer.encode(
+            "hello unaffable world ! hello world",
+            None,
+            128,
+            &TruncationStrategy::LongestFirst,
+            0,
+        );
+        let mut builder = MlmExampleBuilder::new(&vocab, "[MASK]", MlmConfig::default())?;
+        builder.set_seed(42);
+        let mut other_builder = MlmExampleBuilder::new(&vocab, "[MASK]", MlmConfig::default())?;
+        other_builder.set_seed(42);
+
+        //        When
+        let seeded_examples = (0..10)
+            .map(|_| builder.build_with_seed(&tokenized_input, 7))
+            .collect::<Vec<MlmExample>>();
+        let mut rng = ChaCha8Rng::seed_from_u64(7);
+        let rng_example = builder.build(&tokenized_input, &mut rng);
+        let default_rng_examples = (0..10)
+            .map(|_| builder.build_with_default_rng(&tokenized_input))
+            .collect::<Vec<MlmExample>>();
+        let cloned_builder = other_builder.clone();
+        let other_examples = (0..10)
+            .map(|_| other_builder.build_with_default_rng(&tokenized_input))
+            .collect::<Vec<MlmExample>>();
+        let cloned_examples = (0..10)
+            .map(|_| cloned_builder.build_with_default_rng(&tokenized_input))
+            .collect::<Vec<MlmExample>>();
+
+        //        Then
+        assert!(seeded_examples
+            .iter()
+            .all(|example| *example == seeded_examples[0]));
+        assert_eq!(seeded_examples[0], rng_example);
+        assert_eq!(default_rng_examples, other_examples);
+        assert_eq!(other_examples, cloned_examples);
+        assert!(default_rng_examples
+            .iter()
+            .any(|example| *example != default_rng_examples[0]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_nsp_examples() -> anyhow::Result<()> {
+        //        Given
+        let tokenizer = BertTokenizer::from_existing_vocab(generate_test_vocab(), true, true);
+        let documents = vec![
+            vec!["hello world", "unaffable !", "hello"],
+            vec!["world !"],
+            vec![],
+        ];
+        let mut rng = ChaCha8Rng::seed_from_u64(42);
+        let truncation_strategy = TruncationStrategy::LongestFirst;
+
+        //        When
+        let positives = build_nsp_examples(
+            &tokenizer,
+            &documents,
+            0.0,
+            16,
+            &truncation_strategy,
+            &mut rng,
+        )?;
+        let negatives = build_nsp_examples(
+            &tokenizer,
+            &documents,
+            1.0,
+            16,
+            &truncation_strategy,
+            &mut rng,
+        )?;
+        let single_document = build_nsp_examples(
+            &tokenizer,
+            &documents[..1],
+            1.0,
+            16,
+            &truncation_strategy,
+            &mut rng,
+        )?;
+
+        //        Then
+        assert_eq!(positives.len(), 2);
+        assert!(positives.iter().all(|example| example.is_next));
+        assert_eq!(
+            positives[0].tokenized_input.token_ids,
+            vec![4, 0, 1, 5, 8, 9, 10, 3, 5]
+        );
+        assert_eq!(
+            positives[0].tokenized_input.segment_ids,
+            vec![0, 0, 0, 0, 1, 1, 1, 1, 1]
+        );
+        assert_eq!(
+            positives[1].tokenized_input.token_ids,
+            vec![4, 8, 9, 10, 3, 5, 0, 5]
+        );
+        assert_eq!(negatives.len(), 2);
+        assert!(negatives.iter().all(|example| !example.is_next));
+        assert_eq!(
+            negatives[0].tokenized_input.token_ids,
+            vec![4, 0, 1, 5, 1, 3, 5]
+        );
+        assert_eq!(
+            negatives[1].tokenized_input.token_ids,
+            vec![4, 8, 9, 10, 3, 5, 1, 3, 5]
+        );
+        assert_eq!(single_document, positives);
+        assert!(build_nsp_examples(
+            &tokenizer,
+            &documents,
+            1.5,
+            16,
+            &truncation_strategy,
+            &mut rng
+        )
+        .is_err());
+        assert!(matches!(
+            build_nsp_examples(
+                &tokenizer,
+                &documents,
+                0.0,
+                4,
+                &TruncationStrategy::DoNotTruncate,
+                &mut rng
+            ),
+            Err(TokenizerError::ValueError(_))
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,478 @@
+// Copyright 2019 Guillaume Becquin
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//     http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::error::TokenizerError;
+use crate::error::TokenizerError::ValueError;
+use crate::tokenizer::{MultiThreadedTokenizer, Tokenizer};
+use crate::vocab::Vocab;
+use crate::{Mask, Offset, OffsetSize, TokenIdsWithOffsets, TokenizedInput};
+use rayon::prelude::*;
+use std::ops::Range;
+
+/// # Question answering example
+/// SQuAD-style example containing a question, a context and an optional answer span. The answer
+/// start is expressed as a character position in the context.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QaExample {
+    pub question: String,
+    pub context: String,
+    pub answer_start: Option<usize>,
+    pub answer_text: Option<String>,
+}
+
+/// # Question answering feature
+/// Encoded window over a `QaExample` context, containing the question and a span of the context.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QaFeature {
+    /// Index of the example this feature was generated from
+    pub example_index: usize,
+    /// Encoded question and context window
+    pub tokenized_input: TokenizedInput,
+    /// Offsets of each token in the context (in characters). Question and special tokens are registered as None.
+    pub token_to_char: Vec<Option<Offset>>,
+    /// Index of the first token of the answer, if the answer is fully contained in the window
+    pub start_position: Option<usize>,
+    /// Index of the last token of the answer, if the answer is fully contained in the window
+    pub end_position: Option<usize>,
+}
+
+/// Builds question answering features from SQuAD-style examples. Contexts that do not fit in
+/// `max_len` tokens (after the question and special tokens) are split into overlapping windows,
+/// following the overflow window semantics of `encode`: consecutive windows share `stride` tokens.
+///
+///  # Arguments
+/// - tokenizer: tokenizer used for the encoding
+/// - examples (`&[QaExample]`): examples to encode
+/// - max_len (`usize`): maximum length of each feature
+/// - stride (`usize`): number of overlapping context tokens between consecutive windows
+/// - max_query_len (`usize`): maximum number of question tokens (longer questions are truncated)
+///
+///  # Returns
+/// - `Result<Vec<QaFeature>, TokenizerError>` features for all examples
+///
+///  # Example
+///
+/// ```no_run
+/// # fn main() -> anyhow::Result<()> {
+/// use rust_tokenizers::adapters::{build_qa_features, QaExample};
+/// use rust_tokenizers::tokenizer::BertTokenizer;
+/// let tokenizer = BertTokenizer::from_file("path/to/vocab", true, true)?;
+/// let examples = [QaExample {
+///     question: "Where is the Eiffel Tower?".to_string(),
+///     context: "The Eiffel Tower is located in Paris.".to_string(),
+///     answer_start: Some(31),
+///     answer_text: Some("Paris".to_string()),
+/// }];
+/// let features = build_qa_features(&tokenizer, &examples, 384, 128, 64)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn build_qa_features<T, V>(
+    tokenizer: &T,
+    examples: &[QaExample],
+    max_len: usize,
+    stride: usize,
+    max_query_len: usize,
+) -> Result<Vec<QaFeature>, TokenizerError>
+where
+    T: MultiThreadedTokenizer<V>,
+    V: Vocab,
+{
+    let features = examples
+        .par_iter()
+        .enumerate()
+        .map(|(example_index, example)| {
+            build_example_qa_features(
+                tokenizer,
+                example_index,
+                example,
+                max_len,
+                stride,
+                max_query_len,
+            )
+        })
+        .collect::<Result<Vec<Vec<QaFeature>>, TokenizerError>>()?;
+    Ok(features.into_iter().flatten().collect())
+}
+
+fn tokenize_to_ids<T, V>(tokenizer: &T, text: &str) -> TokenIdsWithOffsets
+where
+    T: Tokenizer<V>,
+    V: Vocab,
+{
+    let tokens = tokenizer.tokenize_with_offsets(text);
+    TokenIdsWithOffsets {
+        ids: tokenizer.convert_tokens_to_ids(&tokens.tokens),
+        offsets: tokens.offsets,
+        reference_offsets: tokens.reference_offsets,
+        masks: tokens.masks,
+    }
+}
+
+fn returned_tokens<T, V>(tokenizer: &T, token_ids: &[i64]) -> Option<Vec<String>>
+where
+    T: Tokenizer<V>,
+    V: Vocab,
+{
+    tokenizer.return_tokens().then(|| {
+        token_ids
+            .iter()
+            .map(|id| tokenizer.vocab().id_to_token(id))
+            .collect()
+    })
+}
+
+fn slice_token_ids(token_ids: &TokenIdsWithOffsets, range: Range<usize>) -> TokenIdsWithOffsets {
+    TokenIdsWithOffsets {
+        ids: token_ids.ids[range.clone()].to_vec(),
+        offsets: token_ids.offsets[range.clone()].to_vec(),
+        reference_offsets: token_ids.reference_offsets[range.clone()].to_vec(),
+        masks: token_ids.masks[range].to_vec(),
+    }
+}
+
+fn build_example_qa_features<T, V>(
+    tokenizer: &T,
+    example_index: usize,
+    example: &QaExample,
+    max_len: usize,
+    stride: usize,
+    max_query_len: usize,
+) -> Result<Vec<QaFeature>, TokenizerError>
+where
+    T: Tokenizer<V>,
+    V: Vocab,
+{
+    let question = tokenize_to_ids(tokenizer, &example.question);
+    let question = slice_token_ids(&question, 0..question.ids.len().min(max_query_len));
+    let context = tokenize_to_ids(tokenizer, &example.context);
+
+    let num_special_tokens = tokenizer
+        .build_input_with_special_tokens(
+            slice_token_ids(&question, 0..0),
+            Some(slice_token_ids(&context, 0..0)),
+        )
+        .token_ids
+        .len();
+    let window_len = max_len
+        .checked_sub(question.ids.len() + num_special_tokens)
+        .filter(|&window_len| window_len > stride)
+        .ok_or_else(|| {
+            ValueError(format!(
+                "max_len ({max_len}) too short for the question and stride provided (example {example_index})"
+            ))
+        })?;
+
+    let answer_span = match (example.answer_start, &example.answer_text) {
+        (Some(answer_start), Some(answer_text)) => Some((
+            answer_start as OffsetSize,
+            (answer_start + answer_text.chars().count()) as OffsetSize,
+        )),
+        _ => None,
+    };
+
+    let mut features = Vec::new();
+    let mut window_start = 0;
+    loop {
+        let window_end = (window_start + window_len).min(context.ids.len());
+        let window = slice_token_ids(&context, window_start..window_end);
+        let masked_question = TokenIdsWithOffsets {
+            offsets: vec![None; question.offsets.len()],
+            ..question.clone()
+        };
+        let token_to_char = tokenizer
+            .build_input_with_special_tokens(masked_question, Some(window.clone()))
+            .token_offsets;
+        let merged = tokenizer.build_input_with_special_tokens(question.clone(), Some(window));
+
+        let (start_position, end_position) = match answer_span {
+            Some((answer_begin, answer_end)) => {
+                let start_position = token_to_char.iter().position(|offset| {
+                    offset.is_some_and(|offset| {
+                        offset.begin <= answer_begin && offset.end > answer_begin
+                    })
+                });
+                let end_position = token_to_char.iter().rposition(|offset| {
+                    offset
+                        .is_some_and(|offset| offset.begin < answer_end && offset.end >= answer_end)
+                });
+                match (start_position, end_position) {
+                    (Some(start), Some(end)) if start <= end => (Some(start), Some(end)),
+                    _ => (None, None),
+                }
+            }
+            None => (None, None),
+        };
+
+        let tokens = returned_tokens(tokenizer, &merged.token_ids);
+        features.push(QaFeature {
+            example_index,
+            tokenized_input: TokenizedInput {
+                token_ids: merged.token_ids,
+                segment_ids: merged.segment_ids,
+                special_tokens_mask: merged.special_tokens_mask,
+                overflowing_tokens: vec![],
+                num_truncated_tokens: 0,
+                token_offsets: merged.token_offsets,
+                reference_offsets: merged.reference_offsets,
+                mask: merged.mask,
+                tokens,
+            },
+            token_to_char,
+            start_position,
+            end_position,
+        });
+        if window_end >= context.ids.len() {
+            break;
+        }
+        window_start = window_end - stride;
+    }
+    Ok(features)
+}
+
+/// # Document window
+/// Encoded window over a long document, generated by `encode_long_document`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DocumentWindow {
+    /// Encoded window, including the special tokens. Token offsets are positions in the document.
+    pub tokenized_input: TokenizedInput,
+    /// Range of the window tokens in the tokenized document (excluding the special tokens)
+    pub token_range: Range<usize>,
+    /// Characters of the document covered by the window (None if no token of the window has an offset)
+    pub offset: Option<Offset>,
+}
+
+/// Encodes a document of arbitrary length into windows of at most `max_len` tokens (including the
+/// special tokens). Windows end at word boundaries (a word is never split across windows unless it
+/// does not fit in a single window), and consecutive windows share up to `overlap` tokens, the
+/// overlap being reduced to start the next window at a word boundary.
+///
+///  # Arguments
+/// - tokenizer: tokenizer used for the encoding
+/// - text (`&str`): document to encode
+/// - max_len (`usize`): maximum length of each window, including the special tokens
+/// - overlap (`usize`): maximum number of tokens shared by consecutive windows
+///
+///  # Returns
+/// - `Result<Vec<DocumentWindow>, TokenizerError>` windows covering the document, failing with a
+///   `ValueError` if `max_len` does not leave room for more than `overlap` tokens
+///
+///  # Example
+///
+/// ```no_run
+/// # fn main() -> anyhow::Result<()> {
+/// use rust_tokenizers::adapters::encode_long_document;
+/// use rust_tokenizers::tokenizer::BertTokenizer;
+/// let tokenizer = BertTokenizer::from_file("path/to/vocab", true, true)?;
+/// let document = std::fs::read_to_string("path/to/document.txt")?;
+/// let windows = encode_long_document(&tokenizer, &document, 512, 128)?;
+/// for window in windows {
+///     println!("{:?}: {:?}", window.offset, window.tokenized_input.token_ids);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn encode_long_document<T, V>(
+    tokenizer: &T,
+    text: &str,
+    max_len: usize,
+    overlap: usize,
+) -> Result<Vec<DocumentWindow>, TokenizerError>
+where
+    T: Tokenizer<V>,
+    V: Vocab,
+{
+    let document = tokenize_to_ids(tokenizer, text);
+    let num_special_tokens = tokenizer
+        .build_input_with_special_tokens(slice_token_ids(&document, 0..0), None)
+        .token_ids
+        .len();
+    let window_len = max_len
+        .checked_sub(num_special_tokens)
+        .filter(|&window_len| window_len > overlap)
+        .ok_or_else(|| {
+            ValueError(format!(
+                "max_len ({max_len}) too short for the special tokens and overlap ({overlap}) provided"
+            ))
+        })?;
+
+    let chars = text.chars().collect::<Vec<char>>();
+    let is_word_start = |index: usize| {
+        if index == 0 || index >= document.ids.len() {
+            return true;
+        }
+        if document.masks[index] == Mask::Continuation {
+            return false;
+        }
+        match (document.offsets[index - 1], document.offsets[index]) {
+            (Some(previous), Some(current)) => {
+                previous.end < current.begin
+                    || chars
+                        .get(current.begin as usize)
+                        .is_some_and(|c| c.is_whitespace())
+            }
+            _ => true,
+        }
+    };
+
+    let mut windows = Vec::new();
+    let mut window_start = 0;
+    loop {
+        let mut window_end = (window_start + window_len).min(document.ids.len());
+        if let Some(word_start) = (window_start + 1..=window_end)
+            .rev()
+            .find(|&index| is_word_start(index))
+        {
+            window_end = word_start;
+        }
+        let window = slice_token_ids(&document, window_start..window_end);
+        let offset = window
+            .offsets
+            .iter()
+            .flatten()
+            .copied()
+            .reduce(|first, last| {
+                Offset::new(first.begin.min(last.begin), first.end.max(last.end))
+            });
+        let merged = tokenizer.build_input_with_special_tokens(window, None);
+        let tokens = returned_tokens(tokenizer, &merged.token_ids);
+        windows.push(DocumentWindow {
+            tokenized_input: TokenizedInput {
+                token_ids: merged.token_ids,
+                segment_ids: merged.segment_ids,
+                special_tokens_mask: merged.special_tokens_mask,
+                overflowing_tokens: vec![],
+                num_truncated_tokens: 0,
+                token_offsets: merged.token_offsets,
+                reference_offsets: merged.reference_offsets,
+                mask: merged.mask,
+                tokens,
+            },
+            token_range: window_start..window_end,
+            offset,
+        });
+        if window_end >= document.ids.len() {
+            break;
+        }
+        let mut next_start = window_end.saturating_sub(overlap).max(window_start + 1);
+        while next_start < window_end && !is_word_start(next_start) {
+            next_start += 1;
+        }
+        window_start = next_start;
+    }
+    Ok(windows)
+}
+
+//==============================
+// Unit tests
+//==============================
+#[cfg(test)]
+mod tests {
+    extern crate anyhow;
+
+    use super::*;
+    use crate::adapters::tests::generate_test_vocab;
+    use crate::tokenizer::BertTokenizer;
+
+    #[test]
+    fn test_encode_long_document() -> anyhow::Result<()> {
+        //        Given
+        let tokenizer = BertTokenizer::from_existing_vocab(generate_test_vocab(), true, true);
+        let text = "hello world unaffable hello world !";
+
+        //        When
+        let windows = encode_long_document(&tokenizer, text, 6, 1)?;
+        let split_word_windows = encode_long_document(&tokenizer, "unaffable", 4, 0)?;
+
+        //        Then
+        assert_eq!(
+            windows
+                .iter()
+                .map(|window| window.tokenized_input.token_ids.clone())
+                .collect::<Vec<Vec<i64>>>(),
+            vec![
+                vec![4, 0, 1, 5],
+                vec![4, 1, 8, 9, 10, 5],
+                vec![4, 0, 1, 3, 5]
+            ]
+        );
+        assert_eq!(
+            windows
+                .iter()
+                .map(|window| (window.token_range.clone(), window.offset))
+                .collect::<Vec<(Range<usize>, Option<Offset>)>>(),
+            vec![
+                (0..2, Some(Offset::new(0, 11))),
+                (1..5, Some(Offset::new(6, 21))),
+                (5..8, Some(Offset::new(22, 35))),
+            ]
+        );
+        assert_eq!(
+            windows[2].tokenized_input.token_offsets[3],
+            Some(Offset::new(34, 35))
+        );
+        assert_eq!(
+            split_word_windows
+                .iter()
+                .map(|window| window.token_range.clone())
+                .collect::<Vec<Range<usize>>>(),
+            vec![0..2, 2..3]
+        );
+        assert!(encode_long_document(&tokenizer, text, 3, 1).is_err());
+        assert!(encode_long_document(&tokenizer, text, 0, 0).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_qa_features() -> anyhow::Result<()> {
+        //        Given
+        let tokenizer = BertTokenizer::from_existing_vocab(generate_test_vocab(), true, true);
+        let examples = [QaExample {
+            question: "Hello?".to_string(),
+            context: "hello world hello world !".to_string(),
+            answer_start: Some(18),
+            answer_text: Some("world !".to_string()),
+        }];
+
+        //        When
+        let features = build_qa_features(&tokenizer, &examples, 8, 1, 2)?;
+
+        //        Then
+        assert_eq!(features.len(), 2);
+        assert_eq!(
+            features[0].tokenized_input.token_ids,
+            vec![4, 0, 2, 5, 0, 1, 0, 5]
+        );
+        assert_eq!(features[0].start_position, None);
+        assert_eq!(
+            features[1].tokenized_input.token_ids,
+            vec![4, 0, 2, 5, 0, 1, 3, 5]
+        );
+        assert_eq!(
+            features[1].token_to_char,
+            vec![
+                None,
+                None,
+                None,
+                None,
+                Some(Offset::new(12, 17)),
+                Some(Offset::new(18, 23)),
+                Some(Offset::new(24, 25)),
+                None
+            ]
+        );
+        assert_eq!(features[1].start_position, Some(5));
+        assert_eq!(features[1].end_position, Some(6));
+        assert!(build_qa_features(&tokenizer, &examples, 6, 1, 2).is_err());
+        assert!(build_qa_features(&tokenizer, &examples, 0, 0, 2).is_err());
+        Ok(())
+    }
+}
@@ -1,14 +1,15 @@
 //! # Tokenizer error variants
+use std::path::{Path, PathBuf};
 use thiserror::Error;
 
 #[allow(clippy::upper_case_acronyms)]
 #[derive(Error, Debug)]
 pub enum TokenizerError {
-    #[error("File not found error: {0}")]
-    FileNotFound(String),
+    #[error("File not found error: {}: {message}", .path.display())]
+    FileNotFound { path: PathBuf, message: String },
 
-    #[error("Error when loading vocabulary file, the file may be corrupted or does not match the expected format: {0}")]
-    VocabularyParsingError(String),
+    #[error("Error when loading vocabulary file {}, the file may be corrupted or does not match the expected format: {message}", .path.display())]
+    VocabularyParsingError { path: PathBuf, message: String },
 
     #[error("Token index not found in vocabulary: {0}")]
     IndexNotFound(String),
@@ -16,18 +17,60 @@ pub enum TokenizerError {
     #[error("Token not found in vocabulary: {0}")]
     TokenNotFound(String),
 
+    #[error("Special token not found in vocabulary: {0}")]
+    SpecialTokenNotFound(String),
+
+    #[error("Language code not found in vocabulary: {code}. Supported language codes: {}", .supported.join(", "))]
+    LanguageCodeNotFound {
+        code: String,
+        supported: Vec<String>,
+    },
+
     #[error("Tokenization error: {0}")]
     TokenizationError(String),
 
     #[error("Value error: {0}")]
     ValueError(String),
 
-    #[error("IO error: {0}")]
-    IOError(String),
+    #[error("Unsupported option combination: {0}")]
+    UnsupportedOptionCombination(String),
+
+    #[error("IO error: {}: {message}", .path.display())]
+    IOError { path: PathBuf, message: String },
 }
 
-impl From<csv::Error> for TokenizerError {
-    fn from(error: csv::Error) -> Self {
-        TokenizerError::IOError(error.to_string())
+impl TokenizerError {
+    pub(crate) fn file_not_found<P: AsRef<Path>>(path: P, message: impl ToString) -> Self {
+        TokenizerError::FileNotFound {
+            path: path.as_ref().to_path_buf(),
+            message: message.to_string(),
+        }
+    }
+
+    pub(crate) fn vocabulary_parsing_error<P: AsRef<Path>>(
+        path: P,
+        message: impl ToString,
+    ) -> Self {
+        TokenizerError::VocabularyParsingError {
+            path: path.as_ref().to_path_buf(),
+            message: message.to_string(),
+        }
+    }
+
+    pub(crate) fn io_error<P: AsRef<Path>>(path: P, message: impl ToString) -> Self {
+        TokenizerError::IOError {
+            path: path.as_ref().to_path_buf(),
+            message: message.to_string(),
+        }
+    }
+
+    /// Returns the path of the file that caused the error, if any
+    pub fn path(&self) -> Option<&Path> {
+        match self {
+            TokenizerError::FileNotFound { path, .. }
+            | TokenizerError::VocabularyParsingError { path, .. }
+            | TokenizerError::IOError { path, .. } => Some(path),
+            _ => None,
+        }
     }
 }
@@ -75,8 +75,9 @@ pub mod error;
 pub use tokenizer::base_tokenizer::{
     ConsolidatableTokens, ConsolidatedTokenIterator, Mask, Offset, OffsetSize, Token,
     TokenIdsWithOffsets, TokenIdsWithSpecialTokens, TokenRef, TokenTrait, TokenizedInput,
-    TokensWithOffsets,
+    TokensWithOffsets, VerificationMismatch, VerificationReport, WordSpan,
 };
+pub use tokenizer::PartialDecoding;
 
 #[macro_use]
 extern crate lazy_static;
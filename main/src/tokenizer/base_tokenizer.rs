@@ -12,16 +12,33 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp::min;
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::Range;
 use std::path::Path;
+use std::time::Instant;
 
 use crate::error::TokenizerError;
-use crate::tokenizer::tokenization_utils::{clean_text, lowercase};
+use crate::tokenizer::chat_template::{ChatMessage, ChatTemplate};
+use crate::tokenizer::constants::CLEAN_UP_TOKENIZATION_REPLACEMENTS;
+use crate::tokenizer::partial_decoding::PartialDecoding;
+use crate::tokenizer::tokenization_stats::{
+    record_stats, TokenizationStats, TokenizationStatsCollector,
+};
+use crate::tokenizer::tokenization_utils::{
+    clean_text_with_bidi_control_handling, is_acronym_or_mixed_case, is_emoji_sequence, lowercase,
+    normalize_digits, parse_byte_piece, BpeCache, PatternPreTokenizer,
+};
 use crate::tokenizer::tokenization_utils::{
-    split_on_punct, split_on_special_tokens, strip_accents, tokenize_cjk_chars, truncate_sequences,
-    whitespace_tokenize,
+    complete_utf8_length, decode_utf8_lossy_with_offsets, map_offsets, merge_byte_fallback_pieces,
+    normalize_for_verification, offset_unit_positions, replace_in_pieces, split_in_chunks,
+    split_on_bidi_controls, split_on_emoji_sequences, split_on_punct, split_on_special_tokens,
+    strip_accents, tokenize_cjk_chars, truncate_sequences, unknown_byte_fallback,
+    whitespace_tokenize, without_special_token_splitting,
 };
-use crate::vocab::Vocab;
-use itertools::Itertools;
+use crate::vocab::{SentencePieceModel, Vocab};
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 
@@ -38,6 +55,127 @@ pub enum TruncationStrategy {
     DoNotTruncate,
 }
 
+/// # Moses pre-tokenization variants
+/// Indicates if the text should be split on punctuation following the Moses tokenizer rules
+/// before being passed to the tokenization model (as done by legacy machine translation pipelines)
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum MosesPreTokenization {
+    /// Do not apply Moses pre-tokenization
+    #[default]
+    None,
+    /// Split punctuation following the Moses rules
+    Standard,
+    /// Split punctuation following the Moses rules and split hyphens between alphanumeric
+    /// characters, replacing them by `@-@`
+    AggressiveDashSplits,
+}
+
+/// # Unknown id policy variants
+/// Indicates how token ids that are not found in the vocabulary should be handled when decoding
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum UnknownIdPolicy {
+    /// Return an error (`try_decode` and `try_decode_to_vec`) or panic (other decoding methods)
+    Error,
+    /// Ignore the unknown ids
+    SkipToken,
+    /// Decode the unknown ids as the vocabulary unknown token
+    #[default]
+    ReplaceWithUnk,
+}
+
+/// # Unknown piece policy variants
+/// Indicates how the pieces that are not found in the vocabulary should be handled by the
+/// SentencePiece-based tokenizers when tokenizing. This policy has no effect on the other tokenizers.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum UnknownPiecePolicy {
+    /// Keep the unknown pieces, mapped to the unknown token when converted to ids
+    #[default]
+    ReplaceWithUnk,
+    /// Remove the unknown pieces from the tokenized output
+    SkipToken,
+    /// Decompose the unknown pieces into their UTF-8 bytes (`<0xNN>` pieces) and merge byte pieces
+    /// back into text when decoding. This requires a SentencePiece model trained with byte fallback.
+    ByteFallback,
+}
+
+/// # Offset unit variants
+/// Indicates the unit in which the offsets of the tokens are expressed in the original text
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum OffsetUnit {
+    /// Offsets are character (Unicode scalar value) positions
+    #[default]
+    Char,
+    /// Offsets are positions in the UTF-8 encoding of the text
+    Byte,
+    /// Offsets are positions in UTF-16 code units, as used by JavaScript strings or the Language
+    /// Server Protocol. Characters outside of the Basic Multilingual Plane span 2 code units.
+    Utf16,
+}
+
+/// # Emoji sequence handling variants
+/// Indicates how emoji sequences (including multi-codepoint sequences joined by zero-width joiners,
+/// variation selectors, skin tone modifiers, keycaps and flags) are processed by the BERT-style tokenizers
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum EmojiSequenceHandling {
+    /// Emoji sequences go through the regular cleaning and splitting steps, which may break
+    /// multi-codepoint sequences into several (possibly unknown) pieces
+    #[default]
+    Split,
+    /// Emoji sequences are kept intact as single tokens, mapped to the unknown token if they are
+    /// not found in the vocabulary
+    Keep,
+    /// Emoji sequences are kept intact as single tokens, decomposed into their UTF-8 bytes
+    /// (`<0xNN>` pieces) if they are not found in the vocabulary
+    KeepWithByteFallback,
+}
+
+/// # Bidirectional control character handling variants
+/// Indicates how bidirectional text control characters (e.g. `U+200E` left-to-right mark, `U+202E`
+/// right-to-left override or `U+2067` right-to-left isolate) are processed by the cleaning step
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum BidiControlHandling {
+    /// Bidi control characters are removed with the other control characters. This may change the
+    /// meaning of mixed-direction text.
+    #[default]
+    Strip,
+    /// Bidi control characters are kept in the text. They will be part of the surrounding tokens,
+    /// which may turn these into unknown tokens for vocabularies that do not contain them.
+    Keep,
+    /// Bidi control characters are kept as standalone special tokens. These should be added to the
+    /// vocabulary (e.g. using `add_tokens`) to be mapped to a dedicated id.
+    Special,
+}
+
+/// # Digit normalization variants
+/// Indicates how runs of (ASCII) digits are processed after the pre-tokenization of the text
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub enum DigitNormalization {
+    /// Digits are left unchanged
+    #[default]
+    None,
+    /// Digits are split into single-digit tokens. The word boundary marker preceding a number
+    /// (e.g. `▁` or `Ġ`) is kept as a separate token, while word piece continuation markers (`##`)
+    /// are removed.
+    SplitDigits,
+    /// Runs of digits are replaced by a single placeholder token (e.g. `<num>`) spanning the
+    /// digits in the original text. The placeholder should be part of the vocabulary (for example
+    /// added using `add_tokens`), it is otherwise mapped to the unknown token.
+    Placeholder(String),
+}
+
+/// # Lower casing handling variants
+/// Indicates how tokens are lower-cased by tokenizers created with `lower_case` set to true
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum LowerCaseHandling {
+    /// All tokens are lower-cased
+    #[default]
+    LowerCase,
+    /// Tokens that are fully upper-cased (e.g. "NASA") or have upper-cased characters after their
+    /// first character (e.g. "iPhone") are kept as-is if they are found in the vocabulary. Other
+    /// tokens are lower-cased.
+    PreserveAcronyms,
+}
+
 /// Crate-wide primitive used to store offset positions
 pub type OffsetSize = u32;
 
@@ -120,6 +258,9 @@ impl<'a> TokenRef<'a> {
     /// - text (`&str`): text reference
     /// - offsets (`&[OffsetSize]`): reference positions with respect to the original text
     ///
+    /// # Panics
+    /// If the number of offsets can not be represented as an `OffsetSize`.
+    ///
     /// # Example
     /// ```
     /// use rust_tokenizers::TokenRef;
@@ -134,7 +275,8 @@ impl<'a> TokenRef<'a> {
             text,
             offset: Offset {
                 begin: 0,
-                end: offsets.len() as OffsetSize,
+                end: OffsetSize::try_from(offsets.len())
+                    .expect("token length exceeds the maximum offset position"),
             },
             reference_offsets: offsets,
             mask: Mask::None,
@@ -329,6 +471,9 @@ impl Token {
     /// # Parameters
     /// - text (`String`): text reference
     ///
+    /// # Panics
+    /// If the number of characters of the text can not be represented as an `OffsetSize`.
+    ///
     /// # Example
     /// ```
     /// use rust_tokenizers::Token;
@@ -336,7 +481,8 @@ impl Token {
     /// let token = Token::new(text);
     /// ```
     pub fn new(text: String) -> Token {
-        let text_size: OffsetSize = text.chars().count() as OffsetSize;
+        let text_size = OffsetSize::try_from(text.chars().count())
+            .expect("token length exceeds the maximum offset position");
         Token {
             text,
             offset: Offset {
@@ -394,6 +540,107 @@ pub struct TokenizedInput {
 
     /// Masks tokens providing information on the type of tokens. This vector has the same length as token_ids.
     pub mask: Vec<Mask>,
+
+    /// Token strings of the token ids (including special tokens), populated if the tokenizer is set to return
+    /// them (see `ConfigurableTokenizer::set_return_tokens`). This vector has the same length as token_ids.
+    pub tokens: Option<Vec<String>>,
+}
+
+impl TokenizedInput {
+    /// Returns the spans of the words of the input. Words are the groups of tokens returned by the
+    /// `ConsolidatedTokenIterator` (a token followed by its `Mask::Continuation` sub-tokens),
+    /// special tokens are not part of any word.
+    ///
+    /// # Returns
+    /// - `Vec<WordSpan>` with the range of token indices and the character offsets of each word
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{BertTokenizer, Tokenizer, TruncationStrategy};
+    /// let lower_case = true;
+    /// let strip_accents = true;
+    /// let tokenizer =
+    ///     BertTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
+    ///
+    /// let encoded_input = tokenizer.encode(
+    ///     "Hello, unaffable world!",
+    ///     None,
+    ///     128,
+    ///     &TruncationStrategy::LongestFirst,
+    ///     0,
+    /// );
+    /// for word_span in encoded_input.word_spans() {
+    ///     println!("{:?}: {:?}", word_span.tokens, word_span.offset);
+    /// }
+    /// ```
+    pub fn word_spans(&self) -> Vec<WordSpan> {
+        let tokens = self
+            .mask
+            .iter()
+            .zip(self.token_offsets.iter())
+            .map(|(mask, offset)| EncodedToken {
+                offset: *offset,
+                mask: *mask,
+            })
+            .collect::<Vec<EncodedToken>>();
+        let mut word_spans: Vec<WordSpan> = Vec::new();
+        let mut begin = 0;
+        for word in ConsolidatedTokenIterator::new(&tokens) {
+            let end = begin + word.len();
+            let (start, word) = match word.split_first() {
+                Some((first, sub_tokens)) if first.mask == Mask::Special => (begin + 1, sub_tokens),
+                _ => (begin, word),
+            };
+            if start < end {
+                let offset =
+                    word.iter()
+                        .filter_map(|token| token.offset)
+                        .reduce(|word_offset, offset| {
+                            Offset::new(word_offset.begin, offset.end.max(word_offset.end))
+                        });
+                word_spans.push(WordSpan {
+                    tokens: start..end,
+                    offset,
+                });
+            }
+            begin = end;
+        }
+        word_spans
+    }
+}
+
+/// Mask and offset of a token of a `TokenizedInput`, consolidated into words by
+/// `TokenizedInput::word_spans`
+struct EncodedToken {
+    offset: Option<Offset>,
+    mask: Mask,
+}
+
+impl TokenTrait for EncodedToken {
+    fn offset(&self) -> Option<Offset> {
+        self.offset
+    }
+
+    fn mask(&self) -> Mask {
+        self.mask
+    }
+
+    fn as_str(&self) -> &str {
+        ""
+    }
+}
+
+/// # Word span
+/// Span of a word (a token followed by its `Mask::Continuation` sub-tokens) in an encoded input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WordSpan {
+    /// Range of the indices of the tokens of the word
+    pub tokens: Range<usize>,
+
+    /// Offset of the word in the original text. Words whose tokens can not be related to the
+    /// original text are registered as None.
+    pub offset: Option<Offset>,
 }
 
 /// # Encoded input with special tokens
@@ -422,6 +669,39 @@ pub struct TokenIdsWithSpecialTokens {
     pub mask: Vec<Mask>,
 }
 
+/// # Tokenization stage
+/// Tokens produced by a stage of the tokenization pipeline, returned by `tokenize_debug`
+#[derive(Debug, Clone)]
+pub struct TokenizationStage {
+    /// Name of the pipeline stage (e.g. `whitespace` or `punctuation`)
+    pub name: &'static str,
+
+    /// Tokens after the stage, with their offsets in relation to the original text
+    pub tokens: TokensWithOffsets,
+}
+
+impl fmt::Display for TokenizationStage {
+    /// Formats the stage on a single line (e.g. `punctuation: "Hello" 0..5 | "," 5..6`), suitable
+    /// for logging
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:", self.name)?;
+        for (index, (token, offset)) in self
+            .tokens
+            .tokens
+            .iter()
+            .zip(self.tokens.offsets.iter())
+            .enumerate()
+        {
+            let separator = if index == 0 { " " } else { " | " };
+            match offset {
+                Some(offset) => write!(f, "{separator}{token:?} {}..{}", offset.begin, offset.end)?,
+                None => write!(f, "{separator}{token:?}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
 /// # Tokenized sequence
 /// Intermediate tokenization steps before encoding, addition of special tokens and truncation
 #[derive(Debug, Clone)]
@@ -441,6 +721,95 @@ pub struct TokensWithOffsets {
     pub masks: Vec<Mask>,
 }
 
+impl TokensWithOffsets {
+    /// Keeps the tokens for which the predicate returns true, together with their offsets,
+    /// reference offsets and masks. The predicate is called in order on each token string, offset
+    /// and mask.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{BaseTokenizer, Tokenizer};
+    /// use rust_tokenizers::vocab::BaseVocab;
+    /// use rust_tokenizers::Mask;
+    /// let tokenizer: BaseTokenizer<BaseVocab> =
+    ///     BaseTokenizer::from_file("path/to/vocab/file", false, false).unwrap();
+    ///
+    /// let mut tokens = tokenizer.tokenize_with_offsets("Hello, world!");
+    /// tokens.retain(|_, _, mask| mask != Mask::Punctuation);
+    /// ```
+    pub fn retain<F>(&mut self, mut predicate: F)
+    where
+        F: FnMut(&str, Option<Offset>, Mask) -> bool,
+    {
+        let keep = self
+            .tokens
+            .iter()
+            .zip(self.offsets.iter())
+            .zip(self.masks.iter())
+            .map(|((token, offset), mask)| predicate(token, *offset, *mask))
+            .collect::<Vec<bool>>();
+        let mut keep_iter = keep.iter();
+        self.tokens.retain(|_| *keep_iter.next().unwrap());
+        let mut keep_iter = keep.iter();
+        self.offsets.retain(|_| *keep_iter.next().unwrap());
+        let mut keep_iter = keep.iter();
+        self.reference_offsets
+            .retain(|_| *keep_iter.next().unwrap());
+        let mut keep_iter = keep.iter();
+        self.masks.retain(|_| *keep_iter.next().unwrap());
+    }
+}
+
+/// # Token filter
+/// Post-processing hook applied by a tokenizer to the tokens of each input after tokenization and
+/// before encoding (conversion to ids, truncation and addition of special tokens), added with
+/// `ConfigurableTokenizer::add_token_filter`. Several filters can be chained. Filters may drop, modify or insert tokens, but must keep the
+/// offsets, reference offsets and masks aligned with the tokens (see `TokensWithOffsets::retain`).
+/// Closures taking and returning `TokensWithOffsets` implement this trait.
+pub trait TokenFilter: Send + Sync {
+    /// Returns the filtered tokens of an input
+    fn filter(&self, tokens: TokensWithOffsets) -> TokensWithOffsets;
+}
+
+impl<F> TokenFilter for F
+where
+    F: Fn(TokensWithOffsets) -> TokensWithOffsets + Send + Sync,
+{
+    fn filter(&self, tokens: TokensWithOffsets) -> TokensWithOffsets {
+        self(tokens)
+    }
+}
+
+/// Applies the token filter of a tokenizer (if any) to a tokenized sequence, returning an error if
+/// the filtered tokens, offsets and masks are not aligned
+fn apply_token_filters<T, U>(
+    tokenizer: &U,
+    mut tokens: TokensWithOffsets,
+) -> Result<TokensWithOffsets, TokenizerError>
+where
+    T: Vocab,
+    U: Tokenizer<T> + ?Sized,
+{
+    for token_filter in tokenizer.token_filters() {
+        tokens = token_filter.filter(tokens);
+        let num_tokens = tokens.tokens.len();
+        if tokens.offsets.len() != num_tokens
+            || tokens.reference_offsets.len() != num_tokens
+            || tokens.masks.len() != num_tokens
+        {
+            return Err(TokenizerError::ValueError(format!(
+                "token filter output is not aligned: {} tokens, {} offsets, {} reference offsets and {} masks",
+                num_tokens,
+                tokens.offsets.len(),
+                tokens.reference_offsets.len(),
+                tokens.masks.len()
+            )));
+        }
+    }
+    Ok(tokens)
+}
+
 /// # Encoded sequence
 /// Intermediate tokenization steps before addition of special tokens, after encoding
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -460,6 +829,469 @@ pub struct TokenIdsWithOffsets {
     pub masks: Vec<Mask>,
 }
 
+/// # Round-trip mismatch
+/// Sample of a corpus that could not be recovered after encoding and decoding
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerificationMismatch {
+    /// Position of the sample in the verified corpus
+    pub index: usize,
+
+    /// Original sample text
+    pub input: String,
+
+    /// Text obtained by decoding the encoded sample
+    pub decoded: String,
+}
+
+/// # Round-trip verification report
+/// Summary of a tokenizer self-test over a corpus, returned by `Tokenizer::verify`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VerificationReport {
+    /// Number of samples verified
+    pub num_samples: usize,
+
+    /// Number of samples whose decoded text does not match the input
+    pub num_mismatches: usize,
+
+    /// Total number of tokens produced over the corpus
+    pub num_tokens: usize,
+
+    /// Number of tokens mapped to the unknown token
+    pub num_unknown_tokens: usize,
+
+    /// First mismatching samples (at most `MAX_VERIFICATION_EXAMPLES`)
+    pub examples: Vec<VerificationMismatch>,
+}
+
+impl VerificationReport {
+    /// Maximum number of mismatching samples kept as examples in a report
+    pub const MAX_VERIFICATION_EXAMPLES: usize = 10;
+
+    /// Returns true if all samples of the corpus were recovered
+    pub fn is_ok(&self) -> bool {
+        self.num_mismatches == 0
+    }
+}
+
+/// # Tokenizer description
+/// Structured metadata describing a tokenizer and its configuration, returned by
+/// `Tokenizer::describe`. Options that do not apply to a tokenizer are set to `None`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TokenizerDescription {
+    /// Model family of the tokenizer (e.g. `bert` or `gpt2`)
+    pub model_family: String,
+
+    /// Number of tokens in the vocabulary, including the special tokens
+    pub vocab_size: usize,
+
+    /// Maximum input length of the model, if known. The tokenizers do not store this value, it is
+    /// `None` unless filled by the caller (e.g. from the model configuration)
+    pub max_model_length: Option<usize>,
+
+    /// Special tokens of the vocabulary, mapped to their ids
+    pub special_tokens: BTreeMap<String, i64>,
+
+    /// Flag indicating if the text is lower-cased
+    pub lower_case: Option<bool>,
+
+    /// Flag indicating if accents are stripped from the text
+    pub strip_accents: Option<bool>,
+
+    /// Flag indicating if a space is added at the start of the text
+    pub add_prefix_space: Option<bool>,
+}
+
+impl TokenizerDescription {
+    /// Creates a description for a model family, reading the vocabulary size and special tokens
+    /// from a vocabulary. All options are set to `None`.
+    ///
+    /// # Parameters
+    /// - model_family (`&str`): model family of the tokenizer
+    /// - vocab (`&impl Vocab`): vocabulary of the tokenizer
+    pub fn new<V: Vocab>(model_family: &str, vocab: &V) -> TokenizerDescription {
+        TokenizerDescription {
+            model_family: model_family.to_string(),
+            vocab_size: vocab.values().len(),
+            max_model_length: None,
+            special_tokens: vocab
+                .special_values()
+                .iter()
+                .map(|(token, id)| (token.clone(), *id))
+                .collect(),
+            lower_case: None,
+            strip_accents: None,
+            add_prefix_space: None,
+        }
+    }
+}
+
+/// Maximum number of characters tokenized at once by `Tokenizer::tokenize_with_offsets`. Character
+/// positions are stored as `OffsetSize`: longer inputs are tokenized in chunks.
+pub(crate) const MAX_CHUNK_CHARS: usize = OffsetSize::MAX as usize;
+
+/// Number of preceding tokens decoded with each token by `decode_with_offsets`. This covers the
+/// longest UTF-8 sequence split across byte-level tokens (4 bytes) with some margin.
+const DECODE_CONTEXT_SIZE: usize = 8;
+
+/// Tokenizes a text in chunks of at most `chunk_chars` characters (split at whitespace) with the
+/// chunk tokenization function provided and stitches the results, shifting the offsets of each
+/// chunk by its position in the text. Positions that can not be represented as an `OffsetSize` are
+/// registered as `None`. Tokens inserted by the tokenizer (special tokens without reference offsets)
+/// are only kept for the first chunk. Fails with a `ValueError` if `chunk_chars` is 0.
+pub(crate) fn tokenize_in_chunks<F>(
+    text: &str,
+    chunk_chars: usize,
+    tokenize_chunk: F,
+) -> Result<TokensWithOffsets, TokenizerError>
+where
+    F: Fn(&str) -> TokensWithOffsets,
+{
+    let mut output = TokensWithOffsets {
+        tokens: vec![],
+        offsets: vec![],
+        reference_offsets: vec![],
+        masks: vec![],
+    };
+    for (chunk_index, (chunk_start, chunk)) in
+        split_in_chunks(text, chunk_chars)?.into_iter().enumerate()
+    {
+        append_shifted_tokens(
+            &mut output,
+            tokenize_chunk(chunk),
+            chunk_start,
+            chunk_index == 0,
+        );
+    }
+    Ok(output)
+}
+
+/// Appends tokens to a tokenized sequence, shifting their offsets by `start` characters. Positions
+/// that can not be represented as an `OffsetSize` are registered as `None`. Tokens inserted by the
+/// tokenizer (special tokens without reference offsets) are dropped unless `keep_inserted_tokens`
+/// is true.
+fn append_shifted_tokens(
+    output: &mut TokensWithOffsets,
+    tokens: TokensWithOffsets,
+    start: usize,
+    keep_inserted_tokens: bool,
+) {
+    let shift_position =
+        |position: OffsetSize| OffsetSize::try_from(position as usize + start).ok();
+    for (((token, offset), reference_offsets), mask) in tokens
+        .tokens
+        .into_iter()
+        .zip(tokens.offsets)
+        .zip(tokens.reference_offsets)
+        .zip(tokens.masks)
+    {
+        if !keep_inserted_tokens && mask == Mask::Special && reference_offsets.is_empty() {
+            continue;
+        }
+        let (offset, reference_offsets) = map_offsets(offset, reference_offsets, shift_position);
+        output.tokens.push(token);
+        output.offsets.push(offset);
+        output.reference_offsets.push(reference_offsets);
+        output.masks.push(mask);
+    }
+}
+
+/// Tokenizes a text with the tokenization function provided (applied to the text as a single
+/// initial token), followed by the digit normalization of the resulting tokens. Shared by
+/// `Tokenizer::tokenize_with_offsets` and the tokenization methods taking per-call options.
+pub(crate) fn tokenize_text_with<F>(
+    text: &str,
+    digit_normalization: &DigitNormalization,
+    tokenize_fn: &F,
+) -> TokensWithOffsets
+where
+    F: Fn(TokenRef) -> Vec<Token>,
+{
+    if text.trim().is_empty() {
+        return TokensWithOffsets {
+            tokens: vec![],
+            offsets: vec![],
+            reference_offsets: vec![],
+            masks: vec![],
+        };
+    }
+    let num_chars = text.chars().count();
+    if num_chars > MAX_CHUNK_CHARS {
+        // MAX_CHUNK_CHARS is positive: the chunked tokenization can not fail
+        return tokenize_in_chunks(text, MAX_CHUNK_CHARS, |chunk| {
+            tokenize_text_with(chunk, digit_normalization, tokenize_fn)
+        })
+        .unwrap();
+    }
+    let initial_offsets = (0..num_chars as OffsetSize).collect::<Vec<OffsetSize>>();
+    let initial_token: TokenRef<'_> = TokenRef::new(text, &initial_offsets);
+    let tokens = normalize_digits(tokenize_fn(initial_token), digit_normalization);
+    tokens_with_offsets(tokens)
+}
+
+/// Names of the stages of the `BaseTokenizer` pipeline, as returned by `tokenize_debug`
+pub(crate) const BASE_TOKENIZATION_STAGES: [&str; 4] =
+    ["whitespace", "special_split", "punctuation", "cleaning"];
+
+/// Tokenizes a text with a function returning the tokens produced after each stage of a
+/// tokenization pipeline. A `digit_normalization` stage is appended if digits are normalized.
+pub(crate) fn tokenize_text_to_stages<F>(
+    text: &str,
+    digit_normalization: &DigitNormalization,
+    stages_fn: &F,
+) -> Vec<TokenizationStage>
+where
+    F: Fn(TokenRef) -> Vec<(&'static str, Vec<Token>)>,
+{
+    let initial_offsets = (0..text.chars().count() as OffsetSize).collect::<Vec<OffsetSize>>();
+    let mut stages = stages_fn(TokenRef::new(text, &initial_offsets));
+    if *digit_normalization != DigitNormalization::None {
+        if let Some((_, tokens)) = stages.last() {
+            let tokens = normalize_digits(tokens.clone(), digit_normalization);
+            stages.push(("digit_normalization", tokens));
+        }
+    }
+    stages
+        .into_iter()
+        .map(|(name, tokens)| TokenizationStage {
+            name,
+            tokens: tokens_with_offsets(tokens),
+        })
+        .collect()
+}
+
+/// Collects a sequence of tokens into a `TokensWithOffsets`, computing the offset of each token
+/// from its reference offsets.
+pub(crate) fn tokens_with_offsets(tokens: Vec<Token>) -> TokensWithOffsets {
+    let length = tokens.len();
+    let mut texts = Vec::with_capacity(length);
+    let mut offsets = Vec::with_capacity(length);
+    let mut original_positions = Vec::with_capacity(length);
+    let mut masks = Vec::with_capacity(length);
+
+    for token in tokens {
+        texts.push(token.text);
+        offsets.push(if !token.reference_offsets.is_empty() {
+            Some(Offset {
+                begin: *token.reference_offsets.first().unwrap(),
+                end: *token.reference_offsets.last().unwrap() + 1,
+            })
+        } else {
+            None
+        });
+        original_positions.push(token.reference_offsets);
+        masks.push(token.mask);
+    }
+    TokensWithOffsets {
+        tokens: texts,
+        offsets,
+        reference_offsets: original_positions,
+        masks,
+    }
+}
+
+/// Concatenates a pair of token sequences without adding special tokens (the tokens of the second
+/// sequence have a segment id of 1). Used by the default implementation of
+/// `Tokenizer::build_input_with_special_tokens` and by the encoding methods skipping special tokens.
+pub(crate) fn concatenate_token_ids(
+    mut tokens_ids_with_offsets_1: TokenIdsWithOffsets,
+    tokens_ids_with_offsets_2: Option<TokenIdsWithOffsets>,
+) -> TokenIdsWithSpecialTokens {
+    let mut token_segment_ids: Vec<i8> = vec![0; tokens_ids_with_offsets_1.ids.len()];
+    let mut special_tokens_mask: Vec<i8> = vec![0; tokens_ids_with_offsets_1.ids.len()];
+    if let Some(tokens_ids_with_offsets_2_value) = tokens_ids_with_offsets_2 {
+        let length = tokens_ids_with_offsets_2_value.ids.len();
+        token_segment_ids.extend(vec![1; length]);
+        special_tokens_mask.extend(vec![0; length]);
+        tokens_ids_with_offsets_1
+            .ids
+            .extend(tokens_ids_with_offsets_2_value.ids);
+        tokens_ids_with_offsets_1
+            .offsets
+            .extend(tokens_ids_with_offsets_2_value.offsets);
+        tokens_ids_with_offsets_1
+            .reference_offsets
+            .extend(tokens_ids_with_offsets_2_value.reference_offsets);
+        tokens_ids_with_offsets_1
+            .masks
+            .extend(tokens_ids_with_offsets_2_value.masks);
+    };
+
+    TokenIdsWithSpecialTokens {
+        token_ids: tokens_ids_with_offsets_1.ids,
+        segment_ids: token_segment_ids,
+        special_tokens_mask,
+        token_offsets: tokens_ids_with_offsets_1.offsets,
+        reference_offsets: tokens_ids_with_offsets_1.reference_offsets,
+        mask: tokens_ids_with_offsets_1.masks,
+    }
+}
+
+/// Encodes tokenized sequences (conversion to ids, truncation and addition of special tokens),
+/// shared by the `encode` and `encode_bytes` methods of the `Tokenizer` trait. The token filter of
+/// the tokenizer is applied to the sequences before their encoding. If `add_special_tokens` is
+/// false, the sequences are concatenated without the special tokens of the tokenizer.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn encode_tokens_with_offsets<T, U>(
+    tokenizer: &U,
+    tokens_1: TokensWithOffsets,
+    tokens_2: Option<TokensWithOffsets>,
+    max_len: usize,
+    truncation_strategy: &TruncationStrategy,
+    stride: usize,
+    add_special_tokens: bool,
+) -> Result<TokenizedInput, TokenizerError>
+where
+    T: Vocab,
+    U: Tokenizer<T> + ?Sized,
+{
+    let tokens_1 = apply_token_filters(tokenizer, tokens_1)?;
+    let tokens_2 = tokens_2
+        .map(|tokens| apply_token_filters(tokenizer, tokens))
+        .transpose()?;
+    let token_ids_1 = tokenizer.convert_tokens_to_ids(&tokens_1.tokens);
+    let len_1 = token_ids_1.len();
+    let token_ids_with_offsets_1 = TokenIdsWithOffsets {
+        ids: token_ids_1,
+        offsets: tokens_1.offsets,
+        reference_offsets: tokens_1.reference_offsets,
+        masks: tokens_1.masks,
+    };
+    let (token_ids_with_offsets_2, len_2) = {
+        if let Some(tokens_2) = tokens_2 {
+            let token_ids_2: Vec<i64> = tokenizer.convert_tokens_to_ids(&tokens_2.tokens);
+            let len_2 = token_ids_2.len();
+            (
+                Some(TokenIdsWithOffsets {
+                    ids: token_ids_2,
+                    offsets: tokens_2.offsets,
+                    reference_offsets: tokens_2.reference_offsets,
+                    masks: tokens_2.masks,
+                }),
+                len_2,
+            )
+        } else {
+            (None, 0)
+        }
+    };
+    let build_input = |tokens_1: TokenIdsWithOffsets, tokens_2: Option<TokenIdsWithOffsets>| {
+        if add_special_tokens {
+            tokenizer.build_input_with_special_tokens(tokens_1, tokens_2)
+        } else {
+            concatenate_token_ids(tokens_1, tokens_2)
+        }
+    };
+    let additional_tokens = build_input(
+        TokenIdsWithOffsets {
+            ids: vec![],
+            offsets: vec![],
+            reference_offsets: vec![],
+            masks: vec![],
+        },
+        if token_ids_with_offsets_2.is_some() {
+            Some(TokenIdsWithOffsets {
+                ids: vec![],
+                offsets: vec![],
+                reference_offsets: vec![],
+                masks: vec![],
+            })
+        } else {
+            None
+        },
+    );
+    let total_len = len_1 + len_2 + additional_tokens.token_ids.len();
+    // Special tokens are not truncated, the input tokens are all removed if `max_len` is lower than
+    // the number of special tokens
+    let num_truncated_tokens = min(total_len.saturating_sub(max_len), len_1 + len_2);
+    let (
+        token_ids_with_offsets_1,
+        token_ids_with_offsets_2,
+        overflowing_tokens,
+        _overflowing_offsets,
+    ) = truncate_sequences(
+        token_ids_with_offsets_1,
+        token_ids_with_offsets_2,
+        num_truncated_tokens,
+        truncation_strategy,
+        stride,
+    )?;
+
+    let merged_tokenized_input = build_input(token_ids_with_offsets_1, token_ids_with_offsets_2);
+    let tokens = tokenizer.return_tokens().then(|| {
+        merged_tokenized_input
+            .token_ids
+            .iter()
+            .map(|id| tokenizer.vocab().id_to_token(id))
+            .collect()
+    });
+
+    Ok(TokenizedInput {
+        token_ids: merged_tokenized_input.token_ids,
+        segment_ids: merged_tokenized_input.segment_ids,
+        special_tokens_mask: merged_tokenized_input.special_tokens_mask,
+        overflowing_tokens,
+        num_truncated_tokens,
+        token_offsets: merged_tokenized_input.token_offsets,
+        reference_offsets: merged_tokenized_input.reference_offsets,
+        mask: merged_tokenized_input.mask,
+        tokens,
+    })
+}
+
+/// # Tokenizer options
+/// Options shared by the tokenizers of this crate, applied by the provided methods of the
+/// `Tokenizer` trait. Tokenizers expose their options with `Tokenizer::options` (tokenizers that do
+/// not override this method use the default options) and allow updating them by implementing
+/// `ConfigurableTokenizer`.
+#[derive(Default)]
+pub struct TokenizerOptions {
+    /// Policy applied to ids not found in the vocabulary when decoding
+    pub unknown_id_policy: UnknownIdPolicy,
+
+    /// Normalization applied to the digits of the pre-tokenized text
+    pub digit_normalization: DigitNormalization,
+
+    /// Flag indicating if the encoding output carries the token string of each token id
+    pub return_tokens: bool,
+
+    /// Policy applied by the SentencePiece-based tokenizers to pieces not found in the vocabulary
+    pub unknown_piece_policy: UnknownPiecePolicy,
+
+    /// Chain of filters applied, in order, to the tokens of each input before their encoding
+    pub token_filters: Vec<Box<dyn TokenFilter>>,
+
+    /// Collector of the tokenization statistics, `None` if statistics are not collected
+    pub stats_collector: Option<TokenizationStatsCollector>,
+}
+
+impl TokenizerOptions {
+    /// Creates a new set of default options
+    pub const fn new() -> TokenizerOptions {
+        TokenizerOptions {
+            unknown_id_policy: UnknownIdPolicy::ReplaceWithUnk,
+            digit_normalization: DigitNormalization::None,
+            return_tokens: false,
+            unknown_piece_policy: UnknownPiecePolicy::ReplaceWithUnk,
+            token_filters: Vec::new(),
+            stats_collector: None,
+        }
+    }
+}
+
+impl fmt::Debug for TokenizerOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TokenizerOptions")
+            .field("unknown_id_policy", &self.unknown_id_policy)
+            .field("digit_normalization", &self.digit_normalization)
+            .field("return_tokens", &self.return_tokens)
+            .field("unknown_piece_policy", &self.unknown_piece_policy)
+            .field("token_filters", &self.token_filters.len())
+            .field("stats_collector", &self.stats_collector)
+            .finish()
+    }
+}
+
+static DEFAULT_TOKENIZER_OPTIONS: TokenizerOptions = TokenizerOptions::new();
+
 /// # Base trait for tokenizers
 pub trait Tokenizer<T: Vocab> {
     /// returns a reference to the tokenizer vocabulary
@@ -468,89 +1300,260 @@ pub trait Tokenizer<T: Vocab> {
     /// returns a mutable reference to the tokenizer vocabulary
     fn vocab_mut(&mut self) -> &mut T;
 
-    /// Tokenize a string, returns a vector of tokens as strings.
-    /// Use `tokenize_with_offsets` or `tokenize_to_tokens` to return offset information.
-    ///
-    /// # Parameters
-    /// - text : text (string-like) to tokenize
+    /// returns the options of the tokenizer (see `TokenizerOptions`)
+    fn options(&self) -> &TokenizerOptions {
+        &DEFAULT_TOKENIZER_OPTIONS
+    }
+
+    /// returns the policy applied to ids not found in the vocabulary when decoding
+    fn unknown_id_policy(&self) -> UnknownIdPolicy {
+        self.options().unknown_id_policy
+    }
+
+    /// returns the policy applied by the SentencePiece-based tokenizers to pieces not found in the
+    /// vocabulary when tokenizing
+    fn unknown_piece_policy(&self) -> UnknownPiecePolicy {
+        self.options().unknown_piece_policy
+    }
+
+    /// returns the normalization applied to the digits of the pre-tokenized text
+    fn digit_normalization(&self) -> &DigitNormalization {
+        &self.options().digit_normalization
+    }
+
+    /// returns `true` if the encoding output carries the token string of each token id
+    fn return_tokens(&self) -> bool {
+        self.options().return_tokens
+    }
+
+    /// returns the chain of filters applied, in order, to the tokens before their encoding
+    fn token_filters(&self) -> &[Box<dyn TokenFilter>] {
+        &self.options().token_filters
+    }
+
+    /// returns the statistics collector updated by the `encode_list` and `encode_pair_list` methods,
+    /// if the collection is enabled
+    fn stats_collector(&self) -> Option<&TokenizationStatsCollector> {
+        self.options().stats_collector.as_ref()
+    }
+
+    /// returns the BPE cache of the tokenizer, if any
+    fn bpe_cache(&self) -> Option<&BpeCache> {
+        None
+    }
+
+    /// Returns the statistics aggregated over the `encode_list` and `encode_pair_list` calls since
+    /// the collection was enabled with `ConfigurableTokenizer::set_collect_stats` (or the last
+    /// `ConfigurableTokenizer::reset_stats`), or `None` if the collection is disabled.
     ///
     /// # Returns
-    /// `Vec<String>` containing the tokens string representation
+    /// `Option<TokenizationStats>` with the aggregated statistics
     ///
     /// # Example
     ///
     /// ```no_run
-    /// use rust_tokenizers::tokenizer::{BaseTokenizer, Tokenizer};
+    /// use rust_tokenizers::tokenizer::{
+    ///     BaseTokenizer, ConfigurableTokenizer, Tokenizer, TruncationStrategy,
+    /// };
     /// use rust_tokenizers::vocab::BaseVocab;
     /// let strip_accents = false;
     /// let lower_case = false;
-    /// let tokenizer: BaseTokenizer<BaseVocab> =
+    /// let mut tokenizer: BaseTokenizer<BaseVocab> =
     ///     BaseTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
     ///
-    /// let text = "Hello, world!";
-    /// let tokens = tokenizer.tokenize(text);
+    /// tokenizer.set_collect_stats(true);
+    /// let _ = tokenizer.encode_list(&["Hello, world!"], 128, &TruncationStrategy::LongestFirst, 0);
+    /// let stats = tokenizer.stats().unwrap();
+    /// println!(
+    ///     "{} tokens per input, unknown token rate: {}",
+    ///     stats.average_tokens_per_input(),
+    ///     stats.unknown_token_rate()
+    /// );
     /// ```
-    fn tokenize(&self, text: &str) -> Vec<String> {
-        self.tokenize_with_offsets(text).tokens
+    fn stats(&self) -> Option<TokenizationStats> {
+        let mut stats = self.stats_collector()?.snapshot();
+        if let Some(cache) = self.bpe_cache() {
+            stats.cache_lookups = Some(cache.num_lookups());
+            stats.cache_hits = Some(cache.num_hits());
+        }
+        Some(stats)
     }
 
-    /// Tokenize a string, returning tokens with offset information
-    ///
-    /// # Parameters
-    /// - text : text (string-like) to tokenize
+    /// Returns structured metadata describing the tokenizer: model family, vocabulary size, special
+    /// tokens and options. Tokenizers defined outside of this crate are described as `custom`.
     ///
     /// # Returns
-    /// `TokensWithOffsets` with the tokens and their offset information
+    /// `TokenizerDescription` with the tokenizer metadata
     ///
     /// # Example
     ///
     /// ```no_run
-    /// use rust_tokenizers::tokenizer::{BaseTokenizer, Tokenizer};
-    /// use rust_tokenizers::vocab::BaseVocab;
+    /// use rust_tokenizers::tokenizer::{BertTokenizer, Tokenizer};
     /// let strip_accents = false;
-    /// let lower_case = false;
-    /// let tokenizer: BaseTokenizer<BaseVocab> =
+    /// let lower_case = true;
+    /// let tokenizer =
+    ///     BertTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
+    ///
+    /// let description = tokenizer.describe();
+    /// println!(
+    ///     "{} tokenizer with {} tokens",
+    ///     description.model_family, description.vocab_size
+    /// );
+    /// ```
+    fn describe(&self) -> TokenizerDescription {
+        TokenizerDescription::new("custom", self.vocab())
+    }
+
+    /// Tokenize a string, returns a vector of tokens as strings.
+    /// Use `tokenize_with_offsets` or `tokenize_to_tokens` to return offset information.
+    /// Empty and whitespace-only inputs return an empty vector.
+    ///
+    /// # Parameters
+    /// - text : text (string-like) to tokenize
+    ///
+    /// # Returns
+    /// `Vec<String>` containing the tokens string representation
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{BaseTokenizer, Tokenizer};
+    /// use rust_tokenizers::vocab::BaseVocab;
+    /// let strip_accents = false;
+    /// let lower_case = false;
+    /// let tokenizer: BaseTokenizer<BaseVocab> =
+    ///     BaseTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
+    ///
+    /// let text = "Hello, world!";
+    /// let tokens = tokenizer.tokenize(text);
+    /// ```
+    fn tokenize(&self, text: &str) -> Vec<String> {
+        self.tokenize_with_offsets(text).tokens
+    }
+
+    /// Tokenize a string, returning tokens with offset information. Character positions are
+    /// stored as `OffsetSize`: inputs longer than `OffsetSize::MAX` characters are tokenized in
+    /// chunks (split at whitespace) and tokens located past the maximum position have a `None`
+    /// offset and empty reference offsets.
+    ///
+    /// # Parameters
+    /// - text : text (string-like) to tokenize
+    ///
+    /// # Returns
+    /// `TokensWithOffsets` with the tokens and their offset information
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{BaseTokenizer, Tokenizer};
+    /// use rust_tokenizers::vocab::BaseVocab;
+    /// let strip_accents = false;
+    /// let lower_case = false;
+    /// let tokenizer: BaseTokenizer<BaseVocab> =
     ///     BaseTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
     ///
     /// let text = "Hello, world!";
     /// let tokens = tokenizer.tokenize_with_offsets(text);
     /// ```
     fn tokenize_with_offsets(&self, text: &str) -> TokensWithOffsets {
-        if text.trim().is_empty() {
-            return TokensWithOffsets {
-                tokens: vec![],
-                offsets: vec![],
-                reference_offsets: vec![],
-                masks: vec![],
-            };
+        tokenize_text_with(text, self.digit_normalization(), &|token| {
+            self.tokenize_to_tokens(token)
+        })
+    }
+
+    /// Tokenize raw bytes that may not be valid UTF-8, returning tokens with offset information.
+    /// Invalid UTF-8 sequences are replaced by `U+FFFD` before tokenization, following
+    /// `String::from_utf8_lossy`. In contrast with `tokenize_with_offsets`, the offsets and
+    /// reference offsets returned are byte positions in the original buffer.
+    ///
+    /// # Parameters
+    /// - bytes (`&[u8]`): text to tokenize
+    ///
+    /// # Returns
+    /// `TokensWithOffsets` with the tokens and their offset information (in bytes)
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{BaseTokenizer, Tokenizer};
+    /// use rust_tokenizers::vocab::BaseVocab;
+    /// let strip_accents = false;
+    /// let lower_case = false;
+    /// let tokenizer: BaseTokenizer<BaseVocab> =
+    ///     BaseTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
+    ///
+    /// let bytes = b"Hello, \xF0\x90\x80world!";
+    /// let tokens = tokenizer.tokenize_bytes(bytes);
+    /// ```
+    fn tokenize_bytes(&self, bytes: &[u8]) -> TokensWithOffsets {
+        let (text, byte_positions) = decode_utf8_lossy_with_offsets(bytes);
+        let tokens = self.tokenize_with_offsets(&text);
+        let byte_position = |position: OffsetSize| {
+            byte_positions
+                .get(position as usize)
+                .and_then(|position| OffsetSize::try_from(*position).ok())
+        };
+        let (offsets, reference_offsets) = tokens
+            .offsets
+            .into_iter()
+            .zip(tokens.reference_offsets)
+            .map(|(offset, reference_offsets)| {
+                map_offsets(offset, reference_offsets, byte_position)
+            })
+            .unzip();
+        TokensWithOffsets {
+            tokens: tokens.tokens,
+            offsets,
+            reference_offsets,
+            masks: tokens.masks,
         }
-        let initial_offsets = (0..text.chars().count() as OffsetSize).collect::<Vec<OffsetSize>>();
-        let initial_token: TokenRef<'_> = TokenRef::new(text, &initial_offsets);
-        let tokens = self.tokenize_to_tokens(initial_token);
-        let length = tokens.len();
-        let mut texts = Vec::with_capacity(length);
-        let mut offsets = Vec::with_capacity(length);
-        let mut original_positions = Vec::with_capacity(length);
-        let mut masks = Vec::with_capacity(length);
-
-        for token in tokens {
-            texts.push(token.text);
-            offsets.push(if !token.reference_offsets.is_empty() {
-                Some(Offset {
-                    begin: *token.reference_offsets.first().unwrap(),
-                    end: *token.reference_offsets.last().unwrap() + 1,
-                })
-            } else {
-                None
-            });
-            original_positions.push(token.reference_offsets);
-            masks.push(token.mask);
+    }
+
+    /// Tokenize a string, returning tokens with offset information expressed in the given unit.
+    /// This is equivalent to `tokenize_with_offsets` for `OffsetUnit::Char`.
+    ///
+    /// # Parameters
+    /// - text (`&str`): text to tokenize
+    /// - offset_unit (`OffsetUnit`): unit of the offsets and reference offsets returned
+    ///
+    /// # Returns
+    /// `TokensWithOffsets` with the tokens and their offset information (in `offset_unit`)
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{BaseTokenizer, OffsetUnit, Tokenizer};
+    /// use rust_tokenizers::vocab::BaseVocab;
+    /// let strip_accents = false;
+    /// let lower_case = false;
+    /// let tokenizer: BaseTokenizer<BaseVocab> =
+    ///     BaseTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
+    ///
+    /// let text = "Hello, 🌍 world!";
+    /// let tokens = tokenizer.tokenize_with_offset_unit(text, OffsetUnit::Utf16);
+    /// ```
+    fn tokenize_with_offset_unit(&self, text: &str, offset_unit: OffsetUnit) -> TokensWithOffsets {
+        let tokens = self.tokenize_with_offsets(text);
+        if offset_unit == OffsetUnit::Char {
+            return tokens;
         }
+        let positions = offset_unit_positions(text, offset_unit);
+        let position = |position: OffsetSize| {
+            positions
+                .get(position as usize)
+                .and_then(|position| OffsetSize::try_from(*position).ok())
+        };
+        let (offsets, reference_offsets) = tokens
+            .offsets
+            .into_iter()
+            .zip(tokens.reference_offsets)
+            .map(|(offset, reference_offsets)| map_offsets(offset, reference_offsets, position))
+            .unzip();
         TokensWithOffsets {
-            tokens: texts,
+            tokens: tokens.tokens,
             offsets,
-            reference_offsets: original_positions,
-            masks,
+            reference_offsets,
+            masks: tokens.masks,
         }
     }
 
@@ -694,7 +1697,14 @@ pub trait Tokenizer<T: Vocab> {
     ///
     /// # Returns
     /// `TokenizedInput` containing the encoding output (token indices, token types, segment ids,
-    /// ovrflowing tokens and special token mask)
+    /// ovrflowing tokens and special token mask). Empty and whitespace-only inputs are encoded as the
+    /// special tokens only. Special tokens are never truncated: if `max_len` is lower than the number
+    /// of special tokens added by the tokenizer (e.g. `max_len == 0`), all input tokens are truncated
+    /// and the output only contains the special tokens.
+    ///
+    /// # Panics
+    /// If the input can not be truncated following the `TruncationStrategy` provided. Use `try_encode`
+    /// for a non-panicking alternative.
     ///
     /// # Example
     ///
@@ -724,101 +1734,30 @@ pub trait Tokenizer<T: Vocab> {
         truncation_strategy: &TruncationStrategy,
         stride: usize,
     ) -> TokenizedInput {
-        let tokens = self.tokenize_with_offsets(text_1);
-        let token_ids_1 = self.convert_tokens_to_ids(&tokens.tokens);
-        let len_1 = token_ids_1.len();
-        let token_ids_with_offsets_1 = TokenIdsWithOffsets {
-            ids: token_ids_1,
-            offsets: tokens.offsets,
-            reference_offsets: tokens.reference_offsets,
-            masks: tokens.masks,
-        };
-        let (token_ids_with_offsets_2, len_2) = {
-            if let Some(text) = text_2 {
-                let tokens_2 = self.tokenize_with_offsets(text);
-                let token_ids_2: Vec<i64> = self.convert_tokens_to_ids(&tokens_2.tokens);
-                let len_2 = token_ids_2.len();
-                (
-                    Some(TokenIdsWithOffsets {
-                        ids: token_ids_2,
-                        offsets: tokens_2.offsets,
-                        reference_offsets: tokens_2.reference_offsets,
-                        masks: tokens_2.masks,
-                    }),
-                    len_2,
-                )
-            } else {
-                (None, 0)
-            }
-        };
-        let additional_tokens = self.build_input_with_special_tokens(
-            TokenIdsWithOffsets {
-                ids: vec![],
-                offsets: vec![],
-                reference_offsets: vec![],
-                masks: vec![],
-            },
-            if token_ids_with_offsets_2.is_some() {
-                Some(TokenIdsWithOffsets {
-                    ids: vec![],
-                    offsets: vec![],
-                    reference_offsets: vec![],
-                    masks: vec![],
-                })
-            } else {
-                None
-            },
-        );
-        let total_len = len_1 + len_2 + additional_tokens.token_ids.len();
-        let num_truncated_tokens = if total_len > max_len {
-            total_len - max_len
-        } else {
-            0
-        };
-        let (
-            token_ids_with_offsets_1,
-            token_ids_with_offsets_2,
-            overflowing_tokens,
-            _overflowing_offsets,
-        ) = truncate_sequences(
-            token_ids_with_offsets_1,
-            token_ids_with_offsets_2,
-            num_truncated_tokens,
-            truncation_strategy,
-            stride,
-        )
-        .unwrap();
-
-        let merged_tokenized_input = self
-            .build_input_with_special_tokens(token_ids_with_offsets_1, token_ids_with_offsets_2);
-
-        TokenizedInput {
-            token_ids: merged_tokenized_input.token_ids,
-            segment_ids: merged_tokenized_input.segment_ids,
-            special_tokens_mask: merged_tokenized_input.special_tokens_mask,
-            overflowing_tokens,
-            num_truncated_tokens,
-            token_offsets: merged_tokenized_input.token_offsets,
-            reference_offsets: merged_tokenized_input.reference_offsets,
-            mask: merged_tokenized_input.mask,
-        }
+        self.try_encode(text_1, text_2, max_len, truncation_strategy, stride)
+            .unwrap()
     }
 
-    /// Encode a sequence of string-like texts (tokenization followed by encoding). Not that in contrast
-    /// with `encode` optional second text, each text provided is encoded independently.
+    /// Encode a string-like (tokenization followed by encoding), returning an error instead of panicking
+    /// if the input can not be truncated following the `TruncationStrategy` provided (for example for an
+    /// input exceeding `max_len` with `TruncationStrategy::DoNotTruncate`).
     ///
     /// # Parameters
-    /// - text_list: sequence of input text (`&str`) to encode
-    /// combined into a single encoding by using the `build_input_with_special_tokens` method.
+    /// - text_1: input text (string-like) to encode
+    /// - text_2: optional additional input text (string-like) to encode. When provided, both texts are
+    ///   combined into a single encoding by using the `build_input_with_special_tokens` method.
     /// - max_len (`usize`): maximum combined sequence length. If the combined encoding would exceed this
-    /// max_len, the encoding is truncated following the `TruncationStrategy` provided.
+    ///   max_len, the encoding is truncated following the `TruncationStrategy` provided. Special tokens
+    ///   are never truncated: if `max_len` is lower than the number of special tokens added by the
+    ///   tokenizer (e.g. `max_len == 0`), all input tokens are truncated and the output only contains
+    ///   the special tokens.
     /// - truncation_strategy (`&TruncationStrategy`): strategy to follow for the truncation, if required
     /// - stride (`usize`): amount of tokens to shift the input by if truncation is required
-    /// (allowing for the generation of overlapping sequences with overflowing tokens)
+    ///   (allowing for the generation of overlapping sequences with overflowing tokens)
     ///
     /// # Returns
-    /// `Vec<TokenizedInput>` containing the encoding output (token indices, token types, segment ids,
-    /// ovrflowing tokens and special token mask) for each provided text
+    /// `Result<TokenizedInput, TokenizerError>` containing the encoding output (token indices, token types,
+    /// segment ids, ovrflowing tokens and special token mask)
     ///
     /// # Example
     ///
@@ -832,46 +1771,72 @@ pub trait Tokenizer<T: Vocab> {
     ///
     /// let text_1 = "Hello, world!";
     /// let text_2 = "How is it going?";
-    /// let text_3 = "Very well thank you.";
-    /// let encoded_input = tokenizer.encode_list(
-    ///     &[text_1, text_2, text_3],
+    /// let encoded_input = tokenizer.try_encode(
+    ///     text_1,
+    ///     Some(text_2),
     ///     5,
-    ///     &TruncationStrategy::LongestFirst,
-    ///     2,
+    ///     &TruncationStrategy::DoNotTruncate,
+    ///     0,
     /// );
+    /// assert!(encoded_input.is_err());
     /// ```
-    fn encode_list<S>(
+    fn try_encode(
         &self,
-        text_list: &[S],
+        text_1: &str,
+        text_2: Option<&str>,
         max_len: usize,
         truncation_strategy: &TruncationStrategy,
         stride: usize,
-    ) -> Vec<TokenizedInput>
-    where
-        S: AsRef<str>,
-    {
-        text_list
-            .as_ref()
-            .iter()
-            .map(|text| self.encode(text.as_ref(), None, max_len, truncation_strategy, stride))
-            .collect()
+    ) -> Result<TokenizedInput, TokenizerError> {
+        self.validate_input(text_1)?;
+        if let Some(text_2) = text_2 {
+            self.validate_input(text_2)?;
+        }
+        let tokens_1 = self.tokenize_with_offsets(text_1);
+        let tokens_2 = text_2.map(|text| self.tokenize_with_offsets(text));
+        encode_tokens_with_offsets(
+            self,
+            tokens_1,
+            tokens_2,
+            max_len,
+            truncation_strategy,
+            stride,
+            true,
+        )
     }
 
-    /// Encode a sequence of string-like text pairs (tokenization followed by encoding). This combines
-    /// with `encode` with the list processing of `encode_list`.
+    /// Check that a text can be encoded by the tokenizer, before its tokenization. This is called by
+    /// `try_encode`, `encode_plain`, `encode_bytes` and `encode_with_offset_unit` for each input text
+    /// and accepts any text by default. Tokenizers expecting a specific input format (e.g. a language code prefix)
+    /// override it to return an error for invalid inputs.
     ///
     /// # Parameters
-    /// - text_list: sequence of input text (`&str`) to encode
-    /// combined into a single encoding by using the `build_input_with_special_tokens` method.
+    /// - text (`&str`): input text to validate
+    ///
+    /// # Returns
+    /// `Result<(), TokenizerError>` with an error if the text can not be encoded by the tokenizer
+    fn validate_input(&self, _text: &str) -> Result<(), TokenizerError> {
+        Ok(())
+    }
+
+    /// Encode a string-like (tokenization followed by encoding) without adding the special tokens of
+    /// the tokenizer (e.g. CLS/SEP or BOS/EOS). This returns the raw token ids of the input, for
+    /// example to compute embeddings or to continue a sequence with a language model. When a second
+    /// text is provided, both sequences are concatenated (with segment ids of 0 and 1).
+    ///
+    /// # Parameters
+    /// - text_1: input text (string-like) to encode
+    /// - text_2: optional additional input text (string-like) to encode
     /// - max_len (`usize`): maximum combined sequence length. If the combined encoding would exceed this
-    /// max_len, the encoding is truncated following the `TruncationStrategy` provided.
+    ///   max_len, the encoding is truncated following the `TruncationStrategy` provided.
     /// - truncation_strategy (`&TruncationStrategy`): strategy to follow for the truncation, if required
     /// - stride (`usize`): amount of tokens to shift the input by if truncation is required
-    /// (allowing for the generation of overlapping sequences with overflowing tokens)
+    ///   (allowing for the generation of overlapping sequences with overflowing tokens)
     ///
     /// # Returns
-    /// `Vec<TokenizedInput>` containing the encoding output (token indices, token types, segment ids,
-    /// ovrflowing tokens and special token mask) for each provided text
+    /// `Result<TokenizedInput, TokenizerError>` containing the encoding output without special tokens,
+    /// or an error if the input is rejected by `validate_input` or can not be truncated following the
+    /// `TruncationStrategy` provided
     ///
     /// # Example
     ///
@@ -883,50 +1848,58 @@ pub trait Tokenizer<T: Vocab> {
     /// let tokenizer: BaseTokenizer<BaseVocab> =
     ///     BaseTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
     ///
-    /// let text_1 = "Hello, world!";
-    /// let text_2 = "This is a second sentence";
-    /// let text_3 = "Very well thank you.";
-    /// let text_4 = "This is another second sentence.";
-    /// let encoded_input = tokenizer.encode_pair_list(
-    ///     &[(text_1, text_2), (text_3, text_4)],
-    ///     5,
+    /// let encoded_input = tokenizer.encode_plain(
+    ///     "Hello, world!",
+    ///     None,
+    ///     128,
     ///     &TruncationStrategy::LongestFirst,
-    ///     2,
+    ///     0,
     /// );
     /// ```
-    fn encode_pair_list<S>(
+    fn encode_plain(
         &self,
-        text_list: &[(S, S)],
+        text_1: &str,
+        text_2: Option<&str>,
         max_len: usize,
         truncation_strategy: &TruncationStrategy,
         stride: usize,
-    ) -> Vec<TokenizedInput>
-    where
-        S: AsRef<str>,
-    {
-        text_list
-            .as_ref()
-            .iter()
-            .map(|text| {
-                self.encode(
-                    text.0.as_ref(),
-                    Some(text.1.as_ref()),
-                    max_len,
-                    truncation_strategy,
-                    stride,
-                )
-            })
-            .collect()
+    ) -> Result<TokenizedInput, TokenizerError> {
+        self.validate_input(text_1)?;
+        if let Some(text_2) = text_2 {
+            self.validate_input(text_2)?;
+        }
+        let tokens_1 = self.tokenize_with_offsets(text_1);
+        let tokens_2 = text_2.map(|text| self.tokenize_with_offsets(text));
+        encode_tokens_with_offsets(
+            self,
+            tokens_1,
+            tokens_2,
+            max_len,
+            truncation_strategy,
+            stride,
+            false,
+        )
     }
 
-    /// Decode a sequence of token indices to a sequence of Strings, optionally skipping special indices
+    /// Encode raw bytes that may not be valid UTF-8 (tokenization followed by encoding). Invalid UTF-8
+    /// sequences are replaced by `U+FFFD` before tokenization, following `String::from_utf8_lossy`.
+    /// In contrast with `encode`, the token offsets and reference offsets of the output are byte positions
+    /// in the original buffers.
     ///
     /// # Parameters
-    /// - token_ids (`Vec<i64>`): tokens to decode
-    /// - skip_special_tokens (`bool`): flag indicating if special tokens should be included in the output
+    /// - bytes_1 (`&[u8]`): first text to encode
+    /// - bytes_2 (`Option<&[u8]>`): optional additional text to encode. If provided, both texts are encoded
+    ///   combined into a single encoding by using the `build_input_with_special_tokens` method.
+    /// - max_len (`usize`): maximum combined sequence length. If the combined encoding would exceed this
+    ///   max_len, the encoding is truncated following the `TruncationStrategy` provided.
+    /// - truncation_strategy (`&TruncationStrategy`): strategy to follow for the truncation, if required
+    /// - stride (`usize`): amount of tokens to shift the input by if truncation is required
+    ///   (allowing for the generation of overlapping sequences with overflowing tokens)
     ///
     /// # Returns
-    /// `Vec<String>` decoded token indices
+    /// `Result<TokenizedInput, TokenizerError>` containing the encoding output (token indices, token types,
+    /// segment ids, ovrflowing tokens and special token mask) with offsets expressed in bytes, or an
+    /// error if the input can not be encoded or truncated following the `TruncationStrategy` provided
     ///
     /// # Example
     ///
@@ -938,106 +1911,195 @@ pub trait Tokenizer<T: Vocab> {
     /// let tokenizer: BaseTokenizer<BaseVocab> =
     ///     BaseTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
     ///
-    /// let tokens_ids = vec![0, 1, 2, 42];
-    /// let tokens = tokenizer.decode_to_vec(&tokens_ids, false);
+    /// let bytes = b"Hello, \xF0\x90\x80world!";
+    /// let encoded_input =
+    ///     tokenizer.encode_bytes(bytes, None, 128, &TruncationStrategy::LongestFirst, 0);
     /// ```
-    fn decode_to_vec(&self, token_ids: &[i64], skip_special_tokens: bool) -> Vec<String> {
-        let tokens: Vec<String> = if skip_special_tokens {
-            token_ids
-                .iter()
-                .filter(|id| !self.vocab().special_indices().contains_key(id))
-                .map(|id| self.vocab().id_to_token(id))
-                .collect_vec()
-        } else {
-            token_ids
-                .iter()
-                .map(|id| self.vocab().id_to_token(id))
-                .collect_vec()
-        };
-        tokens
+    fn encode_bytes(
+        &self,
+        bytes_1: &[u8],
+        bytes_2: Option<&[u8]>,
+        max_len: usize,
+        truncation_strategy: &TruncationStrategy,
+        stride: usize,
+    ) -> Result<TokenizedInput, TokenizerError> {
+        self.validate_input(&String::from_utf8_lossy(bytes_1))?;
+        if let Some(bytes_2) = bytes_2 {
+            self.validate_input(&String::from_utf8_lossy(bytes_2))?;
+        }
+        let tokens_1 = self.tokenize_bytes(bytes_1);
+        let tokens_2 = bytes_2.map(|bytes| self.tokenize_bytes(bytes));
+        encode_tokens_with_offsets(
+            self,
+            tokens_1,
+            tokens_2,
+            max_len,
+            truncation_strategy,
+            stride,
+            true,
+        )
     }
 
-    /// Converts a sequence of ids (integer) into a string, using the tokenizer and vocabulary
-    /// with options to remove special tokens and clean up tokenization spaces.
+    /// Encode a string-like (tokenization followed by encoding), with the token offsets and reference
+    /// offsets of the output expressed in the given unit (e.g. UTF-16 code units for JavaScript
+    /// front-ends). This is equivalent to `try_encode` for `OffsetUnit::Char`.
     ///
-    /// # Arguments
-    /// - token_ids: list of tokenized input ids. Can be obtained using the `encode` or `encode_plus` methods.
-    /// - skip_special_tokens: if set to True, will replace special tokens.
-    /// - clean_up_tokenization_spaces: if set to True, will clean up the tokenization spaces.
+    /// # Parameters
+    /// - text_1: input text (string-like) to encode
+    /// - text_2: optional additional input text (string-like) to encode. When provided, both texts are
+    ///   combined into a single encoding by using the `build_input_with_special_tokens` method.
+    /// - max_len (`usize`): maximum combined sequence length. If the combined encoding would exceed this
+    ///   max_len, the encoding is truncated following the `TruncationStrategy` provided.
+    /// - truncation_strategy (`&TruncationStrategy`): strategy to follow for the truncation, if required
+    /// - stride (`usize`): amount of tokens to shift the input by if truncation is required
+    ///   (allowing for the generation of overlapping sequences with overflowing tokens)
+    /// - offset_unit (`OffsetUnit`): unit of the offsets of the output
     ///
     /// # Returns
-    /// - `String`: decoded sentence
+    /// `Result<TokenizedInput, TokenizerError>` containing the encoding output, with offsets expressed
+    /// in `offset_unit`
     ///
     /// # Example
     ///
     /// ```no_run
-    /// use rust_tokenizers::tokenizer::{BaseTokenizer, Tokenizer, TruncationStrategy};
+    /// use rust_tokenizers::tokenizer::{BaseTokenizer, OffsetUnit, Tokenizer, TruncationStrategy};
     /// use rust_tokenizers::vocab::BaseVocab;
     /// let strip_accents = false;
     /// let lower_case = false;
     /// let tokenizer: BaseTokenizer<BaseVocab> =
     ///     BaseTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
     ///
-    /// let skip_special_tokens = true;
-    /// let clean_up_tokenization_spaces = true;
-    /// let tokens = vec![0, 1, 2, 42];
-    /// let decoded = tokenizer.decode(&tokens, skip_special_tokens, clean_up_tokenization_spaces);
+    /// let encoded_input = tokenizer.encode_with_offset_unit(
+    ///     "Hello, 🌍 world!",
+    ///     None,
+    ///     128,
+    ///     &TruncationStrategy::LongestFirst,
+    ///     0,
+    ///     OffsetUnit::Utf16,
+    /// );
     /// ```
-    fn decode(
+    fn encode_with_offset_unit(
         &self,
-        token_ids: &[i64],
-        skip_special_tokens: bool,
-        clean_up_tokenization_spaces: bool,
-    ) -> String {
-        let tokens = self.decode_to_vec(token_ids, skip_special_tokens);
-        let decoded_string = self.convert_tokens_to_string(tokens);
-        if clean_up_tokenization_spaces {
-            self.clean_up_tokenization(decoded_string)
-        } else {
-            decoded_string
+        text_1: &str,
+        text_2: Option<&str>,
+        max_len: usize,
+        truncation_strategy: &TruncationStrategy,
+        stride: usize,
+        offset_unit: OffsetUnit,
+    ) -> Result<TokenizedInput, TokenizerError> {
+        if offset_unit == OffsetUnit::Char {
+            return self.try_encode(text_1, text_2, max_len, truncation_strategy, stride);
         }
+        self.validate_input(text_1)?;
+        if let Some(text_2) = text_2 {
+            self.validate_input(text_2)?;
+        }
+        let tokens_1 = self.tokenize_with_offset_unit(text_1, offset_unit);
+        let tokens_2 = text_2.map(|text| self.tokenize_with_offset_unit(text, offset_unit));
+        encode_tokens_with_offsets(
+            self,
+            tokens_1,
+            tokens_2,
+            max_len,
+            truncation_strategy,
+            stride,
+            true,
+        )
     }
 
-    /// Converts a sequence of strings into a single string. This will clean-up artifacts from tokenization
-    /// (for example `sub ##word`) and generate a single output string
+    /// Encode a conversation formatted with a chat template. The special tokens of the template
+    /// (which should be registered as special tokens of the vocabulary) replace the ones usually
+    /// added by `build_input_with_special_tokens`, which are therefore not inserted. The content of
+    /// the messages is tokenized as regular text: special token values it contains (e.g. a
+    /// `<|im_end|>` written by a user) are not mapped to special tokens. The offsets refer to the
+    /// conversation formatted with `ChatTemplate::format`.
     ///
-    /// # Arguments
-    /// - tokens: list of tokens to concatenate.
+    /// # Parameters
+    /// - messages (`&[ChatMessage]`): messages of the conversation, in order
+    /// - chat_template (`&ChatTemplate`): template defining the format expected by the model
+    /// - add_generation_prompt (`bool`): if true, the assistant prefix is appended after the last
+    ///   message to prompt the model for a reply
+    /// - max_len (`usize`): maximum sequence length. If the encoding would exceed this max_len, the
+    ///   encoding is truncated following the `TruncationStrategy` provided.
+    /// - truncation_strategy (`&TruncationStrategy`): strategy to follow for the truncation, if required
     ///
     /// # Returns
-    /// - `String`: concatenated sentence string
+    /// `Result<TokenizedInput, TokenizerError>` containing the encoding of the formatted conversation,
+    /// or an error if the content of a message is rejected by `validate_input`
     ///
     /// # Example
     ///
     /// ```no_run
-    /// use rust_tokenizers::tokenizer::{BaseTokenizer, Tokenizer, TruncationStrategy};
-    /// use rust_tokenizers::vocab::BaseVocab;
-    /// let strip_accents = false;
-    /// let lower_case = false;
-    /// let tokenizer: BaseTokenizer<BaseVocab> =
-    ///     BaseTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
+    /// use rust_tokenizers::tokenizer::{
+    ///     ChatMessage, ChatTemplate, SentencePieceBpeTokenizer, Tokenizer, TruncationStrategy,
+    /// };
+    /// let mut tokenizer = SentencePieceBpeTokenizer::from_file("path/to/model", false).unwrap();
+    /// tokenizer.add_tokens(&["<|im_start|>", "<|im_end|>"]);
     ///
-    /// let skip_special_tokens = true;
-    /// let clean_up_tokenization_spaces = true;
-    /// let tokens = vec![
-    ///     "Hello".to_string(),
-    ///     ",".to_string(),
-    ///     "World".to_string(),
-    ///     "!".to_string(),
+    /// let messages = [
+    ///     ChatMessage::system("You are a helpful assistant."),
+    ///     ChatMessage::user("Hello, world!"),
     /// ];
-    /// let decoded = tokenizer.convert_tokens_to_string(tokens);
+    /// let encoded_input = tokenizer.encode_chat(
+    ///     &messages,
+    ///     &ChatTemplate::chatml(),
+    ///     true,
+    ///     4096,
+    ///     &TruncationStrategy::LongestFirst,
+    /// );
     /// ```
-    fn convert_tokens_to_string(&self, tokens: Vec<String>) -> String {
-        tokens.join(" ")
+    fn encode_chat(
+        &self,
+        messages: &[ChatMessage],
+        chat_template: &ChatTemplate,
+        add_generation_prompt: bool,
+        max_len: usize,
+        truncation_strategy: &TruncationStrategy,
+    ) -> Result<TokenizedInput, TokenizerError> {
+        let mut tokens = TokensWithOffsets {
+            tokens: vec![],
+            offsets: vec![],
+            reference_offsets: vec![],
+            masks: vec![],
+        };
+        let mut segment_start = 0;
+        for (segment_index, (segment, is_content)) in chat_template
+            .segments(messages, add_generation_prompt)
+            .into_iter()
+            .enumerate()
+        {
+            let segment_tokens = if is_content {
+                self.validate_input(segment)?;
+                without_special_token_splitting(|| self.tokenize_with_offsets(segment))
+            } else {
+                self.tokenize_with_offsets(segment)
+            };
+            append_shifted_tokens(
+                &mut tokens,
+                segment_tokens,
+                segment_start,
+                segment_index == 0,
+            );
+            segment_start += segment.chars().count();
+        }
+        encode_tokens_with_offsets(self, tokens, None, max_len, truncation_strategy, 0, false)
     }
 
-    /// Cleans-up tokenization artifacts (for example whitespace before punctuation)
+    /// Encode a sequence of string-like texts (tokenization followed by encoding). Not that in contrast
+    /// with `encode` optional second text, each text provided is encoded independently.
     ///
-    /// # Arguments
-    /// - input_string (`String`): input string to clean up
+    /// # Parameters
+    /// - text_list: sequence of input text (`&str`) to encode
+    /// combined into a single encoding by using the `build_input_with_special_tokens` method.
+    /// - max_len (`usize`): maximum combined sequence length. If the combined encoding would exceed this
+    /// max_len, the encoding is truncated following the `TruncationStrategy` provided.
+    /// - truncation_strategy (`&TruncationStrategy`): strategy to follow for the truncation, if required
+    /// - stride (`usize`): amount of tokens to shift the input by if truncation is required
+    /// (allowing for the generation of overlapping sequences with overflowing tokens)
     ///
     /// # Returns
-    /// - `String`: clean-up string
+    /// `Vec<TokenizedInput>` containing the encoding output (token indices, token types, segment ids,
+    /// ovrflowing tokens and special token mask) for each provided text
     ///
     /// # Example
     ///
@@ -1049,37 +2111,51 @@ pub trait Tokenizer<T: Vocab> {
     /// let tokenizer: BaseTokenizer<BaseVocab> =
     ///     BaseTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
     ///
-    /// let skip_special_tokens = true;
-    /// let clean_up_tokenization_spaces = true;
-    /// let input_string = "Hello . Do n't pay attention to the punctuation .".to_string();
-    /// let cleaned_string = tokenizer.clean_up_tokenization(input_string);
+    /// let text_1 = "Hello, world!";
+    /// let text_2 = "How is it going?";
+    /// let text_3 = "Very well thank you.";
+    /// let encoded_input = tokenizer.encode_list(
+    ///     &[text_1, text_2, text_3],
+    ///     5,
+    ///     &TruncationStrategy::LongestFirst,
+    ///     2,
+    /// );
     /// ```
-    fn clean_up_tokenization(&self, input_string: String) -> String {
-        input_string
-            .replace(" .", ".")
-            .replace(" !", "!")
-            .replace(" ?", "?")
-            .replace(" ,", ",")
-            .replace(" ' ", "'")
-            .replace(" n't", "n't")
-            .replace(" 'm", "'m")
-            .replace(" do not", " don't")
-            .replace(" 's", "'s")
-            .replace(" 've", "'ve")
-            .replace(" 're", "'re")
+    fn encode_list<S>(
+        &self,
+        text_list: &[S],
+        max_len: usize,
+        truncation_strategy: &TruncationStrategy,
+        stride: usize,
+    ) -> Vec<TokenizedInput>
+    where
+        S: AsRef<str>,
+    {
+        let start = self.stats_collector().is_some().then(Instant::now);
+        let encoded_inputs: Vec<TokenizedInput> = text_list
+            .as_ref()
+            .iter()
+            .map(|text| self.encode(text.as_ref(), None, max_len, truncation_strategy, stride))
+            .collect();
+        record_stats(self, &encoded_inputs, start);
+        encoded_inputs
     }
 
-    /// Converts a list of sequence of ids (integer) into a string, using the tokenizer and vocabulary
-    /// with options to remove special tokens and clean up tokenization spaces. This calls `decode`
-    /// for each provided sequence of ids
+    /// Encode a sequence of string-like text pairs (tokenization followed by encoding). This combines
+    /// with `encode` with the list processing of `encode_list`.
     ///
-    /// # Arguments
-    /// - token_ids: list of list of tokenized input ids. Can be obtained using the `encode` or `encode_plus` methods.
-    /// - skip_special_tokens: if set to True, will replace special tokens.
-    /// - clean_up_tokenization_spaces: if set to True, will clean up the tokenization spaces.
+    /// # Parameters
+    /// - text_list: sequence of input text (`&str`) to encode
+    /// combined into a single encoding by using the `build_input_with_special_tokens` method.
+    /// - max_len (`usize`): maximum combined sequence length. If the combined encoding would exceed this
+    /// max_len, the encoding is truncated following the `TruncationStrategy` provided.
+    /// - truncation_strategy (`&TruncationStrategy`): strategy to follow for the truncation, if required
+    /// - stride (`usize`): amount of tokens to shift the input by if truncation is required
+    /// (allowing for the generation of overlapping sequences with overflowing tokens)
     ///
     /// # Returns
-    /// - `String`: decoded sentence
+    /// `Vec<TokenizedInput>` containing the encoding output (token indices, token types, segment ids,
+    /// ovrflowing tokens and special token mask) for each provided text
     ///
     /// # Example
     ///
@@ -1091,229 +2167,1629 @@ pub trait Tokenizer<T: Vocab> {
     /// let tokenizer: BaseTokenizer<BaseVocab> =
     ///     BaseTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
     ///
-    /// let skip_special_tokens = true;
-    /// let clean_up_tokenization_spaces = true;
-    /// let token_ids_list = vec![vec![0, 1, 2, 42], vec![99, 3]];
-    /// let decoded_list = tokenizer.decode_list(
-    ///     &token_ids_list,
-    ///     skip_special_tokens,
-    ///     clean_up_tokenization_spaces,
+    /// let text_1 = "Hello, world!";
+    /// let text_2 = "This is a second sentence";
+    /// let text_3 = "Very well thank you.";
+    /// let text_4 = "This is another second sentence.";
+    /// let encoded_input = tokenizer.encode_pair_list(
+    ///     &[(text_1, text_2), (text_3, text_4)],
+    ///     5,
+    ///     &TruncationStrategy::LongestFirst,
+    ///     2,
     /// );
     /// ```
-    fn decode_list(
+    fn encode_pair_list<S>(
         &self,
-        token_ids_list: &[Vec<i64>],
-        skip_special_tokens: bool,
-        clean_up_tokenization_spaces: bool,
-    ) -> Vec<String> {
-        token_ids_list
+        text_list: &[(S, S)],
+        max_len: usize,
+        truncation_strategy: &TruncationStrategy,
+        stride: usize,
+    ) -> Vec<TokenizedInput>
+    where
+        S: AsRef<str>,
+    {
+        let start = self.stats_collector().is_some().then(Instant::now);
+        let encoded_inputs: Vec<TokenizedInput> = text_list
+            .as_ref()
             .iter()
-            .map(|token_ids| {
-                self.decode(token_ids, skip_special_tokens, clean_up_tokenization_spaces)
+            .map(|text| {
+                self.encode(
+                    text.0.as_ref(),
+                    Some(text.1.as_ref()),
+                    max_len,
+                    truncation_strategy,
+                    stride,
+                )
             })
-            .collect()
+            .collect();
+        record_stats(self, &encoded_inputs, start);
+        encoded_inputs
     }
 
-    /// Build model inputs from a sequence or a pair of sequence for sequence classification tasks
-    /// by concatenating and adding special tokens.
-    ///
-    /// For example, a RoBERTa sequence has the following format:
-    /// - single sequence: <s> X </s>
-    /// - pair of sequences: <s> A </s></s> B </s>
+    /// Encode of a sequence of string-like texts (tokenization followed by encoding), returning an error
+    /// instead of panicking if any of the inputs can not be truncated following the `TruncationStrategy` provided.
     ///
     /// # Parameters
-    /// - tokens_ids_with_offsets_1 (`TokenIdsWithOffsets`): first sequence
-    /// - tokens_ids_with_offsets_2 (`TokenIdsWithOffsets`): (optional) second sequence
+    /// - text_list: sequence of input text (`&str`) to encode
+    /// - max_len (`usize`): maximum combined sequence length. If the combined encoding would exceed this
+    ///   max_len, the encoding is truncated following the `TruncationStrategy` provided.
+    /// - truncation_strategy (`&TruncationStrategy`): strategy to follow for the truncation, if required
+    /// - stride (`usize`): amount of tokens to shift the input by if truncation is required
+    ///   (allowing for the generation of overlapping sequences with overflowing tokens)
     ///
     /// # Returns
-    /// - `TokenIdsWithSpecialTokens` containing a concatenation of both sequences with added special tokens
+    /// `Result<Vec<TokenizedInput>, TokenizerError>` containing the encoding output (token indices, token types,
+    /// segment ids, ovrflowing tokens and special token mask) for each provided text
     ///
     /// # Example
     ///
     /// ```no_run
     /// use rust_tokenizers::tokenizer::{BaseTokenizer, Tokenizer, TruncationStrategy};
     /// use rust_tokenizers::vocab::BaseVocab;
-    /// use rust_tokenizers::TokenIdsWithOffsets;
     /// let strip_accents = false;
     /// let lower_case = false;
     /// let tokenizer: BaseTokenizer<BaseVocab> =
     ///     BaseTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
     ///
-    /// let skip_special_tokens = true;
-    /// let clean_up_tokenization_spaces = true;
-    /// let first_sequence = "Hello, world";
-    /// let second_sequence = "This is the second sentence";
-    ///
-    /// let first_tokens = tokenizer.tokenize_with_offsets(first_sequence);
-    /// let first_ids = tokenizer.convert_tokens_to_ids(&first_tokens.tokens);
-    /// let first_input = TokenIdsWithOffsets {
-    ///     ids: first_ids,
-    ///     offsets: first_tokens.offsets,
-    ///     reference_offsets: first_tokens.reference_offsets,
-    ///     masks: first_tokens.masks,
-    /// };
-    ///
-    /// let second_tokens = tokenizer.tokenize_with_offsets(second_sequence);
-    /// let second_ids = tokenizer.convert_tokens_to_ids(&second_tokens.tokens);
-    /// let second_input = TokenIdsWithOffsets {
-    ///     ids: second_ids,
-    ///     offsets: second_tokens.offsets,
-    ///     reference_offsets: second_tokens.reference_offsets,
-    ///     masks: second_tokens.masks,
-    /// };
-    ///
-    /// let combined_with_special_tokens =
-    ///     tokenizer.build_input_with_special_tokens(first_input, Some(second_input));
+    /// let text_1 = "Hello, world!";
+    /// let text_2 = "How is it going?";
+    /// let text_3 = "Very well thank you.";
+    /// let encoded_input = tokenizer.try_encode_list(
+    ///     &[text_1, text_2, text_3],
+    ///     5,
+    ///     &TruncationStrategy::LongestFirst,
+    ///     2,
+    /// );
     /// ```
-    fn build_input_with_special_tokens(
+    fn try_encode_list<S>(
         &self,
-        mut tokens_ids_with_offsets_1: TokenIdsWithOffsets,
-        tokens_ids_with_offsets_2: Option<TokenIdsWithOffsets>,
-    ) -> TokenIdsWithSpecialTokens {
-        let mut token_segment_ids: Vec<i8> = vec![0; tokens_ids_with_offsets_1.ids.len()];
-        let mut special_tokens_mask: Vec<i8> = vec![0; tokens_ids_with_offsets_1.ids.len()];
-        if let Some(tokens_ids_with_offsets_2_value) = tokens_ids_with_offsets_2 {
-            let length = tokens_ids_with_offsets_2_value.ids.len();
-            token_segment_ids.extend(vec![1; length]);
-            special_tokens_mask.extend(vec![0; length]);
-            tokens_ids_with_offsets_1
-                .ids
-                .extend(tokens_ids_with_offsets_2_value.ids);
-            tokens_ids_with_offsets_1
-                .offsets
-                .extend(tokens_ids_with_offsets_2_value.offsets);
-            tokens_ids_with_offsets_1
-                .reference_offsets
-                .extend(tokens_ids_with_offsets_2_value.reference_offsets);
-            tokens_ids_with_offsets_1
-                .masks
-                .extend(tokens_ids_with_offsets_2_value.masks);
-        };
-
-        TokenIdsWithSpecialTokens {
-            token_ids: tokens_ids_with_offsets_1.ids,
-            segment_ids: token_segment_ids,
-            special_tokens_mask,
-            token_offsets: tokens_ids_with_offsets_1.offsets,
-            reference_offsets: tokens_ids_with_offsets_1.reference_offsets,
-            mask: tokens_ids_with_offsets_1.masks,
+        text_list: &[S],
+        max_len: usize,
+        truncation_strategy: &TruncationStrategy,
+        stride: usize,
+    ) -> Result<Vec<TokenizedInput>, TokenizerError>
+    where
+        S: AsRef<str>,
+    {
+        let start = self.stats_collector().is_some().then(Instant::now);
+        let encoded_inputs: Result<Vec<TokenizedInput>, TokenizerError> = text_list
+            .as_ref()
+            .iter()
+            .map(|text| self.try_encode(text.as_ref(), None, max_len, truncation_strategy, stride))
+            .collect();
+        if let Ok(encoded_inputs) = &encoded_inputs {
+            record_stats(self, encoded_inputs, start);
         }
+        encoded_inputs
     }
 
-    /// Add arbitrary tokens to the vocabulary.
-    ///
-    /// These tokens are added to the special token map and are ignored from the tokenization
-    /// algorithm chosen (e.g.
+    /// Encode of a sequence of string-like text pairs (tokenization followed by encoding), returning an
+    /// error instead of panicking if any of the inputs can not be truncated following the `TruncationStrategy`
+    /// provided.
     ///
     /// # Parameters
-    /// - tokens (`&[&str]`): list of tokens to add to the vocabulary
-    fn add_tokens(&mut self, tokens: &[&str]) {
-        self.vocab_mut().add_tokens(tokens);
-    }
-
-    /// Add arbitrary tokens to the vocabulary.
+    /// - text_list: sequence of input text pairs (`&str`) to encode
+    /// - max_len (`usize`): maximum combined sequence length. If the combined encoding would exceed this
+    ///   max_len, the encoding is truncated following the `TruncationStrategy` provided.
+    /// - truncation_strategy (`&TruncationStrategy`): strategy to follow for the truncation, if required
+    /// - stride (`usize`): amount of tokens to shift the input by if truncation is required
+    ///   (allowing for the generation of overlapping sequences with overflowing tokens)
     ///
-    /// These tokens are added to the special token map and are ignored from the tokenization
-    /// algorithm chosen (e.g.
+    /// # Returns
+    /// `Result<Vec<TokenizedInput>, TokenizerError>` containing the encoding output (token indices, token types,
+    /// segment ids, ovrflowing tokens and special token mask) for each provided text pair
     ///
-    /// # Parameters
-    /// - num_extra_ids (`i64`): number of tokens to append
-    fn add_extra_ids(&mut self, num_extra_ids: i64) {
-        self.vocab_mut().add_extra_ids(num_extra_ids);
-    }
-}
-
-/// # Extension for multithreaded tokenizers
-pub trait MultiThreadedTokenizer<T: Vocab>
-where
-    Self: Sync + Send + Tokenizer<T>,
-{
-    /// returns a reference to the tokenizer vocabulary
-    fn vocab(&self) -> &T {
-        Tokenizer::<T>::vocab(self)
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{BaseTokenizer, Tokenizer, TruncationStrategy};
+    /// use rust_tokenizers::vocab::BaseVocab;
+    /// let strip_accents = false;
+    /// let lower_case = false;
+    /// let tokenizer: BaseTokenizer<BaseVocab> =
+    ///     BaseTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
+    ///
+    /// let text_1 = "Hello, world!";
+    /// let text_2 = "This is a second sentence";
+    /// let text_3 = "Very well thank you.";
+    /// let text_4 = "This is another second sentence.";
+    /// let encoded_input = tokenizer.try_encode_pair_list(
+    ///     &[(text_1, text_2), (text_3, text_4)],
+    ///     5,
+    ///     &TruncationStrategy::LongestFirst,
+    ///     2,
+    /// );
+    /// ```
+    fn try_encode_pair_list<S>(
+        &self,
+        text_list: &[(S, S)],
+        max_len: usize,
+        truncation_strategy: &TruncationStrategy,
+        stride: usize,
+    ) -> Result<Vec<TokenizedInput>, TokenizerError>
+    where
+        S: AsRef<str>,
+    {
+        let start = self.stats_collector().is_some().then(Instant::now);
+        let encoded_inputs: Result<Vec<TokenizedInput>, TokenizerError> = text_list
+            .as_ref()
+            .iter()
+            .map(|text| {
+                self.try_encode(
+                    text.0.as_ref(),
+                    Some(text.1.as_ref()),
+                    max_len,
+                    truncation_strategy,
+                    stride,
+                )
+            })
+            .collect();
+        if let Ok(encoded_inputs) = &encoded_inputs {
+            record_stats(self, encoded_inputs, start);
+        }
+        encoded_inputs
+    }
+
+    /// Encode string-like text pairs provided as two parallel sequences (e.g. two columns of a
+    /// dataset), the i-th text of `texts_a` being paired with the i-th text of `texts_b`. This avoids
+    /// building an intermediate sequence of pairs for `encode_pair_list`. Inputs that can not be
+    /// truncated following the `TruncationStrategy` provided return an error.
+    ///
+    /// # Parameters
+    /// - texts_a: sequence of first texts (`&str`) of the pairs
+    /// - texts_b: sequence of second texts (`&str`) of the pairs, with the same length as `texts_a`
+    /// - max_len (`usize`): maximum combined sequence length. If the combined encoding would exceed this
+    ///   max_len, the encoding is truncated following the `TruncationStrategy` provided.
+    /// - truncation_strategy (`&TruncationStrategy`): strategy to follow for the truncation, if required
+    /// - stride (`usize`): amount of tokens to shift the input by if truncation is required
+    ///   (allowing for the generation of overlapping sequences with overflowing tokens)
+    ///
+    /// # Returns
+    /// `Result<Vec<TokenizedInput>, TokenizerError>` containing the encoding output for each text pair,
+    /// failing with a `ValueError` if the sequences of texts have different lengths
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{BaseTokenizer, Tokenizer, TruncationStrategy};
+    /// use rust_tokenizers::vocab::BaseVocab;
+    /// let strip_accents = false;
+    /// let lower_case = false;
+    /// let tokenizer: BaseTokenizer<BaseVocab> =
+    ///     BaseTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
+    ///
+    /// let questions = ["Hello, world!", "Very well thank you."];
+    /// let answers = ["This is a second sentence", "This is another second sentence."];
+    /// let encoded_input = tokenizer.encode_pair_list_from_slices(
+    ///     &questions,
+    ///     &answers,
+    ///     5,
+    ///     &TruncationStrategy::LongestFirst,
+    ///     2,
+    /// );
+    /// ```
+    fn encode_pair_list_from_slices<S>(
+        &self,
+        texts_a: &[S],
+        texts_b: &[S],
+        max_len: usize,
+        truncation_strategy: &TruncationStrategy,
+        stride: usize,
+    ) -> Result<Vec<TokenizedInput>, TokenizerError>
+    where
+        S: AsRef<str>,
+    {
+        if texts_a.len() != texts_b.len() {
+            return Err(TokenizerError::ValueError(format!(
+                "Pair sequences must have the same length, got {} and {} texts",
+                texts_a.len(),
+                texts_b.len()
+            )));
+        }
+        let start = self.stats_collector().is_some().then(Instant::now);
+        let encoded_inputs: Result<Vec<TokenizedInput>, TokenizerError> = texts_a
+            .iter()
+            .zip(texts_b.iter())
+            .map(|(text_a, text_b)| {
+                self.try_encode(
+                    text_a.as_ref(),
+                    Some(text_b.as_ref()),
+                    max_len,
+                    truncation_strategy,
+                    stride,
+                )
+            })
+            .collect();
+        if let Ok(encoded_inputs) = &encoded_inputs {
+            record_stats(self, encoded_inputs, start);
+        }
+        encoded_inputs
+    }
+
+    /// Decode a token index to its String representation, applying the tokenizer `UnknownIdPolicy`
+    /// to indices not found in the vocabulary.
+    ///
+    /// # Parameters
+    /// - token_id (`&i64`): token index to decode
+    ///
+    /// # Returns
+    /// `Result<Option<String>, TokenizerError>` with the token string, or `None` if the index should be skipped
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{BaseTokenizer, Tokenizer};
+    /// use rust_tokenizers::vocab::BaseVocab;
+    /// let strip_accents = false;
+    /// let lower_case = false;
+    /// let tokenizer: BaseTokenizer<BaseVocab> =
+    ///     BaseTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
+    ///
+    /// let token = tokenizer.decode_id(&42).unwrap();
+    /// ```
+    fn decode_id(&self, token_id: &i64) -> Result<Option<String>, TokenizerError> {
+        if self.vocab().indices().contains_key(token_id)
+            || self.vocab().special_indices().contains_key(token_id)
+        {
+            return Ok(Some(self.vocab().id_to_token(token_id)));
+        }
+        match self.unknown_id_policy() {
+            UnknownIdPolicy::Error => Err(TokenizerError::IndexNotFound(format!(
+                "index {token_id} not found in the vocabulary"
+            ))),
+            UnknownIdPolicy::SkipToken => Ok(None),
+            UnknownIdPolicy::ReplaceWithUnk => {
+                Ok(Some(self.vocab().get_unknown_value().to_owned()))
+            }
+        }
+    }
+
+    /// Decode a sequence of token indices to a sequence of Strings, optionally skipping special indices.
+    /// Indices not found in the vocabulary are handled following the tokenizer `UnknownIdPolicy`.
+    ///
+    /// # Parameters
+    /// - token_ids (`Vec<i64>`): tokens to decode
+    /// - skip_special_tokens (`bool`): flag indicating if special tokens should be included in the output
+    ///
+    /// # Returns
+    /// `Result<Vec<String>, TokenizerError>` decoded token indices, or an error if an index is not
+    /// found in the vocabulary and the policy is `UnknownIdPolicy::Error`
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{
+    ///     BaseTokenizer, ConfigurableTokenizer, Tokenizer, UnknownIdPolicy,
+    /// };
+    /// use rust_tokenizers::vocab::BaseVocab;
+    /// let strip_accents = false;
+    /// let lower_case = false;
+    /// let mut tokenizer: BaseTokenizer<BaseVocab> =
+    ///     BaseTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
+    /// tokenizer.set_unknown_id_policy(UnknownIdPolicy::Error);
+    ///
+    /// let tokens_ids = vec![0, 1, 2, 42];
+    /// let tokens = tokenizer.try_decode_to_vec(&tokens_ids, false);
+    /// ```
+    fn try_decode_to_vec(
+        &self,
+        token_ids: &[i64],
+        skip_special_tokens: bool,
+    ) -> Result<Vec<String>, TokenizerError> {
+        let mut tokens = Vec::with_capacity(token_ids.len());
+        for token_id in token_ids {
+            if skip_special_tokens && self.vocab().special_indices().contains_key(token_id) {
+                continue;
+            }
+            if let Some(token) = self.decode_id(token_id)? {
+                tokens.push(token);
+            }
+        }
+        Ok(tokens)
+    }
+
+    /// Decode a sequence of token indices to a sequence of Strings, optionally skipping special indices
+    ///
+    /// # Parameters
+    /// - token_ids (`Vec<i64>`): tokens to decode
+    /// - skip_special_tokens (`bool`): flag indicating if special tokens should be included in the output
+    ///
+    /// # Returns
+    /// `Vec<String>` decoded token indices
+    ///
+    /// # Panics
+    /// If an index is not found in the vocabulary and the tokenizer policy is `UnknownIdPolicy::Error`.
+    /// Use `try_decode_to_vec` to handle this case as an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{BaseTokenizer, Tokenizer, TruncationStrategy};
+    /// use rust_tokenizers::vocab::BaseVocab;
+    /// let strip_accents = false;
+    /// let lower_case = false;
+    /// let tokenizer: BaseTokenizer<BaseVocab> =
+    ///     BaseTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
+    ///
+    /// let tokens_ids = vec![0, 1, 2, 42];
+    /// let tokens = tokenizer.decode_to_vec(&tokens_ids, false);
+    /// ```
+    fn decode_to_vec(&self, token_ids: &[i64], skip_special_tokens: bool) -> Vec<String> {
+        self.try_decode_to_vec(token_ids, skip_special_tokens)
+            .unwrap()
+    }
+
+    /// Decodes a slice of token indices to text without splitting multi-byte characters. Bytes of a
+    /// character that is not complete at the end of the slice are returned as pending bytes instead
+    /// of being replaced by U+FFFD, and are prepended to the following call. This allows decoding
+    /// arbitrary sub-slices of a sequence, for example when streaming the output of a generation.
+    /// The tokens are converted with `convert_tokens_to_bytes`: only the tokenizers decoding to raw
+    /// bytes (byte-level BPE) can leave bytes pending, other tokenizers decode each slice as text.
+    ///
+    /// # Parameters
+    /// - token_ids (`&[i64]`): token indices to decode
+    /// - pending_bytes (`&[u8]`): pending bytes returned by the decoding of the previous slice
+    /// - skip_special_tokens (`bool`): flag indicating if special tokens should be skipped
+    ///
+    /// # Returns
+    /// `Result<PartialDecoding, TokenizerError>` with the decoded text and the bytes left pending,
+    /// or an error if an index is not found in the vocabulary and the policy is `UnknownIdPolicy::Error`
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{Gpt2Tokenizer, Tokenizer};
+    /// let lower_case = false;
+    /// let tokenizer =
+    ///     Gpt2Tokenizer::from_file("path/to/vocab/file", "path/to/merges/file", lower_case, false)
+    ///         .unwrap();
+    ///
+    /// let mut pending_bytes = vec![];
+    /// let mut text = String::new();
+    /// for token_id in [15496, 11, 995, 0] {
+    ///     let decoded = tokenizer
+    ///         .decode_partial(&[token_id], &pending_bytes, true)
+    ///         .unwrap();
+    ///     text.push_str(&decoded.text);
+    ///     pending_bytes = decoded.pending_bytes;
+    /// }
+    /// ```
+    fn decode_partial(
+        &self,
+        token_ids: &[i64],
+        pending_bytes: &[u8],
+        skip_special_tokens: bool,
+    ) -> Result<PartialDecoding, TokenizerError> {
+        let mut bytes = pending_bytes.to_vec();
+        bytes.extend(
+            self.convert_tokens_to_bytes(self.try_decode_to_vec(token_ids, skip_special_tokens)?),
+        );
+        let complete_length = complete_utf8_length(&bytes);
+        let pending_bytes = bytes.split_off(complete_length);
+        Ok(PartialDecoding {
+            text: String::from_utf8_lossy(&bytes).to_string(),
+            pending_bytes,
+        })
+    }
+
+    /// Converts a sequence of ids (integer) into a string, using the tokenizer and vocabulary
+    /// with options to remove special tokens and clean up tokenization spaces.
+    ///
+    /// # Arguments
+    /// - token_ids: list of tokenized input ids. Can be obtained using the `encode` or `encode_plus` methods.
+    /// - skip_special_tokens: if set to True, will replace special tokens.
+    /// - clean_up_tokenization_spaces: if set to True, will clean up the tokenization spaces.
+    ///
+    /// # Returns
+    /// - `String`: decoded sentence
+    ///
+    /// # Panics
+    /// If an index is not found in the vocabulary and the tokenizer policy is `UnknownIdPolicy::Error`.
+    /// Use `try_decode` to handle this case as an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{BaseTokenizer, Tokenizer, TruncationStrategy};
+    /// use rust_tokenizers::vocab::BaseVocab;
+    /// let strip_accents = false;
+    /// let lower_case = false;
+    /// let tokenizer: BaseTokenizer<BaseVocab> =
+    ///     BaseTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
+    ///
+    /// let skip_special_tokens = true;
+    /// let clean_up_tokenization_spaces = true;
+    /// let tokens = vec![0, 1, 2, 42];
+    /// let decoded = tokenizer.decode(&tokens, skip_special_tokens, clean_up_tokenization_spaces);
+    /// ```
+    fn decode(
+        &self,
+        token_ids: &[i64],
+        skip_special_tokens: bool,
+        clean_up_tokenization_spaces: bool,
+    ) -> String {
+        self.try_decode(token_ids, skip_special_tokens, clean_up_tokenization_spaces)
+            .unwrap()
+    }
+
+    /// Converts a sequence of ids (integer) into a string, using the tokenizer and vocabulary
+    /// with options to remove special tokens and clean up tokenization spaces. Indices not found in
+    /// the vocabulary are handled following the tokenizer `UnknownIdPolicy`.
+    ///
+    /// # Arguments
+    /// - token_ids: list of tokenized input ids. Can be obtained using the `encode` or `encode_plus` methods.
+    /// - skip_special_tokens: if set to True, will replace special tokens.
+    /// - clean_up_tokenization_spaces: if set to True, will clean up the tokenization spaces.
+    ///
+    /// # Returns
+    /// - `Result<String, TokenizerError>`: decoded sentence, or an error if an index is not found in
+    ///   the vocabulary and the policy is `UnknownIdPolicy::Error`
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{
+    ///     BaseTokenizer, ConfigurableTokenizer, Tokenizer, UnknownIdPolicy,
+    /// };
+    /// use rust_tokenizers::vocab::BaseVocab;
+    /// let strip_accents = false;
+    /// let lower_case = false;
+    /// let mut tokenizer: BaseTokenizer<BaseVocab> =
+    ///     BaseTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
+    /// tokenizer.set_unknown_id_policy(UnknownIdPolicy::Error);
+    ///
+    /// let skip_special_tokens = true;
+    /// let clean_up_tokenization_spaces = true;
+    /// let tokens = vec![0, 1, 2, 42];
+    /// let decoded = tokenizer.try_decode(&tokens, skip_special_tokens, clean_up_tokenization_spaces);
+    /// ```
+    fn try_decode(
+        &self,
+        token_ids: &[i64],
+        skip_special_tokens: bool,
+        clean_up_tokenization_spaces: bool,
+    ) -> Result<String, TokenizerError> {
+        let tokens = self.try_decode_to_vec(token_ids, skip_special_tokens)?;
+        let decoded_string = self.convert_tokens_to_string(tokens);
+        Ok(if clean_up_tokenization_spaces {
+            self.clean_up_tokenization(decoded_string)
+        } else {
+            decoded_string
+        })
+    }
+
+    /// Converts a sequence of ids (integer) into a string, also returning the position of each token
+    /// in the decoded string. The decoded string is identical to the output of `decode`.
+    ///
+    /// # Arguments
+    /// - token_ids: list of tokenized input ids. Can be obtained using the `encode` or `encode_plus` methods.
+    /// - skip_special_tokens: if set to True, will replace special tokens.
+    /// - clean_up_tokenization_spaces: if set to True, will clean up the tokenization spaces.
+    ///
+    /// # Returns
+    /// - `String`: decoded sentence
+    /// - `Vec<Option<Offset>>`: character offsets of each token in the decoded sentence (excluding
+    ///   surrounding whitespaces). Skipped special tokens and tokens not contributing any character to
+    ///   the decoded sentence are registered as None. This vector has the same length as token_ids.
+    ///
+    /// # Panics
+    /// If an index is not found in the vocabulary and the tokenizer policy is `UnknownIdPolicy::Error`.
+    /// Indices skipped following `UnknownIdPolicy::SkipToken` are registered as None.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{BaseTokenizer, Tokenizer, TruncationStrategy};
+    /// use rust_tokenizers::vocab::BaseVocab;
+    /// let strip_accents = false;
+    /// let lower_case = false;
+    /// let tokenizer: BaseTokenizer<BaseVocab> =
+    ///     BaseTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
+    ///
+    /// let skip_special_tokens = true;
+    /// let clean_up_tokenization_spaces = true;
+    /// let tokens = vec![0, 1, 2, 42];
+    /// let (decoded, offsets) =
+    ///     tokenizer.decode_with_offsets(&tokens, skip_special_tokens, clean_up_tokenization_spaces);
+    /// ```
+    fn decode_with_offsets(
+        &self,
+        token_ids: &[i64],
+        skip_special_tokens: bool,
+        clean_up_tokenization_spaces: bool,
+    ) -> (String, Vec<Option<Offset>>) {
+        // The pieces are obtained by decoding each token with its preceding tokens, as the conversion
+        // of tokens to a string depends on the neighbouring tokens (e.g. sub-words or byte-level tokens).
+        // The context is limited to `DECODE_CONTEXT_SIZE` tokens to keep the decoding linear.
+        let mut tokens: Vec<String> = Vec::with_capacity(token_ids.len());
+        let mut piece_indices = Vec::with_capacity(token_ids.len());
+        let mut pieces: Vec<String> = Vec::with_capacity(token_ids.len());
+        for token_id in token_ids {
+            if skip_special_tokens && self.vocab().special_indices().contains_key(token_id) {
+                piece_indices.push(None);
+                continue;
+            }
+            let token = match self.decode_id(token_id).unwrap() {
+                Some(token) => token,
+                None => {
+                    piece_indices.push(None);
+                    continue;
+                }
+            };
+            tokens.push(token);
+            let context_start = tokens.len().saturating_sub(DECODE_CONTEXT_SIZE + 1);
+            let decoded_prefix = self
+                .convert_tokens_to_string(tokens[context_start..tokens.len() - 1].to_vec())
+                .chars()
+                .collect::<Vec<char>>();
+            let current_prefix = self
+                .convert_tokens_to_string(tokens[context_start..].to_vec())
+                .chars()
+                .collect::<Vec<char>>();
+            let common_length = decoded_prefix
+                .iter()
+                .zip(current_prefix.iter())
+                .take_while(|(a, b)| a == b)
+                .count();
+            let mut length_to_remove = decoded_prefix.len() - common_length;
+            for piece in pieces.iter_mut().rev() {
+                if length_to_remove == 0 {
+                    break;
+                }
+                let piece_length = piece.chars().count();
+                let piece_length_to_remove = min(piece_length, length_to_remove);
+                *piece = piece
+                    .chars()
+                    .take(piece_length - piece_length_to_remove)
+                    .collect();
+                length_to_remove -= piece_length_to_remove;
+            }
+            piece_indices.push(Some(pieces.len()));
+            pieces.push(current_prefix[common_length..].iter().collect());
+        }
+        if clean_up_tokenization_spaces {
+            pieces = self.clean_up_tokenization_pieces(pieces);
+        }
+
+        let mut piece_offsets = Vec::with_capacity(pieces.len());
+        let mut position = 0;
+        for piece in pieces.iter() {
+            let piece_length = piece.chars().count() as OffsetSize;
+            let leading_whitespaces = piece.chars().take_while(|c| c.is_whitespace()).count();
+            let trailing_whitespaces = piece
+                .chars()
+                .rev()
+                .take_while(|c| c.is_whitespace())
+                .count();
+            piece_offsets.push(if leading_whitespaces as OffsetSize == piece_length {
+                None
+            } else {
+                Some(Offset::new(
+                    position + leading_whitespaces as OffsetSize,
+                    position + piece_length - trailing_whitespaces as OffsetSize,
+                ))
+            });
+            position += piece_length;
+        }
+        let offsets = piece_indices
+            .into_iter()
+            .map(|piece_index| piece_index.and_then(|index| piece_offsets[index]))
+            .collect();
+        (pieces.concat(), offsets)
+    }
+
+    /// Converts a sequence of strings into a single string. This will clean-up artifacts from tokenization
+    /// (for example `sub ##word`) and generate a single output string
+    ///
+    /// # Arguments
+    /// - tokens: list of tokens to concatenate.
+    ///
+    /// # Returns
+    /// - `String`: concatenated sentence string
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{BaseTokenizer, Tokenizer, TruncationStrategy};
+    /// use rust_tokenizers::vocab::BaseVocab;
+    /// let strip_accents = false;
+    /// let lower_case = false;
+    /// let tokenizer: BaseTokenizer<BaseVocab> =
+    ///     BaseTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
+    ///
+    /// let skip_special_tokens = true;
+    /// let clean_up_tokenization_spaces = true;
+    /// let tokens = vec![
+    ///     "Hello".to_string(),
+    ///     ",".to_string(),
+    ///     "World".to_string(),
+    ///     "!".to_string(),
+    /// ];
+    /// let decoded = tokenizer.convert_tokens_to_string(tokens);
+    /// ```
+    fn convert_tokens_to_string(&self, tokens: Vec<String>) -> String {
+        tokens.join(" ")
+    }
+
+    /// Converts a sequence of tokens into the bytes of the text they represent, without replacing
+    /// incomplete or invalid UTF-8 sequences. Byte-level tokenizers map each character of the
+    /// tokens back to its byte, other tokenizers return the bytes of `convert_tokens_to_string`.
+    /// The output is not trimmed.
+    ///
+    /// # Arguments
+    /// - tokens: list of tokens to convert
+    ///
+    /// # Returns
+    /// - `Vec<u8>`: bytes of the text represented by the tokens
+    fn convert_tokens_to_bytes(&self, tokens: Vec<String>) -> Vec<u8> {
+        self.convert_tokens_to_string(tokens).into_bytes()
+    }
+
+    /// Cleans-up tokenization artifacts (for example whitespace before punctuation)
+    ///
+    /// # Arguments
+    /// - input_string (`String`): input string to clean up
+    ///
+    /// # Returns
+    /// - `String`: clean-up string
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{BaseTokenizer, Tokenizer, TruncationStrategy};
+    /// use rust_tokenizers::vocab::BaseVocab;
+    /// let strip_accents = false;
+    /// let lower_case = false;
+    /// let tokenizer: BaseTokenizer<BaseVocab> =
+    ///     BaseTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
+    ///
+    /// let skip_special_tokens = true;
+    /// let clean_up_tokenization_spaces = true;
+    /// let input_string = "Hello . Do n't pay attention to the punctuation .".to_string();
+    /// let cleaned_string = tokenizer.clean_up_tokenization(input_string);
+    /// ```
+    fn clean_up_tokenization(&self, input_string: String) -> String {
+        CLEAN_UP_TOKENIZATION_REPLACEMENTS
+            .iter()
+            .fold(input_string, |text, (pattern, replacement)| {
+                text.replace(pattern, replacement)
+            })
+    }
+
+    /// Cleans-up tokenization artifacts (for example whitespace before punctuation) of a text split
+    /// in pieces, such as the decoded text of each token. The concatenation of the output pieces is
+    /// identical to the output of `clean_up_tokenization` on the concatenated input pieces, and each
+    /// output piece only contains characters originating from the corresponding input piece.
+    ///
+    /// # Arguments
+    /// - pieces (`Vec<String>`): input pieces to clean up
+    ///
+    /// # Returns
+    /// - `Vec<String>`: clean-up pieces, with the same length as the input
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{BaseTokenizer, Tokenizer, TruncationStrategy};
+    /// use rust_tokenizers::vocab::BaseVocab;
+    /// let strip_accents = false;
+    /// let lower_case = false;
+    /// let tokenizer: BaseTokenizer<BaseVocab> =
+    ///     BaseTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
+    ///
+    /// let pieces = vec!["Hello".to_string(), " .".to_string()];
+    /// let cleaned_pieces = tokenizer.clean_up_tokenization_pieces(pieces);
+    /// ```
+    fn clean_up_tokenization_pieces(&self, pieces: Vec<String>) -> Vec<String> {
+        replace_in_pieces(pieces, &CLEAN_UP_TOKENIZATION_REPLACEMENTS)
+    }
+
+    /// Converts a list of sequence of ids (integer) into a string, using the tokenizer and vocabulary
+    /// with options to remove special tokens and clean up tokenization spaces. This calls `decode`
+    /// for each provided sequence of ids
+    ///
+    /// # Arguments
+    /// - token_ids: list of list of tokenized input ids. Can be obtained using the `encode` or `encode_plus` methods.
+    /// - skip_special_tokens: if set to True, will replace special tokens.
+    /// - clean_up_tokenization_spaces: if set to True, will clean up the tokenization spaces.
+    ///
+    /// # Returns
+    /// - `String`: decoded sentence
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{BaseTokenizer, Tokenizer, TruncationStrategy};
+    /// use rust_tokenizers::vocab::BaseVocab;
+    /// let strip_accents = false;
+    /// let lower_case = false;
+    /// let tokenizer: BaseTokenizer<BaseVocab> =
+    ///     BaseTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
+    ///
+    /// let skip_special_tokens = true;
+    /// let clean_up_tokenization_spaces = true;
+    /// let token_ids_list = vec![vec![0, 1, 2, 42], vec![99, 3]];
+    /// let decoded_list = tokenizer.decode_list(
+    ///     &token_ids_list,
+    ///     skip_special_tokens,
+    ///     clean_up_tokenization_spaces,
+    /// );
+    /// ```
+    fn decode_list(
+        &self,
+        token_ids_list: &[Vec<i64>],
+        skip_special_tokens: bool,
+        clean_up_tokenization_spaces: bool,
+    ) -> Vec<String> {
+        token_ids_list
+            .iter()
+            .map(|token_ids| {
+                self.decode(token_ids, skip_special_tokens, clean_up_tokenization_spaces)
+            })
+            .collect()
+    }
+
+    /// Self-test of the tokenizer over a corpus: each sample is encoded (without special tokens)
+    /// and decoded back, and the result is compared to the input. The comparison is made on a
+    /// normalized form of both texts (NFKD with combining marks removed, lower cased, whitespace
+    /// removed) so that the casing, accent and spacing conventions of the tokenizer are not
+    /// reported. Mismatches are typically caused by characters missing from the vocabulary.
+    /// This is useful to validate a custom vocabulary before training a model.
+    ///
+    /// # Parameters
+    /// - corpus (`IntoIterator<Item = AsRef<str>>`): samples to verify
+    ///
+    /// # Returns
+    /// - `Result<VerificationReport, TokenizerError>` with the number of samples, mismatches, tokens
+    ///   and unknown tokens, and the first mismatching samples. An error is returned if a sample can
+    ///   not be decoded (e.g. for an index not found in the vocabulary with `UnknownIdPolicy::Error`).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{BaseTokenizer, Tokenizer};
+    /// use rust_tokenizers::vocab::BaseVocab;
+    /// let strip_accents = false;
+    /// let lower_case = false;
+    /// let tokenizer: BaseTokenizer<BaseVocab> =
+    ///     BaseTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
+    ///
+    /// let corpus = ["Hello, world!", "This is a second sample."];
+    /// let report = tokenizer.verify(corpus.iter()).unwrap();
+    /// println!(
+    ///     "{} mismatches out of {} samples",
+    ///     report.num_mismatches, report.num_samples
+    /// );
+    /// ```
+    fn verify<I, S>(&self, corpus: I) -> Result<VerificationReport, TokenizerError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let unknown_id = self.vocab().token_to_id(self.vocab().get_unknown_value());
+        let mut report = VerificationReport::default();
+        for (index, sample) in corpus.into_iter().enumerate() {
+            let sample = sample.as_ref();
+            let token_ids = self.convert_tokens_to_ids(&self.tokenize(sample));
+            let decoded = self.try_decode(&token_ids, true, true)?;
+            report.num_samples += 1;
+            report.num_tokens += token_ids.len();
+            report.num_unknown_tokens += token_ids.iter().filter(|id| **id == unknown_id).count();
+            if normalize_for_verification(sample) != normalize_for_verification(&decoded) {
+                report.num_mismatches += 1;
+                if report.examples.len() < VerificationReport::MAX_VERIFICATION_EXAMPLES {
+                    report.examples.push(VerificationMismatch {
+                        index,
+                        input: sample.to_owned(),
+                        decoded,
+                    });
+                }
+            }
+        }
+        Ok(report)
+    }
+
+    /// Build model inputs from a sequence or a pair of sequence for sequence classification tasks
+    /// by concatenating and adding special tokens.
+    ///
+    /// For example, a RoBERTa sequence has the following format:
+    /// - single sequence: <s> X </s>
+    /// - pair of sequences: <s> A </s></s> B </s>
+    ///
+    /// # Parameters
+    /// - tokens_ids_with_offsets_1 (`TokenIdsWithOffsets`): first sequence
+    /// - tokens_ids_with_offsets_2 (`TokenIdsWithOffsets`): (optional) second sequence
+    ///
+    /// # Returns
+    /// - `TokenIdsWithSpecialTokens` containing a concatenation of both sequences with added special tokens.
+    ///   Special tokens inserted by the tokenizer have no source span: their offset is `None` and
+    ///   their reference offsets are empty.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{BaseTokenizer, Tokenizer, TruncationStrategy};
+    /// use rust_tokenizers::vocab::BaseVocab;
+    /// use rust_tokenizers::TokenIdsWithOffsets;
+    /// let strip_accents = false;
+    /// let lower_case = false;
+    /// let tokenizer: BaseTokenizer<BaseVocab> =
+    ///     BaseTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
+    ///
+    /// let skip_special_tokens = true;
+    /// let clean_up_tokenization_spaces = true;
+    /// let first_sequence = "Hello, world";
+    /// let second_sequence = "This is the second sentence";
+    ///
+    /// let first_tokens = tokenizer.tokenize_with_offsets(first_sequence);
+    /// let first_ids = tokenizer.convert_tokens_to_ids(&first_tokens.tokens);
+    /// let first_input = TokenIdsWithOffsets {
+    ///     ids: first_ids,
+    ///     offsets: first_tokens.offsets,
+    ///     reference_offsets: first_tokens.reference_offsets,
+    ///     masks: first_tokens.masks,
+    /// };
+    ///
+    /// let second_tokens = tokenizer.tokenize_with_offsets(second_sequence);
+    /// let second_ids = tokenizer.convert_tokens_to_ids(&second_tokens.tokens);
+    /// let second_input = TokenIdsWithOffsets {
+    ///     ids: second_ids,
+    ///     offsets: second_tokens.offsets,
+    ///     reference_offsets: second_tokens.reference_offsets,
+    ///     masks: second_tokens.masks,
+    /// };
+    ///
+    /// let combined_with_special_tokens =
+    ///     tokenizer.build_input_with_special_tokens(first_input, Some(second_input));
+    /// ```
+    fn build_input_with_special_tokens(
+        &self,
+        tokens_ids_with_offsets_1: TokenIdsWithOffsets,
+        tokens_ids_with_offsets_2: Option<TokenIdsWithOffsets>,
+    ) -> TokenIdsWithSpecialTokens {
+        concatenate_token_ids(tokens_ids_with_offsets_1, tokens_ids_with_offsets_2)
+    }
+
+    /// Add arbitrary tokens to the vocabulary.
+    ///
+    /// These tokens are added to the special token map and are ignored from the tokenization
+    /// algorithm chosen (e.g.
+    ///
+    /// # Parameters
+    /// - tokens (`&[&str]`): list of tokens to add to the vocabulary
+    fn add_tokens(&mut self, tokens: &[&str]) {
+        self.vocab_mut().add_tokens(tokens);
+    }
+
+    /// Add arbitrary tokens to the vocabulary.
+    ///
+    /// These tokens are added to the special token map and are ignored from the tokenization
+    /// algorithm chosen (e.g.
+    ///
+    /// # Parameters
+    /// - num_extra_ids (`i64`): number of tokens to append
+    fn add_extra_ids(&mut self, num_extra_ids: i64) {
+        self.vocab_mut().add_extra_ids(num_extra_ids);
+    }
+}
+
+/// # Extension for tokenizers with configurable options
+/// Setters of the `TokenizerOptions` read by the provided methods of the `Tokenizer` trait.
+/// Implementors only need to give mutable access to the options returned by `Tokenizer::options`.
+pub trait ConfigurableTokenizer<T: Vocab>
+where
+    Self: Tokenizer<T>,
+{
+    /// returns a mutable reference to the options of the tokenizer
+    fn options_mut(&mut self) -> &mut TokenizerOptions;
+
+    /// Sets the policy applied to ids not found in the vocabulary when decoding
+    ///
+    /// # Parameters
+    /// - unknown_id_policy (`UnknownIdPolicy`): unknown id policy
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{BertTokenizer, ConfigurableTokenizer, UnknownIdPolicy};
+    /// let mut tokenizer = BertTokenizer::from_file("path/to/vocab/file", true, true).unwrap();
+    ///
+    /// tokenizer.set_unknown_id_policy(UnknownIdPolicy::SkipToken);
+    /// ```
+    fn set_unknown_id_policy(&mut self, unknown_id_policy: UnknownIdPolicy) {
+        self.options_mut().unknown_id_policy = unknown_id_policy;
+    }
+
+    /// Sets the normalization applied to the digits of the pre-tokenized text
+    ///
+    /// # Parameters
+    /// - digit_normalization (`DigitNormalization`): digit normalization mode
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{
+    ///     BertTokenizer, ConfigurableTokenizer, DigitNormalization, Tokenizer,
+    /// };
+    /// use rust_tokenizers::vocab::Vocab;
+    /// let strip_accents = false;
+    /// let lower_case = true;
+    /// let mut tokenizer =
+    ///     BertTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
+    ///
+    /// tokenizer.vocab_mut().add_tokens(&["<num>"]);
+    /// tokenizer.set_digit_normalization(DigitNormalization::Placeholder("<num>".to_string()));
+    /// let tokens = tokenizer.tokenize("Founded in 1969");
+    /// ```
+    fn set_digit_normalization(&mut self, digit_normalization: DigitNormalization) {
+        self.options_mut().digit_normalization = digit_normalization;
+    }
+
+    /// Sets whether the encoding output carries the token string of each token id (including
+    /// special tokens) in its `tokens` field, aligned with the truncated `token_ids`. Tokens that are
+    /// not part of the vocabulary are represented by the unknown token.
+    ///
+    /// # Parameters
+    /// - return_tokens (`bool`): flag indicating if the token strings should be returned
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{
+    ///     BertTokenizer, ConfigurableTokenizer, Tokenizer, TruncationStrategy,
+    /// };
+    /// let mut tokenizer = BertTokenizer::from_file("path/to/vocab/file", true, true).unwrap();
+    ///
+    /// tokenizer.set_return_tokens(true);
+    /// let encoded_input =
+    ///     tokenizer.encode("Hello, world!", None, 128, &TruncationStrategy::LongestFirst, 0);
+    /// let tokens = encoded_input.tokens.unwrap();
+    /// ```
+    fn set_return_tokens(&mut self, return_tokens: bool) {
+        self.options_mut().return_tokens = return_tokens;
+    }
+
+    /// Sets the policy applied by the SentencePiece-based tokenizers to pieces not found in the
+    /// vocabulary when tokenizing. The policy has no effect on the other tokenizers.
+    ///
+    /// # Parameters
+    /// - unknown_piece_policy (`UnknownPiecePolicy`): unknown piece policy
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{ConfigurableTokenizer, T5Tokenizer, UnknownPiecePolicy};
+    /// let lower_case = false;
+    /// let mut tokenizer = T5Tokenizer::from_file("path/to/vocab/file", lower_case).unwrap();
+    ///
+    /// tokenizer.set_unknown_piece_policy(UnknownPiecePolicy::SkipToken);
+    /// ```
+    fn set_unknown_piece_policy(&mut self, unknown_piece_policy: UnknownPiecePolicy) {
+        self.options_mut().unknown_piece_policy = unknown_piece_policy;
+    }
+
+    /// Enables or disables the byte fallback of the SentencePiece-based tokenizers. Enabling byte
+    /// fallback sets the `UnknownPiecePolicy::ByteFallback` policy, disabling it restores the default
+    /// `UnknownPiecePolicy::ReplaceWithUnk` policy.
+    ///
+    /// # Parameters
+    /// - byte_fallback (`bool`): flag indicating if byte fallback should be used
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{ConfigurableTokenizer, T5Tokenizer};
+    /// let lower_case = false;
+    /// let mut tokenizer = T5Tokenizer::from_file("path/to/vocab/file", lower_case).unwrap();
+    ///
+    /// tokenizer.set_byte_fallback(true);
+    /// ```
+    fn set_byte_fallback(&mut self, byte_fallback: bool) {
+        self.set_unknown_piece_policy(if byte_fallback {
+            UnknownPiecePolicy::ByteFallback
+        } else {
+            UnknownPiecePolicy::ReplaceWithUnk
+        });
+    }
+
+    /// Appends a filter to the chain of filters applied to the tokens of each input after
+    /// tokenization and before their encoding. Filters are applied in the order they were added,
+    /// each filter receiving the output of the previous one.
+    ///
+    /// # Parameters
+    /// - token_filter (`Box<dyn TokenFilter>`): token filter (for example a closure taking and
+    ///   returning `TokensWithOffsets`)
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{
+    ///     BaseTokenizer, ConfigurableTokenizer, Tokenizer, TruncationStrategy,
+    /// };
+    /// use rust_tokenizers::vocab::BaseVocab;
+    /// use rust_tokenizers::{Mask, TokensWithOffsets};
+    /// let mut tokenizer: BaseTokenizer<BaseVocab> =
+    ///     BaseTokenizer::from_file("path/to/vocab/file", false, false).unwrap();
+    ///
+    /// tokenizer.add_token_filter(Box::new(|mut tokens: TokensWithOffsets| {
+    ///     tokens.retain(|_, _, mask| mask != Mask::Punctuation);
+    ///     tokens
+    /// }));
+    /// let encoded = tokenizer.encode("Hello, world!", None, 128, &TruncationStrategy::LongestFirst, 0);
+    /// ```
+    fn add_token_filter(&mut self, token_filter: Box<dyn TokenFilter>) {
+        self.options_mut().token_filters.push(token_filter);
+    }
+
+    /// Removes all the filters applied to the tokens before their encoding
+    fn clear_token_filters(&mut self) {
+        self.options_mut().token_filters.clear();
+    }
+
+    /// returns a mutable reference to the BPE cache of the tokenizer, if any
+    fn bpe_cache_mut(&mut self) -> Option<&mut BpeCache> {
+        None
+    }
+
+    /// Enables or disables the collection of statistics over the `encode_list` and
+    /// `encode_pair_list` calls (see `Tokenizer::stats`). Statistics are not collected by default,
+    /// and the BPE cache lookups are only counted while the collection is enabled. Enabling the
+    /// collection resets the aggregated statistics.
+    ///
+    /// # Parameters
+    /// - collect_stats (`bool`): flag indicating if statistics should be collected
+    fn set_collect_stats(&mut self, collect_stats: bool) {
+        self.options_mut().stats_collector = collect_stats.then(TokenizationStatsCollector::new);
+        if let Some(cache) = self.bpe_cache_mut() {
+            cache.reset_counters();
+            cache.set_count_lookups(collect_stats);
+        }
+    }
+
+    /// Resets the aggregated statistics and the BPE cache counters
+    fn reset_stats(&mut self) {
+        if let Some(stats_collector) = &self.options().stats_collector {
+            stats_collector.reset();
+        }
+        if let Some(cache) = self.bpe_cache_mut() {
+            cache.reset_counters();
+        }
+    }
+}
+
+/// # Extension for tokenizers based on a SentencePiece unigram model
+pub trait UnigramTokenizer<T: Vocab>
+where
+    Self: Tokenizer<T>,
+{
+    /// returns a reference to the tokenizer unigram model
+    fn unigram_model(&self) -> &SentencePieceModel;
+
+    /// Returns the log-probability of a sequence of tokens under the unigram model of the tokenizer
+    /// (sum of the tokens log-probabilities). Tokens that are not part of the model are penalized
+    /// as in SentencePiece (see `SentencePieceModel::score_pieces`).
+    ///
+    /// # Parameters
+    /// - tokens (`&[S]`): sequence of tokens (string-like) to score
+    ///
+    /// # Returns
+    /// `f32` log-probability of the sequence of tokens
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{SentencePieceTokenizer, Tokenizer, UnigramTokenizer};
+    /// let tokenizer = SentencePieceTokenizer::from_file("path/to/model", false).unwrap();
+    ///
+    /// let tokens = tokenizer.tokenize("Hello, world!");
+    /// let score = tokenizer.score_tokens(&tokens);
+    /// ```
+    fn score_tokens<S: AsRef<str>>(&self, tokens: &[S]) -> f32 {
+        self.unigram_model().score_pieces(tokens)
+    }
+
+    /// Tokenizes a text and returns the log-probability of the resulting tokens under the unigram
+    /// model of the tokenizer. This can be used to filter noisy data or compare the segmentation
+    /// quality of different models (longer texts have lower scores: the score may be divided by the
+    /// number of characters or tokens to compare texts of different lengths).
+    ///
+    /// # Parameters
+    /// - text (`&str`): text to score
+    ///
+    /// # Returns
+    /// `f32` log-probability of the tokenized text
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{SentencePieceTokenizer, UnigramTokenizer};
+    /// let tokenizer = SentencePieceTokenizer::from_file("path/to/model", false).unwrap();
+    ///
+    /// let score = tokenizer.score_text("Hello, world!");
+    /// ```
+    fn score_text(&self, text: &str) -> f32 {
+        self.score_tokens(&self.tokenize(text))
+    }
+}
+
+/// # Extension for byte-level BPE tokenizers with a configurable prefix space
+/// Byte-level BPE tokenizers (GPT2, RoBERTa, DeBERTa) encode the space preceding a word as part of
+/// the word token: the first word of a text not starting with a whitespace is therefore encoded
+/// differently from the same word in the middle of a sentence. When `add_prefix_space` is set, a
+/// space is prepended to the input before tokenization (matching the `add_prefix_space` option of
+/// the Python tokenizers). The prefix space is mapped to the position of the first character of the
+/// input and the configured value can be overridden for individual calls.
+pub trait PrefixSpaceTokenizer<T: Vocab>
+where
+    Self: Tokenizer<T>,
+{
+    /// Returns `true` if a space is prepended to inputs not starting with a whitespace
+    fn add_prefix_space(&self) -> bool;
+
+    /// Sets whether a space is prepended to inputs not starting with a whitespace
+    ///
+    /// # Parameters
+    /// - add_prefix_space (`bool`): flag indicating if a space should be prepended to the input text
+    ///   before tokenization
+    fn set_add_prefix_space(&mut self, add_prefix_space: bool);
+
+    /// Tokenize a TokenRef, returning a sequence of tokens, prepending a space to the input
+    /// following the `add_prefix_space` flag provided instead of the tokenizer configuration.
+    ///
+    /// # Parameters
+    /// - text (`TokenRef`): TokenRef to tokenize (this is especially useful for nested tokenization,
+    ///   where a tokenizer is called on the ouput of a pre-tokenizer, such as BERT).
+    /// - add_prefix_space (`bool`): flag indicating if a space should be prepended to the input
+    ///
+    /// # Returns
+    /// `Vec<Token>` tokenization of the original `TokenRef`
+    fn tokenize_to_tokens_with_prefix_space(
+        &self,
+        text: TokenRef,
+        add_prefix_space: bool,
+    ) -> Vec<Token>;
+
+    /// Tokenize a string, overriding the `add_prefix_space` configuration of the tokenizer.
+    ///
+    /// # Parameters
+    /// - text : text (string-like) to tokenize
+    /// - add_prefix_space (`bool`): flag indicating if a space should be prepended to the input
+    ///
+    /// # Returns
+    /// `Vec<String>` containing the tokens string representation
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{Gpt2Tokenizer, PrefixSpaceTokenizer};
+    /// let lower_case = false;
+    /// let tokenizer =
+    ///     Gpt2Tokenizer::from_file("path/to/vocab/file", "path/to/merges/file", lower_case, false)
+    ///         .unwrap();
+    ///
+    /// let tokens = tokenizer.tokenize_with_prefix_space("Hello, world!", true);
+    /// ```
+    fn tokenize_with_prefix_space(&self, text: &str, add_prefix_space: bool) -> Vec<String> {
+        self.tokenize_with_offsets_and_prefix_space(text, add_prefix_space)
+            .tokens
+    }
+
+    /// Tokenize a string, returning tokens with offset information and overriding the
+    /// `add_prefix_space` configuration of the tokenizer.
+    ///
+    /// # Parameters
+    /// - text : text (string-like) to tokenize
+    /// - add_prefix_space (`bool`): flag indicating if a space should be prepended to the input
+    ///
+    /// # Returns
+    /// `TokensWithOffsets` with the tokens and their offset information
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{Gpt2Tokenizer, PrefixSpaceTokenizer};
+    /// let lower_case = false;
+    /// let tokenizer =
+    ///     Gpt2Tokenizer::from_file("path/to/vocab/file", "path/to/merges/file", lower_case, false)
+    ///         .unwrap();
+    ///
+    /// let tokens = tokenizer.tokenize_with_offsets_and_prefix_space("Hello, world!", true);
+    /// ```
+    fn tokenize_with_offsets_and_prefix_space(
+        &self,
+        text: &str,
+        add_prefix_space: bool,
+    ) -> TokensWithOffsets {
+        tokenize_text_with(text, self.digit_normalization(), &|token| {
+            self.tokenize_to_tokens_with_prefix_space(token, add_prefix_space)
+        })
+    }
+
+    /// Encode a string-like (tokenization followed by encoding), overriding the `add_prefix_space`
+    /// configuration of the tokenizer for both inputs.
+    ///
+    /// # Parameters
+    /// - text_1: input text (string-like) to encode
+    /// - text_2: optional additional input text (string-like) to encode. When provided, both texts are
+    ///   combined into a single encoding by using the `build_input_with_special_tokens` method.
+    /// - max_len (`usize`): maximum combined sequence length. If the combined encoding would exceed this
+    ///   max_len, the encoding is truncated following the `TruncationStrategy` provided.
+    /// - truncation_strategy (`&TruncationStrategy`): strategy to follow for the truncation, if required
+    /// - stride (`usize`): amount of tokens to shift the input by if truncation is required
+    ///   (allowing for the generation of overlapping sequences with overflowing tokens)
+    /// - add_prefix_space (`bool`): flag indicating if a space should be prepended to the inputs
+    ///
+    /// # Returns
+    /// `Result<TokenizedInput, TokenizerError>` containing the encoding output, or an error if the
+    /// input can not be truncated following the `TruncationStrategy` provided
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{Gpt2Tokenizer, PrefixSpaceTokenizer, TruncationStrategy};
+    /// let lower_case = false;
+    /// let tokenizer =
+    ///     Gpt2Tokenizer::from_file("path/to/vocab/file", "path/to/merges/file", lower_case, false)
+    ///         .unwrap();
+    ///
+    /// let encoded_input = tokenizer.encode_with_prefix_space(
+    ///     "Hello, world!",
+    ///     None,
+    ///     128,
+    ///     &TruncationStrategy::LongestFirst,
+    ///     0,
+    ///     true,
+    /// );
+    /// ```
+    fn encode_with_prefix_space(
+        &self,
+        text_1: &str,
+        text_2: Option<&str>,
+        max_len: usize,
+        truncation_strategy: &TruncationStrategy,
+        stride: usize,
+        add_prefix_space: bool,
+    ) -> Result<TokenizedInput, TokenizerError> {
+        let tokens_1 = self.tokenize_with_offsets_and_prefix_space(text_1, add_prefix_space);
+        let tokens_2 =
+            text_2.map(|text| self.tokenize_with_offsets_and_prefix_space(text, add_prefix_space));
+        encode_tokens_with_offsets(
+            self,
+            tokens_1,
+            tokens_2,
+            max_len,
+            truncation_strategy,
+            stride,
+            true,
+        )
+    }
+}
+
+/// # Extension for multithreaded tokenizers
+pub trait MultiThreadedTokenizer<T: Vocab>
+where
+    Self: Sync + Send + Tokenizer<T>,
+{
+    /// returns a reference to the tokenizer vocabulary
+    fn vocab(&self) -> &T {
+        Tokenizer::<T>::vocab(self)
+    }
+
+    /// Tokenize a list of strings (with multithreading), where each corresponds to for example a sentence, returns a
+    /// vector of TokensWithOffsets containing the tokens and their offset information. This calls
+    /// `tokenize_with_offsets` on the list provided.
+    ///
+    /// # Parameters
+    /// - text_list: list of strings to tokenize
+    ///
+    /// # Returns
+    /// `Vec<TokensWithOffsets>` with the token strings representation and offsets
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{BaseTokenizer, Tokenizer};
+    /// use rust_tokenizers::vocab::BaseVocab;
+    /// let strip_accents = false;
+    /// let lower_case = false;
+    /// let tokenizer: BaseTokenizer<BaseVocab> =
+    ///     BaseTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
+    ///
+    /// let text = ["Hello, world!", "Second sentence"];
+    /// let tokens = tokenizer.tokenize_list_with_offsets(&text);
+    /// ```
+    fn tokenize_list_with_offsets<S>(&self, text_list: &[S]) -> Vec<TokensWithOffsets>
+    where
+        S: AsRef<str> + Sync,
+    {
+        text_list
+            .as_ref()
+            .par_iter()
+            .map(|text| self.tokenize_with_offsets(text.as_ref()))
+            .collect()
+    }
+
+    /// Multithreaded tokenization of a list of strings, returning tokens with offset information
+    ///
+    /// # Parameters
+    /// - text_list: list of strings to tokenize
+    ///
+    /// # Returns
+    /// `Vec<Vec<String>>` with the token strings representation
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{BaseTokenizer, MultiThreadedTokenizer};
+    /// use rust_tokenizers::vocab::BaseVocab;
+    /// let strip_accents = false;
+    /// let lower_case = false;
+    /// let tokenizer: BaseTokenizer<BaseVocab> =
+    ///     BaseTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
+    ///
+    /// let texts = ["Hello, world!", "Second sentence"];
+    /// let tokens = tokenizer.tokenize_list(&texts);
+    /// ```
+    fn tokenize_list<S>(&self, text_list: &[S]) -> Vec<Vec<String>>
+    where
+        S: AsRef<str> + Sync,
+    {
+        text_list
+            .as_ref()
+            .par_iter()
+            .map(|text| self.tokenize(text.as_ref()))
+            .collect()
+    }
+
+    /// Multithreaded encoding of a sequence of string-like texts (tokenization followed by encoding). Not that in contrast
+    /// with `encode` optional second text, each text provided is encoded independently.
+    ///
+    /// # Parameters
+    /// - text_list: sequence of input text (`&str`) to encode
+    /// combined into a single encoding by using the `build_input_with_special_tokens` method.
+    /// - max_len (`usize`): maximum combined sequence length. If the combined encoding would exceed this
+    /// max_len, the encoding is truncated following the `TruncationStrategy` provided.
+    /// - truncation_strategy (`&TruncationStrategy`): strategy to follow for the truncation, if required
+    /// - stride (`usize`): amount of tokens to shift the input by if truncation is required
+    /// (allowing for the generation of overlapping sequences with overflowing tokens)
+    ///
+    /// # Returns
+    /// `Vec<TokenizedInput>` containing the encoding output (token indices, token types, segment ids,
+    /// ovrflowing tokens and special token mask) for each provided text
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{BaseTokenizer, MultiThreadedTokenizer, TruncationStrategy};
+    /// use rust_tokenizers::vocab::BaseVocab;
+    /// let strip_accents = false;
+    /// let lower_case = false;
+    /// let tokenizer: BaseTokenizer<BaseVocab> =
+    ///     BaseTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
+    ///
+    /// let text_1 = "Hello, world!";
+    /// let text_2 = "How is it going?";
+    /// let text_3 = "Very well thank you.";
+    /// let encoded_input = tokenizer.encode_list(
+    ///     &[text_1, text_2, text_3],
+    ///     5,
+    ///     &TruncationStrategy::LongestFirst,
+    ///     2,
+    /// );
+    /// ```
+    fn encode_list<S>(
+        &self,
+        text_list: &[S],
+        max_len: usize,
+        truncation_strategy: &TruncationStrategy,
+        stride: usize,
+    ) -> Vec<TokenizedInput>
+    where
+        S: AsRef<str> + Sync,
+    {
+        let start = self.stats_collector().is_some().then(Instant::now);
+        let encoded_inputs: Vec<TokenizedInput> = text_list
+            .as_ref()
+            .par_iter()
+            .map(|text| self.encode(text.as_ref(), None, max_len, truncation_strategy, stride))
+            .collect();
+        record_stats(self, &encoded_inputs, start);
+        encoded_inputs
+    }
+
+    /// Multithreaded ncoding of a sequence of string-like text pairs (tokenization followed by encoding). This combines
+    /// with `encode` with the list processing of `encode_list`.
+    ///
+    /// # Parameters
+    /// - text_list: sequence of input text (`&str`) to encode
+    /// combined into a single encoding by using the `build_input_with_special_tokens` method.
+    /// - max_len (`usize`): maximum combined sequence length. If the combined encoding would exceed this
+    /// max_len, the encoding is truncated following the `TruncationStrategy` provided.
+    /// - truncation_strategy (`&TruncationStrategy`): strategy to follow for the truncation, if required
+    /// - stride (`usize`): amount of tokens to shift the input by if truncation is required
+    /// (allowing for the generation of overlapping sequences with overflowing tokens)
+    ///
+    /// # Returns
+    /// `Vec<TokenizedInput>` containing the encoding output (token indices, token types, segment ids,
+    /// ovrflowing tokens and special token mask) for each provided text
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{BaseTokenizer, MultiThreadedTokenizer, TruncationStrategy};
+    /// use rust_tokenizers::vocab::BaseVocab;
+    /// let strip_accents = false;
+    /// let lower_case = false;
+    /// let tokenizer: BaseTokenizer<BaseVocab> =
+    ///     BaseTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
+    ///
+    /// let text_1 = "Hello, world!";
+    /// let text_2 = "This is a second sentence";
+    /// let text_3 = "Very well thank you.";
+    /// let text_4 = "This is another second sentence.";
+    /// let encoded_input = tokenizer.encode_pair_list(
+    ///     &[(text_1, text_2), (text_3, text_4)],
+    ///     5,
+    ///     &TruncationStrategy::LongestFirst,
+    ///     2,
+    /// );
+    /// ```
+    fn encode_pair_list<S>(
+        &self,
+        text_list: &[(S, S)],
+        max_len: usize,
+        truncation_strategy: &TruncationStrategy,
+        stride: usize,
+    ) -> Vec<TokenizedInput>
+    where
+        S: AsRef<str> + Sync,
+    {
+        let start = self.stats_collector().is_some().then(Instant::now);
+        let encoded_inputs: Vec<TokenizedInput> = text_list
+            .as_ref()
+            .par_iter()
+            .map(|text| {
+                self.encode(
+                    text.0.as_ref(),
+                    Some(text.1.as_ref()),
+                    max_len,
+                    truncation_strategy,
+                    stride,
+                )
+            })
+            .collect();
+        record_stats(self, &encoded_inputs, start);
+        encoded_inputs
     }
 
-    /// Tokenize a list of strings (with multithreading), where each corresponds to for example a sentence, returns a
-    /// vector of TokensWithOffsets containing the tokens and their offset information. This calls
-    /// `tokenize_with_offsets` on the list provided.
+    /// Multithreaded encoding of a sequence of string-like texts (tokenization followed by encoding), returning an error
+    /// instead of panicking if any of the inputs can not be truncated following the `TruncationStrategy` provided.
     ///
     /// # Parameters
-    /// - text_list: list of strings to tokenize
+    /// - text_list: sequence of input text (`&str`) to encode
+    /// - max_len (`usize`): maximum combined sequence length. If the combined encoding would exceed this
+    ///   max_len, the encoding is truncated following the `TruncationStrategy` provided.
+    /// - truncation_strategy (`&TruncationStrategy`): strategy to follow for the truncation, if required
+    /// - stride (`usize`): amount of tokens to shift the input by if truncation is required
+    ///   (allowing for the generation of overlapping sequences with overflowing tokens)
     ///
     /// # Returns
-    /// `Vec<TokensWithOffsets>` with the token strings representation and offsets
+    /// `Result<Vec<TokenizedInput>, TokenizerError>` containing the encoding output (token indices, token types,
+    /// segment ids, ovrflowing tokens and special token mask) for each provided text
     ///
     /// # Example
     ///
     /// ```no_run
-    /// use rust_tokenizers::tokenizer::{BaseTokenizer, Tokenizer};
+    /// use rust_tokenizers::tokenizer::{BaseTokenizer, MultiThreadedTokenizer, TruncationStrategy};
     /// use rust_tokenizers::vocab::BaseVocab;
     /// let strip_accents = false;
     /// let lower_case = false;
     /// let tokenizer: BaseTokenizer<BaseVocab> =
     ///     BaseTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
     ///
-    /// let text = ["Hello, world!", "Second sentence"];
-    /// let tokens = tokenizer.tokenize_list_with_offsets(&text);
+    /// let text_1 = "Hello, world!";
+    /// let text_2 = "How is it going?";
+    /// let text_3 = "Very well thank you.";
+    /// let encoded_input = tokenizer.try_encode_list(
+    ///     &[text_1, text_2, text_3],
+    ///     5,
+    ///     &TruncationStrategy::LongestFirst,
+    ///     2,
+    /// );
     /// ```
-    fn tokenize_list_with_offsets<S>(&self, text_list: &[S]) -> Vec<TokensWithOffsets>
+    fn try_encode_list<S>(
+        &self,
+        text_list: &[S],
+        max_len: usize,
+        truncation_strategy: &TruncationStrategy,
+        stride: usize,
+    ) -> Result<Vec<TokenizedInput>, TokenizerError>
     where
         S: AsRef<str> + Sync,
     {
-        text_list
+        let start = self.stats_collector().is_some().then(Instant::now);
+        let encoded_inputs: Result<Vec<TokenizedInput>, TokenizerError> = text_list
             .as_ref()
             .par_iter()
-            .map(|text| self.tokenize_with_offsets(text.as_ref()))
-            .collect()
+            .map(|text| self.try_encode(text.as_ref(), None, max_len, truncation_strategy, stride))
+            .collect();
+        if let Ok(encoded_inputs) = &encoded_inputs {
+            record_stats(self, encoded_inputs, start);
+        }
+        encoded_inputs
     }
 
-    /// Multithreaded tokenization of a list of strings, returning tokens with offset information
+    /// Multithreaded encoding of a sequence of string-like texts without adding the special tokens of
+    /// the tokenizer (see `Tokenizer::encode_plain`).
     ///
     /// # Parameters
-    /// - text_list: list of strings to tokenize
+    /// - text_list: sequence of input text (`&str`) to encode
+    /// - max_len (`usize`): maximum sequence length. If the encoding would exceed this max_len, the
+    ///   encoding is truncated following the `TruncationStrategy` provided.
+    /// - truncation_strategy (`&TruncationStrategy`): strategy to follow for the truncation, if required
+    /// - stride (`usize`): amount of tokens to shift the input by if truncation is required
+    ///   (allowing for the generation of overlapping sequences with overflowing tokens)
     ///
     /// # Returns
-    /// `Vec<Vec<String>>` with the token strings representation
+    /// `Result<Vec<TokenizedInput>, TokenizerError>` containing the encoding output without special
+    /// tokens for each provided text
     ///
     /// # Example
     ///
     /// ```no_run
-    /// use rust_tokenizers::tokenizer::{BaseTokenizer, MultiThreadedTokenizer};
+    /// use rust_tokenizers::tokenizer::{BaseTokenizer, MultiThreadedTokenizer, TruncationStrategy};
     /// use rust_tokenizers::vocab::BaseVocab;
     /// let strip_accents = false;
     /// let lower_case = false;
     /// let tokenizer: BaseTokenizer<BaseVocab> =
     ///     BaseTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
     ///
-    /// let texts = ["Hello, world!", "Second sentence"];
-    /// let tokens = tokenizer.tokenize_list(&texts);
+    /// let encoded_input = tokenizer.encode_list_plain(
+    ///     &["Hello, world!", "How is it going?"],
+    ///     128,
+    ///     &TruncationStrategy::LongestFirst,
+    ///     0,
+    /// );
     /// ```
-    fn tokenize_list<S>(&self, text_list: &[S]) -> Vec<Vec<String>>
+    fn encode_list_plain<S>(
+        &self,
+        text_list: &[S],
+        max_len: usize,
+        truncation_strategy: &TruncationStrategy,
+        stride: usize,
+    ) -> Result<Vec<TokenizedInput>, TokenizerError>
     where
         S: AsRef<str> + Sync,
     {
-        text_list
+        let start = self.stats_collector().is_some().then(Instant::now);
+        let encoded_inputs: Result<Vec<TokenizedInput>, TokenizerError> = text_list
             .as_ref()
             .par_iter()
-            .map(|text| self.tokenize(text.as_ref()))
-            .collect()
+            .map(|text| {
+                self.encode_plain(text.as_ref(), None, max_len, truncation_strategy, stride)
+            })
+            .collect();
+        if let Ok(encoded_inputs) = &encoded_inputs {
+            record_stats(self, encoded_inputs, start);
+        }
+        encoded_inputs
     }
 
-    /// Multithreaded encoding of a sequence of string-like texts (tokenization followed by encoding). Not that in contrast
-    /// with `encode` optional second text, each text provided is encoded independently.
+    /// Multithreaded encoding of a sequence of string-like text pairs (tokenization followed by encoding), returning an
+    /// error instead of panicking if any of the inputs can not be truncated following the `TruncationStrategy`
+    /// provided.
     ///
     /// # Parameters
-    /// - text_list: sequence of input text (`&str`) to encode
-    /// combined into a single encoding by using the `build_input_with_special_tokens` method.
+    /// - text_list: sequence of input text pairs (`&str`) to encode
     /// - max_len (`usize`): maximum combined sequence length. If the combined encoding would exceed this
-    /// max_len, the encoding is truncated following the `TruncationStrategy` provided.
+    ///   max_len, the encoding is truncated following the `TruncationStrategy` provided.
     /// - truncation_strategy (`&TruncationStrategy`): strategy to follow for the truncation, if required
     /// - stride (`usize`): amount of tokens to shift the input by if truncation is required
-    /// (allowing for the generation of overlapping sequences with overflowing tokens)
+    ///   (allowing for the generation of overlapping sequences with overflowing tokens)
     ///
     /// # Returns
-    /// `Vec<TokenizedInput>` containing the encoding output (token indices, token types, segment ids,
-    /// ovrflowing tokens and special token mask) for each provided text
+    /// `Result<Vec<TokenizedInput>, TokenizerError>` containing the encoding output (token indices, token types,
+    /// segment ids, ovrflowing tokens and special token mask) for each provided text pair
     ///
     /// # Example
     ///
@@ -1326,47 +3802,63 @@ where
     ///     BaseTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
     ///
     /// let text_1 = "Hello, world!";
-    /// let text_2 = "How is it going?";
+    /// let text_2 = "This is a second sentence";
     /// let text_3 = "Very well thank you.";
-    /// let encoded_input = tokenizer.encode_list(
-    ///     &[text_1, text_2, text_3],
+    /// let text_4 = "This is another second sentence.";
+    /// let encoded_input = tokenizer.try_encode_pair_list(
+    ///     &[(text_1, text_2), (text_3, text_4)],
     ///     5,
     ///     &TruncationStrategy::LongestFirst,
     ///     2,
     /// );
     /// ```
-    fn encode_list<S>(
+    fn try_encode_pair_list<S>(
         &self,
-        text_list: &[S],
+        text_list: &[(S, S)],
         max_len: usize,
         truncation_strategy: &TruncationStrategy,
         stride: usize,
-    ) -> Vec<TokenizedInput>
+    ) -> Result<Vec<TokenizedInput>, TokenizerError>
     where
         S: AsRef<str> + Sync,
     {
-        text_list
+        let start = self.stats_collector().is_some().then(Instant::now);
+        let encoded_inputs: Result<Vec<TokenizedInput>, TokenizerError> = text_list
             .as_ref()
             .par_iter()
-            .map(|text| self.encode(text.as_ref(), None, max_len, truncation_strategy, stride))
-            .collect()
+            .map(|text| {
+                self.try_encode(
+                    text.0.as_ref(),
+                    Some(text.1.as_ref()),
+                    max_len,
+                    truncation_strategy,
+                    stride,
+                )
+            })
+            .collect();
+        if let Ok(encoded_inputs) = &encoded_inputs {
+            record_stats(self, encoded_inputs, start);
+        }
+        encoded_inputs
     }
 
-    /// Multithreaded ncoding of a sequence of string-like text pairs (tokenization followed by encoding). This combines
-    /// with `encode` with the list processing of `encode_list`.
+    /// Encode string-like text pairs provided as two parallel sequences (e.g. two columns of a
+    /// dataset), the i-th text of `texts_a` being paired with the i-th text of `texts_b`. This avoids
+    /// building an intermediate sequence of pairs for `encode_pair_list`. Inputs that can not be
+    /// truncated following the `TruncationStrategy` provided return an error.
     ///
     /// # Parameters
-    /// - text_list: sequence of input text (`&str`) to encode
-    /// combined into a single encoding by using the `build_input_with_special_tokens` method.
+    /// - texts_a: sequence of first texts (`&str`) of the pairs
+    /// - texts_b: sequence of second texts (`&str`) of the pairs, with the same length as `texts_a`
     /// - max_len (`usize`): maximum combined sequence length. If the combined encoding would exceed this
-    /// max_len, the encoding is truncated following the `TruncationStrategy` provided.
+    ///   max_len, the encoding is truncated following the `TruncationStrategy` provided.
     /// - truncation_strategy (`&TruncationStrategy`): strategy to follow for the truncation, if required
     /// - stride (`usize`): amount of tokens to shift the input by if truncation is required
-    /// (allowing for the generation of overlapping sequences with overflowing tokens)
+    ///   (allowing for the generation of overlapping sequences with overflowing tokens)
     ///
     /// # Returns
-    /// `Vec<TokenizedInput>` containing the encoding output (token indices, token types, segment ids,
-    /// ovrflowing tokens and special token mask) for each provided text
+    /// `Result<Vec<TokenizedInput>, TokenizerError>` containing the encoding output for each text pair,
+    /// failing with a `ValueError` if the sequences of texts have different lengths
     ///
     /// # Example
     ///
@@ -1378,40 +3870,52 @@ where
     /// let tokenizer: BaseTokenizer<BaseVocab> =
     ///     BaseTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
     ///
-    /// let text_1 = "Hello, world!";
-    /// let text_2 = "This is a second sentence";
-    /// let text_3 = "Very well thank you.";
-    /// let text_4 = "This is another second sentence.";
-    /// let encoded_input = tokenizer.encode_pair_list(
-    ///     &[(text_1, text_2), (text_3, text_4)],
+    /// let questions = ["Hello, world!", "Very well thank you."];
+    /// let answers = ["This is a second sentence", "This is another second sentence."];
+    /// let encoded_input = tokenizer.encode_pair_list_from_slices(
+    ///     &questions,
+    ///     &answers,
     ///     5,
     ///     &TruncationStrategy::LongestFirst,
     ///     2,
     /// );
     /// ```
-    fn encode_pair_list<S>(
+    fn encode_pair_list_from_slices<S>(
         &self,
-        text_list: &[(S, S)],
+        texts_a: &[S],
+        texts_b: &[S],
         max_len: usize,
         truncation_strategy: &TruncationStrategy,
         stride: usize,
-    ) -> Vec<TokenizedInput>
+    ) -> Result<Vec<TokenizedInput>, TokenizerError>
     where
         S: AsRef<str> + Sync,
     {
-        text_list
-            .as_ref()
+        if texts_a.len() != texts_b.len() {
+            return Err(TokenizerError::ValueError(format!(
+                "Pair sequences must have the same length, got {} and {} texts",
+                texts_a.len(),
+                texts_b.len()
+            )));
+        }
+        let start = self.stats_collector().is_some().then(Instant::now);
+        let encoded_inputs: Result<Vec<TokenizedInput>, TokenizerError> = texts_a
             .par_iter()
-            .map(|text| {
-                self.encode(
-                    text.0.as_ref(),
-                    Some(text.1.as_ref()),
+            .zip(texts_b.par_iter())
+            .map(|(text_a, text_b)| {
+                self.try_encode(
+                    text_a.as_ref(),
+                    Some(text_b.as_ref()),
                     max_len,
                     truncation_strategy,
                     stride,
                 )
             })
-            .collect()
+            .collect();
+        if let Ok(encoded_inputs) = &encoded_inputs {
+            record_stats(self, encoded_inputs, start);
+        }
+        encoded_inputs
     }
 
     /// Multithreaded conversion a list of sequence of ids (integer) into a string, using the tokenizer and vocabulary
@@ -1474,6 +3978,11 @@ pub struct BaseTokenizer<T: Vocab> {
     vocab: T,
     lower_case: bool,
     strip_accents: bool,
+    emoji_sequence_handling: EmojiSequenceHandling,
+    bidi_control_handling: BidiControlHandling,
+    lower_case_handling: LowerCaseHandling,
+    pattern_pre_tokenizer: Option<PatternPreTokenizer>,
+    options: TokenizerOptions,
 }
 
 impl<T: Vocab + Sync> BaseTokenizer<T> {
@@ -1512,6 +4021,11 @@ impl<T: Vocab + Sync> BaseTokenizer<T> {
             vocab,
             lower_case,
             strip_accents,
+            emoji_sequence_handling: EmojiSequenceHandling::default(),
+            bidi_control_handling: BidiControlHandling::default(),
+            lower_case_handling: LowerCaseHandling::default(),
+            pattern_pre_tokenizer: None,
+            options: TokenizerOptions::default(),
         })
     }
 
@@ -1543,6 +4057,11 @@ impl<T: Vocab + Sync> BaseTokenizer<T> {
             vocab,
             lower_case,
             strip_accents,
+            emoji_sequence_handling: EmojiSequenceHandling::default(),
+            bidi_control_handling: BidiControlHandling::default(),
+            lower_case_handling: LowerCaseHandling::default(),
+            pattern_pre_tokenizer: None,
+            options: TokenizerOptions::default(),
         })
     }
 
@@ -1573,6 +4092,298 @@ impl<T: Vocab + Sync> BaseTokenizer<T> {
             vocab,
             lower_case,
             strip_accents,
+            emoji_sequence_handling: EmojiSequenceHandling::default(),
+            bidi_control_handling: BidiControlHandling::default(),
+            lower_case_handling: LowerCaseHandling::default(),
+            pattern_pre_tokenizer: None,
+            options: TokenizerOptions::default(),
+        }
+    }
+
+    /// Sets the emoji sequence handling of the tokenizer. When emoji sequences are kept, they are
+    /// split from the surrounding text and protected from the cleaning steps (that would otherwise
+    /// remove zero-width joiners and variation selectors). Emoji sequences are split by default.
+    ///
+    /// # Parameters
+    /// - emoji_sequence_handling (`EmojiSequenceHandling`): emoji sequence handling mode
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{BaseTokenizer, EmojiSequenceHandling};
+    /// use rust_tokenizers::vocab::BaseVocab;
+    /// let strip_accents = false;
+    /// let lower_case = false;
+    /// let mut tokenizer: BaseTokenizer<BaseVocab> =
+    ///     BaseTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
+    /// tokenizer.set_emoji_sequence_handling(EmojiSequenceHandling::Keep);
+    /// ```
+    pub fn set_emoji_sequence_handling(&mut self, emoji_sequence_handling: EmojiSequenceHandling) {
+        self.emoji_sequence_handling = emoji_sequence_handling;
+    }
+
+    /// Returns the emoji sequence handling of the tokenizer
+    pub fn emoji_sequence_handling(&self) -> EmojiSequenceHandling {
+        self.emoji_sequence_handling
+    }
+
+    /// Sets the bidirectional control character handling of the tokenizer. These characters are
+    /// stripped by default with the other control characters.
+    ///
+    /// # Parameters
+    /// - bidi_control_handling (`BidiControlHandling`): bidi control character handling mode
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{BaseTokenizer, BidiControlHandling};
+    /// use rust_tokenizers::vocab::BaseVocab;
+    /// let strip_accents = false;
+    /// let lower_case = false;
+    /// let mut tokenizer: BaseTokenizer<BaseVocab> =
+    ///     BaseTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
+    /// tokenizer.set_bidi_control_handling(BidiControlHandling::Special);
+    /// ```
+    pub fn set_bidi_control_handling(&mut self, bidi_control_handling: BidiControlHandling) {
+        self.bidi_control_handling = bidi_control_handling;
+    }
+
+    /// Returns the bidirectional control character handling of the tokenizer
+    pub fn bidi_control_handling(&self) -> BidiControlHandling {
+        self.bidi_control_handling
+    }
+
+    /// Sets the lower casing handling of the tokenizer, allowing to keep acronyms and mixed-case
+    /// words (e.g. "NASA" or "iPhone") found in a cased vocabulary. This has no effect if the
+    /// tokenizer does not lower-case its input. All tokens are lower-cased by default.
+    ///
+    /// # Parameters
+    /// - lower_case_handling (`LowerCaseHandling`): lower casing handling mode
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{BaseTokenizer, LowerCaseHandling};
+    /// use rust_tokenizers::vocab::BaseVocab;
+    /// let strip_accents = false;
+    /// let lower_case = true;
+    /// let mut tokenizer: BaseTokenizer<BaseVocab> =
+    ///     BaseTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
+    /// tokenizer.set_lower_case_handling(LowerCaseHandling::PreserveAcronyms);
+    /// ```
+    pub fn set_lower_case_handling(&mut self, lower_case_handling: LowerCaseHandling) {
+        self.lower_case_handling = lower_case_handling;
+    }
+
+    /// Returns the lower casing handling of the tokenizer
+    pub fn lower_case_handling(&self) -> LowerCaseHandling {
+        self.lower_case_handling
+    }
+
+    /// Sets the pattern pre-tokenizer of the tokenizer, isolating the text matching its patterns
+    /// (for example URLs, emails, hashtags and @handles) into single tokens that are not split on
+    /// punctuation. No pattern pre-tokenizer is used by default.
+    ///
+    /// # Parameters
+    /// - pattern_pre_tokenizer (`Option<PatternPreTokenizer>`): pattern pre-tokenizer
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{BaseTokenizer, PatternPreTokenizer, TextPattern};
+    /// use rust_tokenizers::vocab::BaseVocab;
+    /// use rust_tokenizers::Mask;
+    /// let strip_accents = false;
+    /// let lower_case = false;
+    /// let mut tokenizer: BaseTokenizer<BaseVocab> =
+    ///     BaseTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
+    /// tokenizer.set_pattern_pre_tokenizer(Some(PatternPreTokenizer::new(
+    ///     &[TextPattern::Url, TextPattern::Email],
+    ///     Mask::Special,
+    /// )));
+    /// ```
+    pub fn set_pattern_pre_tokenizer(
+        &mut self,
+        pattern_pre_tokenizer: Option<PatternPreTokenizer>,
+    ) {
+        self.pattern_pre_tokenizer = pattern_pre_tokenizer;
+    }
+
+    /// Returns the pattern pre-tokenizer of the tokenizer, if any
+    pub fn pattern_pre_tokenizer(&self) -> Option<&PatternPreTokenizer> {
+        self.pattern_pre_tokenizer.as_ref()
+    }
+
+    /// Tokenizes a text, returning the tokens produced after each stage of the pipeline to help
+    /// locating unexpected splits. The stages are `whitespace`, `special_split`, `punctuation` and
+    /// `cleaning` (including lower casing and accent stripping), followed by `digit_normalization`
+    /// if enabled. The last stage matches the output of `tokenize_with_offsets`.
+    ///
+    /// # Parameters
+    /// - text (`&str`): text to tokenize
+    ///
+    /// # Returns
+    /// `Vec<TokenizationStage>` with the tokens and offsets after each stage
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::BaseTokenizer;
+    /// use rust_tokenizers::vocab::BaseVocab;
+    /// let strip_accents = false;
+    /// let lower_case = false;
+    /// let tokenizer: BaseTokenizer<BaseVocab> =
+    ///     BaseTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
+    ///
+    /// for stage in tokenizer.tokenize_debug("Hello, world!") {
+    ///     println!("{stage}");
+    /// }
+    /// ```
+    pub fn tokenize_debug(&self, text: &str) -> Vec<TokenizationStage> {
+        tokenize_text_to_stages(text, &self.options.digit_normalization, &|token| {
+            self.tokenize_to_stages(token)
+        })
+    }
+
+    fn split_punctuation<'a>(&self, token: TokenRef<'a>) -> Vec<TokenRef<'a>> {
+        match &self.pattern_pre_tokenizer {
+            //isolate the pattern matches (URLs, emails...), these are not split any further
+            Some(pattern_pre_tokenizer) => pattern_pre_tokenizer
+                .split_with_matches(token)
+                .into_iter()
+                .flat_map(|(token, is_match)| {
+                    if is_match {
+                        vec![token]
+                    } else {
+                        self.split_pre_tokens(token)
+                    }
+                })
+                .collect(),
+            None => self.split_pre_tokens(token),
+        }
+    }
+
+    fn normalize_token(&self, token: TokenRef) -> Token {
+        // v-- this is where the token gets owned, all steps above handle TokenRefs (dealing with &str)
+        let mut token = Token::from(token);
+        if token.mask != Mask::Special
+            && token.mask != Mask::Unknown
+            && !self.is_kept_emoji_token(&token.text)
+        {
+            clean_text_with_bidi_control_handling(&mut token, true, self.bidi_control_handling);
+            //apply the necessary transformations to the actual tokens (unless it's a special value or an emoji sequence)
+            if self.lower_case
+                && !(self.lower_case_handling == LowerCaseHandling::PreserveAcronyms
+                    && is_acronym_or_mixed_case(&token.text)
+                    && self.vocab.values().contains_key(&token.text))
+            {
+                lowercase(&mut token);
+            }
+            if self.strip_accents {
+                strip_accents(&mut token);
+            }
+        }
+        token
+    }
+
+    fn emoji_byte_fallback(&self, token: Token) -> Vec<Token> {
+        if self.emoji_sequence_handling == EmojiSequenceHandling::KeepWithByteFallback
+            && is_emoji_sequence(&token.text)
+        {
+            unknown_byte_fallback(token.as_ref(), &self.vocab).unwrap_or_else(|| vec![token])
+        } else {
+            vec![token]
+        }
+    }
+
+    /// Tokenizes a `TokenRef`, returning the tokens produced after each stage of the pipeline
+    /// (named following `BASE_TOKENIZATION_STAGES`).
+    pub(crate) fn tokenize_to_stages(
+        &self,
+        initial_token: TokenRef,
+    ) -> Vec<(&'static str, Vec<Token>)> {
+        let mut stages = Vec::with_capacity(BASE_TOKENIZATION_STAGES.len());
+        let cleaned_tokens = self.tokenize_with_stages(initial_token, |name, tokens| {
+            stages.push((
+                name,
+                tokens.iter().map(|token| Token::from(*token)).collect(),
+            ))
+        });
+        stages.push((BASE_TOKENIZATION_STAGES[3], cleaned_tokens));
+        stages
+    }
+
+    /// Tokenizes a `TokenRef`, passing the tokens produced after each stage preceding the cleaning
+    /// (named following `BASE_TOKENIZATION_STAGES`) to `inspect_stage`.
+    fn tokenize_with_stages<F>(&self, initial_token: TokenRef, mut inspect_stage: F) -> Vec<Token>
+    where
+        F: FnMut(&'static str, &[TokenRef]),
+    {
+        let [whitespace, special_split, punctuation, _] = BASE_TOKENIZATION_STAGES;
+        //split on whitespace
+        let tokens = whitespace_tokenize(initial_token);
+        inspect_stage(whitespace, &tokens);
+        //split on special tokens
+        let tokens = tokens
+            .into_iter()
+            .flat_map(|token| split_on_special_tokens(token, &self.vocab))
+            .collect::<Vec<TokenRef>>();
+        inspect_stage(special_split, &tokens);
+        let tokens = tokens
+            .into_iter()
+            .flat_map(|token| self.split_punctuation(token))
+            .collect::<Vec<TokenRef>>();
+        inspect_stage(punctuation, &tokens);
+        tokens
+            .into_iter()
+            .map(|token| self.normalize_token(token))
+            .filter(|token| !token.text.is_empty())
+            .flat_map(|token| self.emoji_byte_fallback(token))
+            .collect()
+    }
+
+    fn split_pre_tokens<'a>(&self, token: TokenRef<'a>) -> Vec<TokenRef<'a>> {
+        //split on bidi control characters if they should be kept as special tokens
+        let tokens = if self.bidi_control_handling == BidiControlHandling::Special {
+            split_on_bidi_controls(token)
+        } else {
+            vec![token]
+        };
+        tokens
+            .into_iter()
+            .flat_map(|token| {
+                //split on emoji sequences if they should be kept intact
+                if self.emoji_sequence_handling == EmojiSequenceHandling::Split {
+                    vec![token]
+                } else {
+                    split_on_emoji_sequences(token)
+                }
+            })
+            .flat_map(|token| {
+                //emoji sequences kept intact are not split any further
+                if self.is_kept_emoji_token(token.text) {
+                    vec![token]
+                } else {
+                    //split on punctuation (with care for maintaining special values), then
+                    //tokenize CJK characters so each character is one token
+                    split_on_punct(token)
+                        .into_iter()
+                        .flat_map(tokenize_cjk_chars)
+                        .collect()
+                }
+            })
+            .collect()
+    }
+
+    /// Returns true if a token is an emoji sequence kept intact by the tokenizer, or one of the
+    /// byte fallback pieces of such a sequence. These tokens are not cleaned, normalized or split.
+    pub(crate) fn is_kept_emoji_token(&self, text: &str) -> bool {
+        match self.emoji_sequence_handling {
+            EmojiSequenceHandling::Split => false,
+            EmojiSequenceHandling::Keep => is_emoji_sequence(text),
+            EmojiSequenceHandling::KeepWithByteFallback => {
+                is_emoji_sequence(text) || parse_byte_piece(text).is_some()
+            }
         }
     }
 }
@@ -1585,51 +4396,39 @@ impl<T: Vocab + Sync + Send> Tokenizer<T> for BaseTokenizer<T> {
         &mut self.vocab
     }
 
+    fn options(&self) -> &TokenizerOptions {
+        &self.options
+    }
+
+    fn describe(&self) -> TokenizerDescription {
+        TokenizerDescription {
+            lower_case: Some(self.lower_case),
+            strip_accents: Some(self.strip_accents),
+            ..TokenizerDescription::new("base", &self.vocab)
+        }
+    }
+
     fn tokenize_to_tokens(&self, initial_token: TokenRef) -> Vec<Token> {
-        //split on whitespace
-        let tokens: Vec<Token> = whitespace_tokenize(initial_token)
-            .into_iter()
-            .flat_map(|token| {
-                //split on special tokens
-                split_on_special_tokens(token, &self.vocab)
-            })
-            .flat_map(|token| {
-                //split on punctuation (with care for maintaining special values)
-                split_on_punct(token)
-            })
-            .flat_map(|token| {
-                //tokenize CJK characters so each character is one token
-                tokenize_cjk_chars(token)
-            })
-            .map(|token| {
-                // v-- this is where the token gets owned, all steps above handle TokenRefs (dealing with &str)
-                let mut token = Token {
-                    text: token.text.to_string(),
-                    offset: token.offset,
-                    reference_offsets: token.reference_offsets.to_vec(),
-                    mask: token.mask,
-                };
-                if token.mask != Mask::Special && token.mask != Mask::Unknown {
-                    clean_text(&mut token, true);
-                    //apply the necessary transformations to the actual tokens (unless it's a special value)
-                    if self.lower_case {
-                        lowercase(&mut token);
-                    }
-                    if self.strip_accents {
-                        strip_accents(&mut token);
-                    }
-                }
-                token
-            })
-            .filter(|token| !token.text.is_empty())
-            .collect();
+        self.tokenize_with_stages(initial_token, |_, _| {})
+    }
 
-        tokens
+    fn convert_tokens_to_string(&self, tokens: Vec<String>) -> String {
+        if self.emoji_sequence_handling == EmojiSequenceHandling::KeepWithByteFallback {
+            merge_byte_fallback_pieces(tokens).join(" ")
+        } else {
+            tokens.join(" ")
+        }
     }
 }
 
 impl<T: Vocab + Sync + Send> MultiThreadedTokenizer<T> for BaseTokenizer<T> {}
 
+impl<T: Vocab + Sync + Send> ConfigurableTokenizer<T> for BaseTokenizer<T> {
+    fn options_mut(&mut self) -> &mut TokenizerOptions {
+        &mut self.options
+    }
+}
+
 //==============================
 // Unit tests
 //==============================
@@ -1638,8 +4437,10 @@ mod tests {
     extern crate anyhow;
 
     use super::*;
+    use crate::tokenizer::{StopwordFilter, StopwordHandling};
     use crate::vocab::base_vocab::{swap_key_values, SpecialTokenMap};
     use crate::vocab::BertVocab;
+    use itertools::Itertools;
     use std::collections::HashMap;
 
     fn generate_test_vocab() -> BertVocab {
@@ -2198,6 +4999,7 @@ mod tests {
                     ],
                     reference_offsets: vec![vec![0, 1, 2, 3, 4], vec![6, 7, 8, 9, 10], vec![11]],
                     mask: vec![Mask::None, Mask::None, Mask::Punctuation],
+                    tokens: None,
                 },
             ),
             (
@@ -2229,6 +5031,7 @@ mod tests {
                         Mask::None,
                         Mask::Punctuation,
                     ],
+                    tokens: None,
                 },
             ),
             (
@@ -2275,6 +5078,7 @@ mod tests {
                         Mask::Special,
                         Mask::None,
                     ],
+                    tokens: None,
                 },
             ),
             (
@@ -2321,6 +5125,7 @@ mod tests {
                         Mask::Punctuation,
                         Mask::None,
                     ],
+                    tokens: None,
                 },
             ),
         ];
@@ -2355,6 +5160,229 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_encode_bytes() {
+        //        Given
+        let vocab = generate_test_vocab();
+        let base_tokenizer: BaseTokenizer<BertVocab> =
+            BaseTokenizer::from_existing_vocab(vocab, true, true);
+        let truncation_strategy = TruncationStrategy::LongestFirst;
+        let bytes = b"h\xC3\xA9llo \xF0\x90\x80 world!";
+
+        //        When
+        let encoded_input = base_tokenizer
+            .encode_bytes(bytes, Some(b"\xFFhello"), 128, &truncation_strategy, 0)
+            .unwrap();
+
+        //        Then
+        assert_eq!(encoded_input.token_ids, vec![0, 1, 3, 0]);
+        assert_eq!(
+            encoded_input.token_offsets,
+            vec![
+                Some(Offset::new(0, 6)),
+                Some(Offset::new(11, 16)),
+                Some(Offset::new(16, 17)),
+                Some(Offset::new(1, 6)),
+            ]
+        );
+        assert_eq!(
+            encoded_input.reference_offsets,
+            vec![
+                vec![0, 1, 3, 4, 5],
+                vec![11, 12, 13, 14, 15],
+                vec![16],
+                vec![1, 2, 3, 4, 5],
+            ]
+        );
+        assert!(base_tokenizer
+            .encode_bytes(bytes, None, 1, &TruncationStrategy::DoNotTruncate, 0)
+            .is_err());
+    }
+
+    #[test]
+    fn test_encode_with_offset_unit() {
+        //        Given
+        let vocab = generate_test_vocab();
+        let base_tokenizer: BaseTokenizer<BertVocab> =
+            BaseTokenizer::from_existing_vocab(vocab, true, true);
+        let truncation_strategy = TruncationStrategy::LongestFirst;
+        let text = "h\u{e9}llo \u{1F914} world!";
+
+        //        When
+        let encode = |offset_unit| {
+            base_tokenizer
+                .encode_with_offset_unit(text, None, 128, &truncation_strategy, 0, offset_unit)
+                .unwrap()
+        };
+        let char_encoded_input = encode(OffsetUnit::Char);
+        let byte_encoded_input = encode(OffsetUnit::Byte);
+        let utf16_encoded_input = encode(OffsetUnit::Utf16);
+
+        //        Then
+        assert_eq!(
+            char_encoded_input,
+            base_tokenizer.encode(text, None, 128, &truncation_strategy, 0)
+        );
+        assert_eq!(utf16_encoded_input.token_ids, vec![0, 2, 1, 3]);
+        assert_eq!(
+            utf16_encoded_input.token_offsets,
+            vec![
+                Some(Offset::new(0, 5)),
+                Some(Offset::new(6, 8)),
+                Some(Offset::new(9, 14)),
+                Some(Offset::new(14, 15)),
+            ]
+        );
+        assert_eq!(
+            utf16_encoded_input.reference_offsets,
+            vec![
+                vec![0, 1, 2, 3, 4],
+                vec![6],
+                vec![9, 10, 11, 12, 13],
+                vec![14],
+            ]
+        );
+        assert_eq!(
+            byte_encoded_input.token_offsets,
+            base_tokenizer
+                .encode_bytes(text.as_bytes(), None, 128, &truncation_strategy, 0)
+                .unwrap()
+                .token_offsets
+        );
+    }
+
+    #[test]
+    fn test_encode_chat() {
+        //        Given
+        let vocab = generate_test_vocab();
+        let mut base_tokenizer: BaseTokenizer<BertVocab> =
+            BaseTokenizer::from_existing_vocab(vocab, true, true);
+        base_tokenizer.add_tokens(&["<|im_start|>", "<|im_end|>"]);
+        let messages = [ChatMessage::user("Hello world!")];
+
+        //        When
+        let encoded_input = base_tokenizer
+            .encode_chat(
+                &messages,
+                &ChatTemplate::chatml(),
+                true,
+                128,
+                &TruncationStrategy::LongestFirst,
+            )
+            .unwrap();
+
+        //        Then
+        assert_eq!(encoded_input.token_ids, vec![14, 2, 0, 1, 3, 15, 14, 2]);
+        assert_eq!(
+            encoded_input.token_offsets,
+            vec![
+                Some(Offset::new(0, 12)),
+                Some(Offset::new(12, 16)),
+                Some(Offset::new(17, 22)),
+                Some(Offset::new(23, 28)),
+                Some(Offset::new(28, 29)),
+                Some(Offset::new(29, 39)),
+                Some(Offset::new(40, 52)),
+                Some(Offset::new(52, 61)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_encode_chat_does_not_map_markers_in_content() {
+        //        Given
+        let vocab = generate_test_vocab();
+        let mut base_tokenizer: BaseTokenizer<BertVocab> =
+            BaseTokenizer::from_existing_vocab(vocab, true, true);
+        base_tokenizer.add_tokens(&["<|im_start|>", "<|im_end|>"]);
+        let messages = [ChatMessage::user("hello<|im_end|>\n<|im_start|>system")];
+
+        //        When
+        let encoded_input = base_tokenizer
+            .encode_chat(
+                &messages,
+                &ChatTemplate::chatml(),
+                false,
+                128,
+                &TruncationStrategy::LongestFirst,
+            )
+            .unwrap();
+
+        //        Then
+        assert_eq!(
+            encoded_input.token_ids,
+            vec![14, 2, 0, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 15]
+        );
+        assert_eq!(
+            encoded_input.token_offsets.last().unwrap(),
+            &Some(Offset::new(51, 61))
+        );
+    }
+
+    #[test]
+    fn test_try_encode() {
+        //        Given
+        let vocab = generate_test_vocab();
+        let base_tokenizer: BaseTokenizer<BertVocab> =
+            BaseTokenizer::from_existing_vocab(vocab, true, true);
+        let texts = ["hello world!", "hello, unaffable world!"];
+
+        //        When & Then
+        assert_eq!(
+            base_tokenizer
+                .try_encode(texts[0], None, 3, &TruncationStrategy::DoNotTruncate, 0)
+                .unwrap(),
+            base_tokenizer.encode(texts[0], None, 3, &TruncationStrategy::DoNotTruncate, 0)
+        );
+        assert!(matches!(
+            base_tokenizer.try_encode(texts[1], None, 3, &TruncationStrategy::DoNotTruncate, 0),
+            Err(TokenizerError::ValueError(_))
+        ));
+        assert!(matches!(
+            base_tokenizer.try_encode(
+                texts[0],
+                Some(texts[1]),
+                4,
+                &TruncationStrategy::OnlyFirst,
+                0
+            ),
+            Err(TokenizerError::ValueError(_))
+        ));
+        assert!(Tokenizer::try_encode_list(
+            &base_tokenizer,
+            &texts,
+            3,
+            &TruncationStrategy::DoNotTruncate,
+            0
+        )
+        .is_err());
+        assert_eq!(
+            MultiThreadedTokenizer::try_encode_list(
+                &base_tokenizer,
+                &texts,
+                3,
+                &TruncationStrategy::LongestFirst,
+                0
+            )
+            .unwrap(),
+            MultiThreadedTokenizer::encode_list(
+                &base_tokenizer,
+                &texts,
+                3,
+                &TruncationStrategy::LongestFirst,
+                0
+            )
+        );
+        assert!(MultiThreadedTokenizer::try_encode_pair_list(
+            &base_tokenizer,
+            &[(texts[0], texts[1])],
+            5,
+            &TruncationStrategy::DoNotTruncate,
+            0
+        )
+        .is_err());
+    }
+
     #[test]
     fn test_encode_sentence_pair() {
         //        Given
@@ -2375,6 +5403,7 @@ mod tests {
                     token_offsets: vec!(Some(Offset::new(0, 5)), Some(Offset::new(6, 11)), Some(Offset::new(11, 12)), Some(Offset::new(0, 4)), Some(Offset::new(5, 7)), Some(Offset::new(8, 11)), Some(Offset::new(12, 18)), Some(Offset::new(19, 27))),
                     reference_offsets: vec!(vec!(0, 1, 2, 3, 4), vec!(6, 7, 8, 9, 10), vec!(11), vec!(0, 1, 2, 3), vec!(5, 6), vec!(8, 9, 10), vec!(12, 13, 14, 15, 16, 17), vec!(19, 20, 21, 22, 23, 24, 25, 26)),
                     mask: vec!(Mask::None, Mask::None, Mask::Punctuation, Mask::None, Mask::None, Mask::None, Mask::None, Mask::None),
+                    tokens: None,
                 }
             ),
 //            Truncation of sentence 2 (longest)
@@ -2391,6 +5420,7 @@ mod tests {
                     ),
                     reference_offsets: vec!(vec!(0, 1, 2, 3, 4), vec!(6, 7, 8, 9, 10), vec!(11), vec!(0), vec!(1, 2, 3, 4), vec!(6, 7), vec!(9, 10, 11), vec!(13, 14, 15, 16, 17, 18), vec!(20, 21, 22, 23, 24, 25, 26, 27), vec!(28)),
                     mask: vec!(Mask::None, Mask::None, Mask::Punctuation, Mask::Punctuation, Mask::None, Mask::None, Mask::None, Mask::None, Mask::None, Mask::Punctuation),
+                    tokens: None,
                 }
             ),
 //            Truncation of sentence 1 (longest)
@@ -2407,6 +5437,7 @@ mod tests {
                     ),
                     reference_offsets: vec!(vec!(0, 1, 2, 3, 4), vec!(6, 7, 8, 9, 10), vec!(13, 14, 15, 16, 17), vec!(20, 21, 22, 23, 24), vec!(27, 28, 29, 30, 31), vec!(34, 35, 36, 37, 38), vec!(41, 42, 43, 44, 45), vec!(0), vec!(1), vec!(2)),
                     mask: vec!(Mask::Unknown, Mask::None, Mask::None, Mask::None, Mask::None, Mask::None, Mask::None, Mask::Punctuation, Mask::Punctuation, Mask::Punctuation),
+                    tokens: None,
                 }
             ),
 //            Truncation of both sentences (longest)
@@ -2423,6 +5454,7 @@ mod tests {
                     ),
                     reference_offsets: vec!(vec!(0, 1, 2, 3, 4), vec!(6, 7, 8, 9, 10), vec!(13, 14, 15, 16, 17), vec!(20, 21, 22, 23, 24), vec!(27, 28, 29, 30, 31), vec!(0), vec!(1), vec!(2), vec!(3), vec!(4)),
                     mask: vec!(Mask::Unknown, Mask::None, Mask::None, Mask::None, Mask::None, Mask::Punctuation, Mask::Punctuation, Mask::Punctuation, Mask::Punctuation, Mask::Punctuation),
+                    tokens: None,
                 }
             )
         ];
@@ -2515,6 +5547,70 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_decode_with_offsets() {
+        //        Given
+        let vocab = generate_test_vocab();
+        let base_tokenizer: BaseTokenizer<BertVocab> =
+            BaseTokenizer::from_existing_vocab(vocab, true, true);
+        let test_tuples = [
+            (
+                vec![4, 0, 11, 12, 13, 1, 3, 5],
+                true,
+                true,
+                "hello una ##ffa ##ble world!",
+                vec![
+                    None,
+                    Some(Offset::new(0, 5)),
+                    Some(Offset::new(6, 9)),
+                    Some(Offset::new(10, 15)),
+                    Some(Offset::new(16, 21)),
+                    Some(Offset::new(22, 27)),
+                    Some(Offset::new(27, 28)),
+                    None,
+                ],
+            ),
+            (
+                vec![4, 0, 3, 5],
+                false,
+                false,
+                "[CLS] hello ! [SEP]",
+                vec![
+                    Some(Offset::new(0, 5)),
+                    Some(Offset::new(6, 11)),
+                    Some(Offset::new(12, 13)),
+                    Some(Offset::new(14, 19)),
+                ],
+            ),
+            (vec![], true, true, "", vec![]),
+        ];
+
+        //        When & Then
+        for (token_ids, skip_special_tokens, clean_up, expected_string, expected_offsets) in
+            test_tuples.iter()
+        {
+            let (decoded, offsets) =
+                base_tokenizer.decode_with_offsets(token_ids, *skip_special_tokens, *clean_up);
+            assert_eq!(decoded, *expected_string);
+            assert_eq!(
+                decoded,
+                base_tokenizer.decode(token_ids, *skip_special_tokens, *clean_up)
+            );
+            assert_eq!(&offsets, expected_offsets);
+        }
+
+        let long_token_ids = [0, 11, 12, 13, 1, 3].repeat(100);
+        let (decoded, offsets) = base_tokenizer.decode_with_offsets(&long_token_ids, true, true);
+        assert_eq!(decoded, base_tokenizer.decode(&long_token_ids, true, true));
+        let period = offsets[6].unwrap().begin - offsets[0].unwrap().begin;
+        for (offset, repeated_offset) in offsets.iter().zip(offsets[6..].iter()) {
+            assert_eq!(
+                repeated_offset.unwrap(),
+                Offset::new(offset.unwrap().begin + period, offset.unwrap().end + period)
+            );
+        }
+    }
+
     #[test]
     fn test_decode_skip_special_tokens() {
         //        Given
@@ -2609,6 +5705,410 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_decode_unknown_id_policy() {
+        //        Given
+        let vocab = generate_test_vocab();
+        let mut base_tokenizer: BaseTokenizer<BertVocab> =
+            BaseTokenizer::from_existing_vocab(vocab, true, true);
+        let source_ids = vec![0, 100, 3];
+
+        //        When & Then
+        assert_eq!(
+            base_tokenizer.unknown_id_policy(),
+            UnknownIdPolicy::ReplaceWithUnk
+        );
+        assert_eq!(
+            base_tokenizer.decode(&source_ids, false, false),
+            "hello [UNK] !"
+        );
+
+        base_tokenizer.set_unknown_id_policy(UnknownIdPolicy::SkipToken);
+        assert_eq!(base_tokenizer.decode(&source_ids, false, false), "hello !");
+        let (decoded, offsets) = base_tokenizer.decode_with_offsets(&source_ids, false, false);
+        assert_eq!(decoded, "hello !");
+        assert_eq!(
+            offsets,
+            vec![Some(Offset::new(0, 5)), None, Some(Offset::new(6, 7))]
+        );
+
+        base_tokenizer.set_unknown_id_policy(UnknownIdPolicy::Error);
+        assert!(matches!(
+            base_tokenizer.try_decode(&source_ids, false, false),
+            Err(TokenizerError::IndexNotFound(_))
+        ));
+        assert!(base_tokenizer
+            .try_decode_to_vec(&source_ids, false)
+            .is_err());
+        assert_eq!(
+            base_tokenizer.try_decode(&[0, 1, 3], false, false).unwrap(),
+            "hello world !"
+        );
+    }
+
+    #[test]
+    fn test_verify() {
+        //        Given
+        let vocab = generate_test_vocab();
+        let base_tokenizer: BaseTokenizer<BertVocab> =
+            BaseTokenizer::from_existing_vocab(vocab, true, true);
+        let corpus = vec!["Hello  World!", "hello, world", "", "中华人", "una"];
+
+        //        When
+        let report = base_tokenizer.verify(corpus).unwrap();
+
+        //        Then
+        assert!(!report.is_ok());
+        assert_eq!(report.num_samples, 5);
+        assert_eq!(report.num_mismatches, 1);
+        assert_eq!(report.num_tokens, 10);
+        assert_eq!(report.num_unknown_tokens, 1);
+        assert_eq!(
+            report.examples,
+            vec![VerificationMismatch {
+                index: 1,
+                input: "hello, world".to_owned(),
+                decoded: "hello world".to_owned(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_token_filter() {
+        //        Given
+        let vocab = generate_test_vocab();
+        let mut base_tokenizer: BaseTokenizer<BertVocab> =
+            BaseTokenizer::from_existing_vocab(vocab, true, true);
+        let text = "Hello, hello  world!";
+        let truncation_strategy = TruncationStrategy::LongestFirst;
+
+        //        When
+        let unfiltered = base_tokenizer.encode(text, None, 128, &truncation_strategy, 0);
+        base_tokenizer.add_token_filter(Box::new(|mut tokens: TokensWithOffsets| {
+            tokens.retain(|_, _, mask| mask != Mask::Punctuation);
+            tokens
+        }));
+        base_tokenizer.add_token_filter(Box::new(|mut tokens: TokensWithOffsets| {
+            let mut previous_token = String::new();
+            tokens.retain(|token, _, _| {
+                if token == previous_token {
+                    return false;
+                }
+                previous_token = token.to_owned();
+                true
+            });
+            tokens
+        }));
+        let filtered =
+            base_tokenizer.encode(text, Some("world world"), 128, &truncation_strategy, 0);
+        base_tokenizer.add_token_filter(Box::new(|mut tokens: TokensWithOffsets| {
+            tokens.tokens.push("!".to_owned());
+            tokens
+        }));
+        let misaligned = base_tokenizer.try_encode(text, None, 128, &truncation_strategy, 0);
+        base_tokenizer.clear_token_filters();
+
+        //        Then
+        assert_eq!(unfiltered.token_ids, vec![0, 2, 0, 1, 3]);
+        assert_eq!(filtered.token_ids, vec![0, 1, 1]);
+        assert_eq!(filtered.segment_ids, vec![0, 0, 1]);
+        assert_eq!(
+            filtered.token_offsets,
+            vec![
+                Some(Offset::new(0, 5)),
+                Some(Offset::new(14, 19)),
+                Some(Offset::new(0, 5)),
+            ]
+        );
+        assert_eq!(filtered.reference_offsets[1], vec![14, 15, 16, 17, 18]);
+        assert!(matches!(misaligned, Err(TokenizerError::ValueError(_))));
+        assert!(base_tokenizer.token_filters().is_empty());
+    }
+
+    #[test]
+    fn test_encode_pair_list_from_slices() {
+        //        Given
+        let vocab = generate_test_vocab();
+        let base_tokenizer: BaseTokenizer<BertVocab> =
+            BaseTokenizer::from_existing_vocab(vocab, true, true);
+        let texts_a = ["hello world!", "hello"];
+        let texts_b = ["world", "world hello !"];
+        let truncation_strategy = TruncationStrategy::LongestFirst;
+
+        //        When
+        let encoded = Tokenizer::encode_pair_list_from_slices(
+            &base_tokenizer,
+            &texts_a,
+            &texts_b,
+            5,
+            &truncation_strategy,
+            0,
+        )
+        .unwrap();
+        let encoded_multithreaded = MultiThreadedTokenizer::encode_pair_list_from_slices(
+            &base_tokenizer,
+            &texts_a,
+            &texts_b,
+            5,
+            &truncation_strategy,
+            0,
+        )
+        .unwrap();
+        let mismatched = Tokenizer::encode_pair_list_from_slices(
+            &base_tokenizer,
+            &texts_a,
+            &texts_b[..1],
+            5,
+            &truncation_strategy,
+            0,
+        );
+
+        //        Then
+        let expected = Tokenizer::encode_pair_list(
+            &base_tokenizer,
+            &[(texts_a[0], texts_b[0]), (texts_a[1], texts_b[1])],
+            5,
+            &truncation_strategy,
+            0,
+        );
+        assert_eq!(encoded, expected);
+        assert_eq!(encoded_multithreaded, expected);
+        assert!(matches!(mismatched, Err(TokenizerError::ValueError(_))));
+    }
+
+    #[test]
+    fn test_digit_normalization() {
+        //        Given
+        let vocab = generate_test_vocab();
+        let mut base_tokenizer: BaseTokenizer<BertVocab> =
+            BaseTokenizer::from_existing_vocab(vocab, true, true);
+        base_tokenizer.vocab_mut().add_tokens(&["<num>"]);
+        let text = "Hello 2024 world, 7";
+
+        //        When
+        let unchanged = base_tokenizer.tokenize(text);
+        base_tokenizer.set_digit_normalization(DigitNormalization::SplitDigits);
+        let split = base_tokenizer.tokenize(text);
+        base_tokenizer
+            .set_digit_normalization(DigitNormalization::Placeholder("<num>".to_string()));
+        let placeholders = base_tokenizer.tokenize_with_offsets(text);
+        let encoded = base_tokenizer.encode(text, None, 128, &TruncationStrategy::LongestFirst, 0);
+
+        //        Then
+        assert_eq!(unchanged, vec!["hello", "2024", "world", ",", "7"]);
+        assert_eq!(split, vec!["hello", "2", "0", "2", "4", "world", ",", "7"]);
+        assert_eq!(
+            placeholders.tokens,
+            vec!["hello", "<num>", "world", ",", "<num>"]
+        );
+        assert_eq!(placeholders.offsets[1], Some(Offset::new(6, 10)));
+        assert_eq!(placeholders.reference_offsets[1], vec![6, 7, 8, 9]);
+        assert_eq!(
+            encoded.token_ids[1],
+            Tokenizer::vocab(&base_tokenizer).token_to_id("<num>")
+        );
+        assert_ne!(
+            encoded.token_ids[1],
+            Tokenizer::vocab(&base_tokenizer).token_to_id("[UNK]")
+        );
+    }
+
+    #[test]
+    fn test_stopword_filter() {
+        //        Given
+        let vocab = generate_test_vocab();
+        let mut base_tokenizer: BaseTokenizer<BertVocab> =
+            BaseTokenizer::from_existing_vocab(vocab, true, true);
+        let text = "Hello, world!";
+        let truncation_strategy = TruncationStrategy::LongestFirst;
+
+        //        When
+        let mark_filter = StopwordFilter::new(["HELLO"], StopwordHandling::Mark, true);
+        base_tokenizer.set_return_tokens(true);
+        base_tokenizer.add_token_filter(Box::new(mark_filter.clone()));
+        let marked = base_tokenizer.encode(text, None, 128, &truncation_strategy, 0);
+        let marked_stopwords =
+            mark_filter.stopword_mask(marked.tokens.as_ref().unwrap(), &marked.mask);
+        base_tokenizer.set_return_tokens(false);
+        base_tokenizer.clear_token_filters();
+        base_tokenizer.add_token_filter(Box::new(StopwordFilter::new(
+            ["hello"],
+            StopwordHandling::Drop,
+            true,
+        )));
+        let dropped = base_tokenizer.encode(text, None, 128, &truncation_strategy, 0);
+        base_tokenizer.clear_token_filters();
+        base_tokenizer.add_token_filter(Box::new(StopwordFilter::new(
+            ["HELLO"],
+            StopwordHandling::Drop,
+            false,
+        )));
+        let case_sensitive = base_tokenizer.encode(text, None, 128, &truncation_strategy, 0);
+
+        //        Then
+        assert_eq!(marked.token_ids, vec![0, 2, 1, 3]);
+        assert_eq!(
+            marked.mask,
+            vec![Mask::None, Mask::Punctuation, Mask::None, Mask::Punctuation]
+        );
+        assert_eq!(marked_stopwords, vec![true, false, false, false]);
+        assert_eq!(dropped.token_ids, vec![2, 1, 3]);
+        assert_eq!(
+            dropped.token_offsets,
+            vec![
+                Some(Offset::new(5, 6)),
+                Some(Offset::new(7, 12)),
+                Some(Offset::new(12, 13)),
+            ]
+        );
+        assert_eq!(case_sensitive.token_ids, vec![0, 2, 1, 3]);
+    }
+
+    #[test]
+    fn test_tokenization_stats() {
+        //        Given
+        let vocab = generate_test_vocab();
+        let mut base_tokenizer: BaseTokenizer<BertVocab> =
+            BaseTokenizer::from_existing_vocab(vocab, true, true);
+        let texts = ["Hello  World!", "unaffable"];
+
+        //        When
+        let _ = Tokenizer::encode_list(
+            &base_tokenizer,
+            &texts,
+            2,
+            &TruncationStrategy::LongestFirst,
+            0,
+        );
+        let disabled_stats = base_tokenizer.stats();
+        base_tokenizer.set_collect_stats(true);
+        let _ = Tokenizer::encode_list(
+            &base_tokenizer,
+            &texts,
+            2,
+            &TruncationStrategy::LongestFirst,
+            0,
+        );
+        let _ = MultiThreadedTokenizer::encode_pair_list(
+            &base_tokenizer,
+            &[("hello", "world")],
+            10,
+            &TruncationStrategy::LongestFirst,
+            0,
+        );
+        let stats = base_tokenizer.stats().unwrap();
+        base_tokenizer.reset_stats();
+
+        //        Then
+        assert_eq!(disabled_stats, None);
+        assert_eq!(stats.num_inputs, 3);
+        assert_eq!(stats.num_tokens, 5);
+        assert_eq!(stats.num_truncated_tokens, 1);
+        assert_eq!(stats.num_unknown_tokens, 1);
+        assert_eq!(stats.cache_lookups, None);
+        assert_eq!(stats.cache_hit_rate(), None);
+        assert_eq!(stats.average_tokens_per_input(), 2.0);
+        assert_eq!(stats.unknown_token_rate(), 0.2);
+        assert_eq!(
+            base_tokenizer.stats().unwrap(),
+            TokenizationStats::default()
+        );
+    }
+
+    #[test]
+    fn test_tokenize_in_chunks() {
+        //        Given
+        let vocab = generate_test_vocab();
+        let base_tokenizer: BaseTokenizer<BertVocab> =
+            BaseTokenizer::from_existing_vocab(vocab, true, true);
+        let text = "Hello world! hello  中华人 world!";
+
+        //        When
+        let expected = base_tokenizer.tokenize_with_offsets(text);
+        let chunked_tokens = (7..=text.chars().count())
+            .map(|chunk_chars| {
+                tokenize_in_chunks(text, chunk_chars, |chunk| {
+                    base_tokenizer.tokenize_with_offsets(chunk)
+                })
+                .unwrap()
+            })
+            .collect::<Vec<TokensWithOffsets>>();
+
+        //        Then
+        for tokens in chunked_tokens.iter() {
+            assert_eq!(tokens.tokens, expected.tokens);
+            assert_eq!(tokens.offsets, expected.offsets);
+            assert_eq!(tokens.reference_offsets, expected.reference_offsets);
+            assert_eq!(tokens.masks, expected.masks);
+        }
+        assert!(
+            tokenize_in_chunks(text, 0, |chunk| base_tokenizer.tokenize_with_offsets(chunk))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_word_spans() {
+        //        Given
+        let tokenized_input = TokenizedInput {
+            token_ids: vec![4, 0, 1, 3, 1, 2, 5],
+            segment_ids: vec![0; 7],
+            special_tokens_mask: vec![1, 0, 0, 0, 0, 0, 1],
+            overflowing_tokens: vec![],
+            num_truncated_tokens: 0,
+            token_offsets: vec![
+                None,
+                Some(Offset::new(0, 3)),
+                Some(Offset::new(3, 5)),
+                Some(Offset::new(5, 6)),
+                Some(Offset::new(7, 12)),
+                None,
+                None,
+            ],
+            reference_offsets: vec![
+                vec![],
+                vec![0, 1, 2],
+                vec![3, 4],
+                vec![5],
+                vec![7, 8, 9, 10, 11],
+                vec![],
+                vec![],
+            ],
+            mask: vec![
+                Mask::Special,
+                Mask::Begin,
+                Mask::Continuation,
+                Mask::Punctuation,
+                Mask::Begin,
+                Mask::Continuation,
+                Mask::Special,
+            ],
+            tokens: None,
+        };
+
+        //        When
+        let word_spans = tokenized_input.word_spans();
+
+        //        Then
+        assert_eq!(
+            word_spans,
+            vec![
+                WordSpan {
+                    tokens: 1..3,
+                    offset: Some(Offset::new(0, 5)),
+                },
+                WordSpan {
+                    tokens: 3..4,
+                    offset: Some(Offset::new(5, 6)),
+                },
+                WordSpan {
+                    tokens: 4..6,
+                    offset: Some(Offset::new(7, 12)),
+                },
+            ]
+        );
+    }
+
     #[test]
     fn test_consolidated_token_iterator() {
         let tokens = vec![
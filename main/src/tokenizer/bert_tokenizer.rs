@@ -16,10 +16,15 @@ use std::path::Path;
 
 use crate::error::TokenizerError;
 use crate::tokenizer::base_tokenizer::{
-    BaseTokenizer, Mask, MultiThreadedTokenizer, Offset, OffsetSize, Token, TokenIdsWithOffsets,
-    TokenIdsWithSpecialTokens, TokenRef, Tokenizer,
+    tokenize_text_to_stages, BaseTokenizer, BidiControlHandling, ConfigurableTokenizer,
+    EmojiSequenceHandling, LowerCaseHandling, Mask, MultiThreadedTokenizer, Offset, OffsetSize,
+    Token, TokenIdsWithOffsets, TokenIdsWithSpecialTokens, TokenRef, TokenizationStage, Tokenizer,
+    TokenizerDescription, TokenizerOptions, BASE_TOKENIZATION_STAGES,
+};
+use crate::tokenizer::tokenization_utils::{
+    merge_byte_fallback_pieces, split_on_special_tokens, tokenize_wordpiece, ContinuationMarker,
+    PatternPreTokenizer,
 };
-use crate::tokenizer::tokenization_utils::tokenize_wordpiece;
 use crate::vocab::{BertVocab, Vocab};
 
 /// # BERT tokenizer
@@ -29,6 +34,9 @@ use crate::vocab::{BertVocab, Vocab};
 pub struct BertTokenizer {
     vocab: BertVocab,
     base_tokenizer: BaseTokenizer<BertVocab>,
+    normalize_special_tokens: bool,
+    options: TokenizerOptions,
+    continuation_marker: ContinuationMarker,
 }
 
 impl BertTokenizer {
@@ -60,6 +68,9 @@ impl BertTokenizer {
         Ok(BertTokenizer {
             vocab,
             base_tokenizer,
+            normalize_special_tokens: false,
+            options: TokenizerOptions::default(),
+            continuation_marker: ContinuationMarker::default(),
         })
     }
 
@@ -99,6 +110,9 @@ impl BertTokenizer {
         Ok(BertTokenizer {
             vocab,
             base_tokenizer,
+            normalize_special_tokens: false,
+            options: TokenizerOptions::default(),
+            continuation_marker: ContinuationMarker::default(),
         })
     }
     /// Create a new instance of a `BertTokenizer` from an existing vocabulary
@@ -129,8 +143,247 @@ impl BertTokenizer {
         BertTokenizer {
             vocab,
             base_tokenizer,
+            normalize_special_tokens: false,
+            options: TokenizerOptions::default(),
+            continuation_marker: ContinuationMarker::default(),
+        }
+    }
+
+    /// Sets the emoji sequence handling of the tokenizer. Emoji sequences kept intact are not split
+    /// into word pieces (see `EmojiSequenceHandling` for the available modes).
+    ///
+    /// # Parameters
+    /// - emoji_sequence_handling (`EmojiSequenceHandling`): emoji sequence handling mode
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{EmojiSequenceHandling, BertTokenizer};
+    /// let strip_accents = false;
+    /// let lower_case = false;
+    /// let mut tokenizer =
+    ///     BertTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
+    ///
+    /// tokenizer.set_emoji_sequence_handling(EmojiSequenceHandling::Keep);
+    /// ```
+    pub fn set_emoji_sequence_handling(&mut self, emoji_sequence_handling: EmojiSequenceHandling) {
+        self.base_tokenizer
+            .set_emoji_sequence_handling(emoji_sequence_handling);
+    }
+
+    /// Sets the bidirectional control character handling of the tokenizer (see `BidiControlHandling`
+    /// for the available modes). These characters are stripped by default.
+    ///
+    /// # Parameters
+    /// - bidi_control_handling (`BidiControlHandling`): bidi control character handling mode
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{BidiControlHandling, BertTokenizer};
+    /// let strip_accents = false;
+    /// let lower_case = false;
+    /// let mut tokenizer =
+    ///     BertTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
+    ///
+    /// tokenizer.set_bidi_control_handling(BidiControlHandling::Special);
+    /// ```
+    pub fn set_bidi_control_handling(&mut self, bidi_control_handling: BidiControlHandling) {
+        self.base_tokenizer
+            .set_bidi_control_handling(bidi_control_handling);
+    }
+
+    /// Sets the lower casing handling of the tokenizer (see `LowerCaseHandling` for the available
+    /// modes). This allows keeping acronyms and mixed-case words (e.g. "NASA" or "iPhone") found in
+    /// a cased vocabulary when lower-casing the input.
+    ///
+    /// # Parameters
+    /// - lower_case_handling (`LowerCaseHandling`): lower casing handling mode
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{BertTokenizer, LowerCaseHandling};
+    /// let strip_accents = false;
+    /// let lower_case = true;
+    /// let mut tokenizer =
+    ///     BertTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
+    ///
+    /// tokenizer.set_lower_case_handling(LowerCaseHandling::PreserveAcronyms);
+    /// ```
+    pub fn set_lower_case_handling(&mut self, lower_case_handling: LowerCaseHandling) {
+        self.base_tokenizer
+            .set_lower_case_handling(lower_case_handling);
+    }
+
+    /// Sets the pattern pre-tokenizer of the tokenizer, isolating the text matching its patterns
+    /// (for example URLs, emails, hashtags and @handles) into single tokens. Matches with a
+    /// `Mask::Special` mask are not split into word pieces, and are mapped to the unknown token
+    /// unless added to the vocabulary.
+    ///
+    /// # Parameters
+    /// - pattern_pre_tokenizer (`Option<PatternPreTokenizer>`): pattern pre-tokenizer
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{BertTokenizer, PatternPreTokenizer, TextPattern};
+    /// use rust_tokenizers::Mask;
+    /// let strip_accents = false;
+    /// let lower_case = true;
+    /// let mut tokenizer =
+    ///     BertTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
+    ///
+    /// tokenizer.set_pattern_pre_tokenizer(Some(PatternPreTokenizer::new(
+    ///     &[TextPattern::Hashtag, TextPattern::Handle],
+    ///     Mask::None,
+    /// )));
+    /// ```
+    pub fn set_pattern_pre_tokenizer(
+        &mut self,
+        pattern_pre_tokenizer: Option<PatternPreTokenizer>,
+    ) {
+        self.base_tokenizer
+            .set_pattern_pre_tokenizer(pattern_pre_tokenizer);
+    }
+
+    /// Tokenizes a text, returning the tokens produced after each stage of the pipeline to help
+    /// locating unexpected splits. The stages of the `BaseTokenizer` (`whitespace`,
+    /// `special_split`, `punctuation` and `cleaning`) are followed by the WordPiece `subword`
+    /// stage, and `digit_normalization` if enabled. The last stage matches the output of
+    /// `tokenize_with_offsets`.
+    ///
+    /// # Parameters
+    /// - text (`&str`): text to tokenize
+    ///
+    /// # Returns
+    /// `Vec<TokenizationStage>` with the tokens and offsets after each stage
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::BertTokenizer;
+    /// let strip_accents = false;
+    /// let lower_case = true;
+    /// let tokenizer =
+    ///     BertTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
+    ///
+    /// for stage in tokenizer.tokenize_debug("Hello, unaffable world!") {
+    ///     println!("{stage}");
+    /// }
+    /// ```
+    pub fn tokenize_debug(&self, text: &str) -> Vec<TokenizationStage> {
+        tokenize_text_to_stages(text, &self.options.digit_normalization, &|initial_token| {
+            let mut stages = BASE_TOKENIZATION_STAGES
+                .iter()
+                .map(|name| (*name, Vec::new()))
+                .collect::<Vec<(&'static str, Vec<Token>)>>();
+            for span in self.split_added_tokens(initial_token) {
+                if span.mask == Mask::None {
+                    let span_stages = self.base_tokenizer.tokenize_to_stages(span);
+                    for ((_, tokens), (_, span_tokens)) in stages.iter_mut().zip(span_stages) {
+                        tokens.extend(span_tokens);
+                    }
+                } else {
+                    for (_, tokens) in stages.iter_mut() {
+                        tokens.push(span.into());
+                    }
+                }
+            }
+            let subword_tokens = stages
+                .last()
+                .map(|(_, tokens)| {
+                    tokens
+                        .iter()
+                        .cloned()
+                        .flat_map(|token| self.split_subwords(token))
+                        .collect()
+                })
+                .unwrap_or_default();
+            stages.push(("subword", subword_tokens));
+            stages
+        })
+    }
+
+    fn split_added_tokens<'a>(&self, initial_token: TokenRef<'a>) -> Vec<TokenRef<'a>> {
+        //split on special tokens before the normalization of the base tokenizer, whose vocabulary
+        //does not contain the tokens added to this tokenizer
+        if self.normalize_special_tokens {
+            vec![initial_token]
+        } else {
+            split_on_special_tokens(initial_token, &self.vocab)
+        }
+    }
+
+    fn split_subwords(&self, token: Token) -> Vec<Token> {
+        //emoji sequences and special pattern matches kept intact by the base tokenizer
+        //are not split into word pieces
+        if self.base_tokenizer.is_kept_emoji_token(&token.text)
+            || self.is_special_pattern_match(&token)
+        {
+            vec![token]
+        } else {
+            tokenize_wordpiece(token.as_ref(), &self.vocab, 100, &self.continuation_marker)
         }
     }
+
+    fn is_special_pattern_match(&self, token: &Token) -> bool {
+        token.mask == Mask::Special
+            && self
+                .base_tokenizer
+                .pattern_pre_tokenizer()
+                .is_some_and(|pattern_pre_tokenizer| {
+                    pattern_pre_tokenizer.mask() == Mask::Special
+                        && pattern_pre_tokenizer.is_match(&token.text)
+                })
+    }
+
+    /// Sets whether special tokens go through the normalization of the tokenizer (lower casing, accent stripping)
+    /// before being matched. By default, special tokens are matched as-is in the input text, including
+    /// tokens added to the vocabulary after the tokenizer creation (e.g. `[CTRL]`). Enabling this
+    /// option restores the legacy behaviour, where added special tokens containing uppercase characters
+    /// or accents are normalized and may be split.
+    ///
+    /// # Parameters
+    /// - normalize_special_tokens (`bool`): flag indicating if special tokens should be normalized
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::BertTokenizer;
+    /// let strip_accents = false;
+    /// let lower_case = true;
+    /// let mut tokenizer =
+    ///     BertTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
+    ///
+    /// tokenizer.set_normalize_special_tokens(true);
+    /// ```
+    pub fn set_normalize_special_tokens(&mut self, normalize_special_tokens: bool) {
+        self.normalize_special_tokens = normalize_special_tokens;
+    }
+
+    /// Sets the convention marking the word pieces of a word split into several subwords, used both
+    /// for the WordPiece tokenization and the decoding. Defaults to a `##` continuation prefix;
+    /// vocabularies with other markers (e.g. `++` prefixes or CTRL-style `@@` suffixes) can be used
+    /// by setting the matching `ContinuationMarker`.
+    ///
+    /// # Parameters
+    /// - continuation_marker (`ContinuationMarker`): subword continuation marker of the vocabulary
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{BertTokenizer, ContinuationMarker};
+    /// let strip_accents = false;
+    /// let lower_case = true;
+    /// let mut tokenizer =
+    ///     BertTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
+    ///
+    /// tokenizer.set_continuation_marker(ContinuationMarker::Suffix("@@".to_string()));
+    /// ```
+    pub fn set_continuation_marker(&mut self, continuation_marker: ContinuationMarker) {
+        self.continuation_marker = continuation_marker;
+    }
 }
 
 impl Tokenizer<BertVocab> for BertTokenizer {
@@ -141,17 +394,43 @@ impl Tokenizer<BertVocab> for BertTokenizer {
         &mut self.vocab
     }
 
+    fn options(&self) -> &TokenizerOptions {
+        &self.options
+    }
+
+    fn describe(&self) -> TokenizerDescription {
+        let base_description = self.base_tokenizer.describe();
+        TokenizerDescription {
+            lower_case: base_description.lower_case,
+            strip_accents: base_description.strip_accents,
+            ..TokenizerDescription::new("bert", &self.vocab)
+        }
+    }
+
     fn tokenize_to_tokens(&self, initial_token: TokenRef) -> Vec<Token> {
         //the base tokenizers does most of the work, we simply add a wordpiece tokenizer on top
-        self.base_tokenizer
-            .tokenize_to_tokens(initial_token)
+        self.split_added_tokens(initial_token)
             .into_iter()
-            .flat_map(|token| tokenize_wordpiece(token.as_ref(), &self.vocab, 100))
+            .flat_map(|span| {
+                if span.mask == Mask::None {
+                    self.base_tokenizer.tokenize_to_tokens(span)
+                } else {
+                    vec![span.to_owned()]
+                }
+            })
+            .flat_map(|token| self.split_subwords(token))
             .collect()
     }
 
     fn convert_tokens_to_string(&self, tokens: Vec<String>) -> String {
-        tokens.join(" ").replace(" ##", "").trim().to_owned()
+        let tokens = if self.base_tokenizer.emoji_sequence_handling()
+            == EmojiSequenceHandling::KeepWithByteFallback
+        {
+            merge_byte_fallback_pieces(tokens)
+        } else {
+            tokens
+        };
+        self.continuation_marker.join_word_pieces(&tokens)
     }
 
     fn build_input_with_special_tokens(
@@ -208,6 +487,12 @@ impl Tokenizer<BertVocab> for BertTokenizer {
 
 impl MultiThreadedTokenizer<BertVocab> for BertTokenizer {}
 
+impl ConfigurableTokenizer<BertVocab> for BertTokenizer {
+    fn options_mut(&mut self) -> &mut TokenizerOptions {
+        &mut self.options
+    }
+}
+
 //==============================
 // Unit tests
 //==============================
@@ -215,6 +500,8 @@ impl MultiThreadedTokenizer<BertVocab> for BertTokenizer {}
 mod tests {
     use super::*;
     use crate::tokenizer::base_tokenizer::TruncationStrategy;
+    use crate::tokenizer::tokenization_utils::TextPattern;
+    use crate::tokenizer::VocabTrie;
     use crate::vocab::base_vocab::{swap_key_values, SpecialTokenMap};
     use crate::vocab::BertVocab;
     use crate::TokenizedInput;
@@ -348,6 +635,279 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_bert_tokenizer_emoji_sequences() {
+        //        Given
+        let vocab = generate_test_vocab();
+        let mut bert_tokenizer: BertTokenizer =
+            BertTokenizer::from_existing_vocab(vocab, true, true);
+        let source_text = "Hello 👨\u{200D}👩\u{200D}👧world!";
+
+        //        When
+        bert_tokenizer.set_emoji_sequence_handling(EmojiSequenceHandling::Keep);
+        let kept_tokens = bert_tokenizer.tokenize(source_text);
+        bert_tokenizer.set_emoji_sequence_handling(EmojiSequenceHandling::KeepWithByteFallback);
+        let byte_tokens = bert_tokenizer.tokenize(source_text);
+
+        //        Then
+        assert_eq!(
+            kept_tokens,
+            vec!["hello", "👨\u{200D}👩\u{200D}👧", "world", "!"]
+        );
+        assert_eq!(byte_tokens.len(), 3 + 18);
+        assert_eq!(byte_tokens[1], "<0xF0>");
+        assert_eq!(byte_tokens[4], "<0xA8>");
+        assert_eq!(
+            bert_tokenizer.convert_tokens_to_string(byte_tokens),
+            "hello 👨\u{200D}👩\u{200D}👧 world !"
+        );
+    }
+
+    #[test]
+    fn test_bert_tokenizer_bidi_controls() {
+        //        Given
+        let vocab = generate_test_vocab();
+        let mut bert_tokenizer: BertTokenizer =
+            BertTokenizer::from_existing_vocab(vocab, true, true);
+        let source_text = "hello\u{200F} world";
+
+        //        When
+        let stripped_tokens = bert_tokenizer.tokenize(source_text);
+        bert_tokenizer.set_bidi_control_handling(BidiControlHandling::Keep);
+        let kept_tokens = bert_tokenizer.tokenize(source_text);
+        bert_tokenizer.set_bidi_control_handling(BidiControlHandling::Special);
+        let special_tokens = bert_tokenizer.tokenize_with_offsets(source_text);
+
+        //        Then
+        assert_eq!(stripped_tokens, vec!["hello", "world"]);
+        assert_eq!(kept_tokens, vec!["[UNK]", "world"]);
+        assert_eq!(special_tokens.tokens, vec!["hello", "[UNK]", "world"]);
+        assert_eq!(
+            special_tokens.masks,
+            vec![Mask::None, Mask::Unknown, Mask::None]
+        );
+        assert_eq!(special_tokens.offsets[1], Some(Offset { begin: 5, end: 6 }));
+    }
+
+    #[test]
+    fn test_bert_tokenizer_pattern_pre_tokenizer() {
+        //        Given
+        let vocab = generate_test_vocab();
+        let mut bert_tokenizer: BertTokenizer =
+            BertTokenizer::from_existing_vocab(vocab, true, true);
+        let source_text = "Hello www.World.com!";
+
+        //        When
+        let default_tokens = bert_tokenizer.tokenize(source_text);
+        bert_tokenizer.set_pattern_pre_tokenizer(Some(PatternPreTokenizer::new(
+            &[TextPattern::Url],
+            Mask::Special,
+        )));
+        let special_tokens = bert_tokenizer.tokenize_with_offsets(source_text);
+        bert_tokenizer.set_pattern_pre_tokenizer(Some(PatternPreTokenizer::new(
+            &[TextPattern::Url],
+            Mask::None,
+        )));
+        let none_tokens = bert_tokenizer.tokenize(source_text);
+
+        //        Then
+        assert_eq!(
+            default_tokens,
+            vec!["hello", "[UNK]", "[UNK]", "world", "[UNK]", "[UNK]", "!"]
+        );
+        assert_eq!(special_tokens.tokens, vec!["hello", "www.World.com", "!"]);
+        assert_eq!(
+            special_tokens.masks,
+            vec![Mask::None, Mask::Special, Mask::Punctuation]
+        );
+        assert_eq!(
+            special_tokens.offsets[1],
+            Some(Offset { begin: 6, end: 19 })
+        );
+        assert_eq!(none_tokens, vec!["hello", "[UNK]", "!"]);
+    }
+
+    #[test]
+    fn test_bert_tokenizer_preserve_acronyms() {
+        //        Given
+        let mut vocab = generate_test_vocab();
+        for (token, id) in [("NASA", 14), ("iPhone", 15)].iter() {
+            vocab.values_mut().insert(token.to_string(), *id);
+            vocab.indices_mut().insert(*id, token.to_string());
+        }
+        let mut bert_tokenizer: BertTokenizer =
+            BertTokenizer::from_existing_vocab(vocab, true, true);
+        let source_text = "NASA iPhone Hello WORLD";
+
+        //        When
+        let lower_cased_tokens = bert_tokenizer.tokenize(source_text);
+        bert_tokenizer.set_lower_case_handling(LowerCaseHandling::PreserveAcronyms);
+        let preserved_tokens = bert_tokenizer.tokenize_with_offsets(source_text);
+
+        //        Then
+        assert_eq!(lower_cased_tokens, vec!["[UNK]", "[UNK]", "hello", "world"]);
+        assert_eq!(
+            preserved_tokens.tokens,
+            vec!["NASA", "iPhone", "hello", "world"]
+        );
+        assert_eq!(
+            preserved_tokens.offsets[1],
+            Some(Offset { begin: 5, end: 11 })
+        );
+    }
+
+    #[test]
+    fn test_bert_tokenizer_added_special_tokens() {
+        //        Given
+        let vocab = generate_test_vocab();
+        let mut bert_tokenizer: BertTokenizer =
+            BertTokenizer::from_existing_vocab(vocab, true, true);
+        bert_tokenizer.add_tokens(&["[CTRL]"]);
+        let source_text = "Hello [CTRL]World";
+
+        //        When
+        let tokens = bert_tokenizer.tokenize_with_offsets(source_text);
+        let token_ids = bert_tokenizer.convert_tokens_to_ids(&tokens.tokens);
+        bert_tokenizer.set_normalize_special_tokens(true);
+        let legacy_tokens = bert_tokenizer.tokenize(source_text);
+
+        //        Then
+        assert_eq!(tokens.tokens, vec!["hello", "[CTRL]", "world"]);
+        assert_eq!(tokens.masks, vec![Mask::None, Mask::Special, Mask::None]);
+        assert_eq!(
+            tokens.offsets,
+            vec![
+                Some(Offset { begin: 0, end: 5 }),
+                Some(Offset { begin: 6, end: 12 }),
+                Some(Offset { begin: 12, end: 17 })
+            ]
+        );
+        assert_eq!(token_ids, vec![0, 14, 1]);
+        assert_eq!(
+            legacy_tokens,
+            vec!["hello", "[UNK]", "[UNK]", "[UNK]", "world"]
+        );
+    }
+
+    #[test]
+    fn test_bert_tokenizer_continuation_marker() {
+        //        Given
+        let mut vocab = generate_test_vocab();
+        vocab.extend_vocabulary(&["una@@", "ffa@@", "ble"]);
+        let mut bert_tokenizer: BertTokenizer =
+            BertTokenizer::from_existing_vocab(vocab, true, true);
+        bert_tokenizer.set_continuation_marker(ContinuationMarker::Suffix("@@".to_string()));
+        let source_text = "Hello unaffable world!";
+
+        //        When
+        let tokens = bert_tokenizer.tokenize_with_offsets(source_text);
+        let token_ids = bert_tokenizer.convert_tokens_to_ids(&tokens.tokens);
+        let decoded = bert_tokenizer.decode(&token_ids, false, false);
+
+        //        Then
+        assert_eq!(
+            tokens.tokens,
+            vec!["hello", "una@@", "ffa@@", "ble", "world", "!"]
+        );
+        assert_eq!(
+            tokens.masks,
+            vec![
+                Mask::None,
+                Mask::Begin,
+                Mask::Continuation,
+                Mask::Continuation,
+                Mask::None,
+                Mask::Punctuation
+            ]
+        );
+        assert_eq!(token_ids, vec![0, 14, 15, 16, 1, 3]);
+        assert_eq!(decoded, "hello unaffable world !");
+    }
+
+    #[test]
+    fn test_bert_tokenizer_debug_stages() {
+        //        Given
+        let vocab = generate_test_vocab();
+        let bert_tokenizer: BertTokenizer = BertTokenizer::from_existing_vocab(vocab, true, true);
+        let text = "Hello, unaffable [MASK] world!";
+
+        //        When
+        let stages = bert_tokenizer.tokenize_debug(text);
+        let tokens = bert_tokenizer.tokenize_with_offsets(text);
+
+        //        Then
+        assert_eq!(
+            stages
+                .iter()
+                .map(|stage| (stage.name, stage.tokens.tokens.clone()))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    "whitespace",
+                    vec!["Hello,", "unaffable", "[MASK]", "world!"]
+                ),
+                (
+                    "special_split",
+                    vec!["Hello,", "unaffable", "[MASK]", "world!"]
+                ),
+                (
+                    "punctuation",
+                    vec!["Hello", ",", "unaffable", "[MASK]", "world", "!"]
+                ),
+                (
+                    "cleaning",
+                    vec!["hello", ",", "unaffable", "[MASK]", "world", "!"]
+                ),
+                (
+                    "subword",
+                    vec!["hello", "[UNK]", "una", "##ffa", "##ble", "[MASK]", "world", "!"]
+                ),
+            ]
+            .into_iter()
+            .map(|(name, tokens)| (name, tokens.into_iter().map(String::from).collect()))
+            .collect::<Vec<(&str, Vec<String>)>>()
+        );
+        let last_stage = stages.last().unwrap();
+        assert_eq!(last_stage.tokens.tokens, tokens.tokens);
+        assert_eq!(last_stage.tokens.offsets, tokens.offsets);
+        assert_eq!(
+            stages[2].to_string(),
+            "punctuation: \"Hello\" 0..5 | \",\" 5..6 | \"unaffable\" 7..16 | \"[MASK]\" 17..23 \
+             | \"world\" 24..29 | \"!\" 29..30"
+        );
+    }
+
+    #[test]
+    fn test_bert_tokenizer_describe() {
+        //        Given
+        let vocab = generate_test_vocab();
+        let mut bert_tokenizer: BertTokenizer =
+            BertTokenizer::from_existing_vocab(vocab, true, false);
+        bert_tokenizer.add_tokens(&["[CTRL]"]);
+
+        //        When
+        let description = bert_tokenizer.describe();
+
+        //        Then
+        assert_eq!(description.model_family, "bert");
+        assert_eq!(description.vocab_size, 15);
+        assert_eq!(description.max_model_length, None);
+        assert_eq!(
+            description.special_tokens.into_iter().collect::<Vec<_>>(),
+            vec![
+                ("[CLS]".to_string(), 4),
+                ("[CTRL]".to_string(), 14),
+                ("[MASK]".to_string(), 6),
+                ("[PAD]".to_string(), 10),
+                ("[SEP]".to_string(), 5),
+                ("[UNK]".to_string(), 2),
+            ]
+        );
+        assert_eq!(description.lower_case, Some(true));
+        assert_eq!(description.strip_accents, Some(false));
+        assert_eq!(description.add_prefix_space, None);
+    }
+
     #[test]
     fn test_encode() {
         //        Given
@@ -387,6 +947,7 @@ mod tests {
                         Mask::Punctuation,
                         Mask::Special,
                     ],
+                    tokens: None,
                 },
             ),
             (
@@ -430,6 +991,7 @@ mod tests {
                         Mask::Punctuation,
                         Mask::Special,
                     ],
+                    tokens: None,
                 },
             ),
             (
@@ -482,6 +1044,7 @@ mod tests {
                         Mask::Unknown,
                         Mask::Special,
                     ],
+                    tokens: None,
                 },
             ),
         ];
@@ -516,6 +1079,81 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_encode_plain() {
+        //        Given
+        let vocab = generate_test_vocab();
+        let bert_tokenizer: BertTokenizer = BertTokenizer::from_existing_vocab(vocab, true, true);
+        let truncation_strategy = TruncationStrategy::LongestFirst;
+
+        //        When
+        let encoded_input = bert_tokenizer
+            .encode_plain("hello[MASK] world!", None, 128, &truncation_strategy, 0)
+            .unwrap();
+        let encoded_pair = bert_tokenizer
+            .encode_plain("hello world!", Some("hello"), 3, &truncation_strategy, 0)
+            .unwrap();
+        let encoded_list = MultiThreadedTokenizer::encode_list_plain(
+            &bert_tokenizer,
+            &["hello[MASK] world!"],
+            128,
+            &truncation_strategy,
+            0,
+        )
+        .unwrap();
+
+        //        Then
+        assert_eq!(encoded_input.token_ids, vec![0, 6, 1, 3]);
+        assert_eq!(encoded_input.special_tokens_mask, vec![0, 0, 0, 0]);
+        assert_eq!(encoded_input.token_offsets.len(), 4);
+        assert_eq!(encoded_pair.token_ids, vec![0, 1, 0]);
+        assert_eq!(encoded_pair.segment_ids, vec![0, 0, 1]);
+        assert_eq!(encoded_pair.num_truncated_tokens, 1);
+        assert_eq!(encoded_list[0].token_ids, encoded_input.token_ids);
+    }
+
+    #[test]
+    fn test_encode_return_tokens() {
+        //        Given
+        let vocab = generate_test_vocab();
+        let mut bert_tokenizer: BertTokenizer =
+            BertTokenizer::from_existing_vocab(vocab, true, true);
+        let truncation_strategy = TruncationStrategy::LongestFirst;
+
+        //        When
+        let default_input =
+            bert_tokenizer.encode("hello world!", None, 128, &truncation_strategy, 0);
+        bert_tokenizer.set_return_tokens(true);
+        let encoded_input =
+            bert_tokenizer.encode("hello world!", Some("hello"), 5, &truncation_strategy, 0);
+
+        //        Then
+        assert!(default_input.tokens.is_none());
+        assert_eq!(encoded_input.token_ids, vec![4, 0, 5, 0, 5]);
+        assert_eq!(
+            encoded_input.tokens.unwrap(),
+            vec!["[CLS]", "hello", "[SEP]", "hello", "[SEP]"]
+        );
+    }
+
+    #[test]
+    fn test_vocab_trie() {
+        //        Given
+        let vocab = generate_test_vocab();
+        let bert_tokenizer: BertTokenizer = BertTokenizer::from_existing_vocab(vocab, true, true);
+
+        //        When
+        let trie = VocabTrie::from_tokenizer(&bert_tokenizer);
+
+        //        Then
+        assert_eq!(trie.len(), 9);
+        assert_eq!(trie.allowed_token_ids(" hello world"), vec![0]);
+        assert_eq!(trie.allowed_token_ids(" un"), vec![11]);
+        assert_eq!(trie.allowed_token_ids("ffa"), vec![12]);
+        assert_eq!(trie.allowed_token_ids(" "), vec![0, 1, 3, 7, 8, 9, 11]);
+        assert!(trie.allowed_token_ids("[CLS]").is_empty());
+    }
+
     #[test]
     fn test_encode_sentence_pair() {
         //        Given
@@ -537,6 +1175,7 @@ mod tests {
                     ),
                     reference_offsets: vec!(vec!(), vec!(0, 1, 2, 3, 4), vec!(6, 7, 8, 9, 10), vec!(), vec!(0, 1, 2, 3), vec!(5, 6), vec!(8, 9, 10), vec!(12, 13, 14, 15, 16, 17), vec!(19, 20, 21, 22, 23, 24, 25, 26), vec!()),
                     mask: vec!(Mask::Special, Mask::None, Mask::None, Mask::Special, Mask::Unknown, Mask::Unknown, Mask::Unknown, Mask::Unknown, Mask::Unknown, Mask::Special),
+                    tokens: None,
                 }
             ),
 //            Truncation of sentence 2 (longest)
@@ -553,6 +1192,7 @@ mod tests {
                     ),
                     reference_offsets: vec!(vec!(), vec!(0, 1, 2, 3, 4), vec!(6, 7, 8, 9, 10), vec!(), vec!(0), vec!(1, 2, 3, 4), vec!(6, 7), vec!(9, 10, 11), vec!(13, 14, 15, 16, 17, 18), vec!()),
                     mask: vec!(Mask::Special, Mask::None, Mask::None, Mask::Special, Mask::Punctuation, Mask::Unknown, Mask::Unknown, Mask::Unknown, Mask::Unknown, Mask::Special),
+                    tokens: None,
                 }
             ),
 //            Truncation of sentence 1 (longest)
@@ -569,6 +1209,7 @@ mod tests {
                     ),
                     reference_offsets: vec!(vec!(), vec!(0, 1, 2, 3, 4), vec!(6, 7, 8, 9, 10), vec!(13, 14, 15, 16, 17), vec!(20, 21, 22, 23, 24), vec!(), vec!(0), vec!(1), vec!(2), vec!()),
                     mask: vec!(Mask::Special, Mask::Unknown, Mask::None, Mask::None, Mask::None, Mask::Special, Mask::Punctuation, Mask::Punctuation, Mask::Punctuation, Mask::Special),
+                    tokens: None,
                 }
             ),
 //            Truncation of both sentences (longest)
@@ -585,6 +1226,7 @@ mod tests {
                     ),
                     reference_offsets: vec!(vec!(), vec!(0, 1, 2, 3, 4), vec!(6, 7, 8, 9, 10), vec!(13, 14, 15, 16, 17), vec!(), vec!(0), vec!(1), vec!(2), vec!(3), vec!()),
                     mask: vec!(Mask::Special, Mask::Unknown, Mask::None, Mask::None, Mask::Special, Mask::Punctuation, Mask::Punctuation, Mask::Punctuation, Mask::Punctuation, Mask::Special),
+                    tokens: None,
                 }
             )
         ];
@@ -675,6 +1317,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_decode_with_offsets() {
+        //        Given
+        let vocab = generate_test_vocab();
+        let bert_tokenizer: BertTokenizer = BertTokenizer::from_existing_vocab(vocab, true, true);
+        let token_ids = vec![4, 0, 2, 11, 12, 13, 1, 3, 5];
+
+        //        When
+        let (decoded, offsets) = bert_tokenizer.decode_with_offsets(&token_ids, true, true);
+
+        //        Then
+        assert_eq!(decoded, "hello unaffable world!");
+        assert_eq!(
+            offsets,
+            vec![
+                None,
+                Some(Offset::new(0, 5)),
+                None,
+                Some(Offset::new(6, 9)),
+                Some(Offset::new(9, 12)),
+                Some(Offset::new(12, 15)),
+                Some(Offset::new(16, 21)),
+                Some(Offset::new(21, 22)),
+                None,
+            ]
+        );
+    }
+
     #[test]
     fn test_decode_skip_special_tokens() {
         //        Given
@@ -0,0 +1,261 @@
+// Copyright 2019 Guillaume Becquin
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//     http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::error::TokenizerError;
+use crate::vocab::base_vocab::open_text_file;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// # Chat message role
+/// Author of a message in a conversation formatted by a `ChatTemplate`
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChatRole {
+    /// Instructions setting the behaviour of the assistant
+    System,
+    /// Message written by the user
+    User,
+    /// Reply generated by the model
+    Assistant,
+}
+
+/// # Chat message
+/// Single turn of a conversation, to be formatted by a `ChatTemplate`
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct ChatMessage {
+    pub role: ChatRole,
+    pub content: String,
+}
+
+impl ChatMessage {
+    /// Creates a new chat message for the given role
+    pub fn new(role: ChatRole, content: impl Into<String>) -> Self {
+        ChatMessage {
+            role,
+            content: content.into(),
+        }
+    }
+
+    /// Creates a new system message
+    pub fn system(content: impl Into<String>) -> Self {
+        Self::new(ChatRole::System, content)
+    }
+
+    /// Creates a new user message
+    pub fn user(content: impl Into<String>) -> Self {
+        Self::new(ChatRole::User, content)
+    }
+
+    /// Creates a new assistant message
+    pub fn assistant(content: impl Into<String>) -> Self {
+        Self::new(ChatRole::Assistant, content)
+    }
+}
+
+/// # Chat template
+/// Definition of the format of a conversation expected by a chat model. Each message is wrapped
+/// between the prefix and suffix of its role, and the formatted conversation is optionally
+/// preceded by a beginning of sequence token. The prefixes and suffixes usually contain special
+/// tokens (e.g. `<|im_start|>`), which must be registered as special tokens of the vocabulary
+/// (for example using `Tokenizer::add_tokens`) so that they are not split during tokenization.
+///
+/// Templates can be deserialized from a JSON definition, in which missing fields default to an
+/// empty string:
+/// ```json
+/// {
+///   "bos_token": "<s>",
+///   "user_prefix": "[INST] ",
+///   "user_suffix": " [/INST]",
+///   "assistant_suffix": "</s>"
+/// }
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChatTemplate {
+    /// Optional token prepended to the formatted conversation
+    pub bos_token: Option<String>,
+    pub system_prefix: String,
+    pub system_suffix: String,
+    pub user_prefix: String,
+    pub user_suffix: String,
+    pub assistant_prefix: String,
+    pub assistant_suffix: String,
+}
+
+impl ChatTemplate {
+    /// Template of the ChatML format (`<|im_start|>role\ncontent<|im_end|>\n`)
+    pub fn chatml() -> Self {
+        ChatTemplate {
+            bos_token: None,
+            system_prefix: "<|im_start|>system\n".to_string(),
+            system_suffix: "<|im_end|>\n".to_string(),
+            user_prefix: "<|im_start|>user\n".to_string(),
+            user_suffix: "<|im_end|>\n".to_string(),
+            assistant_prefix: "<|im_start|>assistant\n".to_string(),
+            assistant_suffix: "<|im_end|>\n".to_string(),
+        }
+    }
+
+    /// Template of the LLaMA 3 instruct models
+    pub fn llama3() -> Self {
+        ChatTemplate {
+            bos_token: Some("<|begin_of_text|>".to_string()),
+            system_prefix: "<|start_header_id|>system<|end_header_id|>\n\n".to_string(),
+            system_suffix: "<|eot_id|>".to_string(),
+            user_prefix: "<|start_header_id|>user<|end_header_id|>\n\n".to_string(),
+            user_suffix: "<|eot_id|>".to_string(),
+            assistant_prefix: "<|start_header_id|>assistant<|end_header_id|>\n\n".to_string(),
+            assistant_suffix: "<|eot_id|>".to_string(),
+        }
+    }
+
+    /// Reads a chat template definition from a JSON file
+    ///
+    /// # Parameters
+    /// - path (`&Path`): path to the JSON template definition
+    ///
+    /// # Returns
+    /// - `ChatTemplate` read from the file, or an error if the file can not be read or parsed
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::ChatTemplate;
+    /// let template = ChatTemplate::from_file("path/to/chat_template.json").unwrap();
+    /// ```
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, TokenizerError> {
+        let br = open_text_file(&path)?;
+        serde_json::from_reader(br).map_err(|e| {
+            TokenizerError::ValueError(format!(
+                "Invalid chat template file {}: {e}",
+                path.as_ref().display()
+            ))
+        })
+    }
+
+    /// Returns the prefix and suffix wrapping the messages of a role
+    pub fn role_markers(&self, role: ChatRole) -> (&str, &str) {
+        match role {
+            ChatRole::System => (&self.system_prefix, &self.system_suffix),
+            ChatRole::User => (&self.user_prefix, &self.user_suffix),
+            ChatRole::Assistant => (&self.assistant_prefix, &self.assistant_suffix),
+        }
+    }
+
+    /// Formats a conversation following the template
+    ///
+    /// # Parameters
+    /// - messages (`&[ChatMessage]`): messages of the conversation, in order
+    /// - add_generation_prompt (`bool`): if true, the assistant prefix is appended after the last
+    ///   message to prompt the model for a reply
+    ///
+    /// # Returns
+    /// - `String` containing the formatted conversation
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_tokenizers::tokenizer::{ChatMessage, ChatTemplate};
+    /// let template = ChatTemplate::chatml();
+    /// let prompt = template.format(&[ChatMessage::user("Hello!")], true);
+    /// assert_eq!(
+    ///     prompt,
+    ///     "<|im_start|>user\nHello!<|im_end|>\n<|im_start|>assistant\n"
+    /// );
+    /// ```
+    pub fn format(&self, messages: &[ChatMessage], add_generation_prompt: bool) -> String {
+        self.segments(messages, add_generation_prompt)
+            .into_iter()
+            .map(|(segment, _)| segment)
+            .collect()
+    }
+
+    /// Splits the formatted conversation into its non-empty segments, in order. Each segment is
+    /// flagged as message content (`true`) or as a marker of the template (`false`), so that the
+    /// content can be tokenized without matching the special tokens of the template.
+    pub(crate) fn segments<'a>(
+        &'a self,
+        messages: &'a [ChatMessage],
+        add_generation_prompt: bool,
+    ) -> Vec<(&'a str, bool)> {
+        let mut segments = vec![(self.bos_token.as_deref().unwrap_or_default(), false)];
+        for message in messages {
+            let (prefix, suffix) = self.role_markers(message.role);
+            segments.push((prefix, false));
+            segments.push((message.content.as_str(), true));
+            segments.push((suffix, false));
+        }
+        if add_generation_prompt {
+            segments.push((self.assistant_prefix.as_str(), false));
+        }
+        segments.retain(|(segment, _)| !segment.is_empty());
+        segments
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_chat_template() {
+        //        Given
+        let template = ChatTemplate::llama3();
+        let messages = [
+            ChatMessage::system("Be concise."),
+            ChatMessage::user("Hi"),
+            ChatMessage::assistant("Hello!"),
+            ChatMessage::user("Bye"),
+        ];
+
+        //        When
+        let with_prompt = template.format(&messages, true);
+        let without_prompt = template.format(&messages[..3], false);
+
+        //        Then
+        assert_eq!(
+            with_prompt,
+            "<|begin_of_text|><|start_header_id|>system<|end_header_id|>\n\nBe concise.<|eot_id|>\
+             <|start_header_id|>user<|end_header_id|>\n\nHi<|eot_id|>\
+             <|start_header_id|>assistant<|end_header_id|>\n\nHello!<|eot_id|>\
+             <|start_header_id|>user<|end_header_id|>\n\nBye<|eot_id|>\
+             <|start_header_id|>assistant<|end_header_id|>\n\n"
+        );
+        assert_eq!(
+            without_prompt,
+            "<|begin_of_text|><|start_header_id|>system<|end_header_id|>\n\nBe concise.<|eot_id|>\
+             <|start_header_id|>user<|end_header_id|>\n\nHi<|eot_id|>\
+             <|start_header_id|>assistant<|end_header_id|>\n\nHello!<|eot_id|>"
+        );
+    }
+
+    #[test]
+    fn test_chat_template_from_json() -> anyhow::Result<()> {
+        //        Given
+        let definition = r#"{"bos_token": "<s>", "user_prefix": "[INST] ", "user_suffix": " [/INST]", "assistant_suffix": "</s>"}"#;
+
+        //        When
+        let template: ChatTemplate = serde_json::from_str(definition)?;
+        let prompt = template.format(
+            &[
+                ChatMessage::user("Hi"),
+                ChatMessage::assistant("Hello"),
+                ChatMessage::user("Bye"),
+            ],
+            true,
+        );
+
+        //        Then
+        assert_eq!(template.system_prefix, "");
+        assert_eq!(prompt, "<s>[INST] Hi [/INST]Hello</s>[INST] Bye [/INST]");
+        Ok(())
+    }
+}
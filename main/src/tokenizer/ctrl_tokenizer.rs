@@ -17,14 +17,15 @@ use crate::tokenizer::tokenization_utils::{
     ctrl_bpe, fix_mask, lowercase, split_on_bpe_pairs, split_on_regex, split_on_special_tokens,
     BpeCache,
 };
-use crate::tokenizer::{MultiThreadedTokenizer, Tokenizer};
+use crate::tokenizer::{
+    ConfigurableTokenizer, MultiThreadedTokenizer, Tokenizer, TokenizerDescription,
+    TokenizerOptions,
+};
 use crate::vocab::bpe_vocab::BpePairVocab;
 use crate::vocab::{OpenAiGptVocab, Vocab};
 use crate::{Mask, Token, TokenRef};
 use regex::Regex;
-use std::collections::HashMap;
 use std::path::Path;
-use std::sync::RwLock;
 
 /// # CTRL tokenizer
 /// CTRL tokenizer performing:
@@ -38,6 +39,7 @@ pub struct CtrlTokenizer {
     cache: BpeCache,
     regex_pattern: Regex,
     lower_case: bool,
+    options: TokenizerOptions,
 }
 
 impl CtrlTokenizer {
@@ -64,7 +66,7 @@ impl CtrlTokenizer {
     ) -> Result<CtrlTokenizer, TokenizerError> {
         let vocab = OpenAiGptVocab::from_file(vocab_path)?;
         let bpe_ranks = BpePairVocab::from_file(merges_path)?;
-        let cache = RwLock::new(HashMap::new());
+        let cache = BpeCache::new();
         let regex_pattern = Regex::new(r"\S+\n?").unwrap();
         Ok(CtrlTokenizer {
             vocab,
@@ -72,6 +74,7 @@ impl CtrlTokenizer {
             cache,
             regex_pattern,
             lower_case,
+            options: TokenizerOptions::default(),
         })
     }
 
@@ -108,7 +111,7 @@ impl CtrlTokenizer {
             special_token_mapping_path,
         )?;
         let bpe_ranks = BpePairVocab::from_file(merges_path)?;
-        let cache = RwLock::new(HashMap::new());
+        let cache = BpeCache::new();
         let regex_pattern = Regex::new(r"\S+\n?").unwrap();
         Ok(CtrlTokenizer {
             vocab,
@@ -116,6 +119,7 @@ impl CtrlTokenizer {
             cache,
             regex_pattern,
             lower_case,
+            options: TokenizerOptions::default(),
         })
     }
 
@@ -142,7 +146,7 @@ impl CtrlTokenizer {
         merges: BpePairVocab,
         lower_case: bool,
     ) -> CtrlTokenizer {
-        let cache = RwLock::new(HashMap::new());
+        let cache = BpeCache::new();
         let regex_pattern = Regex::new(r"\S+\n?").unwrap();
         CtrlTokenizer {
             vocab,
@@ -150,6 +154,7 @@ impl CtrlTokenizer {
             cache,
             regex_pattern,
             lower_case,
+            options: TokenizerOptions::default(),
         }
     }
 }
@@ -162,6 +167,21 @@ impl Tokenizer<OpenAiGptVocab> for CtrlTokenizer {
         &mut self.vocab
     }
 
+    fn options(&self) -> &TokenizerOptions {
+        &self.options
+    }
+
+    fn describe(&self) -> TokenizerDescription {
+        TokenizerDescription {
+            lower_case: Some(self.lower_case),
+            ..TokenizerDescription::new("ctrl", &self.vocab)
+        }
+    }
+
+    fn bpe_cache(&self) -> Option<&BpeCache> {
+        Some(&self.cache)
+    }
+
     fn tokenize_to_tokens(&self, initial_token: TokenRef) -> Vec<Token> {
         let mut tokens = split_on_special_tokens(initial_token, &self.vocab)
             .into_iter()
@@ -198,6 +218,16 @@ impl Tokenizer<OpenAiGptVocab> for CtrlTokenizer {
 
 impl MultiThreadedTokenizer<OpenAiGptVocab> for CtrlTokenizer {}
 
+impl ConfigurableTokenizer<OpenAiGptVocab> for CtrlTokenizer {
+    fn options_mut(&mut self) -> &mut TokenizerOptions {
+        &mut self.options
+    }
+
+    fn bpe_cache_mut(&mut self) -> Option<&mut BpeCache> {
+        Some(&mut self.cache)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -372,6 +402,7 @@ mod tests {
                         Mask::Continuation,
                         Mask::Continuation,
                     ],
+                    tokens: None,
                 },
             ),
             (
@@ -421,6 +452,7 @@ mod tests {
                         Mask::Continuation,
                         Mask::Continuation,
                     ],
+                    tokens: None,
                 },
             ),
             (
@@ -434,6 +466,7 @@ mod tests {
                     token_offsets: vec![],
                     reference_offsets: vec![],
                     mask: vec![],
+                    tokens: None,
                 },
             ),
         ];
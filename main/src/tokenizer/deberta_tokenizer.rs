@@ -13,11 +13,12 @@
 
 use crate::error::TokenizerError;
 use crate::tokenizer::constants::UNICODE_TO_BYTES;
-use crate::tokenizer::tokenization_utils::{
-    bpe, fix_mask, split_on_bpe_pairs, split_on_regex_with_lookahead, split_on_special_tokens,
+use crate::tokenizer::tokenization_utils::{byte_level_tokens_to_bytes, tokenize_byte_level_bpe};
+use crate::tokenizer::tokenization_utils::{prefix_with_space, BpeCache};
+use crate::tokenizer::{
+    ConfigurableTokenizer, MultiThreadedTokenizer, PrefixSpaceTokenizer, Tokenizer,
+    TokenizerDescription, TokenizerOptions,
 };
-use crate::tokenizer::tokenization_utils::{lowercase, BpeCache};
-use crate::tokenizer::{MultiThreadedTokenizer, Tokenizer};
 use crate::vocab::bpe_vocab::BpePairVocab;
 use crate::vocab::{DeBERTaVocab, Vocab};
 use crate::{
@@ -25,10 +26,8 @@ use crate::{
 };
 use itertools::Itertools;
 use regex::Regex;
-use std::collections::HashMap;
 use std::iter::Iterator;
 use std::path::Path;
-use std::sync::RwLock;
 
 /// # DeBERTa tokenizer
 /// DeBERTa tokenizer (based on GPT2) performing:
@@ -43,6 +42,8 @@ pub struct DeBERTaTokenizer {
     pattern_lookahead: Regex,
     pattern_tokenization: Regex,
     lower_case: bool,
+    add_prefix_space: bool,
+    options: TokenizerOptions,
 }
 
 impl DeBERTaTokenizer {
@@ -70,7 +71,7 @@ impl DeBERTaTokenizer {
     ) -> Result<Self, TokenizerError> {
         let vocab = DeBERTaVocab::from_file(vocab_path)?;
         let bpe_ranks = BpePairVocab::from_file(merges_path)?;
-        let cache = RwLock::new(HashMap::new());
+        let cache = BpeCache::new();
         let pattern_lookahead = Regex::new(r"\s+\S").unwrap();
         let pattern_tokenization =
             Regex::new(r"'s|'t|'re|'ve|'m|'ll|'d| ?\p{L}+| ?\p{N}+| ?[^\s\p{L}\p{N}]+|\s+")
@@ -82,6 +83,8 @@ impl DeBERTaTokenizer {
             pattern_lookahead,
             pattern_tokenization,
             lower_case,
+            add_prefix_space: false,
+            options: TokenizerOptions::default(),
         })
     }
 
@@ -117,7 +120,7 @@ impl DeBERTaTokenizer {
             special_token_mapping_path,
         )?;
         let bpe_ranks = BpePairVocab::from_file(merges_path)?;
-        let cache = RwLock::new(HashMap::new());
+        let cache = BpeCache::new();
         let pattern_lookahead = Regex::new(r"\s+\S").unwrap();
         let pattern_tokenization =
             Regex::new(r"'s|'t|'re|'ve|'m|'ll|'d| ?\p{L}+| ?\p{N}+| ?[^\s\p{L}\p{N}]+|\s+")
@@ -129,6 +132,8 @@ impl DeBERTaTokenizer {
             pattern_lookahead,
             pattern_tokenization,
             lower_case,
+            add_prefix_space: false,
+            options: TokenizerOptions::default(),
         })
     }
 
@@ -155,7 +160,7 @@ impl DeBERTaTokenizer {
         merges: BpePairVocab,
         lower_case: bool,
     ) -> DeBERTaTokenizer {
-        let cache = RwLock::new(HashMap::new());
+        let cache = BpeCache::new();
         let pattern_lookahead = Regex::new(r"\s+\S").unwrap();
         let pattern_tokenization =
             Regex::new(r"'s|'t|'re|'ve|'m|'ll|'d| ?\p{L}+| ?\p{N}+| ?[^\s\p{L}\p{N}]+|\s+")
@@ -167,6 +172,8 @@ impl DeBERTaTokenizer {
             pattern_lookahead,
             pattern_tokenization,
             lower_case,
+            add_prefix_space: false,
+            options: TokenizerOptions::default(),
         }
     }
 }
@@ -179,38 +186,24 @@ impl Tokenizer<DeBERTaVocab> for DeBERTaTokenizer {
         &mut self.vocab
     }
 
-    fn tokenize_to_tokens(&self, initial_token: TokenRef) -> Vec<Token> {
-        let mut tokens = split_on_special_tokens(initial_token, &self.vocab)
-            .into_iter()
-            .map(|token| token.to_owned())
-            .collect::<Vec<Token>>();
+    fn options(&self) -> &TokenizerOptions {
+        &self.options
+    }
 
-        let mut sub_tokens = Vec::new();
-        for token in tokens.iter_mut() {
-            if token.mask != Mask::Special && token.mask != Mask::Unknown {
-                if self.lower_case {
-                    lowercase(token);
-                }
-                for token in split_on_regex_with_lookahead(
-                    token.as_ref(),
-                    &self.pattern_lookahead,
-                    &self.pattern_tokenization,
-                ) {
-                    sub_tokens.extend(split_on_bpe_pairs(
-                        token,
-                        bpe,
-                        &self.bpe_ranks,
-                        &self.cache,
-                        true,
-                    ));
-                }
-            } else {
-                sub_tokens.push(token.clone());
-            }
+    fn describe(&self) -> TokenizerDescription {
+        TokenizerDescription {
+            lower_case: Some(self.lower_case),
+            add_prefix_space: Some(self.add_prefix_space),
+            ..TokenizerDescription::new("deberta", &self.vocab)
         }
+    }
 
-        fix_mask(&mut sub_tokens);
-        sub_tokens
+    fn bpe_cache(&self) -> Option<&BpeCache> {
+        Some(&self.cache)
+    }
+
+    fn tokenize_to_tokens(&self, initial_token: TokenRef) -> Vec<Token> {
+        self.tokenize_to_tokens_with_prefix_space(initial_token, self.add_prefix_space)
     }
 
     fn convert_tokens_to_string(&self, tokens: Vec<String>) -> String {
@@ -225,6 +218,10 @@ impl Tokenizer<DeBERTaVocab> for DeBERTaTokenizer {
         String::from_utf8_lossy(tokens.as_slice()).to_string()
     }
 
+    fn convert_tokens_to_bytes(&self, tokens: Vec<String>) -> Vec<u8> {
+        byte_level_tokens_to_bytes(&tokens)
+    }
+
     fn build_input_with_special_tokens(
         &self,
         tokens_ids_with_offsets_1: TokenIdsWithOffsets,
@@ -278,4 +275,44 @@ impl Tokenizer<DeBERTaVocab> for DeBERTaTokenizer {
     }
 }
 
+impl PrefixSpaceTokenizer<DeBERTaVocab> for DeBERTaTokenizer {
+    fn add_prefix_space(&self) -> bool {
+        self.add_prefix_space
+    }
+
+    fn set_add_prefix_space(&mut self, add_prefix_space: bool) {
+        self.add_prefix_space = add_prefix_space;
+    }
+
+    fn tokenize_to_tokens_with_prefix_space(
+        &self,
+        initial_token: TokenRef,
+        add_prefix_space: bool,
+    ) -> Vec<Token> {
+        let mut initial_token: Token = initial_token.to_owned();
+        if add_prefix_space {
+            prefix_with_space(&mut initial_token);
+        }
+        tokenize_byte_level_bpe(
+            initial_token.as_ref(),
+            &self.vocab,
+            &self.bpe_ranks,
+            &self.cache,
+            &self.pattern_lookahead,
+            &self.pattern_tokenization,
+            self.lower_case,
+        )
+    }
+}
+
 impl MultiThreadedTokenizer<DeBERTaVocab> for DeBERTaTokenizer {}
+
+impl ConfigurableTokenizer<DeBERTaVocab> for DeBERTaTokenizer {
+    fn options_mut(&mut self) -> &mut TokenizerOptions {
+        &mut self.options
+    }
+
+    fn bpe_cache_mut(&mut self) -> Option<&mut BpeCache> {
+        Some(&mut self.cache)
+    }
+}
@@ -13,10 +13,14 @@
 
 use crate::error::TokenizerError;
 use crate::tokenizer::tokenization_utils::{
-    clean_text, decompose_nfkc, is_whitespace, split_on_special_tokens, strip_accents,
+    clean_text, decompose_nfkc, is_whitespace, split_on_punct, split_on_special_tokens,
+    strip_accents,
 };
 use crate::tokenizer::tokenization_utils::{lowercase, unknown_byte_fallback};
-use crate::tokenizer::{MultiThreadedTokenizer, Tokenizer};
+use crate::tokenizer::{
+    ConfigurableTokenizer, MultiThreadedTokenizer, Tokenizer, TokenizerDescription,
+    TokenizerOptions, UnigramTokenizer,
+};
 use crate::vocab::{DeBERTaV2Vocab, SentencePieceModel, Vocab};
 use crate::{
     Mask, Offset, OffsetSize, Token, TokenIdsWithOffsets, TokenIdsWithSpecialTokens, TokenRef,
@@ -31,6 +35,7 @@ use std::path::Path;
 /// - NFKC decomposition
 /// - (optional) lower casing
 /// - (optional) accent stripping
+/// - (optional) splitting on punctuation
 /// - SentencePiece BPE decomposition
 pub struct DeBERTaV2Tokenizer {
     model: SentencePieceModel,
@@ -38,6 +43,8 @@ pub struct DeBERTaV2Tokenizer {
     lower_case: bool,
     strip_accents: bool,
     add_prefix_space: bool,
+    split_by_punct: bool,
+    options: TokenizerOptions,
 }
 impl DeBERTaV2Tokenizer {
     /// Create a new instance of a `DeBERTaV2Tokenizer`
@@ -78,6 +85,8 @@ impl DeBERTaV2Tokenizer {
             lower_case,
             strip_accents,
             add_prefix_space,
+            split_by_punct: false,
+            options: TokenizerOptions::default(),
         })
     }
 
@@ -122,9 +131,53 @@ impl DeBERTaV2Tokenizer {
             lower_case,
             strip_accents,
             add_prefix_space,
+            split_by_punct: false,
+            options: TokenizerOptions::default(),
         })
     }
 
+    /// Create a new instance of a `DeBERTaV2Tokenizer` from the bytes of a SentencePiece protobuf
+    /// file, allowing to embed the model in a self-contained binary (e.g. using `include_bytes!`).
+    ///
+    /// # Parameters
+    /// - bytes (`&[u8]`): content of the SentencePiece model file
+    /// - lower_case (`bool`): flag indicating if the text should be lower-cased as part of the tokenization
+    /// - strip_accents (`bool`): flag indicating if accents should be stripped from the text
+    /// - add_prefix_space (`bool`): flag indicating if a space should be added to the start of the input text
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::DeBERTaV2Tokenizer;
+    /// let lower_case = false;
+    /// let strip_accents = false;
+    /// let add_prefix_space = false;
+    /// let bytes = std::fs::read("path/to/spm.model").unwrap();
+    /// let tokenizer = DeBERTaV2Tokenizer::from_protobuf_bytes(
+    ///     &bytes,
+    ///     lower_case,
+    ///     strip_accents,
+    ///     add_prefix_space,
+    /// )
+    /// .unwrap();
+    /// ```
+    pub fn from_protobuf_bytes(
+        bytes: &[u8],
+        lower_case: bool,
+        strip_accents: bool,
+        add_prefix_space: bool,
+    ) -> Result<DeBERTaV2Tokenizer, TokenizerError> {
+        let model = SentencePieceModel::from_protobuf_bytes(bytes)?;
+        let vocab = DeBERTaV2Vocab::from_protobuf_bytes(bytes)?;
+        Ok(Self::from_existing_vocab_and_model(
+            vocab,
+            model,
+            lower_case,
+            strip_accents,
+            add_prefix_space,
+        ))
+    }
+
     /// Create a new instance of a `DeBERTaV2Tokenizer` from an existing vocabulary and model
     ///
     /// # Parameters
@@ -166,9 +219,75 @@ impl DeBERTaV2Tokenizer {
             lower_case,
             strip_accents,
             add_prefix_space,
+            split_by_punct: false,
+            options: TokenizerOptions::default(),
         }
     }
 
+    /// Returns `true` if the text is split on punctuation before the SentencePiece decomposition
+    pub fn split_by_punct(&self) -> bool {
+        self.split_by_punct
+    }
+
+    /// Sets whether the text is split on punctuation before the SentencePiece decomposition,
+    /// following the `split_by_punct` configuration of the reference DeBERTa (v2) tokenizer.
+    /// Each punctuation character and each span of text between punctuation characters is then
+    /// decomposed independently, stripped of surrounding whitespaces and prefixed with `▁`.
+    /// This should be enabled for checkpoints trained with this configuration (disabled by default).
+    ///
+    /// # Parameters
+    /// - split_by_punct (`bool`): flag indicating if the text should be split on punctuation
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{DeBERTaV2Tokenizer, Tokenizer};
+    /// let mut tokenizer =
+    ///     DeBERTaV2Tokenizer::from_file("path/to/vocab/file", false, false, false).unwrap();
+    /// tokenizer.set_split_by_punct(true);
+    ///
+    /// let tokens = tokenizer.tokenize("Hello, world!");
+    /// ```
+    pub fn set_split_by_punct(&mut self, split_by_punct: bool) {
+        self.split_by_punct = split_by_punct;
+    }
+
+    fn tokenize_words_by_punct(&self, token: &Token) -> Vec<Token> {
+        let mut sub_tokens = Vec::new();
+        for word in split_on_punct(token.as_ref()) {
+            let characters = word.text.chars().collect::<Vec<char>>();
+            let start = match characters.iter().position(|c| !is_whitespace(c)) {
+                Some(start) => start,
+                None => continue,
+            };
+            let end = characters.iter().rposition(|c| !is_whitespace(c)).unwrap() + 1;
+            let mut reference_offsets = word.reference_offsets[start..end].to_vec();
+            reference_offsets.insert(0, reference_offsets[0]);
+            let mut word = Token {
+                text: characters[start..end].iter().collect(),
+                offset: Offset {
+                    begin: word.offset.begin + start as OffsetSize,
+                    end: word.offset.begin + end as OffsetSize,
+                },
+                reference_offsets,
+                mask: word.mask,
+            };
+            word.text = word.text.replace(|c: char| is_whitespace(&c), "\u{2581}");
+            word.text.insert(0, '\u{2581}');
+            sub_tokens.extend(self.decompose_pieces(&word));
+        }
+        sub_tokens
+    }
+
+    fn decompose_pieces(&self, token: &Token) -> Vec<Token> {
+        let output = self.model.decode_forward_token_ref(token.as_ref());
+        let decoded = self.model.decode_backward(&output);
+
+        let mut output: Vec<Token> = self.model.parse_nodes_to_tokens(decoded);
+        self.post_process_pieces(&mut output);
+        output
+    }
+
     fn post_process_pieces<'a>(&self, tokens: &'a mut Vec<Token>) -> &'a Vec<Token> {
         let mut positions_to_update: Vec<(usize, Vec<Token>)> = vec![];
         for (token_idx, token) in tokens.iter().enumerate() {
@@ -227,6 +346,19 @@ impl Tokenizer<DeBERTaV2Vocab> for DeBERTaV2Tokenizer {
         &mut self.vocab
     }
 
+    fn options(&self) -> &TokenizerOptions {
+        &self.options
+    }
+
+    fn describe(&self) -> TokenizerDescription {
+        TokenizerDescription {
+            lower_case: Some(self.lower_case),
+            strip_accents: Some(self.strip_accents),
+            add_prefix_space: Some(self.add_prefix_space),
+            ..TokenizerDescription::new("deberta_v2", &self.vocab)
+        }
+    }
+
     fn tokenize_to_tokens(&self, initial_token: TokenRef) -> Vec<Token> {
         let mut initial_token: Token = initial_token.to_owned();
         if !is_whitespace(&initial_token.text.chars().next().unwrap()) & self.add_prefix_space {
@@ -250,17 +382,16 @@ impl Tokenizer<DeBERTaV2Vocab> for DeBERTaV2Tokenizer {
                 if self.strip_accents {
                     strip_accents(token);
                 }
+                if self.split_by_punct {
+                    sub_tokens.extend(self.tokenize_words_by_punct(token));
+                    continue;
+                }
                 token.text = token.text.replace(|c: char| is_whitespace(&c), "\u{2581}");
                 if !token.text.starts_with('\u{2581}') {
                     token.text.insert(0, '\u{2581}');
                     token.reference_offsets.insert(0, 0);
                 };
-                let output = self.model.decode_forward_token_ref(token.as_ref());
-                let decoded = self.model.decode_backward(&output);
-
-                let mut output: Vec<Token> = self.model.parse_nodes_to_tokens(decoded);
-                self.post_process_pieces(&mut output);
-                sub_tokens.extend(output)
+                sub_tokens.extend(self.decompose_pieces(token))
             } else {
                 sub_tokens.push(token.clone());
             }
@@ -329,3 +460,15 @@ impl Tokenizer<DeBERTaV2Vocab> for DeBERTaV2Tokenizer {
 }
 
 impl MultiThreadedTokenizer<DeBERTaV2Vocab> for DeBERTaV2Tokenizer {}
+
+impl ConfigurableTokenizer<DeBERTaV2Vocab> for DeBERTaV2Tokenizer {
+    fn options_mut(&mut self) -> &mut TokenizerOptions {
+        &mut self.options
+    }
+}
+
+impl UnigramTokenizer<DeBERTaV2Vocab> for DeBERTaV2Tokenizer {
+    fn unigram_model(&self) -> &SentencePieceModel {
+        &self.model
+    }
+}
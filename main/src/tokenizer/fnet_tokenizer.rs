@@ -14,12 +14,15 @@ use std::path::Path;
 
 use crate::error::TokenizerError;
 use crate::tokenizer::tokenization_utils::{
-    clean_text, decompose_nfkc, is_whitespace, lowercase, replace_string, split_on_special_tokens,
-    strip_accents,
+    apply_unknown_piece_policy, clean_text, decompose_nfkc, is_whitespace, lowercase,
+    merge_byte_fallback_pieces, replace_string, split_on_special_tokens, strip_accents,
 };
 use crate::vocab::{FNetVocab, SentencePieceBpeModel};
 
-use crate::tokenizer::base_tokenizer::{TokenIdsWithOffsets, TokenIdsWithSpecialTokens};
+use crate::tokenizer::base_tokenizer::{
+    ConfigurableTokenizer, TokenIdsWithOffsets, TokenIdsWithSpecialTokens, TokenizerDescription,
+    TokenizerOptions, UnknownPiecePolicy,
+};
 use crate::tokenizer::MultiThreadedTokenizer;
 use crate::tokenizer::Tokenizer;
 use crate::vocab::Vocab;
@@ -29,7 +32,7 @@ use crate::{Mask, Offset, OffsetSize, Token, TokenRef};
 /// FNet tokenizer performing:
 /// - splitting on special characters
 /// - text cleaning
-/// - NFKC decomposition
+/// - (optional) NFKC decomposition
 /// - (optional) lower casing
 /// - (optional) accent stripping
 /// - SentencePiece BPE decomposition
@@ -38,6 +41,8 @@ pub struct FNetTokenizer {
     vocab: FNetVocab,
     lower_case: bool,
     strip_accents: bool,
+    nfkc_normalization: bool,
+    options: TokenizerOptions,
 }
 
 impl FNetTokenizer {
@@ -70,6 +75,8 @@ impl FNetTokenizer {
             vocab,
             lower_case,
             strip_accents,
+            nfkc_normalization: true,
+            options: TokenizerOptions::default(),
         })
     }
 
@@ -110,6 +117,8 @@ impl FNetTokenizer {
             vocab,
             lower_case,
             strip_accents,
+            nfkc_normalization: true,
+            options: TokenizerOptions::default(),
         })
     }
 
@@ -145,9 +154,31 @@ impl FNetTokenizer {
             vocab,
             lower_case,
             strip_accents,
+            nfkc_normalization: true,
+            options: TokenizerOptions::default(),
         }
     }
 
+    /// Enables or disables the NFKC normalization of the text before tokenization. NFKC
+    /// normalization is enabled by default, matching the reference FNet tokenizer.
+    ///
+    /// # Parameters
+    /// - nfkc_normalization (`bool`): flag indicating if the text should be NFKC-normalized before tokenization
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::FNetTokenizer;
+    /// let strip_accents = false;
+    /// let lower_case = false;
+    /// let mut tokenizer =
+    ///     FNetTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
+    /// tokenizer.set_nfkc_normalization(false);
+    /// ```
+    pub fn set_nfkc_normalization(&mut self, nfkc_normalization: bool) {
+        self.nfkc_normalization = nfkc_normalization;
+    }
+
     fn post_process_pieces<'a>(&self, tokens: &'a mut Vec<Token>) -> &'a Vec<Token> {
         let mut positions_to_update: Vec<(usize, Vec<Token>)> = vec![];
         for (token_idx, token) in tokens.iter().enumerate() {
@@ -200,6 +231,18 @@ impl Tokenizer<FNetVocab> for FNetTokenizer {
         &mut self.vocab
     }
 
+    fn options(&self) -> &TokenizerOptions {
+        &self.options
+    }
+
+    fn describe(&self) -> TokenizerDescription {
+        TokenizerDescription {
+            lower_case: Some(self.lower_case),
+            strip_accents: Some(self.strip_accents),
+            ..TokenizerDescription::new("fnet", &self.vocab)
+        }
+    }
+
     fn tokenize_to_tokens(&self, text: TokenRef) -> Vec<Token> {
         let mut tokens = split_on_special_tokens(text, &self.vocab)
             .into_iter()
@@ -212,7 +255,9 @@ impl Tokenizer<FNetVocab> for FNetTokenizer {
                 replace_string(token, "``", "\"");
                 replace_string(token, "\'\'", "\"");
                 clean_text(token, true);
-                decompose_nfkc(token);
+                if self.nfkc_normalization {
+                    decompose_nfkc(token);
+                }
                 if self.lower_case {
                     lowercase(token);
                 }
@@ -231,10 +276,15 @@ impl Tokenizer<FNetVocab> for FNetTokenizer {
                 sub_tokens.push(token.clone());
             }
         }
-        sub_tokens
+        apply_unknown_piece_policy(sub_tokens, &self.vocab, self.unknown_piece_policy())
     }
 
     fn convert_tokens_to_string(&self, tokens: Vec<String>) -> String {
+        let tokens = if self.unknown_piece_policy() == UnknownPiecePolicy::ByteFallback {
+            merge_byte_fallback_pieces(tokens)
+        } else {
+            tokens
+        };
         tokens
             .into_iter()
             .map(|v| v.replace('\u{2581}', " "))
@@ -295,3 +345,9 @@ impl Tokenizer<FNetVocab> for FNetTokenizer {
 }
 
 impl MultiThreadedTokenizer<FNetVocab> for FNetTokenizer {}
+
+impl ConfigurableTokenizer<FNetVocab> for FNetTokenizer {
+    fn options_mut(&mut self) -> &mut TokenizerOptions {
+        &mut self.options
+    }
+}
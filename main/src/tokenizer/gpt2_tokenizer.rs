@@ -15,19 +15,20 @@
 use crate::error::TokenizerError;
 use crate::tokenizer::constants::UNICODE_TO_BYTES;
 use crate::tokenizer::tokenization_utils::{
-    bpe, fix_mask, split_on_bpe_pairs, split_on_regex_with_lookahead, split_on_special_tokens,
+    byte_level_tokens_to_bytes, learn_byte_level_merges, tokenize_byte_level_bpe,
+};
+use crate::tokenizer::tokenization_utils::{prefix_with_space, BpeCache};
+use crate::tokenizer::{
+    ConfigurableTokenizer, MultiThreadedTokenizer, PrefixSpaceTokenizer, Tokenizer,
+    TokenizerDescription, TokenizerOptions,
 };
-use crate::tokenizer::tokenization_utils::{lowercase, BpeCache};
-use crate::tokenizer::{MultiThreadedTokenizer, Tokenizer};
 use crate::vocab::bpe_vocab::BpePairVocab;
 use crate::vocab::{Gpt2Vocab, Vocab};
-use crate::{Mask, Token, TokenRef};
+use crate::{Token, TokenRef};
 use itertools::Itertools;
 use regex::Regex;
-use std::collections::HashMap;
 use std::iter::Iterator;
 use std::path::Path;
-use std::sync::RwLock;
 
 /// # GPT2 tokenizer
 /// GPT2 tokenizer performing:
@@ -42,6 +43,8 @@ pub struct Gpt2Tokenizer {
     pattern_lookahead: Regex,
     pattern_tokenization: Regex,
     lower_case: bool,
+    add_prefix_space: bool,
+    options: TokenizerOptions,
 }
 
 impl Gpt2Tokenizer {
@@ -52,23 +55,31 @@ impl Gpt2Tokenizer {
     /// - vocab_path (`&str`): path to the vocabulary file
     /// - merges_path (`&str`): path to the merges file (use as part of the BPE encoding process)
     /// - lower_case (`bool`): flag indicating if the text should be lower-cased as part of the tokenization
+    /// - add_prefix_space (`bool`): flag indicating if a space should be prepended to the input text before tokenization
     ///
     /// # Example
     ///
     /// ```no_run
     /// use rust_tokenizers::tokenizer::{Gpt2Tokenizer, Tokenizer};
     /// let lower_case = false;
-    /// let tokenizer =
-    ///     Gpt2Tokenizer::from_file("path/to/vocab/file", "path/to/merges/file", lower_case).unwrap();
+    /// let add_prefix_space = true;
+    /// let tokenizer = Gpt2Tokenizer::from_file(
+    ///     "path/to/vocab/file",
+    ///     "path/to/merges/file",
+    ///     lower_case,
+    ///     add_prefix_space,
+    /// )
+    /// .unwrap();
     /// ```
     pub fn from_file<P: AsRef<Path>, M: AsRef<Path>>(
         vocab_path: P,
         merges_path: M,
         lower_case: bool,
+        add_prefix_space: bool,
     ) -> Result<Gpt2Tokenizer, TokenizerError> {
         let vocab = Gpt2Vocab::from_file(vocab_path)?;
         let bpe_ranks = BpePairVocab::from_file(merges_path)?;
-        let cache = RwLock::new(HashMap::new());
+        let cache = BpeCache::new();
         let pattern_lookahead = Regex::new(r"\s+\S").unwrap();
         let pattern_tokenization =
             Regex::new(r"'s|'t|'re|'ve|'m|'ll|'d| ?\p{L}+| ?\p{N}+| ?[^\s\p{L}\p{N}]+|\s+")
@@ -80,6 +91,8 @@ impl Gpt2Tokenizer {
             pattern_lookahead,
             pattern_tokenization,
             lower_case,
+            add_prefix_space,
+            options: TokenizerOptions::default(),
         })
     }
 
@@ -90,6 +103,7 @@ impl Gpt2Tokenizer {
     /// - vocab_path (`&str`): path to the vocabulary file
     /// - merges_path (`&str`): path to the merges file (use as part of the BPE encoding process)
     /// - lower_case (`bool`): flag indicating if the text should be lower-cased as part of the tokenization
+    /// - add_prefix_space (`bool`): flag indicating if a space should be prepended to the input text before tokenization
     /// - special_token_mapping_path (`&str`): path to a special token mapping file to overwrite default special tokens
     ///
     /// # Example
@@ -97,10 +111,12 @@ impl Gpt2Tokenizer {
     /// ```no_run
     /// use rust_tokenizers::tokenizer::{Gpt2Tokenizer, Tokenizer};
     /// let lower_case = false;
+    /// let add_prefix_space = true;
     /// let tokenizer = Gpt2Tokenizer::from_file_with_special_token_mapping(
     ///     "path/to/vocab/file",
     ///     "path/to/merges/file",
     ///     lower_case,
+    ///     add_prefix_space,
     ///     "path/to/special/token/mapping/file",
     /// )
     /// .unwrap();
@@ -109,6 +125,7 @@ impl Gpt2Tokenizer {
         vocab_path: V,
         merges_path: M,
         lower_case: bool,
+        add_prefix_space: bool,
         special_token_mapping_path: S,
     ) -> Result<Gpt2Tokenizer, TokenizerError> {
         let vocab = Gpt2Vocab::from_file_with_special_token_mapping(
@@ -116,7 +133,7 @@ impl Gpt2Tokenizer {
             special_token_mapping_path,
         )?;
         let bpe_ranks = BpePairVocab::from_file(merges_path)?;
-        let cache = RwLock::new(HashMap::new());
+        let cache = BpeCache::new();
         let pattern_lookahead = Regex::new(r"\s+\S").unwrap();
         let pattern_tokenization =
             Regex::new(r"'s|'t|'re|'ve|'m|'ll|'d| ?\p{L}+| ?\p{N}+| ?[^\s\p{L}\p{N}]+|\s+")
@@ -128,6 +145,8 @@ impl Gpt2Tokenizer {
             pattern_lookahead,
             pattern_tokenization,
             lower_case,
+            add_prefix_space,
+            options: TokenizerOptions::default(),
         })
     }
 
@@ -137,6 +156,7 @@ impl Gpt2Tokenizer {
     /// - vocab (`Gpt2Vocab`): GPT-like vocabulary
     /// - merges (`BpePairVocab`): BPE pairs vocabulary
     /// - lower_case (`bool`): flag indicating if the text should be lower-cased as part of the tokenization
+    /// - add_prefix_space (`bool`): flag indicating if a space should be prepended to the input text before tokenization
     ///
     /// # Example
     ///
@@ -144,17 +164,20 @@ impl Gpt2Tokenizer {
     /// use rust_tokenizers::tokenizer::{Gpt2Tokenizer, Tokenizer};
     /// use rust_tokenizers::vocab::{BpePairVocab, Gpt2Vocab, Vocab};
     /// let lower_case = false;
+    /// let add_prefix_space = true;
     /// let vocab = Gpt2Vocab::from_file("path/to/vocab/file").unwrap();
     /// let merges = BpePairVocab::from_file("path/to/merges/file").unwrap();
     ///
-    /// let tokenizer = Gpt2Tokenizer::from_existing_vocab_and_merges(vocab, merges, lower_case);
+    /// let tokenizer =
+    ///     Gpt2Tokenizer::from_existing_vocab_and_merges(vocab, merges, lower_case, add_prefix_space);
     /// ```
     pub fn from_existing_vocab_and_merges(
         vocab: Gpt2Vocab,
         merges: BpePairVocab,
         lower_case: bool,
+        add_prefix_space: bool,
     ) -> Gpt2Tokenizer {
-        let cache = RwLock::new(HashMap::new());
+        let cache = BpeCache::new();
         let pattern_lookahead = Regex::new(r"\s+\S").unwrap();
         let pattern_tokenization =
             Regex::new(r"'s|'t|'re|'ve|'m|'ll|'d| ?\p{L}+| ?\p{N}+| ?[^\s\p{L}\p{N}]+|\s+")
@@ -166,8 +189,57 @@ impl Gpt2Tokenizer {
             pattern_lookahead,
             pattern_tokenization,
             lower_case,
+            add_prefix_space,
+            options: TokenizerOptions::default(),
         }
     }
+
+    /// Continues the training of the BPE merges on a new corpus, for cheap domain adaptation. The
+    /// corpus is pre-tokenized as for the tokenization (byte-level words), up to `num_merges`
+    /// merges of the most frequent pairs of symbols are appended to the merges of the tokenizer, and
+    /// the merged symbols are added to its vocabulary (see `BpePairVocab::learn_merges`).
+    ///
+    /// # Parameters
+    /// - corpus: iterator over the samples (string-like) of the new corpus
+    /// - num_merges (`usize`): maximum number of merges to learn
+    ///
+    /// # Returns
+    /// `Vec<(String, String)>` with the pairs merged, in order of their rank
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{Gpt2Tokenizer, Tokenizer};
+    /// let lower_case = false;
+    /// let add_prefix_space = true;
+    /// let mut tokenizer = Gpt2Tokenizer::from_file(
+    ///     "path/to/vocab/file",
+    ///     "path/to/merges/file",
+    ///     lower_case,
+    ///     add_prefix_space,
+    /// )
+    /// .unwrap();
+    ///
+    /// let corpus = ["The patient was given acetaminophen.", "Acetaminophen overdose."];
+    /// let new_merges = tokenizer.learn_merges(corpus.iter(), 100);
+    /// ```
+    pub fn learn_merges<I, S>(&mut self, corpus: I, num_merges: usize) -> Vec<(String, String)>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        learn_byte_level_merges(
+            corpus,
+            num_merges,
+            &mut self.vocab,
+            &mut self.bpe_ranks,
+            &mut self.cache,
+            self.lower_case,
+            self.add_prefix_space,
+            &self.pattern_lookahead,
+            &self.pattern_tokenization,
+        )
+    }
 }
 
 impl Tokenizer<Gpt2Vocab> for Gpt2Tokenizer {
@@ -178,38 +250,24 @@ impl Tokenizer<Gpt2Vocab> for Gpt2Tokenizer {
         &mut self.vocab
     }
 
-    fn tokenize_to_tokens(&self, initial_token: TokenRef) -> Vec<Token> {
-        let mut tokens = split_on_special_tokens(initial_token, &self.vocab)
-            .into_iter()
-            .map(|token| token.to_owned())
-            .collect::<Vec<Token>>();
-
-        let mut sub_tokens = Vec::new();
-        for token in tokens.iter_mut() {
-            if token.mask != Mask::Special && token.mask != Mask::Unknown {
-                if self.lower_case {
-                    lowercase(token);
-                }
-                for token in split_on_regex_with_lookahead(
-                    token.as_ref(),
-                    &self.pattern_lookahead,
-                    &self.pattern_tokenization,
-                ) {
-                    sub_tokens.extend(split_on_bpe_pairs(
-                        token,
-                        bpe,
-                        &self.bpe_ranks,
-                        &self.cache,
-                        true,
-                    ));
-                }
-            } else {
-                sub_tokens.push(token.clone());
-            }
+    fn options(&self) -> &TokenizerOptions {
+        &self.options
+    }
+
+    fn describe(&self) -> TokenizerDescription {
+        TokenizerDescription {
+            lower_case: Some(self.lower_case),
+            add_prefix_space: Some(self.add_prefix_space),
+            ..TokenizerDescription::new("gpt2", &self.vocab)
         }
+    }
 
-        fix_mask(&mut sub_tokens);
-        sub_tokens
+    fn bpe_cache(&self) -> Option<&BpeCache> {
+        Some(&self.cache)
+    }
+
+    fn tokenize_to_tokens(&self, initial_token: TokenRef) -> Vec<Token> {
+        self.tokenize_to_tokens_with_prefix_space(initial_token, self.add_prefix_space)
     }
 
     fn convert_tokens_to_string(&self, tokens: Vec<String>) -> String {
@@ -223,17 +281,61 @@ impl Tokenizer<Gpt2Vocab> for Gpt2Tokenizer {
             .collect::<Vec<u8>>();
         String::from_utf8_lossy(tokens.as_slice()).to_string()
     }
+
+    fn convert_tokens_to_bytes(&self, tokens: Vec<String>) -> Vec<u8> {
+        byte_level_tokens_to_bytes(&tokens)
+    }
+}
+
+impl PrefixSpaceTokenizer<Gpt2Vocab> for Gpt2Tokenizer {
+    fn add_prefix_space(&self) -> bool {
+        self.add_prefix_space
+    }
+
+    fn set_add_prefix_space(&mut self, add_prefix_space: bool) {
+        self.add_prefix_space = add_prefix_space;
+    }
+
+    fn tokenize_to_tokens_with_prefix_space(
+        &self,
+        initial_token: TokenRef,
+        add_prefix_space: bool,
+    ) -> Vec<Token> {
+        let mut initial_token: Token = initial_token.to_owned();
+        if add_prefix_space {
+            prefix_with_space(&mut initial_token);
+        }
+        tokenize_byte_level_bpe(
+            initial_token.as_ref(),
+            &self.vocab,
+            &self.bpe_ranks,
+            &self.cache,
+            &self.pattern_lookahead,
+            &self.pattern_tokenization,
+            self.lower_case,
+        )
+    }
 }
 
 impl MultiThreadedTokenizer<Gpt2Vocab> for Gpt2Tokenizer {}
 
+impl ConfigurableTokenizer<Gpt2Vocab> for Gpt2Tokenizer {
+    fn options_mut(&mut self) -> &mut TokenizerOptions {
+        &mut self.options
+    }
+
+    fn bpe_cache_mut(&mut self) -> Option<&mut BpeCache> {
+        Some(&mut self.cache)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::tokenizer::base_tokenizer::TruncationStrategy;
     use crate::vocab::base_vocab::{swap_key_values, SpecialTokenMap};
     use crate::vocab::Gpt2Vocab;
-    use crate::{Offset, TokenizedInput};
+    use crate::{Mask, Offset, TokenizedInput};
     use std::collections::HashMap;
 
     fn generate_test_vocab() -> Gpt2Vocab {
@@ -248,6 +350,8 @@ mod tests {
             ("o@@".to_owned(), 7),
             ("Ġear".to_owned(), 8),
             ("th".to_owned(), 9),
+            ("Ã".to_owned(), 10),
+            ("©".to_owned(), 11),
         ]
         .iter()
         .cloned()
@@ -299,13 +403,48 @@ mod tests {
         BpePairVocab { values }
     }
 
+    #[test]
+    fn test_gpt2_learn_merges() {
+        //        Given
+        let mut gpt2_tokenizer: Gpt2Tokenizer = Gpt2Tokenizer::from_existing_vocab_and_merges(
+            generate_test_vocab(),
+            generate_test_merges(),
+            false,
+            false,
+        );
+        let corpus = ["the ant", "the ant ant", "<|endoftext|>ant"];
+
+        //        When
+        let new_merges = gpt2_tokenizer.learn_merges(corpus.iter(), 10);
+
+        //        Then
+        assert_eq!(
+            new_merges,
+            vec![
+                ("a".to_owned(), "n".to_owned()),
+                ("an".to_owned(), "t".to_owned()),
+                ("Ġ".to_owned(), "ant".to_owned()),
+            ]
+        );
+        assert_eq!(gpt2_tokenizer.bpe_ranks.rank("Ġ", "ant"), Some(12));
+        assert_eq!(gpt2_tokenizer.vocab.token_to_id("Ġant"), 14);
+        assert!(!gpt2_tokenizer.vocab.special_values.contains_key("Ġant"));
+        assert_eq!(gpt2_tokenizer.tokenize("the ant"), vec!["the", "Ġant"]);
+        assert_eq!(
+            gpt2_tokenizer
+                .encode("the ant", None, 128, &TruncationStrategy::LongestFirst, 0)
+                .token_ids,
+            vec![4, 14]
+        );
+    }
+
     #[test]
     fn test_gpt2_tokenizer() {
         //        Given
         let vocab = generate_test_vocab();
         let merges = generate_test_merges();
         let gpt2_tokenizer: Gpt2Tokenizer =
-            Gpt2Tokenizer::from_existing_vocab_and_merges(vocab, merges, true);
+            Gpt2Tokenizer::from_existing_vocab_and_merges(vocab, merges, true, false);
         let test_tuples = [
             ("the Earth", vec!["the", "Ġear", "th"]),
             ("", vec![]),
@@ -328,13 +467,77 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_gpt2_tokenizer_add_prefix_space() {
+        //        Given
+        let vocab = generate_test_vocab();
+        let merges = generate_test_merges();
+        let mut gpt2_tokenizer: Gpt2Tokenizer =
+            Gpt2Tokenizer::from_existing_vocab_and_merges(vocab, merges, true, false);
+        let test_tuples = [
+            ("the Earth", vec!["Ġthe", "Ġear", "th"]),
+            ("", vec![]),
+            ("   t", vec!["Ġ", "Ġ", "Ġt"]),
+            ("<|endoftext|>the", vec!["<|endoftext|>", "the"]),
+        ];
+
+        //        When & Then
+        assert!(!gpt2_tokenizer.add_prefix_space());
+        for (source_text, expected_result) in test_tuples.iter() {
+            assert_eq!(
+                gpt2_tokenizer.tokenize_with_prefix_space(source_text, true),
+                *expected_result
+            );
+        }
+        gpt2_tokenizer.set_add_prefix_space(true);
+        for (source_text, expected_result) in test_tuples.iter() {
+            assert_eq!(gpt2_tokenizer.tokenize(source_text), *expected_result);
+        }
+        let prefix_space_tokenizer = Gpt2Tokenizer::from_existing_vocab_and_merges(
+            generate_test_vocab(),
+            generate_test_merges(),
+            true,
+            true,
+        );
+        assert!(prefix_space_tokenizer.add_prefix_space());
+        for (source_text, expected_result) in test_tuples.iter() {
+            assert_eq!(
+                prefix_space_tokenizer.tokenize(source_text),
+                *expected_result
+            );
+        }
+        assert_eq!(
+            gpt2_tokenizer.tokenize_with_prefix_space("the Earth", false),
+            vec!["the", "Ġear", "th"]
+        );
+        assert_eq!(
+            gpt2_tokenizer.tokenize_with_offsets("the Earth").offsets,
+            vec![
+                Some(Offset::new(0, 3)),
+                Some(Offset::new(3, 7)),
+                Some(Offset::new(7, 9)),
+            ]
+        );
+        let encoded_input = gpt2_tokenizer
+            .encode_with_prefix_space(
+                "the Earth",
+                None,
+                128,
+                &TruncationStrategy::LongestFirst,
+                0,
+                false,
+            )
+            .unwrap();
+        assert_eq!(encoded_input.token_ids, vec![4, 8, 9]);
+    }
+
     #[test]
     fn test_gpt2_tokenizer_no_lower_casing() {
         //        Given
         let vocab = generate_test_vocab();
         let merges = generate_test_merges();
         let gpt2_tokenizer: Gpt2Tokenizer =
-            Gpt2Tokenizer::from_existing_vocab_and_merges(vocab, merges, false);
+            Gpt2Tokenizer::from_existing_vocab_and_merges(vocab, merges, false, false);
         let test_tuples = [
             ("the Earth", vec!["the", "Ġ", "E", "a", "r", "th"]),
             ("", vec![]),
@@ -362,7 +565,7 @@ mod tests {
         let vocab = generate_test_vocab();
         let merges = generate_test_merges();
         let gpt2_tokenizer: Gpt2Tokenizer =
-            Gpt2Tokenizer::from_existing_vocab_and_merges(vocab, merges, true);
+            Gpt2Tokenizer::from_existing_vocab_and_merges(vocab, merges, true, false);
         let truncation_strategy = TruncationStrategy::LongestFirst;
         let test_tuples = [
             (
@@ -380,6 +583,7 @@ mod tests {
                     ],
                     reference_offsets: vec![vec![0, 1, 2], vec![3, 4, 5, 6], vec![7, 8]],
                     mask: vec![Mask::None, Mask::Begin, Mask::Continuation],
+                    tokens: None,
                 },
             ),
             (
@@ -393,6 +597,7 @@ mod tests {
                     token_offsets: vec![],
                     reference_offsets: vec![],
                     mask: vec![],
+                    tokens: None,
                 },
             ),
             (
@@ -406,6 +611,7 @@ mod tests {
                     token_offsets: vec![],
                     reference_offsets: vec![],
                     mask: vec![],
+                    tokens: None,
                 },
             ),
         ];
@@ -438,7 +644,7 @@ mod tests {
         let vocab = generate_test_vocab();
         let merges = generate_test_merges();
         let gpt2_tokenizer: Gpt2Tokenizer =
-            Gpt2Tokenizer::from_existing_vocab_and_merges(vocab, merges, true);
+            Gpt2Tokenizer::from_existing_vocab_and_merges(vocab, merges, true, false);
         let skip_special_tokens = false;
         let clean_up_tokenization_spaces = false;
         let test_tuples = [(vec![4, 8, 9], "the earth")];
@@ -466,4 +672,32 @@ mod tests {
             expected_results
         );
     }
+
+    #[test]
+    fn test_decode_partial() {
+        //        Given
+        let vocab = generate_test_vocab();
+        let merges = generate_test_merges();
+        let gpt2_tokenizer: Gpt2Tokenizer =
+            Gpt2Tokenizer::from_existing_vocab_and_merges(vocab, merges, true, false);
+
+        //        When
+        let first_slice = gpt2_tokenizer
+            .decode_partial(&[4, 5, 10], &[], true)
+            .unwrap();
+        let second_slice = gpt2_tokenizer
+            .decode_partial(&[11, 6], &first_slice.pending_bytes, true)
+            .unwrap();
+        let full_sequence = gpt2_tokenizer
+            .decode_partial(&[4, 5, 10, 11], &[], false)
+            .unwrap();
+
+        //        Then
+        assert_eq!(first_slice.text, "the ");
+        assert_eq!(first_slice.pending_bytes, vec![0xC3]);
+        assert_eq!(second_slice.text, "é");
+        assert!(second_slice.pending_bytes.is_empty());
+        assert_eq!(full_sequence.text, "the é");
+        assert!(full_sequence.pending_bytes.is_empty());
+    }
 }
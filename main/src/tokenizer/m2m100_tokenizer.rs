@@ -14,10 +14,14 @@ use std::path::Path;
 
 use crate::error::TokenizerError;
 use crate::tokenizer::base_tokenizer::{
-    Mask, Offset, OffsetSize, Token, TokenIdsWithOffsets, TokenIdsWithSpecialTokens, TokenRef,
+    ConfigurableTokenizer, Mask, Offset, OffsetSize, Token, TokenIdsWithOffsets,
+    TokenIdsWithSpecialTokens, TokenRef, TokenizerDescription, TokenizerOptions,
+    UnknownPiecePolicy,
 };
 use crate::tokenizer::tokenization_utils::{
-    clean_text, decompose_nfkc, is_whitespace, lowercase, split_on_language_code,
+    apply_unknown_piece_policy, chevron_language_code_prefix, clean_text, decompose_nfkc,
+    is_whitespace, lowercase, merge_byte_fallback_pieces, split_on_language_code,
+    validate_language_code, validate_language_code_prefix,
 };
 use crate::tokenizer::{MultiThreadedTokenizer, Tokenizer};
 use crate::vocab::{M2M100Vocab, SentencePieceBpeModel, Vocab};
@@ -34,6 +38,9 @@ pub struct M2M100Tokenizer {
     model: SentencePieceBpeModel,
     vocab: M2M100Vocab,
     lower_case: bool,
+    src_lang: Option<String>,
+    tgt_lang: Option<String>,
+    options: TokenizerOptions,
 }
 
 impl M2M100Tokenizer {
@@ -69,6 +76,9 @@ impl M2M100Tokenizer {
             model,
             vocab,
             lower_case,
+            src_lang: None,
+            tgt_lang: None,
+            options: TokenizerOptions::default(),
         })
     }
 
@@ -110,9 +120,43 @@ impl M2M100Tokenizer {
             model,
             vocab,
             lower_case,
+            src_lang: None,
+            tgt_lang: None,
+            options: TokenizerOptions::default(),
         })
     }
 
+    /// Create a new instance of a `M2M100Tokenizer` from the bytes of a JSON vocabulary file and of
+    /// a SentencePiece protobuf file, allowing to embed them in a self-contained binary (e.g.
+    /// using `include_bytes!`).
+    ///
+    /// # Parameters
+    /// - vocab_bytes (`&[u8]`): content of the vocabulary file
+    /// - model_bytes (`&[u8]`): content of the SentencePiece model file
+    /// - lower_case (`bool`): flag indicating if the text should be lower-cased as part of the tokenization
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::M2M100Tokenizer;
+    /// let lower_case = false;
+    /// let vocab_bytes = std::fs::read("path/to/vocab.json").unwrap();
+    /// let model_bytes = std::fs::read("path/to/sentencepiece.bpe.model").unwrap();
+    /// let tokenizer =
+    ///     M2M100Tokenizer::from_protobuf_bytes(&vocab_bytes, &model_bytes, lower_case).unwrap();
+    /// ```
+    pub fn from_protobuf_bytes(
+        vocab_bytes: &[u8],
+        model_bytes: &[u8],
+        lower_case: bool,
+    ) -> Result<M2M100Tokenizer, TokenizerError> {
+        let vocab = M2M100Vocab::from_json_bytes(vocab_bytes)?;
+        let model = SentencePieceBpeModel::from_protobuf_bytes(model_bytes)?;
+        Ok(Self::from_existing_vocab_and_model(
+            vocab, model, lower_case,
+        ))
+    }
+
     /// Create a new instance of a `M2M100Tokenizer` from an existing vocabulary and model
     ///
     /// # Parameters
@@ -140,8 +184,102 @@ impl M2M100Tokenizer {
             model,
             vocab,
             lower_case,
+            src_lang: None,
+            tgt_lang: None,
+            options: TokenizerOptions::default(),
         }
     }
+
+    /// Sets the source language code, added at the start of inputs that are not already prefixed
+    /// by a language code. By default, no source language is set and the language code is expected
+    /// to be provided in the input text.
+    ///
+    /// # Parameters
+    /// - src_lang (`&str`): source language code (e.g. `>>fr.<<`)
+    ///
+    /// # Returns
+    /// `Result<(), TokenizerError>`, failing with a `LanguageCodeNotFound` error listing the supported
+    /// language codes if the code is not a language code of the vocabulary
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::M2M100Tokenizer;
+    /// let lower_case = false;
+    /// let mut tokenizer =
+    ///     M2M100Tokenizer::from_files("path/to/vocab/file", "path/to/model/file", lower_case)
+    ///         .unwrap();
+    /// tokenizer.set_src_lang(">>fr.<<").unwrap();
+    /// ```
+    pub fn set_src_lang(&mut self, src_lang: &str) -> Result<(), TokenizerError> {
+        validate_language_code(src_lang, &self.vocab.language_codes_bytes)?;
+        self.src_lang = Some(src_lang.to_string());
+        Ok(())
+    }
+
+    /// Returns the source language code, if set
+    pub fn src_lang(&self) -> Option<&str> {
+        self.src_lang.as_deref()
+    }
+
+    /// Sets the target language code. The tokenizer does not use the target language when encoding:
+    /// it is provided for downstream generation (e.g. as the first token forced for the decoder).
+    ///
+    /// # Parameters
+    /// - tgt_lang (`&str`): target language code (e.g. `>>de.<<`)
+    ///
+    /// # Returns
+    /// `Result<(), TokenizerError>`, failing with a `LanguageCodeNotFound` error listing the supported
+    /// language codes if the code is not a language code of the vocabulary
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::M2M100Tokenizer;
+    /// let lower_case = false;
+    /// let mut tokenizer =
+    ///     M2M100Tokenizer::from_files("path/to/vocab/file", "path/to/model/file", lower_case)
+    ///         .unwrap();
+    /// tokenizer.set_tgt_lang(">>de.<<").unwrap();
+    /// ```
+    pub fn set_tgt_lang(&mut self, tgt_lang: &str) -> Result<(), TokenizerError> {
+        validate_language_code(tgt_lang, &self.vocab.language_codes_bytes)?;
+        self.tgt_lang = Some(tgt_lang.to_string());
+        Ok(())
+    }
+
+    /// Returns the target language code, if set
+    pub fn tgt_lang(&self) -> Option<&str> {
+        self.tgt_lang.as_deref()
+    }
+
+    /// Validates the language code prefixing a text (e.g. `>>fr.<< Bonjour`), if any.
+    /// This validation is performed by `try_encode` for all inputs.
+    ///
+    /// # Parameters
+    /// - text (`&str`): text to validate
+    ///
+    /// # Returns
+    /// `Result<(), TokenizerError>`, failing with a `LanguageCodeNotFound` error listing the supported
+    /// language codes if the text starts with a language code that is not part of the vocabulary
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::M2M100Tokenizer;
+    /// let lower_case = false;
+    /// let tokenizer =
+    ///     M2M100Tokenizer::from_files("path/to/vocab/file", "path/to/model/file", lower_case)
+    ///         .unwrap();
+    /// assert!(tokenizer.validate_language_prefix(">>xx.<< Hello").is_err());
+    /// ```
+    pub fn validate_language_prefix(&self, text: &str) -> Result<(), TokenizerError> {
+        validate_language_code_prefix(
+            text,
+            &self.vocab.language_codes_bytes,
+            chevron_language_code_prefix,
+        )
+    }
 }
 
 impl Tokenizer<M2M100Vocab> for M2M100Tokenizer {
@@ -152,13 +290,36 @@ impl Tokenizer<M2M100Vocab> for M2M100Tokenizer {
         &mut self.vocab
     }
 
+    fn options(&self) -> &TokenizerOptions {
+        &self.options
+    }
+
+    fn describe(&self) -> TokenizerDescription {
+        TokenizerDescription {
+            lower_case: Some(self.lower_case),
+            ..TokenizerDescription::new("m2m100", &self.vocab)
+        }
+    }
+
+    fn validate_input(&self, text: &str) -> Result<(), TokenizerError> {
+        self.validate_language_prefix(text)
+    }
+
     fn tokenize_to_tokens(&self, text: TokenRef) -> Vec<Token> {
         let tokens = split_on_language_code(text, 7, &self.vocab.language_codes_bytes);
         let (code_token, mut token) = match tokens.len() {
             0 => {
                 return vec![];
             }
-            1 => (None, tokens[0].to_owned()),
+            1 => (
+                self.src_lang.as_ref().map(|src_lang| Token {
+                    text: src_lang.clone(),
+                    offset: Offset::new(text.offset.begin, text.offset.begin),
+                    reference_offsets: vec![],
+                    mask: Mask::Special,
+                }),
+                tokens[0].to_owned(),
+            ),
             _ => (Some(tokens[0].to_owned()), tokens[1].to_owned()),
         };
 
@@ -167,6 +328,15 @@ impl Tokenizer<M2M100Vocab> for M2M100Tokenizer {
         if self.lower_case {
             lowercase(&mut token);
         }
+        let mut output: Vec<Token> = Vec::new();
+        if let Some(code) = code_token {
+            output.push(code);
+        };
+        if token.text.is_empty() {
+            // nothing left to tokenize after the cleaning step
+            return output;
+        }
+
         token.text = token.text.replace(|c: char| is_whitespace(&c), "\u{2581}");
 
         if !token.text.starts_with('\u{2581}') {
@@ -176,16 +346,17 @@ impl Tokenizer<M2M100Vocab> for M2M100Tokenizer {
                 .insert(0, token.reference_offsets[0]);
         };
 
-        let mut output: Vec<Token> = Vec::new();
-        if let Some(code) = code_token {
-            output.push(code);
-        };
         output.extend(self.model.tokenize_to_tokens(token.as_ref()));
 
-        output
+        apply_unknown_piece_policy(output, &self.vocab, self.unknown_piece_policy())
     }
 
     fn convert_tokens_to_string(&self, tokens: Vec<String>) -> String {
+        let tokens = if self.unknown_piece_policy() == UnknownPiecePolicy::ByteFallback {
+            merge_byte_fallback_pieces(tokens)
+        } else {
+            tokens
+        };
         tokens
             .into_iter()
             .map(|v| v.replace('\u{2581}', " "))
@@ -206,25 +377,26 @@ impl Tokenizer<M2M100Vocab> for M2M100Tokenizer {
         let mut offsets: Vec<Option<Offset>> = vec![];
         let mut original_offsets: Vec<Vec<OffsetSize>> = vec![];
         let mut mask: Vec<Mask> = vec![];
+        // The language code (split from the input text or set as the source language) is a special
+        // token without offsets. The first token of inputs without a language code is left as-is.
+        let starts_with_language_code =
+            tokens_ids_with_offsets_1.masks.first() == Some(&Mask::Special);
         special_tokens_mask.extend(vec![0; tokens_ids_with_offsets_1.ids.len()]);
-        if !special_tokens_mask.is_empty() {
+        if starts_with_language_code {
             special_tokens_mask[0] = 1;
         }
         token_segment_ids.extend(vec![0; tokens_ids_with_offsets_1.ids.len()]);
         output.extend(tokens_ids_with_offsets_1.ids);
         offsets.extend(tokens_ids_with_offsets_1.offsets);
-        if !offsets.is_empty() {
+        if starts_with_language_code {
             offsets[0] = None;
         }
         original_offsets.extend(tokens_ids_with_offsets_1.reference_offsets);
-        if !original_offsets.is_empty() {
+        if starts_with_language_code {
             original_offsets[0] = vec![];
         }
 
         mask.extend(tokens_ids_with_offsets_1.masks);
-        if !mask.is_empty() {
-            mask[0] = Mask::Special;
-        }
         if let Some(tokens_ids_with_offsets_2_value) = tokens_ids_with_offsets_2 {
             let length = tokens_ids_with_offsets_2_value.ids.len();
             special_tokens_mask.extend(vec![0; length]);
@@ -254,3 +426,9 @@ impl Tokenizer<M2M100Vocab> for M2M100Tokenizer {
 }
 
 impl MultiThreadedTokenizer<M2M100Vocab> for M2M100Tokenizer {}
+
+impl ConfigurableTokenizer<M2M100Vocab> for M2M100Tokenizer {
+    fn options_mut(&mut self) -> &mut TokenizerOptions {
+        &mut self.options
+    }
+}
@@ -15,12 +15,17 @@ use std::path::Path;
 
 use crate::error::TokenizerError;
 use crate::tokenizer::base_tokenizer::{
-    Mask, Offset, OffsetSize, Token, TokenIdsWithOffsets, TokenIdsWithSpecialTokens, TokenRef,
+    ConfigurableTokenizer, Mask, MosesPreTokenization, Offset, OffsetSize, Token,
+    TokenIdsWithOffsets, TokenIdsWithSpecialTokens, TokenRef, TokenizerDescription,
+    TokenizerOptions, UnigramTokenizer,
 };
+use crate::tokenizer::constants::CLEAN_UP_TOKENIZATION_REPLACEMENTS;
 use crate::tokenizer::tokenization_utils::{
-    clean_text, decompose_nfkc, is_whitespace, lowercase, split_at_regex,
+    apply_unknown_piece_policy, clean_text, decompose_nfkc, is_whitespace, lowercase,
+    merge_byte_fallback_pieces, moses_detokenize, moses_detokenize_pieces, moses_tokenize,
+    replace_in_pieces, split_at_regex,
 };
-use crate::tokenizer::{MultiThreadedTokenizer, Tokenizer};
+use crate::tokenizer::{MultiThreadedTokenizer, Tokenizer, UnknownPiecePolicy};
 use crate::vocab::{MarianVocab, SentencePieceModel, Vocab};
 use regex::Regex;
 
@@ -30,12 +35,16 @@ use regex::Regex;
 /// - text cleaning
 /// - NFKC decomposition
 /// - (optional) lower casing
+/// - (optional) Moses pre-tokenization
 /// - SentencePiece decomposition
 pub struct MarianTokenizer {
     model: SentencePieceModel,
     vocab: MarianVocab,
     pattern_language_code: Regex,
     lower_case: bool,
+    moses_pre_tokenization: MosesPreTokenization,
+    moses_detokenization: Option<String>,
+    options: TokenizerOptions,
 }
 
 impl MarianTokenizer {
@@ -69,6 +78,9 @@ impl MarianTokenizer {
             vocab,
             pattern_language_code,
             lower_case,
+            moses_pre_tokenization: MosesPreTokenization::None,
+            moses_detokenization: None,
+            options: TokenizerOptions::default(),
         })
     }
 
@@ -111,9 +123,43 @@ impl MarianTokenizer {
             vocab,
             pattern_language_code,
             lower_case,
+            moses_pre_tokenization: MosesPreTokenization::None,
+            moses_detokenization: None,
+            options: TokenizerOptions::default(),
         })
     }
 
+    /// Create a new instance of a `MarianTokenizer` from the bytes of a JSON vocabulary file and of
+    /// a SentencePiece protobuf file, allowing to embed them in a self-contained binary (e.g.
+    /// using `include_bytes!`).
+    ///
+    /// # Parameters
+    /// - vocab_bytes (`&[u8]`): content of the vocabulary file
+    /// - model_bytes (`&[u8]`): content of the SentencePiece model file
+    /// - lower_case (`bool`): flag indicating if the text should be lower-cased as part of the tokenization
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::MarianTokenizer;
+    /// let lower_case = false;
+    /// let vocab_bytes = std::fs::read("path/to/vocab.json").unwrap();
+    /// let model_bytes = std::fs::read("path/to/source.spm").unwrap();
+    /// let tokenizer =
+    ///     MarianTokenizer::from_protobuf_bytes(&vocab_bytes, &model_bytes, lower_case).unwrap();
+    /// ```
+    pub fn from_protobuf_bytes(
+        vocab_bytes: &[u8],
+        model_bytes: &[u8],
+        lower_case: bool,
+    ) -> Result<MarianTokenizer, TokenizerError> {
+        let vocab = MarianVocab::from_json_bytes(vocab_bytes)?;
+        let model = SentencePieceModel::from_protobuf_bytes(model_bytes)?;
+        Ok(Self::from_existing_vocab_and_model(
+            vocab, model, lower_case,
+        ))
+    }
+
     /// Create a new instance of a `MarianTokenizer` from an existing vocabulary and model
     ///
     /// # Parameters
@@ -143,8 +189,55 @@ impl MarianTokenizer {
             vocab,
             pattern_language_code,
             lower_case,
+            moses_pre_tokenization: MosesPreTokenization::None,
+            moses_detokenization: None,
+            options: TokenizerOptions::default(),
         }
     }
+
+    /// Sets the Moses pre-tokenization mode of the tokenizer. When enabled, the text is split on
+    /// punctuation following the Moses tokenizer rules before the SentencePiece decomposition.
+    /// Moses pre-tokenization is disabled by default.
+    ///
+    /// # Parameters
+    /// - moses_pre_tokenization (`MosesPreTokenization`): Moses pre-tokenization mode
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{MarianTokenizer, MosesPreTokenization, Tokenizer};
+    /// let lower_case = false;
+    /// let mut tokenizer =
+    ///     MarianTokenizer::from_files("path/to/vocab/file", "path/to/model/file", lower_case)
+    ///         .unwrap();
+    /// tokenizer.set_moses_pre_tokenization(MosesPreTokenization::AggressiveDashSplits);
+    /// ```
+    pub fn set_moses_pre_tokenization(&mut self, moses_pre_tokenization: MosesPreTokenization) {
+        self.moses_pre_tokenization = moses_pre_tokenization;
+    }
+
+    /// Sets the language of the Moses detokenization of the tokenizer. When set, the clean-up of
+    /// decoded texts (`clean_up_tokenization_spaces` when decoding) follows the Moses detokenizer
+    /// rules for this language (see `moses_detokenize`) instead of the default English-centric
+    /// clean-up. Moses detokenization is disabled by default.
+    ///
+    /// # Parameters
+    /// - language (`Option<&str>`): ISO 639-1 code of the target language, `None` to use the default clean-up
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{MarianTokenizer, Tokenizer};
+    /// let lower_case = false;
+    /// let mut tokenizer =
+    ///     MarianTokenizer::from_files("path/to/vocab/file", "path/to/model/file", lower_case)
+    ///         .unwrap();
+    /// tokenizer.set_moses_detokenization(Some("fr"));
+    /// let decoded = tokenizer.decode(&[0, 1, 2], true, true);
+    /// ```
+    pub fn set_moses_detokenization(&mut self, language: Option<&str>) {
+        self.moses_detokenization = language.map(str::to_owned);
+    }
 }
 
 impl Tokenizer<MarianVocab> for MarianTokenizer {
@@ -155,6 +248,17 @@ impl Tokenizer<MarianVocab> for MarianTokenizer {
         &mut self.vocab
     }
 
+    fn options(&self) -> &TokenizerOptions {
+        &self.options
+    }
+
+    fn describe(&self) -> TokenizerDescription {
+        TokenizerDescription {
+            lower_case: Some(self.lower_case),
+            ..TokenizerDescription::new("marian", &self.vocab)
+        }
+    }
+
     fn tokenize_to_tokens(&self, text: TokenRef) -> Vec<Token> {
         let tokens = split_at_regex(text, &self.pattern_language_code);
         let (code_token, mut token) = match tokens.len() {
@@ -181,6 +285,28 @@ impl Tokenizer<MarianVocab> for MarianTokenizer {
         if self.lower_case {
             lowercase(&mut token);
         }
+        if self.moses_pre_tokenization != MosesPreTokenization::None {
+            let sub_tokens = moses_tokenize(
+                token.as_ref(),
+                self.moses_pre_tokenization == MosesPreTokenization::AggressiveDashSplits,
+            );
+            let mut joined_token = Token::new("".to_string());
+            joined_token.reference_offsets.clear();
+            for sub_token in sub_tokens {
+                if !joined_token.text.is_empty() {
+                    joined_token.text.push(' ');
+                    joined_token
+                        .reference_offsets
+                        .push(sub_token.reference_offsets[0]);
+                }
+                joined_token.text.push_str(&sub_token.text);
+                joined_token
+                    .reference_offsets
+                    .extend(sub_token.reference_offsets);
+            }
+            joined_token.offset = token.offset;
+            token = joined_token;
+        }
         token.text = token.text.replace(|c: char| is_whitespace(&c), "\u{2581}");
         if !token.text.starts_with('\u{2581}') {
             token.text.insert(0, '\u{2581}');
@@ -221,10 +347,15 @@ impl Tokenizer<MarianVocab> for MarianTokenizer {
             is_prev_unknown = node.index == 0;
         }
         self.model.populate_masks(output.as_mut_slice(), '\u{2581}');
-        output
+        apply_unknown_piece_policy(output, &self.vocab, self.unknown_piece_policy())
     }
 
     fn convert_tokens_to_string(&self, tokens: Vec<String>) -> String {
+        let tokens = if self.unknown_piece_policy() == UnknownPiecePolicy::ByteFallback {
+            merge_byte_fallback_pieces(tokens)
+        } else {
+            tokens
+        };
         tokens
             .into_iter()
             .map(|v| v.replace('\u{2581}', " "))
@@ -232,6 +363,24 @@ impl Tokenizer<MarianVocab> for MarianTokenizer {
             .join("")
     }
 
+    fn clean_up_tokenization(&self, input_string: String) -> String {
+        match &self.moses_detokenization {
+            Some(language) => moses_detokenize(&input_string, language),
+            None => CLEAN_UP_TOKENIZATION_REPLACEMENTS
+                .iter()
+                .fold(input_string, |text, (pattern, replacement)| {
+                    text.replace(pattern, replacement)
+                }),
+        }
+    }
+
+    fn clean_up_tokenization_pieces(&self, pieces: Vec<String>) -> Vec<String> {
+        match &self.moses_detokenization {
+            Some(language) => moses_detokenize_pieces(pieces, language),
+            None => replace_in_pieces(pieces, &CLEAN_UP_TOKENIZATION_REPLACEMENTS),
+        }
+    }
+
     fn build_input_with_special_tokens(
         &self,
         tokens_ids_with_offsets_1: TokenIdsWithOffsets,
@@ -278,3 +427,15 @@ impl Tokenizer<MarianVocab> for MarianTokenizer {
 }
 
 impl MultiThreadedTokenizer<MarianVocab> for MarianTokenizer {}
+
+impl ConfigurableTokenizer<MarianVocab> for MarianTokenizer {
+    fn options_mut(&mut self) -> &mut TokenizerOptions {
+        &mut self.options
+    }
+}
+
+impl UnigramTokenizer<MarianVocab> for MarianTokenizer {
+    fn unigram_model(&self) -> &SentencePieceModel {
+        &self.model
+    }
+}
@@ -14,12 +14,15 @@ use std::path::Path;
 
 use crate::error::TokenizerError;
 use crate::tokenizer::base_tokenizer::{
-    Mask, Offset, OffsetSize, Token, TokenIdsWithOffsets, TokenIdsWithSpecialTokens, TokenRef,
+    ConfigurableTokenizer, Mask, Offset, OffsetSize, Token, TokenIdsWithOffsets,
+    TokenIdsWithSpecialTokens, TokenRef, TokenizerDescription, TokenizerOptions, UnigramTokenizer,
 };
 use crate::tokenizer::tokenization_utils::{
-    clean_text, decompose_nfkc, is_whitespace, lowercase, split_on_language_code,
+    apply_unknown_piece_policy, chevron_language_code_prefix, clean_text, decompose_nfkc,
+    is_whitespace, lowercase, merge_byte_fallback_pieces, split_on_language_code,
+    validate_language_code, validate_language_code_prefix,
 };
-use crate::tokenizer::{MultiThreadedTokenizer, Tokenizer};
+use crate::tokenizer::{MultiThreadedTokenizer, Tokenizer, UnknownPiecePolicy};
 use crate::vocab::{MBart50Vocab, SentencePieceModel, Vocab};
 
 /// # MBart50 tokenizer
@@ -34,6 +37,9 @@ pub struct MBart50Tokenizer {
     model: SentencePieceModel,
     vocab: MBart50Vocab,
     lower_case: bool,
+    src_lang: Option<String>,
+    tgt_lang: Option<String>,
+    options: TokenizerOptions,
 }
 
 impl MBart50Tokenizer {
@@ -61,6 +67,9 @@ impl MBart50Tokenizer {
             model,
             vocab,
             lower_case,
+            src_lang: None,
+            tgt_lang: None,
+            options: TokenizerOptions::default(),
         })
     }
 
@@ -96,9 +105,38 @@ impl MBart50Tokenizer {
             model,
             vocab,
             lower_case,
+            src_lang: None,
+            tgt_lang: None,
+            options: TokenizerOptions::default(),
         })
     }
 
+    /// Create a new instance of a `MBart50Tokenizer` from the bytes of a SentencePiece protobuf
+    /// file, allowing to embed the model in a self-contained binary (e.g. using `include_bytes!`).
+    ///
+    /// # Parameters
+    /// - bytes (`&[u8]`): content of the SentencePiece model file
+    /// - lower_case (`bool`): flag indicating if the text should be lower-cased as part of the tokenization
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::MBart50Tokenizer;
+    /// let lower_case = false;
+    /// let bytes = std::fs::read("path/to/sentencepiece.bpe.model").unwrap();
+    /// let tokenizer = MBart50Tokenizer::from_protobuf_bytes(&bytes, lower_case).unwrap();
+    /// ```
+    pub fn from_protobuf_bytes(
+        bytes: &[u8],
+        lower_case: bool,
+    ) -> Result<MBart50Tokenizer, TokenizerError> {
+        let model = SentencePieceModel::from_protobuf_bytes(bytes)?;
+        let vocab = MBart50Vocab::from_protobuf_bytes(bytes)?;
+        Ok(Self::from_existing_vocab_and_model(
+            vocab, model, lower_case,
+        ))
+    }
+
     /// Create a new instance of a `MBart50Tokenizer` from an existing vocabulary and model
     ///
     /// # Parameters
@@ -126,8 +164,96 @@ impl MBart50Tokenizer {
             model,
             vocab,
             lower_case,
+            src_lang: None,
+            tgt_lang: None,
+            options: TokenizerOptions::default(),
         }
     }
+
+    /// Sets the source language code, added at the start of inputs that are not already prefixed
+    /// by a language code. By default, no source language is set and the language code is expected
+    /// to be provided in the input text.
+    ///
+    /// # Parameters
+    /// - src_lang (`&str`): source language code (e.g. `>>fr<<`)
+    ///
+    /// # Returns
+    /// `Result<(), TokenizerError>`, failing with a `LanguageCodeNotFound` error listing the supported
+    /// language codes if the code is not a language code of the vocabulary
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::MBart50Tokenizer;
+    /// let lower_case = false;
+    /// let mut tokenizer = MBart50Tokenizer::from_file("path/to/model/file", lower_case).unwrap();
+    /// tokenizer.set_src_lang(">>fr<<").unwrap();
+    /// ```
+    pub fn set_src_lang(&mut self, src_lang: &str) -> Result<(), TokenizerError> {
+        validate_language_code(src_lang, &self.vocab.language_codes_bytes)?;
+        self.src_lang = Some(src_lang.to_string());
+        Ok(())
+    }
+
+    /// Returns the source language code, if set
+    pub fn src_lang(&self) -> Option<&str> {
+        self.src_lang.as_deref()
+    }
+
+    /// Sets the target language code. The tokenizer does not use the target language when encoding:
+    /// it is provided for downstream generation (e.g. as the first token forced for the decoder).
+    ///
+    /// # Parameters
+    /// - tgt_lang (`&str`): target language code (e.g. `>>de<<`)
+    ///
+    /// # Returns
+    /// `Result<(), TokenizerError>`, failing with a `LanguageCodeNotFound` error listing the supported
+    /// language codes if the code is not a language code of the vocabulary
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::MBart50Tokenizer;
+    /// let lower_case = false;
+    /// let mut tokenizer = MBart50Tokenizer::from_file("path/to/model/file", lower_case).unwrap();
+    /// tokenizer.set_tgt_lang(">>de<<").unwrap();
+    /// ```
+    pub fn set_tgt_lang(&mut self, tgt_lang: &str) -> Result<(), TokenizerError> {
+        validate_language_code(tgt_lang, &self.vocab.language_codes_bytes)?;
+        self.tgt_lang = Some(tgt_lang.to_string());
+        Ok(())
+    }
+
+    /// Returns the target language code, if set
+    pub fn tgt_lang(&self) -> Option<&str> {
+        self.tgt_lang.as_deref()
+    }
+
+    /// Validates the language code prefixing a text (e.g. `>>fr<< Bonjour`), if any.
+    /// This validation is performed by `try_encode` for all inputs.
+    ///
+    /// # Parameters
+    /// - text (`&str`): text to validate
+    ///
+    /// # Returns
+    /// `Result<(), TokenizerError>`, failing with a `LanguageCodeNotFound` error listing the supported
+    /// language codes if the text starts with a language code that is not part of the vocabulary
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::MBart50Tokenizer;
+    /// let lower_case = false;
+    /// let tokenizer = MBart50Tokenizer::from_file("path/to/model/file", lower_case).unwrap();
+    /// assert!(tokenizer.validate_language_prefix(">>xx<< Hello").is_err());
+    /// ```
+    pub fn validate_language_prefix(&self, text: &str) -> Result<(), TokenizerError> {
+        validate_language_code_prefix(
+            text,
+            &self.vocab.language_codes_bytes,
+            chevron_language_code_prefix,
+        )
+    }
 }
 
 impl Tokenizer<MBart50Vocab> for MBart50Tokenizer {
@@ -138,13 +264,36 @@ impl Tokenizer<MBart50Vocab> for MBart50Tokenizer {
         &mut self.vocab
     }
 
+    fn options(&self) -> &TokenizerOptions {
+        &self.options
+    }
+
+    fn describe(&self) -> TokenizerDescription {
+        TokenizerDescription {
+            lower_case: Some(self.lower_case),
+            ..TokenizerDescription::new("mbart50", &self.vocab)
+        }
+    }
+
+    fn validate_input(&self, text: &str) -> Result<(), TokenizerError> {
+        self.validate_language_prefix(text)
+    }
+
     fn tokenize_to_tokens(&self, text: TokenRef) -> Vec<Token> {
         let tokens = split_on_language_code(text, 6, &self.vocab.language_codes_bytes);
         let (code_token, mut token) = match tokens.len() {
             0 => {
                 return vec![];
             }
-            1 => (None, tokens[0].to_owned()),
+            1 => (
+                self.src_lang.as_ref().map(|src_lang| Token {
+                    text: src_lang.clone(),
+                    offset: Offset::new(text.offset.begin, text.offset.begin),
+                    reference_offsets: vec![],
+                    mask: Mask::Special,
+                }),
+                tokens[0].to_owned(),
+            ),
             _ => (Some(tokens[0].to_owned()), tokens[1].to_owned()),
         };
 
@@ -153,6 +302,10 @@ impl Tokenizer<MBart50Vocab> for MBart50Tokenizer {
         if self.lower_case {
             lowercase(&mut token);
         }
+        if token.text.is_empty() {
+            // nothing left to tokenize after the cleaning step
+            return code_token.into_iter().collect();
+        }
         token.text = token.text.replace(|c: char| is_whitespace(&c), "\u{2581}");
         if !token.text.starts_with('\u{2581}') {
             token.text.insert(0, '\u{2581}');
@@ -168,10 +321,15 @@ impl Tokenizer<MBart50Vocab> for MBart50Tokenizer {
             output.push(code);
         };
         output.extend(self.model.parse_nodes_to_tokens(decoded));
-        output
+        apply_unknown_piece_policy(output, &self.vocab, self.unknown_piece_policy())
     }
 
     fn convert_tokens_to_string(&self, tokens: Vec<String>) -> String {
+        let tokens = if self.unknown_piece_policy() == UnknownPiecePolicy::ByteFallback {
+            merge_byte_fallback_pieces(tokens)
+        } else {
+            tokens
+        };
         tokens
             .into_iter()
             .map(|v| v.replace('\u{2581}', " "))
@@ -192,8 +350,12 @@ impl Tokenizer<MBart50Vocab> for MBart50Tokenizer {
         let mut offsets: Vec<Option<Offset>> = vec![];
         let mut original_offsets: Vec<Vec<OffsetSize>> = vec![];
         let mut mask: Vec<Mask> = vec![];
+        // The language code (split from the input text or set as the source language) is a special
+        // token without offsets. The first token of inputs without a language code is left as-is.
+        let starts_with_language_code =
+            tokens_ids_with_offsets_1.masks.first() == Some(&Mask::Special);
         special_tokens_mask.extend(vec![0; tokens_ids_with_offsets_1.ids.len()]);
-        if !special_tokens_mask.is_empty() {
+        if starts_with_language_code {
             special_tokens_mask[0] = 1;
         }
         special_tokens_mask.push(1);
@@ -201,19 +363,16 @@ impl Tokenizer<MBart50Vocab> for MBart50Tokenizer {
         output.extend(tokens_ids_with_offsets_1.ids);
         output.push(self.vocab.token_to_id(self.vocab.get_sep_value()));
         offsets.extend(tokens_ids_with_offsets_1.offsets);
-        if !offsets.is_empty() {
+        if starts_with_language_code {
             offsets[0] = None;
         }
         offsets.push(None);
         original_offsets.extend(tokens_ids_with_offsets_1.reference_offsets);
-        if !original_offsets.is_empty() {
+        if starts_with_language_code {
             original_offsets[0] = vec![];
         }
         original_offsets.push(vec![]);
         mask.extend(tokens_ids_with_offsets_1.masks);
-        if !mask.is_empty() {
-            mask[0] = Mask::Special;
-        }
         mask.push(Mask::Special);
         if let Some(tokens_ids_with_offsets_2_value) = tokens_ids_with_offsets_2 {
             let length = tokens_ids_with_offsets_2_value.ids.len();
@@ -241,3 +400,15 @@ impl Tokenizer<MBart50Vocab> for MBart50Tokenizer {
 }
 
 impl MultiThreadedTokenizer<MBart50Vocab> for MBart50Tokenizer {}
+
+impl ConfigurableTokenizer<MBart50Vocab> for MBart50Tokenizer {
+    fn options_mut(&mut self) -> &mut TokenizerOptions {
+        &mut self.options
+    }
+}
+
+impl UnigramTokenizer<MBart50Vocab> for MBart50Tokenizer {
+    fn unigram_model(&self) -> &SentencePieceModel {
+        &self.model
+    }
+}
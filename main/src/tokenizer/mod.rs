@@ -34,10 +34,20 @@
 //!     - DeBERTa (v2)
 //!
 //! All tokenizers are `Send`, `Sync` and support multi-threaded tokenization and encoding.
+//!
+//! ## Concurrency model
+//! Tokenizers are immutable once configured: the vocabularies and models are only read by the
+//! `&self` tokenization, encoding and decoding methods, while all setters take `&mut self`. A
+//! tokenizer can therefore be shared across threads (for example behind an `Arc`) without any
+//! locking. The only interior mutability is the cache of BPE outputs used by the BPE tokenizers
+//! (GPT, GPT2, RoBERTa, CTRL, DeBERTa and Reformer), whose operations never block: a cache shard in
+//! use by another thread results in a cache miss (or a skipped insertion) rather than a wait.
+//! These guarantees are checked at compile time for all tokenizers.
 
 mod albert_tokenizer;
 pub(crate) mod base_tokenizer;
 mod bert_tokenizer;
+mod chat_template;
 mod constants;
 mod ctrl_tokenizer;
 mod deberta_tokenizer;
@@ -49,20 +59,33 @@ mod marian_tokenizer;
 mod mbart50_tokenizer;
 mod nllb_tokenizer;
 mod openai_gpt_tokenizer;
+mod partial_decoding;
 mod pegasus_tokenizer;
 mod prophetnet_tokenizer;
 mod reformer_tokenizer;
 mod roberta_tokenizer;
 mod sentence_piece_bpe_tokenizer;
 mod sentence_piece_tokenizer;
+mod stopword_filter;
 mod t5_tokenizer;
+mod tokenization_stats;
 pub(crate) mod tokenization_utils;
+#[rustfmt::skip]
+mod unicode_tables;
+mod vocab_trie;
 mod xlm_roberta_tokenizer;
 mod xlnet_tokenizer;
 
 pub use albert_tokenizer::AlbertTokenizer;
-pub use base_tokenizer::{BaseTokenizer, MultiThreadedTokenizer, Tokenizer, TruncationStrategy};
+pub use base_tokenizer::{
+    BaseTokenizer, BidiControlHandling, ConfigurableTokenizer, DigitNormalization,
+    EmojiSequenceHandling, LowerCaseHandling, MosesPreTokenization, MultiThreadedTokenizer,
+    OffsetUnit, PrefixSpaceTokenizer, TokenFilter, TokenizationStage, Tokenizer,
+    TokenizerDescription, TokenizerOptions, TruncationStrategy, UnigramTokenizer, UnknownIdPolicy,
+    UnknownPiecePolicy,
+};
 pub use bert_tokenizer::BertTokenizer;
+pub use chat_template::{ChatMessage, ChatRole, ChatTemplate};
 pub use ctrl_tokenizer::CtrlTokenizer;
 pub use deberta_tokenizer::DeBERTaTokenizer;
 pub use deberta_v2_tokenizer::DeBERTaV2Tokenizer;
@@ -73,13 +96,45 @@ pub use marian_tokenizer::MarianTokenizer;
 pub use mbart50_tokenizer::MBart50Tokenizer;
 pub use nllb_tokenizer::NLLBTokenizer;
 pub use openai_gpt_tokenizer::OpenAiGptTokenizer;
-pub use pegasus_tokenizer::PegasusTokenizer;
+pub use partial_decoding::PartialDecoding;
+pub use pegasus_tokenizer::{GapSentenceExample, PegasusTokenizer};
 pub use prophetnet_tokenizer::ProphetNetTokenizer;
 pub use reformer_tokenizer::ReformerTokenizer;
 pub use roberta_tokenizer::RobertaTokenizer;
 pub use sentence_piece_bpe_tokenizer::SentencePieceBpeTokenizer;
 pub use sentence_piece_tokenizer::SentencePieceTokenizer;
+pub use stopword_filter::{StopwordFilter, StopwordHandling};
 pub use t5_tokenizer::T5Tokenizer;
-pub use tokenization_utils::truncate_sequences;
+pub use tokenization_stats::{TokenizationStats, TokenizationStatsCollector};
+pub use tokenization_utils::{
+    is_emoji_sequence, moses_detokenize, moses_detokenize_pieces, truncate_sequences, BpeCache,
+    ContinuationMarker, PatternPreTokenizer, TextPattern,
+};
+pub use unicode_tables::UNICODE_VERSION;
+pub use vocab_trie::VocabTrie;
 pub use xlm_roberta_tokenizer::XLMRobertaTokenizer;
 pub use xlnet_tokenizer::XLNetTokenizer;
+
+static_assertions::assert_impl_all!(AlbertTokenizer: Send, Sync);
+static_assertions::assert_impl_all!(BaseTokenizer<crate::vocab::BaseVocab>: Send, Sync);
+static_assertions::assert_impl_all!(BertTokenizer: Send, Sync);
+static_assertions::assert_impl_all!(CtrlTokenizer: Send, Sync);
+static_assertions::assert_impl_all!(DeBERTaTokenizer: Send, Sync);
+static_assertions::assert_impl_all!(DeBERTaV2Tokenizer: Send, Sync);
+static_assertions::assert_impl_all!(FNetTokenizer: Send, Sync);
+static_assertions::assert_impl_all!(Gpt2Tokenizer: Send, Sync);
+static_assertions::assert_impl_all!(M2M100Tokenizer: Send, Sync);
+static_assertions::assert_impl_all!(MarianTokenizer: Send, Sync);
+static_assertions::assert_impl_all!(MBart50Tokenizer: Send, Sync);
+static_assertions::assert_impl_all!(NLLBTokenizer: Send, Sync);
+static_assertions::assert_impl_all!(OpenAiGptTokenizer: Send, Sync);
+static_assertions::assert_impl_all!(PegasusTokenizer: Send, Sync);
+static_assertions::assert_impl_all!(ProphetNetTokenizer: Send, Sync);
+static_assertions::assert_impl_all!(ReformerTokenizer: Send, Sync);
+static_assertions::assert_impl_all!(RobertaTokenizer: Send, Sync);
+static_assertions::assert_impl_all!(SentencePieceBpeTokenizer: Send, Sync);
+static_assertions::assert_impl_all!(SentencePieceTokenizer: Send, Sync);
+static_assertions::assert_impl_all!(T5Tokenizer: Send, Sync);
+static_assertions::assert_impl_all!(XLMRobertaTokenizer: Send, Sync);
+static_assertions::assert_impl_all!(XLNetTokenizer: Send, Sync);
+static_assertions::assert_impl_all!(tokenization_utils::BpeCache: Send, Sync);
@@ -12,7 +12,6 @@
 
 use std::path::Path;
 
-use crate::vocab::EXTENDED_FAIRSEQ_LANGUAGE_CODES;
 use crate::{
     error::TokenizerError,
     vocab::{NLLBVocab, SentencePieceBpeModel, Vocab},
@@ -20,14 +19,21 @@ use crate::{
 };
 
 use super::{
-    tokenization_utils::{clean_text, decompose_nfkc, is_whitespace, split_on_language_code},
-    MultiThreadedTokenizer, Tokenizer,
+    tokenization_utils::{
+        apply_unknown_piece_policy, clean_text, decompose_nfkc, is_whitespace,
+        merge_byte_fallback_pieces, script_language_code_prefix, split_on_language_code,
+        validate_language_code, validate_language_code_prefix,
+    },
+    ConfigurableTokenizer, MultiThreadedTokenizer, Tokenizer, TokenizerDescription,
+    TokenizerOptions, UnknownPiecePolicy,
 };
 
 pub struct NLLBTokenizer {
     model: SentencePieceBpeModel,
     vocab: NLLBVocab,
     src_lang: String,
+    tgt_lang: Option<String>,
+    options: TokenizerOptions,
 }
 
 impl NLLBTokenizer {
@@ -44,6 +50,8 @@ impl NLLBTokenizer {
             model,
             vocab,
             src_lang,
+            tgt_lang: None,
+            options: TokenizerOptions::default(),
         })
     }
 
@@ -58,18 +66,125 @@ impl NLLBTokenizer {
             model,
             vocab,
             src_lang,
+            tgt_lang: None,
+            options: TokenizerOptions::default(),
         })
     }
 
+    /// Create a new instance of a `NLLBTokenizer` from the bytes of a `tokenizer.json` file and
+    /// of a SentencePiece protobuf file, allowing to embed them in a self-contained binary (e.g.
+    /// using `include_bytes!`).
+    ///
+    /// # Parameters
+    /// - vocab_bytes (`&[u8]`): content of the vocabulary file
+    /// - model_bytes (`&[u8]`): content of the SentencePiece model file
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::NLLBTokenizer;
+    /// let vocab_bytes = std::fs::read("path/to/tokenizer.json").unwrap();
+    /// let model_bytes = std::fs::read("path/to/sentencepiece.bpe.model").unwrap();
+    /// let tokenizer =
+    ///     NLLBTokenizer::from_protobuf_bytes(&vocab_bytes, &model_bytes).unwrap();
+    /// ```
+    pub fn from_protobuf_bytes(
+        vocab_bytes: &[u8],
+        model_bytes: &[u8],
+    ) -> Result<NLLBTokenizer, TokenizerError> {
+        let vocab = NLLBVocab::from_json_bytes(vocab_bytes)?;
+        let model = SentencePieceBpeModel::from_protobuf_bytes(model_bytes)?;
+        let src_lang = String::from("eng_Latn");
+        Ok(Self {
+            model,
+            vocab,
+            src_lang,
+            tgt_lang: None,
+            options: TokenizerOptions::default(),
+        })
+    }
+
+    /// Sets the source language code, appended to the encoded inputs (`eng_Latn` by default).
+    ///
+    /// # Parameters
+    /// - src_lang (`&str`): source language code (e.g. `fra_Latn`)
+    ///
+    /// # Returns
+    /// `Result<(), TokenizerError>`, failing with a `LanguageCodeNotFound` error listing the supported
+    /// language codes if the code is not a language code of the vocabulary
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::NLLBTokenizer;
+    /// let mut tokenizer =
+    ///     NLLBTokenizer::from_files("path/to/vocab/file", "path/to/model/file").unwrap();
+    /// tokenizer.set_src_lang("fra_Latn").unwrap();
+    /// ```
     pub fn set_src_lang(&mut self, src_lang: &str) -> Result<(), TokenizerError> {
-        if !EXTENDED_FAIRSEQ_LANGUAGE_CODES.contains(&src_lang) {
-            Err(TokenizerError::TokenNotFound(format!(
-                "{src_lang} is not a valid language tag."
-            )))
-        } else {
-            self.src_lang = src_lang.to_string();
-            Ok(())
-        }
+        validate_language_code(src_lang, &self.vocab.language_codes_bytes)?;
+        self.src_lang = src_lang.to_string();
+        Ok(())
+    }
+
+    /// Returns the source language code appended to the encoded inputs
+    pub fn src_lang(&self) -> &str {
+        &self.src_lang
+    }
+
+    /// Sets the target language code. The tokenizer does not use the target language when encoding:
+    /// it is provided for downstream generation (e.g. as the first token forced for the decoder).
+    ///
+    /// # Parameters
+    /// - tgt_lang (`&str`): target language code (e.g. `deu_Latn`)
+    ///
+    /// # Returns
+    /// `Result<(), TokenizerError>`, failing with a `LanguageCodeNotFound` error listing the supported
+    /// language codes if the code is not a language code of the vocabulary
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::NLLBTokenizer;
+    /// let mut tokenizer =
+    ///     NLLBTokenizer::from_files("path/to/vocab/file", "path/to/model/file").unwrap();
+    /// tokenizer.set_tgt_lang("deu_Latn").unwrap();
+    /// ```
+    pub fn set_tgt_lang(&mut self, tgt_lang: &str) -> Result<(), TokenizerError> {
+        validate_language_code(tgt_lang, &self.vocab.language_codes_bytes)?;
+        self.tgt_lang = Some(tgt_lang.to_string());
+        Ok(())
+    }
+
+    /// Returns the target language code, if set
+    pub fn tgt_lang(&self) -> Option<&str> {
+        self.tgt_lang.as_deref()
+    }
+
+    /// Validates the language code prefixing a text (e.g. `fra_Latn Bonjour`), if any.
+    /// This validation is performed by `try_encode` for all inputs.
+    ///
+    /// # Parameters
+    /// - text (`&str`): text to validate
+    ///
+    /// # Returns
+    /// `Result<(), TokenizerError>`, failing with a `LanguageCodeNotFound` error listing the supported
+    /// language codes if the text starts with a language code that is not part of the vocabulary
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::NLLBTokenizer;
+    /// let tokenizer =
+    ///     NLLBTokenizer::from_files("path/to/vocab/file", "path/to/model/file").unwrap();
+    /// assert!(tokenizer.validate_language_prefix("xyz_Abcd Hello").is_err());
+    /// ```
+    pub fn validate_language_prefix(&self, text: &str) -> Result<(), TokenizerError> {
+        validate_language_code_prefix(
+            text,
+            &self.vocab.language_codes_bytes,
+            script_language_code_prefix,
+        )
     }
 }
 
@@ -81,6 +196,18 @@ impl Tokenizer<NLLBVocab> for NLLBTokenizer {
         &mut self.vocab
     }
 
+    fn options(&self) -> &TokenizerOptions {
+        &self.options
+    }
+
+    fn describe(&self) -> TokenizerDescription {
+        TokenizerDescription::new("nllb", &self.vocab)
+    }
+
+    fn validate_input(&self, text: &str) -> Result<(), TokenizerError> {
+        self.validate_language_prefix(text)
+    }
+
     fn tokenize_to_tokens(&self, text: crate::TokenRef) -> Vec<crate::Token> {
         let tokens = split_on_language_code(text, 8, &self.vocab.language_codes_bytes);
         let (code_token, mut token) = match tokens.len() {
@@ -94,6 +221,15 @@ impl Tokenizer<NLLBVocab> for NLLBTokenizer {
         clean_text(&mut token, true);
         decompose_nfkc(&mut token);
 
+        let mut output: Vec<Token> = Vec::new();
+        if let Some(code) = code_token {
+            output.push(code);
+        };
+        if token.text.is_empty() {
+            // nothing left to tokenize after the cleaning step
+            return output;
+        }
+
         token.text = token.text.replace(|c: char| is_whitespace(&c), "\u{2581}");
 
         if !token.text.starts_with('\u{2581}') {
@@ -103,16 +239,17 @@ impl Tokenizer<NLLBVocab> for NLLBTokenizer {
                 .insert(0, token.reference_offsets[0]);
         };
 
-        let mut output: Vec<Token> = Vec::new();
-        if let Some(code) = code_token {
-            output.push(code);
-        };
         output.extend(self.model.tokenize_to_tokens(token.as_ref()));
 
-        output
+        apply_unknown_piece_policy(output, &self.vocab, self.unknown_piece_policy())
     }
 
     fn convert_tokens_to_string(&self, tokens: Vec<String>) -> String {
+        let tokens = if self.unknown_piece_policy() == UnknownPiecePolicy::ByteFallback {
+            merge_byte_fallback_pieces(tokens)
+        } else {
+            tokens
+        };
         tokens
             .into_iter()
             .map(|v| v.replace('\u{2581}', " "))
@@ -174,3 +311,9 @@ impl Tokenizer<NLLBVocab> for NLLBTokenizer {
 }
 
 impl MultiThreadedTokenizer<NLLBVocab> for NLLBTokenizer {}
+
+impl ConfigurableTokenizer<NLLBVocab> for NLLBTokenizer {
+    fn options_mut(&mut self) -> &mut TokenizerOptions {
+        &mut self.options
+    }
+}
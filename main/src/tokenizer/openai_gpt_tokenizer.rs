@@ -13,14 +13,17 @@
 // limitations under the License.
 
 use crate::error::TokenizerError;
-use crate::tokenizer::tokenization_utils::{openai_gpt_bpe, split_on_bpe_pairs, BpeCache};
-use crate::tokenizer::{BaseTokenizer, MultiThreadedTokenizer, Tokenizer};
+use crate::tokenizer::tokenization_utils::{
+    openai_gpt_bpe, split_on_bpe_pairs, split_on_special_tokens, BpeCache,
+};
+use crate::tokenizer::{
+    BaseTokenizer, ConfigurableTokenizer, MultiThreadedTokenizer, Tokenizer, TokenizerDescription,
+    TokenizerOptions,
+};
 use crate::vocab::bpe_vocab::BpePairVocab;
 use crate::vocab::{OpenAiGptVocab, Vocab};
 use crate::{Mask, Token, TokenRef};
-use std::collections::HashMap;
 use std::path::Path;
-use std::sync::RwLock;
 
 /// # GPT tokenizer
 /// GPT tokenizer performing:
@@ -31,6 +34,8 @@ pub struct OpenAiGptTokenizer {
     base_tokenizer: BaseTokenizer<OpenAiGptVocab>,
     bpe_ranks: BpePairVocab,
     cache: BpeCache,
+    normalize_special_tokens: bool,
+    options: TokenizerOptions,
 }
 
 impl OpenAiGptTokenizer {
@@ -59,12 +64,14 @@ impl OpenAiGptTokenizer {
         let vocab = OpenAiGptVocab::from_file(vocab_path)?;
         let base_tokenizer = BaseTokenizer::from_existing_vocab(vocab.clone(), lower_case, true);
         let bpe_ranks = BpePairVocab::from_file(merges_path)?;
-        let cache = RwLock::new(HashMap::new());
+        let cache = BpeCache::new();
         Ok(OpenAiGptTokenizer {
             vocab,
             base_tokenizer,
             bpe_ranks,
             cache,
+            normalize_special_tokens: false,
+            options: TokenizerOptions::default(),
         })
     }
 
@@ -102,12 +109,14 @@ impl OpenAiGptTokenizer {
         )?;
         let base_tokenizer = BaseTokenizer::from_existing_vocab(vocab.clone(), lower_case, true);
         let bpe_ranks = BpePairVocab::from_file(merges_path)?;
-        let cache = RwLock::new(HashMap::new());
+        let cache = BpeCache::new();
         Ok(OpenAiGptTokenizer {
             vocab,
             base_tokenizer,
             bpe_ranks,
             cache,
+            normalize_special_tokens: false,
+            options: TokenizerOptions::default(),
         })
     }
 
@@ -135,14 +144,40 @@ impl OpenAiGptTokenizer {
         lower_case: bool,
     ) -> OpenAiGptTokenizer {
         let base_tokenizer = BaseTokenizer::from_existing_vocab(vocab.clone(), lower_case, true);
-        let cache = RwLock::new(HashMap::new());
+        let cache = BpeCache::new();
         OpenAiGptTokenizer {
             vocab,
             base_tokenizer,
             bpe_ranks: merges,
             cache,
+            normalize_special_tokens: false,
+            options: TokenizerOptions::default(),
         }
     }
+
+    /// Sets whether special tokens go through the normalization of the tokenizer (lower casing, accent stripping)
+    /// before being matched. By default, special tokens are matched as-is in the input text, including
+    /// tokens added to the vocabulary after the tokenizer creation (e.g. `[CTRL]`). Enabling this
+    /// option restores the legacy behaviour, where added special tokens containing uppercase characters
+    /// or accents are normalized and may be split.
+    ///
+    /// # Parameters
+    /// - normalize_special_tokens (`bool`): flag indicating if special tokens should be normalized
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::OpenAiGptTokenizer;
+    /// let lower_case = true;
+    /// let mut tokenizer =
+    ///     OpenAiGptTokenizer::from_file("path/to/vocab/file", "path/to/merges/file", lower_case)
+    ///         .unwrap();
+    ///
+    /// tokenizer.set_normalize_special_tokens(true);
+    /// ```
+    pub fn set_normalize_special_tokens(&mut self, normalize_special_tokens: bool) {
+        self.normalize_special_tokens = normalize_special_tokens;
+    }
 }
 
 impl Tokenizer<OpenAiGptVocab> for OpenAiGptTokenizer {
@@ -153,11 +188,40 @@ impl Tokenizer<OpenAiGptVocab> for OpenAiGptTokenizer {
         &mut self.vocab
     }
 
+    fn options(&self) -> &TokenizerOptions {
+        &self.options
+    }
+
+    fn describe(&self) -> TokenizerDescription {
+        let base_description = self.base_tokenizer.describe();
+        TokenizerDescription {
+            lower_case: base_description.lower_case,
+            strip_accents: base_description.strip_accents,
+            ..TokenizerDescription::new("openai_gpt", &self.vocab)
+        }
+    }
+
+    fn bpe_cache(&self) -> Option<&BpeCache> {
+        Some(&self.cache)
+    }
+
     fn tokenize_to_tokens(&self, initial_token: TokenRef) -> Vec<Token> {
-        let tokens: Vec<Token> = self
-            .base_tokenizer
-            .tokenize_to_tokens(initial_token)
+        //split on special tokens before the normalization of the base tokenizer, whose vocabulary
+        //does not contain the tokens added to this tokenizer
+        let spans = if self.normalize_special_tokens {
+            vec![initial_token]
+        } else {
+            split_on_special_tokens(initial_token, &self.vocab)
+        };
+        let tokens: Vec<Token> = spans
             .into_iter()
+            .flat_map(|span| {
+                if span.mask == Mask::None {
+                    self.base_tokenizer.tokenize_to_tokens(span)
+                } else {
+                    vec![span.to_owned()]
+                }
+            })
             .flat_map(|token| {
                 if token.mask != Mask::Special && token.mask != Mask::Unknown {
                     split_on_bpe_pairs(
@@ -183,6 +247,16 @@ impl Tokenizer<OpenAiGptVocab> for OpenAiGptTokenizer {
 
 impl MultiThreadedTokenizer<OpenAiGptVocab> for OpenAiGptTokenizer {}
 
+impl ConfigurableTokenizer<OpenAiGptVocab> for OpenAiGptTokenizer {
+    fn options_mut(&mut self) -> &mut TokenizerOptions {
+        &mut self.options
+    }
+
+    fn bpe_cache_mut(&mut self) -> Option<&mut BpeCache> {
+        Some(&mut self.cache)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -333,6 +407,7 @@ mod tests {
                     ],
                     reference_offsets: vec![vec![0, 1, 2], vec![4, 5], vec![6, 7, 8]],
                     mask: vec![Mask::None, Mask::Begin, Mask::Continuation],
+                    tokens: None,
                 },
             ),
             (
@@ -346,6 +421,7 @@ mod tests {
                     token_offsets: vec![],
                     reference_offsets: vec![],
                     mask: vec![],
+                    tokens: None,
                 },
             ),
             (
@@ -359,6 +435,7 @@ mod tests {
                     token_offsets: vec![],
                     reference_offsets: vec![],
                     mask: vec![],
+                    tokens: None,
                 },
             ),
         ];
@@ -0,0 +1,22 @@
+// Copyright 2019 Guillaume Becquin
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//     http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// # Partially decoded byte-level sequence
+/// Output of the decoding of a (possibly incomplete) slice of byte-level token indices
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PartialDecoding {
+    /// Text decoded from the complete UTF-8 characters of the sequence
+    pub text: String,
+
+    /// Trailing bytes of a multi-byte character that is not complete yet. These should be passed
+    /// back to the next call, together with the following token indices.
+    pub pending_bytes: Vec<u8>,
+}
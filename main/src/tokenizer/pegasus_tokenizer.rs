@@ -15,11 +15,19 @@ use std::path::Path;
 
 use crate::error::TokenizerError;
 use crate::tokenizer::base_tokenizer::{
-    Mask, Offset, OffsetSize, Token, TokenIdsWithOffsets, TokenIdsWithSpecialTokens, TokenRef,
+    encode_tokens_with_offsets, Mask, Offset, OffsetSize, Token, TokenIdsWithOffsets,
+    TokenIdsWithSpecialTokens, TokenRef, TokenizedInput, TokensWithOffsets, TruncationStrategy,
+};
+use crate::tokenizer::tokenization_utils::{
+    apply_unknown_piece_policy, clean_text, decompose_nfkc, is_whitespace, lowercase,
+    merge_byte_fallback_pieces,
+};
+use crate::tokenizer::{
+    ConfigurableTokenizer, MultiThreadedTokenizer, Tokenizer, TokenizerDescription,
+    TokenizerOptions, UnigramTokenizer, UnknownPiecePolicy,
 };
-use crate::tokenizer::tokenization_utils::{clean_text, decompose_nfkc, is_whitespace, lowercase};
-use crate::tokenizer::{MultiThreadedTokenizer, Tokenizer};
 use crate::vocab::{PegasusVocab, SentencePieceModel, Vocab};
+use std::collections::HashMap;
 
 /// # Pegasus tokenizer
 /// Pegasus tokenizer performing:
@@ -31,6 +39,7 @@ pub struct PegasusTokenizer {
     model: SentencePieceModel,
     vocab: PegasusVocab,
     lower_case: bool,
+    options: TokenizerOptions,
 }
 
 impl PegasusTokenizer {
@@ -58,6 +67,7 @@ impl PegasusTokenizer {
             model,
             vocab,
             lower_case,
+            options: TokenizerOptions::default(),
         })
     }
 
@@ -93,9 +103,36 @@ impl PegasusTokenizer {
             model,
             vocab,
             lower_case,
+            options: TokenizerOptions::default(),
         })
     }
 
+    /// Create a new instance of a `PegasusTokenizer` from the bytes of a SentencePiece protobuf
+    /// file, allowing to embed the model in a self-contained binary (e.g. using `include_bytes!`).
+    ///
+    /// # Parameters
+    /// - bytes (`&[u8]`): content of the SentencePiece model file
+    /// - lower_case (`bool`): flag indicating if the text should be lower-cased as part of the tokenization
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::PegasusTokenizer;
+    /// let lower_case = false;
+    /// let bytes = std::fs::read("path/to/spiece.model").unwrap();
+    /// let tokenizer = PegasusTokenizer::from_protobuf_bytes(&bytes, lower_case).unwrap();
+    /// ```
+    pub fn from_protobuf_bytes(
+        bytes: &[u8],
+        lower_case: bool,
+    ) -> Result<PegasusTokenizer, TokenizerError> {
+        let model = SentencePieceModel::from_protobuf_bytes(bytes)?;
+        let vocab = PegasusVocab::from_protobuf_bytes(bytes)?;
+        Ok(Self::from_existing_vocab_and_model(
+            vocab, model, lower_case,
+        ))
+    }
+
     /// Create a new instance of a `PegasusTokenizer` from an existing vocabulary and model
     ///
     /// # Parameters
@@ -123,7 +160,216 @@ impl PegasusTokenizer {
             model,
             vocab,
             lower_case,
+            options: TokenizerOptions::default(),
+        }
+    }
+
+    /// Selects the principal sentences of a document as gap sentences, following the independent
+    /// selection strategy of Pegasus (`Ind-Orig`): each sentence is scored by the ROUGE-1 F1 score
+    /// between its words and the words of the rest of the document, and the highest scoring
+    /// sentences are selected. Words are compared lower-cased, splitting on non-alphanumeric
+    /// characters. Ties are broken by position in the document.
+    ///
+    /// # Parameters
+    /// - sentences (`&[&str]`): sentences of the document
+    /// - num_gap_sentences (`usize`): number of sentences to select
+    ///
+    /// # Returns
+    /// `Vec<usize>` indices of the selected sentences, in increasing order
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_tokenizers::tokenizer::PegasusTokenizer;
+    /// let sentences = [
+    ///     "Pegasus is pre-trained with gap sentences.",
+    ///     "The weather is nice.",
+    ///     "Gap sentences are masked in the input.",
+    /// ];
+    ///
+    /// let gap_sentence_indices = PegasusTokenizer::select_principal_sentences(&sentences, 1);
+    /// assert_eq!(gap_sentence_indices, vec![0]);
+    /// ```
+    pub fn select_principal_sentences(sentences: &[&str], num_gap_sentences: usize) -> Vec<usize> {
+        fn word_counts(text: &str) -> HashMap<String, usize> {
+            let mut counts = HashMap::new();
+            for word in text
+                .split(|c: char| !c.is_alphanumeric())
+                .filter(|word| !word.is_empty())
+            {
+                *counts.entry(word.to_lowercase()).or_insert(0) += 1;
+            }
+            counts
         }
+
+        let sentence_counts: Vec<HashMap<String, usize>> = sentences
+            .iter()
+            .map(|sentence| word_counts(sentence))
+            .collect();
+        let mut document_counts: HashMap<&str, usize> = HashMap::new();
+        for counts in sentence_counts.iter() {
+            for (word, count) in counts {
+                *document_counts.entry(word.as_str()).or_insert(0) += count;
+            }
+        }
+        let document_length: usize = document_counts.values().sum();
+
+        let mut scores: Vec<(usize, f64)> = sentence_counts
+            .iter()
+            .enumerate()
+            .map(|(index, counts)| {
+                let sentence_length: usize = counts.values().sum();
+                let reference_length = document_length - sentence_length;
+                let overlap: usize = counts
+                    .iter()
+                    .map(|(word, count)| (*count).min(document_counts[word.as_str()] - count))
+                    .sum();
+                let score = if overlap == 0 {
+                    0.0
+                } else {
+                    2.0 * overlap as f64 / (sentence_length + reference_length) as f64
+                };
+                (index, score)
+            })
+            .collect();
+        scores.sort_by(|(index_a, score_a), (index_b, score_b)| {
+            score_b.total_cmp(score_a).then(index_a.cmp(index_b))
+        });
+        let mut selected: Vec<usize> = scores
+            .into_iter()
+            .take(num_gap_sentences)
+            .map(|(index, _)| index)
+            .collect();
+        selected.sort_unstable();
+        selected
+    }
+
+    /// Builds a gap sentence generation pre-training example from the sentences of a document: the
+    /// gap sentences are replaced by the sentence mask token (`<mask_1>`) in the input, and joined
+    /// to form the target. The input and target are truncated to their maximum length, and end with
+    /// the EOS token. Token-level masking of the remaining sentences with the mask token
+    /// (`<mask_2>`) can be applied to the input with `adapters::MlmExampleBuilder`: sentence mask
+    /// tokens are flagged in the special tokens mask and never selected.
+    ///
+    /// # Parameters
+    /// - sentences (`&[&str]`): sentences of the document
+    /// - gap_sentence_indices (`&[usize]`): indices of the gap sentences (for example selected with
+    ///   `select_principal_sentences`)
+    /// - max_len (`usize`): maximum length of the input
+    /// - max_target_len (`usize`): maximum length of the target
+    ///
+    /// # Returns
+    /// `Result<GapSentenceExample, TokenizerError>` example, or an error if a gap sentence index is
+    /// out of bounds
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::PegasusTokenizer;
+    /// let lower_case = false;
+    /// let tokenizer = PegasusTokenizer::from_file("path/to/model/file", lower_case).unwrap();
+    /// let sentences = [
+    ///     "Pegasus is pre-trained with gap sentences.",
+    ///     "The weather is nice.",
+    ///     "Gap sentences are masked in the input.",
+    /// ];
+    ///
+    /// let gap_sentence_indices = PegasusTokenizer::select_principal_sentences(&sentences, 1);
+    /// let example = tokenizer
+    ///     .build_gap_sentence_example(&sentences, &gap_sentence_indices, 512, 128)
+    ///     .unwrap();
+    /// ```
+    pub fn build_gap_sentence_example(
+        &self,
+        sentences: &[&str],
+        gap_sentence_indices: &[usize],
+        max_len: usize,
+        max_target_len: usize,
+    ) -> Result<GapSentenceExample, TokenizerError> {
+        if let Some(index) = gap_sentence_indices
+            .iter()
+            .find(|&&index| index >= sentences.len())
+        {
+            return Err(TokenizerError::ValueError(format!(
+                "gap sentence index {index} out of bounds for a document of {} sentences",
+                sentences.len()
+            )));
+        }
+        let mut gap_sentence_indices = gap_sentence_indices.to_vec();
+        gap_sentence_indices.sort_unstable();
+        gap_sentence_indices.dedup();
+        let sentence_mask = self.vocab.get_sentence_mask_value();
+
+        let mut tokens = TokensWithOffsets {
+            tokens: vec![],
+            offsets: vec![],
+            reference_offsets: vec![],
+            masks: vec![],
+        };
+        let mut position: OffsetSize = 0;
+        for (index, sentence) in sentences.iter().enumerate() {
+            if gap_sentence_indices.binary_search(&index).is_ok() {
+                tokens.tokens.push(sentence_mask.to_owned());
+                tokens.offsets.push(None);
+                tokens.reference_offsets.push(vec![]);
+                tokens.masks.push(Mask::Special);
+            } else {
+                let sentence_tokens = self.tokenize_with_offsets(sentence);
+                tokens.tokens.extend(sentence_tokens.tokens);
+                tokens
+                    .offsets
+                    .extend(sentence_tokens.offsets.into_iter().map(|offset| {
+                        offset.map(|offset| {
+                            Offset::new(offset.begin + position, offset.end + position)
+                        })
+                    }));
+                tokens.reference_offsets.extend(
+                    sentence_tokens
+                        .reference_offsets
+                        .into_iter()
+                        .map(|offsets| offsets.into_iter().map(|o| o + position).collect()),
+                );
+                tokens.masks.extend(sentence_tokens.masks);
+            }
+            position += sentence.chars().count() as OffsetSize + 1;
+        }
+        let mut input = encode_tokens_with_offsets(
+            self,
+            tokens,
+            None,
+            max_len,
+            &TruncationStrategy::LongestFirst,
+            0,
+            true,
+        )?;
+        let sentence_mask_id = self.vocab.token_to_id(sentence_mask);
+        for (token_id, special_token) in input
+            .token_ids
+            .iter()
+            .zip(input.special_tokens_mask.iter_mut())
+        {
+            if *token_id == sentence_mask_id {
+                *special_token = 1;
+            }
+        }
+
+        let target_text = gap_sentence_indices
+            .iter()
+            .map(|&index| sentences[index])
+            .collect::<Vec<&str>>()
+            .join(" ");
+        let target = self.try_encode(
+            &target_text,
+            None,
+            max_target_len,
+            &TruncationStrategy::LongestFirst,
+            0,
+        )?;
+        Ok(GapSentenceExample {
+            input,
+            target,
+            gap_sentence_indices,
+        })
     }
 }
 
@@ -135,6 +381,17 @@ impl Tokenizer<PegasusVocab> for PegasusTokenizer {
         &mut self.vocab
     }
 
+    fn options(&self) -> &TokenizerOptions {
+        &self.options
+    }
+
+    fn describe(&self) -> TokenizerDescription {
+        TokenizerDescription {
+            lower_case: Some(self.lower_case),
+            ..TokenizerDescription::new("pegasus", &self.vocab)
+        }
+    }
+
     fn tokenize_to_tokens(&self, text: TokenRef) -> Vec<Token> {
         let mut token = text.to_owned();
         clean_text(&mut token, true);
@@ -180,10 +437,15 @@ impl Tokenizer<PegasusVocab> for PegasusTokenizer {
             is_prev_unknown = node.index == 0;
         }
         self.model.populate_masks(output.as_mut_slice(), '\u{2581}');
-        output
+        apply_unknown_piece_policy(output, &self.vocab, self.unknown_piece_policy())
     }
 
     fn convert_tokens_to_string(&self, tokens: Vec<String>) -> String {
+        let tokens = if self.unknown_piece_policy() == UnknownPiecePolicy::ByteFallback {
+            merge_byte_fallback_pieces(tokens)
+        } else {
+            tokens
+        };
         tokens
             .into_iter()
             .map(|v| v.replace('\u{2581}', " "))
@@ -237,3 +499,29 @@ impl Tokenizer<PegasusVocab> for PegasusTokenizer {
 }
 
 impl MultiThreadedTokenizer<PegasusVocab> for PegasusTokenizer {}
+
+impl ConfigurableTokenizer<PegasusVocab> for PegasusTokenizer {
+    fn options_mut(&mut self) -> &mut TokenizerOptions {
+        &mut self.options
+    }
+}
+
+impl UnigramTokenizer<PegasusVocab> for PegasusTokenizer {
+    fn unigram_model(&self) -> &SentencePieceModel {
+        &self.model
+    }
+}
+
+/// # Gap sentence generation example
+/// Pre-training example for the Pegasus gap sentence generation objective, built by
+/// `PegasusTokenizer::build_gap_sentence_example`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GapSentenceExample {
+    /// Encoded document, with each gap sentence replaced by the sentence mask token (`<mask_1>`).
+    /// Offsets refer to the sentences of the document joined by a space.
+    pub input: TokenizedInput,
+    /// Encoded target, made of the gap sentences joined by a space
+    pub target: TokenizedInput,
+    /// Indices of the gap sentences in the document, in increasing order
+    pub gap_sentence_indices: Vec<usize>,
+}
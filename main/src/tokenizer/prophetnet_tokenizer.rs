@@ -15,10 +15,13 @@ use std::path::Path;
 
 use crate::error::TokenizerError;
 use crate::tokenizer::base_tokenizer::{
-    BaseTokenizer, Mask, MultiThreadedTokenizer, Offset, OffsetSize, Token, TokenIdsWithOffsets,
-    TokenIdsWithSpecialTokens, TokenRef, Tokenizer,
+    BaseTokenizer, BidiControlHandling, ConfigurableTokenizer, EmojiSequenceHandling, Mask,
+    MultiThreadedTokenizer, Offset, OffsetSize, Token, TokenIdsWithOffsets,
+    TokenIdsWithSpecialTokens, TokenRef, Tokenizer, TokenizerDescription, TokenizerOptions,
+};
+use crate::tokenizer::tokenization_utils::{
+    merge_byte_fallback_pieces, split_on_special_tokens, tokenize_wordpiece, ContinuationMarker,
 };
-use crate::tokenizer::tokenization_utils::tokenize_wordpiece;
 use crate::vocab::{ProphetNetVocab, Vocab};
 
 /// # ProphetNet tokenizer
@@ -28,6 +31,9 @@ use crate::vocab::{ProphetNetVocab, Vocab};
 pub struct ProphetNetTokenizer {
     vocab: ProphetNetVocab,
     base_tokenizer: BaseTokenizer<ProphetNetVocab>,
+    normalize_special_tokens: bool,
+    options: TokenizerOptions,
+    continuation_marker: ContinuationMarker,
 }
 
 impl ProphetNetTokenizer {
@@ -59,6 +65,9 @@ impl ProphetNetTokenizer {
         Ok(ProphetNetTokenizer {
             vocab,
             base_tokenizer,
+            normalize_special_tokens: false,
+            options: TokenizerOptions::default(),
+            continuation_marker: ContinuationMarker::default(),
         })
     }
 
@@ -100,6 +109,9 @@ impl ProphetNetTokenizer {
         Ok(ProphetNetTokenizer {
             vocab,
             base_tokenizer,
+            normalize_special_tokens: false,
+            options: TokenizerOptions::default(),
+            continuation_marker: ContinuationMarker::default(),
         })
     }
 
@@ -131,8 +143,102 @@ impl ProphetNetTokenizer {
         ProphetNetTokenizer {
             vocab,
             base_tokenizer,
+            normalize_special_tokens: false,
+            options: TokenizerOptions::default(),
+            continuation_marker: ContinuationMarker::default(),
         }
     }
+
+    /// Sets the emoji sequence handling of the tokenizer. Emoji sequences kept intact are not split
+    /// into word pieces (see `EmojiSequenceHandling` for the available modes).
+    ///
+    /// # Parameters
+    /// - emoji_sequence_handling (`EmojiSequenceHandling`): emoji sequence handling mode
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{EmojiSequenceHandling, ProphetNetTokenizer};
+    /// let strip_accents = false;
+    /// let lower_case = false;
+    /// let mut tokenizer =
+    ///     ProphetNetTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
+    ///
+    /// tokenizer.set_emoji_sequence_handling(EmojiSequenceHandling::Keep);
+    /// ```
+    pub fn set_emoji_sequence_handling(&mut self, emoji_sequence_handling: EmojiSequenceHandling) {
+        self.base_tokenizer
+            .set_emoji_sequence_handling(emoji_sequence_handling);
+    }
+
+    /// Sets the bidirectional control character handling of the tokenizer (see `BidiControlHandling`
+    /// for the available modes). These characters are stripped by default.
+    ///
+    /// # Parameters
+    /// - bidi_control_handling (`BidiControlHandling`): bidi control character handling mode
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{BidiControlHandling, ProphetNetTokenizer};
+    /// let strip_accents = false;
+    /// let lower_case = false;
+    /// let mut tokenizer =
+    ///     ProphetNetTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
+    ///
+    /// tokenizer.set_bidi_control_handling(BidiControlHandling::Special);
+    /// ```
+    pub fn set_bidi_control_handling(&mut self, bidi_control_handling: BidiControlHandling) {
+        self.base_tokenizer
+            .set_bidi_control_handling(bidi_control_handling);
+    }
+
+    /// Sets whether special tokens go through the normalization of the tokenizer (lower casing, accent stripping)
+    /// before being matched. By default, special tokens are matched as-is in the input text, including
+    /// tokens added to the vocabulary after the tokenizer creation (e.g. `[CTRL]`). Enabling this
+    /// option restores the legacy behaviour, where added special tokens containing uppercase characters
+    /// or accents are normalized and may be split.
+    ///
+    /// # Parameters
+    /// - normalize_special_tokens (`bool`): flag indicating if special tokens should be normalized
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::ProphetNetTokenizer;
+    /// let strip_accents = false;
+    /// let lower_case = true;
+    /// let mut tokenizer =
+    ///     ProphetNetTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
+    ///
+    /// tokenizer.set_normalize_special_tokens(true);
+    /// ```
+    pub fn set_normalize_special_tokens(&mut self, normalize_special_tokens: bool) {
+        self.normalize_special_tokens = normalize_special_tokens;
+    }
+
+    /// Sets the convention marking the word pieces of a word split into several subwords, used both
+    /// for the WordPiece tokenization and the decoding. Defaults to a `##` continuation prefix;
+    /// vocabularies with other markers (e.g. `++` prefixes or CTRL-style `@@` suffixes) can be used
+    /// by setting the matching `ContinuationMarker`.
+    ///
+    /// # Parameters
+    /// - continuation_marker (`ContinuationMarker`): subword continuation marker of the vocabulary
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{ProphetNetTokenizer, ContinuationMarker};
+    /// let strip_accents = false;
+    /// let lower_case = true;
+    /// let mut tokenizer =
+    ///     ProphetNetTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
+    ///
+    /// tokenizer.set_continuation_marker(ContinuationMarker::Suffix("@@".to_string()));
+    /// ```
+    pub fn set_continuation_marker(&mut self, continuation_marker: ContinuationMarker) {
+        self.continuation_marker = continuation_marker;
+    }
 }
 
 impl Tokenizer<ProphetNetVocab> for ProphetNetTokenizer {
@@ -143,17 +249,57 @@ impl Tokenizer<ProphetNetVocab> for ProphetNetTokenizer {
         &mut self.vocab
     }
 
+    fn options(&self) -> &TokenizerOptions {
+        &self.options
+    }
+
+    fn describe(&self) -> TokenizerDescription {
+        let base_description = self.base_tokenizer.describe();
+        TokenizerDescription {
+            lower_case: base_description.lower_case,
+            strip_accents: base_description.strip_accents,
+            ..TokenizerDescription::new("prophetnet", &self.vocab)
+        }
+    }
+
     fn tokenize_to_tokens(&self, initial_token: TokenRef) -> Vec<Token> {
+        //split on special tokens before the normalization of the base tokenizer, whose vocabulary
+        //does not contain the tokens added to this tokenizer
+        let spans = if self.normalize_special_tokens {
+            vec![initial_token]
+        } else {
+            split_on_special_tokens(initial_token, &self.vocab)
+        };
         //the base tokenizers does most of the work, we simply add a wordpiece tokenizer on top
-        self.base_tokenizer
-            .tokenize_to_tokens(initial_token)
+        spans
             .into_iter()
-            .flat_map(|token| tokenize_wordpiece(token.as_ref(), &self.vocab, 100))
+            .flat_map(|span| {
+                if span.mask == Mask::None {
+                    self.base_tokenizer.tokenize_to_tokens(span)
+                } else {
+                    vec![span.to_owned()]
+                }
+            })
+            .flat_map(|token| {
+                //emoji sequences kept intact by the base tokenizer are not split into word pieces
+                if self.base_tokenizer.is_kept_emoji_token(&token.text) {
+                    vec![token]
+                } else {
+                    tokenize_wordpiece(token.as_ref(), &self.vocab, 100, &self.continuation_marker)
+                }
+            })
             .collect()
     }
 
     fn convert_tokens_to_string(&self, tokens: Vec<String>) -> String {
-        tokens.join(" ").replace(" ##", "").trim().to_owned()
+        let tokens = if self.base_tokenizer.emoji_sequence_handling()
+            == EmojiSequenceHandling::KeepWithByteFallback
+        {
+            merge_byte_fallback_pieces(tokens)
+        } else {
+            tokens
+        };
+        self.continuation_marker.join_word_pieces(&tokens)
     }
 
     fn build_input_with_special_tokens(
@@ -206,6 +352,12 @@ impl Tokenizer<ProphetNetVocab> for ProphetNetTokenizer {
 
 impl MultiThreadedTokenizer<ProphetNetVocab> for ProphetNetTokenizer {}
 
+impl ConfigurableTokenizer<ProphetNetVocab> for ProphetNetTokenizer {
+    fn options_mut(&mut self) -> &mut TokenizerOptions {
+        &mut self.options
+    }
+}
+
 //==============================
 // Unit tests
 //==============================
@@ -386,6 +538,7 @@ mod tests {
                         Mask::Punctuation,
                         Mask::Special,
                     ],
+                    tokens: None,
                 },
             ),
             (
@@ -426,6 +579,7 @@ mod tests {
                         Mask::Punctuation,
                         Mask::Special,
                     ],
+                    tokens: None,
                 },
             ),
             (
@@ -475,6 +629,7 @@ mod tests {
                         Mask::Unknown,
                         Mask::Special,
                     ],
+                    tokens: None,
                 },
             ),
         ];
@@ -531,6 +686,7 @@ mod tests {
                     ),
                     reference_offsets: vec!(vec!(0, 1, 2, 3, 4), vec!(6, 7, 8, 9, 10), vec!(), vec!(0, 1, 2, 3), vec!(5, 6), vec!(8, 9, 10), vec!(12, 13, 14, 15, 16, 17), vec!(19, 20, 21, 22, 23, 24, 25, 26), vec!()),
                     mask: vec!(Mask::None, Mask::None, Mask::Special, Mask::Unknown, Mask::Unknown, Mask::Unknown, Mask::Unknown, Mask::Unknown, Mask::Special),
+                    tokens: None,
                 }
             ),
 //            Truncation of sentence 2 (longest)
@@ -547,6 +703,7 @@ mod tests {
                     ),
                     reference_offsets: vec!(vec!(0, 1, 2, 3, 4), vec!(6, 7, 8, 9, 10), vec!(), vec!(0), vec!(1, 2, 3, 4), vec!(6, 7), vec!(9, 10, 11), vec!(13, 14, 15, 16, 17, 18), vec!(20, 21, 22, 23, 24, 25, 26, 27), vec!()),
                     mask: vec!(Mask::None, Mask::None, Mask::Special, Mask::Punctuation, Mask::Unknown, Mask::Unknown, Mask::Unknown, Mask::Unknown, Mask::Unknown, Mask::Special),
+                    tokens: None,
                 }
             ),
 //            Truncation of sentence 1 (longest)
@@ -563,6 +720,7 @@ mod tests {
                     ),
                     reference_offsets: vec!(vec!(0, 1, 2, 3, 4), vec!(6, 7, 8, 9, 10), vec!(13, 14, 15, 16, 17), vec!(20, 21, 22, 23, 24), vec!(27, 28, 29, 30, 31), vec!(), vec!(0), vec!(1), vec!(2), vec!()),
                     mask: vec!(Mask::Unknown, Mask::None, Mask::None, Mask::None, Mask::None, Mask::Special, Mask::Punctuation, Mask::Punctuation, Mask::Punctuation, Mask::Special),
+                    tokens: None,
                 }
             ),
 //            Truncation of both sentences (longest)
@@ -579,6 +737,7 @@ mod tests {
                     ),
                     reference_offsets: vec!(vec!(0, 1, 2, 3, 4), vec!(6, 7, 8, 9, 10), vec!(13, 14, 15, 16, 17), vec!(20, 21, 22, 23, 24), vec!(), vec!(0), vec!(1), vec!(2), vec!(3), vec!()),
                     mask: vec!(Mask::Unknown, Mask::None, Mask::None, Mask::None, Mask::Special, Mask::Punctuation, Mask::Punctuation, Mask::Punctuation, Mask::Punctuation, Mask::Special),
+                    tokens: None,
                 }
             )
         ];
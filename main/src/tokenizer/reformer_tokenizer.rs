@@ -12,17 +12,19 @@
 // limitations under the License.
 
 use crate::error::TokenizerError;
-use crate::tokenizer::base_tokenizer::{Token, TokenRef};
+use crate::tokenizer::base_tokenizer::{Token, TokenRef, TruncationStrategy};
 use crate::tokenizer::tokenization_utils::{
     bpe, clean_text, decompose_nfkc, fix_mask, is_whitespace, lowercase, split_on_bpe_pairs,
     split_on_special_tokens, whitespace_tokenize, BpeCache,
 };
-use crate::tokenizer::{MultiThreadedTokenizer, Tokenizer};
+use crate::tokenizer::{
+    ConfigurableTokenizer, MultiThreadedTokenizer, Tokenizer, TokenizerDescription,
+    TokenizerOptions,
+};
 use crate::vocab::{BpePairVocab, ReformerVocab, Vocab};
-use crate::Mask;
-use std::collections::HashMap;
+use crate::{Mask, TokenizedInput};
+use std::cmp::{max, min};
 use std::path::Path;
-use std::sync::RwLock;
 
 /// # Reformer tokenizer
 pub struct ReformerTokenizer {
@@ -30,6 +32,8 @@ pub struct ReformerTokenizer {
     bpe_ranks: BpePairVocab,
     cache: BpeCache,
     lower_case: bool,
+    options: TokenizerOptions,
+    bucket_length: Option<usize>,
 }
 
 impl ReformerTokenizer {
@@ -54,12 +58,14 @@ impl ReformerTokenizer {
     ) -> Result<ReformerTokenizer, TokenizerError> {
         let vocab = ReformerVocab::from_file(&path)?;
         let bpe_ranks = BpePairVocab::from_sentencepiece_file(path)?;
-        let cache = RwLock::new(HashMap::new());
+        let cache = BpeCache::new();
         Ok(ReformerTokenizer {
             vocab,
             bpe_ranks,
             cache,
             lower_case,
+            options: TokenizerOptions::default(),
+            bucket_length: None,
         })
     }
 
@@ -92,14 +98,220 @@ impl ReformerTokenizer {
         let vocab =
             ReformerVocab::from_file_with_special_token_mapping(&path, special_token_mapping_path)?;
         let bpe_ranks = BpePairVocab::from_sentencepiece_file(path)?;
-        let cache = RwLock::new(HashMap::new());
+        let cache = BpeCache::new();
         Ok(ReformerTokenizer {
             vocab,
             bpe_ranks,
             cache,
             lower_case,
+            options: TokenizerOptions::default(),
+            bucket_length: None,
         })
     }
+
+    /// Create a new instance of a `ReformerTokenizer` from the bytes of a SentencePiece protobuf
+    /// file, allowing to embed the model in a self-contained binary (e.g. using `include_bytes!`).
+    ///
+    /// # Parameters
+    /// - bytes (`&[u8]`): content of the SentencePiece model file
+    /// - lower_case (`bool`): flag indicating if the text should be lower-cased as part of the tokenization
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::ReformerTokenizer;
+    /// let lower_case = false;
+    /// let bytes = std::fs::read("path/to/spiece.model").unwrap();
+    /// let tokenizer = ReformerTokenizer::from_protobuf_bytes(&bytes, lower_case).unwrap();
+    /// ```
+    pub fn from_protobuf_bytes(
+        bytes: &[u8],
+        lower_case: bool,
+    ) -> Result<ReformerTokenizer, TokenizerError> {
+        let vocab = ReformerVocab::from_protobuf_bytes(bytes)?;
+        let bpe_ranks = BpePairVocab::from_sentencepiece_bytes(bytes)?;
+        let cache = BpeCache::new();
+        Ok(ReformerTokenizer {
+            vocab,
+            bpe_ranks,
+            cache,
+            lower_case,
+            options: TokenizerOptions::default(),
+            bucket_length: None,
+        })
+    }
+
+    /// Sets the chunk lengths of the LSH and local self-attention layers of the Reformer model the
+    /// inputs are encoded for. The length of Reformer inputs has to be a multiple of the least
+    /// common multiple of these chunk lengths (the bucket length), which is used by
+    /// `target_length` and `encode_to_bucket_length`.
+    ///
+    /// # Parameters
+    /// - lsh_attn_chunk_length (`usize`): chunk length of the LSH self-attention layers
+    /// - local_attn_chunk_length (`usize`): chunk length of the local self-attention layers
+    ///
+    /// # Returns
+    /// `Result<(), TokenizerError>`, an error if one of the chunk lengths is 0
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::ReformerTokenizer;
+    /// let lower_case = false;
+    /// let mut tokenizer = ReformerTokenizer::from_file("path/to/vocab/file", lower_case).unwrap();
+    /// tokenizer.set_attention_chunk_lengths(64, 64).unwrap();
+    /// ```
+    pub fn set_attention_chunk_lengths(
+        &mut self,
+        lsh_attn_chunk_length: usize,
+        local_attn_chunk_length: usize,
+    ) -> Result<(), TokenizerError> {
+        if lsh_attn_chunk_length == 0 || local_attn_chunk_length == 0 {
+            return Err(TokenizerError::ValueError(format!(
+                "attention chunk lengths must be positive, got {lsh_attn_chunk_length} and {local_attn_chunk_length}"
+            )));
+        }
+        let (mut a, mut b) = (lsh_attn_chunk_length, local_attn_chunk_length);
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        self.bucket_length = Some(lsh_attn_chunk_length / a * local_attn_chunk_length);
+        Ok(())
+    }
+
+    /// Returns the bucket length (least common multiple of the attention chunk lengths), if the
+    /// attention chunk lengths are set
+    pub fn bucket_length(&self) -> Option<usize> {
+        self.bucket_length
+    }
+
+    /// Computes the length Reformer inputs of `num_tokens` tokens should be padded or truncated to:
+    /// the smallest multiple of the bucket length containing all tokens (and at least one bucket),
+    /// limited to the largest multiple of the bucket length not exceeding `max_len`.
+    ///
+    /// # Parameters
+    /// - num_tokens (`usize`): number of tokens of the input
+    /// - max_len (`usize`): maximum length of the input
+    ///
+    /// # Returns
+    /// `Result<usize, TokenizerError>` target length, or an error if the attention chunk lengths are
+    /// not set or `max_len` is lower than the bucket length
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::ReformerTokenizer;
+    /// let lower_case = false;
+    /// let mut tokenizer = ReformerTokenizer::from_file("path/to/vocab/file", lower_case).unwrap();
+    /// tokenizer.set_attention_chunk_lengths(64, 128).unwrap();
+    ///
+    /// assert_eq!(tokenizer.target_length(200, 512).unwrap(), 256);
+    /// ```
+    pub fn target_length(
+        &self,
+        num_tokens: usize,
+        max_len: usize,
+    ) -> Result<usize, TokenizerError> {
+        let bucket_length = self.bucket_length.ok_or_else(|| {
+            TokenizerError::ValueError(
+                "attention chunk lengths must be set to compute the target length".to_string(),
+            )
+        })?;
+        let max_target_length = max_len / bucket_length * bucket_length;
+        if max_target_length == 0 {
+            return Err(TokenizerError::ValueError(format!(
+                "max_len ({max_len}) is lower than the bucket length ({bucket_length})"
+            )));
+        }
+        let target_length = max(num_tokens, 1).div_ceil(bucket_length) * bucket_length;
+        Ok(min(target_length, max_target_length))
+    }
+
+    /// Encodes a string-like, truncating it to the largest multiple of the bucket length not
+    /// exceeding `max_len` and padding it to its target length (see `target_length`). Padding tokens
+    /// use the padding token of the vocabulary if defined, or the unknown token otherwise, and are
+    /// flagged in the special tokens mask with `Mask::Special` masks and no offsets.
+    ///
+    /// # Parameters
+    /// - text_1 (`&str`): first input text
+    /// - text_2 (`Option<&str>`): optional second input text
+    /// - max_len (`usize`): maximum combined sequence length
+    /// - truncation_strategy (`&TruncationStrategy`): truncation strategy
+    /// - stride (`usize`): amount of tokens to shift the input by if truncation is required
+    ///
+    /// # Returns
+    /// `Result<TokenizedInput, TokenizerError>` padded encoded input, or an error if the attention
+    /// chunk lengths are not set, `max_len` is lower than the bucket length or the truncation fails
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{ReformerTokenizer, TruncationStrategy};
+    /// let lower_case = false;
+    /// let mut tokenizer = ReformerTokenizer::from_file("path/to/vocab/file", lower_case).unwrap();
+    /// tokenizer.set_attention_chunk_lengths(64, 64).unwrap();
+    ///
+    /// let encoded_input = tokenizer
+    ///     .encode_to_bucket_length(
+    ///         "Hello, world!",
+    ///         None,
+    ///         512,
+    ///         &TruncationStrategy::LongestFirst,
+    ///         0,
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(encoded_input.token_ids.len(), 64);
+    /// ```
+    pub fn encode_to_bucket_length(
+        &self,
+        text_1: &str,
+        text_2: Option<&str>,
+        max_len: usize,
+        truncation_strategy: &TruncationStrategy,
+        stride: usize,
+    ) -> Result<TokenizedInput, TokenizerError> {
+        let max_target_length = self.target_length(max_len, max_len)?;
+        let mut encoded_input = self.try_encode(
+            text_1,
+            text_2,
+            max_target_length,
+            truncation_strategy,
+            stride,
+        )?;
+        let num_padding_tokens = self.target_length(encoded_input.token_ids.len(), max_len)?
+            - encoded_input.token_ids.len();
+
+        let pad_token = self
+            .vocab
+            .special_token_map
+            .pad_token
+            .as_deref()
+            .unwrap_or_else(|| self.vocab.get_unknown_value());
+        let pad_id = self.vocab.token_to_id(pad_token);
+        let pad_segment_id = encoded_input.segment_ids.last().copied().unwrap_or(0);
+        encoded_input
+            .token_ids
+            .extend(vec![pad_id; num_padding_tokens]);
+        encoded_input
+            .segment_ids
+            .extend(vec![pad_segment_id; num_padding_tokens]);
+        encoded_input
+            .special_tokens_mask
+            .extend(vec![1; num_padding_tokens]);
+        encoded_input
+            .token_offsets
+            .extend(vec![None; num_padding_tokens]);
+        encoded_input
+            .reference_offsets
+            .extend(vec![vec![]; num_padding_tokens]);
+        encoded_input
+            .mask
+            .extend(vec![Mask::Special; num_padding_tokens]);
+        if let Some(tokens) = encoded_input.tokens.as_mut() {
+            tokens.extend(vec![pad_token.to_owned(); num_padding_tokens]);
+        }
+        Ok(encoded_input)
+    }
 }
 
 impl Tokenizer<ReformerVocab> for ReformerTokenizer {
@@ -110,6 +322,21 @@ impl Tokenizer<ReformerVocab> for ReformerTokenizer {
         &mut self.vocab
     }
 
+    fn options(&self) -> &TokenizerOptions {
+        &self.options
+    }
+
+    fn describe(&self) -> TokenizerDescription {
+        TokenizerDescription {
+            lower_case: Some(self.lower_case),
+            ..TokenizerDescription::new("reformer", &self.vocab)
+        }
+    }
+
+    fn bpe_cache(&self) -> Option<&BpeCache> {
+        Some(&self.cache)
+    }
+
     fn tokenize_to_tokens(&self, text: TokenRef) -> Vec<Token> {
         let mut tokens = split_on_special_tokens(text, &self.vocab)
             .into_iter()
@@ -181,3 +408,13 @@ impl Tokenizer<ReformerVocab> for ReformerTokenizer {
 }
 
 impl MultiThreadedTokenizer<ReformerVocab> for ReformerTokenizer {}
+
+impl ConfigurableTokenizer<ReformerVocab> for ReformerTokenizer {
+    fn options_mut(&mut self) -> &mut TokenizerOptions {
+        &mut self.options
+    }
+
+    fn bpe_cache_mut(&mut self) -> Option<&mut BpeCache> {
+        Some(&mut self.cache)
+    }
+}
@@ -19,19 +19,19 @@ use crate::tokenizer::base_tokenizer::{
 };
 use crate::tokenizer::constants::UNICODE_TO_BYTES;
 use crate::tokenizer::tokenization_utils::{
-    bpe, fix_mask, is_whitespace, split_on_bpe_pairs, split_on_regex_with_lookahead,
-    split_on_special_tokens,
+    byte_level_tokens_to_bytes, learn_byte_level_merges, tokenize_byte_level_bpe,
+};
+use crate::tokenizer::tokenization_utils::{prefix_with_space, BpeCache};
+use crate::tokenizer::{
+    ConfigurableTokenizer, MultiThreadedTokenizer, PrefixSpaceTokenizer, TokenizerDescription,
+    TokenizerOptions,
 };
-use crate::tokenizer::tokenization_utils::{lowercase, BpeCache};
-use crate::tokenizer::MultiThreadedTokenizer;
 use crate::vocab::bpe_vocab::BpePairVocab;
 use crate::vocab::{RobertaVocab, Vocab};
 use itertools::Itertools;
 use regex::Regex;
-use std::collections::HashMap;
 use std::iter::Iterator;
 use std::path::Path;
-use std::sync::RwLock;
 
 /// # RoBERTa tokenizer
 /// RoBERTa tokenizer performing:
@@ -47,6 +47,7 @@ pub struct RobertaTokenizer {
     pattern_tokenization: Regex,
     lower_case: bool,
     add_prefix_space: bool,
+    options: TokenizerOptions,
 }
 
 impl RobertaTokenizer {
@@ -80,7 +81,7 @@ impl RobertaTokenizer {
     ) -> Result<RobertaTokenizer, TokenizerError> {
         let vocab = RobertaVocab::from_file(vocab_path)?;
         let bpe_ranks = BpePairVocab::from_file(merges_path)?;
-        let cache = RwLock::new(HashMap::new());
+        let cache = BpeCache::new();
         let pattern_lookahead = Regex::new(r"\s+\S").unwrap();
         let pattern_tokenization =
             Regex::new(r"'s|'t|'re|'ve|'m|'ll|'d| ?\p{L}+| ?\p{N}+| ?[^\s\p{L}\p{N}]+|\s+")
@@ -93,6 +94,7 @@ impl RobertaTokenizer {
             pattern_tokenization,
             lower_case,
             add_prefix_space,
+            options: TokenizerOptions::default(),
         })
     }
 
@@ -133,7 +135,7 @@ impl RobertaTokenizer {
             special_token_mapping_path,
         )?;
         let bpe_ranks = BpePairVocab::from_file(merges_path)?;
-        let cache = RwLock::new(HashMap::new());
+        let cache = BpeCache::new();
         let pattern_lookahead = Regex::new(r"\s+\S").unwrap();
         let pattern_tokenization =
             Regex::new(r"'s|'t|'re|'ve|'m|'ll|'d| ?\p{L}+| ?\p{N}+| ?[^\s\p{L}\p{N}]+|\s+")
@@ -146,6 +148,7 @@ impl RobertaTokenizer {
             pattern_tokenization,
             lower_case,
             add_prefix_space,
+            options: TokenizerOptions::default(),
         })
     }
 
@@ -179,7 +182,7 @@ impl RobertaTokenizer {
         lower_case: bool,
         add_prefix_space: bool,
     ) -> RobertaTokenizer {
-        let cache = RwLock::new(HashMap::new());
+        let cache = BpeCache::new();
         let pattern_lookahead = Regex::new(r"\s+\S").unwrap();
         let pattern_tokenization =
             Regex::new(r"'s|'t|'re|'ve|'m|'ll|'d| ?\p{L}+| ?\p{N}+| ?[^\s\p{L}\p{N}]+|\s+")
@@ -192,8 +195,56 @@ impl RobertaTokenizer {
             pattern_tokenization,
             lower_case,
             add_prefix_space,
+            options: TokenizerOptions::default(),
         }
     }
+
+    /// Continues the training of the BPE merges on a new corpus, for cheap domain adaptation. The
+    /// corpus is pre-tokenized as for the tokenization (byte-level words), up to `num_merges`
+    /// merges of the most frequent pairs of symbols are appended to the merges of the tokenizer, and
+    /// the merged symbols are added to its vocabulary (see `BpePairVocab::learn_merges`).
+    ///
+    /// # Parameters
+    /// - corpus: iterator over the samples (string-like) of the new corpus
+    /// - num_merges (`usize`): maximum number of merges to learn
+    ///
+    /// # Returns
+    /// `Vec<(String, String)>` with the pairs merged, in order of their rank
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{RobertaTokenizer, Tokenizer};
+    /// let lower_case = false;
+    /// let add_prefix_space = true;
+    /// let mut tokenizer = RobertaTokenizer::from_file(
+    ///     "path/to/vocab/file",
+    ///     "path/to/merges/file",
+    ///     lower_case,
+    ///     add_prefix_space,
+    /// )
+    /// .unwrap();
+    ///
+    /// let corpus = ["The patient was given acetaminophen.", "Acetaminophen overdose."];
+    /// let new_merges = tokenizer.learn_merges(corpus.iter(), 100);
+    /// ```
+    pub fn learn_merges<I, S>(&mut self, corpus: I, num_merges: usize) -> Vec<(String, String)>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        learn_byte_level_merges(
+            corpus,
+            num_merges,
+            &mut self.vocab,
+            &mut self.bpe_ranks,
+            &mut self.cache,
+            self.lower_case,
+            self.add_prefix_space,
+            &self.pattern_lookahead,
+            &self.pattern_tokenization,
+        )
+    }
 }
 
 impl Tokenizer<RobertaVocab> for RobertaTokenizer {
@@ -204,46 +255,24 @@ impl Tokenizer<RobertaVocab> for RobertaTokenizer {
         &mut self.vocab
     }
 
-    fn tokenize_to_tokens(&self, initial_token: TokenRef) -> Vec<Token> {
-        if initial_token.text.is_empty() {
-            return vec![];
-        }
-        let mut initial_token: Token = initial_token.to_owned();
-        if !is_whitespace(&initial_token.text.chars().next().unwrap()) & self.add_prefix_space {
-            initial_token.text.insert(0, ' ');
-            initial_token.reference_offsets.insert(0, 0);
-        };
-        let mut tokens: Vec<Token> = split_on_special_tokens(initial_token.as_ref(), &self.vocab)
-            .into_iter()
-            .map(|token| token.to_owned())
-            .collect::<Vec<Token>>();
-
-        let mut sub_tokens = Vec::new();
-        for token in tokens.iter_mut() {
-            if token.mask != Mask::Special && token.mask != Mask::Unknown {
-                if self.lower_case {
-                    lowercase(token);
-                }
-                for token in split_on_regex_with_lookahead(
-                    token.as_ref(),
-                    &self.pattern_lookahead,
-                    &self.pattern_tokenization,
-                ) {
-                    sub_tokens.extend(split_on_bpe_pairs(
-                        token,
-                        bpe,
-                        &self.bpe_ranks,
-                        &self.cache,
-                        true,
-                    ));
-                }
-            } else {
-                sub_tokens.push(token.clone());
-            }
+    fn options(&self) -> &TokenizerOptions {
+        &self.options
+    }
+
+    fn describe(&self) -> TokenizerDescription {
+        TokenizerDescription {
+            lower_case: Some(self.lower_case),
+            add_prefix_space: Some(self.add_prefix_space),
+            ..TokenizerDescription::new("roberta", &self.vocab)
         }
+    }
 
-        fix_mask(&mut sub_tokens);
-        sub_tokens
+    fn bpe_cache(&self) -> Option<&BpeCache> {
+        Some(&self.cache)
+    }
+
+    fn tokenize_to_tokens(&self, initial_token: TokenRef) -> Vec<Token> {
+        self.tokenize_to_tokens_with_prefix_space(initial_token, self.add_prefix_space)
     }
 
     fn convert_tokens_to_string(&self, tokens: Vec<String>) -> String {
@@ -259,6 +288,10 @@ impl Tokenizer<RobertaVocab> for RobertaTokenizer {
         String::from_utf8_lossy(&tokens).to_string()
     }
 
+    fn convert_tokens_to_bytes(&self, tokens: Vec<String>) -> Vec<u8> {
+        byte_level_tokens_to_bytes(&tokens)
+    }
+
     fn build_input_with_special_tokens(
         &self,
         tokens_ids_with_offsets_1: TokenIdsWithOffsets,
@@ -299,9 +332,11 @@ impl Tokenizer<RobertaVocab> for RobertaTokenizer {
             output.push(self.vocab.token_to_id(self.vocab.get_sep_value()));
             offsets.push(None);
             offsets.extend(tokens_ids_with_offsets_2_value.offsets);
+            original_offsets.push(vec![]);
             original_offsets.extend(tokens_ids_with_offsets_2_value.reference_offsets);
             offsets.push(None);
             original_offsets.push(vec![]);
+            mask.push(Mask::Special);
             mask.extend(tokens_ids_with_offsets_2_value.masks);
             mask.push(Mask::Special);
         }
@@ -316,8 +351,48 @@ impl Tokenizer<RobertaVocab> for RobertaTokenizer {
     }
 }
 
+impl PrefixSpaceTokenizer<RobertaVocab> for RobertaTokenizer {
+    fn add_prefix_space(&self) -> bool {
+        self.add_prefix_space
+    }
+
+    fn set_add_prefix_space(&mut self, add_prefix_space: bool) {
+        self.add_prefix_space = add_prefix_space;
+    }
+
+    fn tokenize_to_tokens_with_prefix_space(
+        &self,
+        initial_token: TokenRef,
+        add_prefix_space: bool,
+    ) -> Vec<Token> {
+        let mut initial_token: Token = initial_token.to_owned();
+        if add_prefix_space {
+            prefix_with_space(&mut initial_token);
+        }
+        tokenize_byte_level_bpe(
+            initial_token.as_ref(),
+            &self.vocab,
+            &self.bpe_ranks,
+            &self.cache,
+            &self.pattern_lookahead,
+            &self.pattern_tokenization,
+            self.lower_case,
+        )
+    }
+}
+
 impl MultiThreadedTokenizer<RobertaVocab> for RobertaTokenizer {}
 
+impl ConfigurableTokenizer<RobertaVocab> for RobertaTokenizer {
+    fn options_mut(&mut self) -> &mut TokenizerOptions {
+        &mut self.options
+    }
+
+    fn bpe_cache_mut(&mut self) -> Option<&mut BpeCache> {
+        Some(&mut self.cache)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -461,6 +536,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_roberta_tokenizer_prefix_space_override() {
+        //        Given
+        let vocab = generate_test_vocab();
+        let merges = generate_test_merges();
+        let mut roberta_tokenizer: RobertaTokenizer =
+            RobertaTokenizer::from_existing_vocab_and_merges(vocab, merges, true, true);
+
+        //        When
+        let with_prefix_space = roberta_tokenizer.tokenize_with_offsets("The Earth");
+        let without_prefix_space =
+            roberta_tokenizer.tokenize_with_offsets_and_prefix_space("The Earth", false);
+        roberta_tokenizer.set_add_prefix_space(false);
+
+        //        Then
+        assert_eq!(with_prefix_space.tokens, vec!["Ġthe", "Ġear", "th"]);
+        assert_eq!(
+            with_prefix_space.reference_offsets,
+            vec![vec![0, 0, 1, 2], vec![3, 4, 5, 6], vec![7, 8]]
+        );
+        assert_eq!(without_prefix_space.tokens, vec!["the", "Ġear", "th"]);
+        assert!(!roberta_tokenizer.add_prefix_space());
+        assert_eq!(
+            roberta_tokenizer.tokenize("The Earth"),
+            without_prefix_space.tokens
+        );
+    }
+
     #[test]
     fn test_roberta_tokenizer_no_lower_casing() {
         //        Given
@@ -588,6 +691,7 @@ mod tests {
                         Mask::Continuation,
                         Mask::Special,
                     ],
+                    tokens: None,
                 },
             ),
             (
@@ -615,6 +719,7 @@ mod tests {
                         Mask::Continuation,
                         Mask::Special,
                     ],
+                    tokens: None,
                 },
             ),
             (
@@ -628,6 +733,7 @@ mod tests {
                     token_offsets: vec![None, None],
                     reference_offsets: vec![vec![], vec![]],
                     mask: vec![Mask::Special, Mask::Special],
+                    tokens: None,
                 },
             ),
         ];
@@ -654,6 +760,56 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_encode_pair() {
+        //        Given
+        let vocab = generate_test_vocab();
+        let merges = generate_test_merges();
+        let roberta_tokenizer: RobertaTokenizer =
+            RobertaTokenizer::from_existing_vocab_and_merges(vocab, merges, true, true);
+        let truncation_strategy = TruncationStrategy::LongestFirst;
+
+        //        When
+        let encoded_input =
+            roberta_tokenizer.encode("the", Some("the"), 128, &truncation_strategy, 0);
+
+        //        Then
+        assert_eq!(encoded_input.token_ids, vec![8, 4, 9, 9, 4, 9]);
+        assert_eq!(
+            encoded_input.token_offsets,
+            vec![
+                None,
+                Some(Offset { begin: 0, end: 3 }),
+                None,
+                None,
+                Some(Offset { begin: 0, end: 3 }),
+                None,
+            ]
+        );
+        assert_eq!(
+            encoded_input.reference_offsets,
+            vec![
+                vec![],
+                vec![0, 0, 1, 2],
+                vec![],
+                vec![],
+                vec![0, 0, 1, 2],
+                vec![]
+            ]
+        );
+        assert_eq!(
+            encoded_input.mask,
+            vec![
+                Mask::Special,
+                Mask::None,
+                Mask::Special,
+                Mask::Special,
+                Mask::None,
+                Mask::Special,
+            ]
+        );
+    }
+
     #[test]
     fn test_decode() {
         //        Given
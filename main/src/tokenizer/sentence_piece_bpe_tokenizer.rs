@@ -14,8 +14,14 @@
 use std::path::Path;
 
 use crate::error::TokenizerError;
-use crate::tokenizer::tokenization_utils::{clean_text, decompose_nfkc, is_whitespace, lowercase};
-use crate::tokenizer::{MultiThreadedTokenizer, Tokenizer};
+use crate::tokenizer::tokenization_utils::{
+    apply_unknown_piece_policy, clean_text, decompose_nfkc, is_whitespace, lowercase,
+    merge_byte_fallback_pieces,
+};
+use crate::tokenizer::{
+    ConfigurableTokenizer, MultiThreadedTokenizer, Tokenizer, TokenizerDescription,
+    TokenizerOptions, UnknownPiecePolicy,
+};
 use crate::vocab::{SentencePieceBpeModel, SentencePieceVocab, Vocab};
 use crate::{Token, TokenRef};
 
@@ -29,6 +35,7 @@ pub struct SentencePieceBpeTokenizer {
     model: SentencePieceBpeModel,
     vocab: SentencePieceVocab,
     lower_case: bool,
+    options: TokenizerOptions,
 }
 
 impl SentencePieceBpeTokenizer {
@@ -67,6 +74,7 @@ impl SentencePieceBpeTokenizer {
             model,
             vocab,
             lower_case,
+            options: TokenizerOptions::default(),
         })
     }
 
@@ -95,9 +103,36 @@ impl SentencePieceBpeTokenizer {
             model,
             vocab,
             lower_case,
+            options: TokenizerOptions::default(),
         })
     }
 
+    /// Create a new instance of a `SentencePieceBpeTokenizer` from the bytes of a SentencePiece protobuf file,
+    /// allowing to embed the model in a self-contained binary (e.g. using `include_bytes!`).
+    ///
+    /// # Parameters
+    /// - bytes (`&[u8]`): content of the SentencePiece model file
+    /// - lower_case (`bool`): flag indicating if the text should be lower-cased as part of the tokenization
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::SentencePieceBpeTokenizer;
+    /// let lower_case = false;
+    /// let bytes = std::fs::read("path/to/spiece.model").unwrap();
+    /// let tokenizer = SentencePieceBpeTokenizer::from_protobuf_bytes(&bytes, lower_case).unwrap();
+    /// ```
+    pub fn from_protobuf_bytes(
+        bytes: &[u8],
+        lower_case: bool,
+    ) -> Result<SentencePieceBpeTokenizer, TokenizerError> {
+        let model = SentencePieceBpeModel::from_protobuf_bytes(bytes)?;
+        let vocab = SentencePieceVocab::from_protobuf_bytes(bytes)?;
+        Ok(Self::from_existing_vocab_and_model(
+            vocab, model, lower_case,
+        ))
+    }
+
     /// Create a new instance of a `SentencePieceBpeTokenizer` from an existing vocabulary and model
     ///
     /// # Parameters
@@ -127,6 +162,7 @@ impl SentencePieceBpeTokenizer {
             model,
             vocab,
             lower_case,
+            options: TokenizerOptions::default(),
         }
     }
 }
@@ -139,6 +175,17 @@ impl Tokenizer<SentencePieceVocab> for SentencePieceBpeTokenizer {
         &mut self.vocab
     }
 
+    fn options(&self) -> &TokenizerOptions {
+        &self.options
+    }
+
+    fn describe(&self) -> TokenizerDescription {
+        TokenizerDescription {
+            lower_case: Some(self.lower_case),
+            ..TokenizerDescription::new("sentence_piece_bpe", &self.vocab)
+        }
+    }
+
     fn tokenize_to_tokens(&self, text: TokenRef) -> Vec<Token> {
         let mut token = text.to_owned();
         clean_text(&mut token, true);
@@ -151,10 +198,16 @@ impl Tokenizer<SentencePieceVocab> for SentencePieceBpeTokenizer {
             token.text.insert(0, '\u{2581}');
             token.reference_offsets.insert(0, 0);
         };
-        self.model.tokenize_to_tokens(token.as_ref())
+        let output = self.model.tokenize_to_tokens(token.as_ref());
+        apply_unknown_piece_policy(output, &self.vocab, self.unknown_piece_policy())
     }
 
     fn convert_tokens_to_string(&self, tokens: Vec<String>) -> String {
+        let tokens = if self.unknown_piece_policy() == UnknownPiecePolicy::ByteFallback {
+            merge_byte_fallback_pieces(tokens)
+        } else {
+            tokens
+        };
         tokens
             .into_iter()
             .map(|v| v.replace('\u{2581}', " "))
@@ -164,3 +217,9 @@ impl Tokenizer<SentencePieceVocab> for SentencePieceBpeTokenizer {
 }
 
 impl MultiThreadedTokenizer<SentencePieceVocab> for SentencePieceBpeTokenizer {}
+
+impl ConfigurableTokenizer<SentencePieceVocab> for SentencePieceBpeTokenizer {
+    fn options_mut(&mut self) -> &mut TokenizerOptions {
+        &mut self.options
+    }
+}
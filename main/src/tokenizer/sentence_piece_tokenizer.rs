@@ -14,9 +14,14 @@ use std::path::Path;
 
 use crate::error::TokenizerError;
 use crate::tokenizer::base_tokenizer::{Token, TokenRef};
-use crate::tokenizer::tokenization_utils::{clean_text, lowercase};
+use crate::tokenizer::tokenization_utils::{
+    apply_unknown_piece_policy, clean_text, lowercase, merge_byte_fallback_pieces,
+};
 use crate::tokenizer::tokenization_utils::{decompose_nfkc, is_whitespace};
-use crate::tokenizer::{MultiThreadedTokenizer, Tokenizer};
+use crate::tokenizer::{
+    ConfigurableTokenizer, MultiThreadedTokenizer, Tokenizer, TokenizerDescription,
+    TokenizerOptions, UnigramTokenizer, UnknownPiecePolicy,
+};
 use crate::vocab::{SentencePieceModel, SentencePieceVocab, Vocab};
 
 /// # SentencePiece tokenizer
@@ -29,6 +34,7 @@ pub struct SentencePieceTokenizer {
     model: SentencePieceModel,
     vocab: SentencePieceVocab,
     lower_case: bool,
+    options: TokenizerOptions,
 }
 
 impl SentencePieceTokenizer {
@@ -57,6 +63,7 @@ impl SentencePieceTokenizer {
             model,
             vocab,
             lower_case,
+            options: TokenizerOptions::default(),
         })
     }
 
@@ -95,8 +102,36 @@ impl SentencePieceTokenizer {
             model,
             vocab,
             lower_case,
+            options: TokenizerOptions::default(),
         })
     }
+
+    /// Create a new instance of a `SentencePieceTokenizer` from the bytes of a SentencePiece protobuf file,
+    /// allowing to embed the model in a self-contained binary (e.g. using `include_bytes!`).
+    ///
+    /// # Parameters
+    /// - bytes (`&[u8]`): content of the SentencePiece model file
+    /// - lower_case (`bool`): flag indicating if the text should be lower-cased as part of the tokenization
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::SentencePieceTokenizer;
+    /// let lower_case = false;
+    /// let bytes = std::fs::read("path/to/spiece.model").unwrap();
+    /// let tokenizer = SentencePieceTokenizer::from_protobuf_bytes(&bytes, lower_case).unwrap();
+    /// ```
+    pub fn from_protobuf_bytes(
+        bytes: &[u8],
+        lower_case: bool,
+    ) -> Result<SentencePieceTokenizer, TokenizerError> {
+        let model = SentencePieceModel::from_protobuf_bytes(bytes)?;
+        let vocab = SentencePieceVocab::from_protobuf_bytes(bytes)?;
+        Ok(Self::from_existing_vocab_and_model(
+            vocab, model, lower_case,
+        ))
+    }
+
     /// Create a new instance of a `SentencePieceTokenizer` from an existing vocabulary and model
     ///
     /// # Parameters
@@ -125,6 +160,7 @@ impl SentencePieceTokenizer {
             model,
             vocab,
             lower_case,
+            options: TokenizerOptions::default(),
         }
     }
 }
@@ -137,6 +173,17 @@ impl Tokenizer<SentencePieceVocab> for SentencePieceTokenizer {
         &mut self.vocab
     }
 
+    fn options(&self) -> &TokenizerOptions {
+        &self.options
+    }
+
+    fn describe(&self) -> TokenizerDescription {
+        TokenizerDescription {
+            lower_case: Some(self.lower_case),
+            ..TokenizerDescription::new("sentence_piece", &self.vocab)
+        }
+    }
+
     fn tokenize_to_tokens(&self, text: TokenRef) -> Vec<Token> {
         let mut token = text.to_owned();
         clean_text(&mut token, true);
@@ -151,10 +198,16 @@ impl Tokenizer<SentencePieceVocab> for SentencePieceTokenizer {
         };
         let output = self.model.decode_forward_token_ref(token.as_ref());
         let decoded = self.model.decode_backward(&output);
-        self.model.parse_nodes_to_tokens(decoded)
+        let output = self.model.parse_nodes_to_tokens(decoded);
+        apply_unknown_piece_policy(output, &self.vocab, self.unknown_piece_policy())
     }
 
     fn convert_tokens_to_string(&self, tokens: Vec<String>) -> String {
+        let tokens = if self.unknown_piece_policy() == UnknownPiecePolicy::ByteFallback {
+            merge_byte_fallback_pieces(tokens)
+        } else {
+            tokens
+        };
         tokens
             .into_iter()
             .map(|v| v.replace('\u{2581}', " "))
@@ -164,3 +217,15 @@ impl Tokenizer<SentencePieceVocab> for SentencePieceTokenizer {
 }
 
 impl MultiThreadedTokenizer<SentencePieceVocab> for SentencePieceTokenizer {}
+
+impl ConfigurableTokenizer<SentencePieceVocab> for SentencePieceTokenizer {
+    fn options_mut(&mut self) -> &mut TokenizerOptions {
+        &mut self.options
+    }
+}
+
+impl UnigramTokenizer<SentencePieceVocab> for SentencePieceTokenizer {
+    fn unigram_model(&self) -> &SentencePieceModel {
+        &self.model
+    }
+}
@@ -0,0 +1,195 @@
+// Copyright 2019 Guillaume Becquin
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//     http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::error::TokenizerError;
+use crate::tokenizer::base_tokenizer::{Mask, TokenFilter, TokensWithOffsets};
+use crate::vocab::base_vocab::read_flat_file;
+use std::collections::HashSet;
+use std::path::Path;
+
+/// # Stopword handling variants
+/// Indicates how the tokens of stopwords are processed by a `StopwordFilter`
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum StopwordHandling {
+    /// The tokens of stopwords are kept, they are identified with `StopwordFilter::stopword_mask`
+    #[default]
+    Mark,
+    /// The tokens of stopwords are dropped before encoding
+    Drop,
+}
+
+/// # Stopword filter
+/// Token filter marking or dropping the tokens of stopwords before encoding, set on a tokenizer
+/// with `ConfigurableTokenizer::add_token_filter`. Words are made of a token followed by its
+/// `Mask::Continuation` sub-tokens: their text is rebuilt from the tokens, removing the word
+/// boundary and continuation markers (`▁`, `Ġ` and `##`) before matching the stopwords. Special
+/// tokens are never considered as stopwords. The offsets of the remaining tokens are unchanged.
+/// Marked stopwords are not reflected in the token masks: they are reported by `stopword_mask`,
+/// as a vector of flags parallel to the tokens of an encoded input.
+#[derive(Debug, Clone)]
+pub struct StopwordFilter {
+    stopwords: HashSet<String>,
+    handling: StopwordHandling,
+    lower_case: bool,
+}
+
+impl StopwordFilter {
+    /// Creates a new stopword filter
+    ///
+    /// # Parameters
+    /// - stopwords: stopwords (string-like)
+    /// - handling (`StopwordHandling`): marks or drops the tokens of stopwords
+    /// - lower_case (`bool`): lower-case the words and stopwords before matching
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{
+    ///     BertTokenizer, ConfigurableTokenizer, StopwordFilter, StopwordHandling, Tokenizer,
+    ///     TruncationStrategy,
+    /// };
+    /// let mut tokenizer = BertTokenizer::from_file("path/to/vocab", true, true).unwrap();
+    ///
+    /// let stopwords = ["the", "a", "of"];
+    /// let stopword_filter = StopwordFilter::new(stopwords.iter(), StopwordHandling::Drop, true);
+    /// tokenizer.add_token_filter(Box::new(stopword_filter));
+    /// let encoded = tokenizer.encode(
+    ///     "The capital of France",
+    ///     None,
+    ///     128,
+    ///     &TruncationStrategy::LongestFirst,
+    ///     0,
+    /// );
+    /// ```
+    pub fn new<I, S>(stopwords: I, handling: StopwordHandling, lower_case: bool) -> StopwordFilter
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let stopwords = stopwords
+            .into_iter()
+            .map(|stopword| {
+                if lower_case {
+                    stopword.as_ref().to_lowercase()
+                } else {
+                    stopword.as_ref().to_owned()
+                }
+            })
+            .collect();
+        StopwordFilter {
+            stopwords,
+            handling,
+            lower_case,
+        }
+    }
+
+    /// Creates a new stopword filter from a file containing a stopword per line
+    ///
+    /// # Parameters
+    /// - path: path to the stopwords file
+    /// - handling (`StopwordHandling`): marks or drops the tokens of stopwords
+    /// - lower_case (`bool`): lower-case the words and stopwords before matching
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{StopwordFilter, StopwordHandling};
+    /// let stopword_filter =
+    ///     StopwordFilter::from_file("path/to/stopwords.txt", StopwordHandling::Mark, true).unwrap();
+    /// ```
+    pub fn from_file<P: AsRef<Path>>(
+        path: P,
+        handling: StopwordHandling,
+        lower_case: bool,
+    ) -> Result<StopwordFilter, TokenizerError> {
+        let stopwords = read_flat_file(path)?;
+        Ok(StopwordFilter::new(stopwords.keys(), handling, lower_case))
+    }
+
+    /// Returns true if a word is a stopword
+    pub fn is_stopword(&self, word: &str) -> bool {
+        if self.lower_case {
+            self.stopwords.contains(&word.to_lowercase())
+        } else {
+            self.stopwords.contains(word)
+        }
+    }
+
+    /// Returns flags indicating if each token is part of a stopword
+    ///
+    /// # Parameters
+    /// - tokens (`&[S]`): token strings (string-like)
+    /// - masks (`&[Mask]`): masks of the tokens, used to group the tokens into words
+    ///
+    /// # Returns
+    /// - `Vec<bool>` with the same length as the tokens, true for the tokens of stopwords
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{
+    ///     BertTokenizer, ConfigurableTokenizer, StopwordFilter, StopwordHandling, Tokenizer,
+    ///     TruncationStrategy,
+    /// };
+    /// let mut tokenizer = BertTokenizer::from_file("path/to/vocab", true, true).unwrap();
+    /// tokenizer.set_return_tokens(true);
+    ///
+    /// let stopword_filter = StopwordFilter::new(["the", "of"], StopwordHandling::Mark, true);
+    /// let encoded = tokenizer.encode(
+    ///     "The capital of France",
+    ///     None,
+    ///     128,
+    ///     &TruncationStrategy::LongestFirst,
+    ///     0,
+    /// );
+    /// let is_stopword = stopword_filter.stopword_mask(&encoded.tokens.unwrap(), &encoded.mask);
+    /// ```
+    pub fn stopword_mask<S: AsRef<str>>(&self, tokens: &[S], masks: &[Mask]) -> Vec<bool> {
+        let mut is_stopword = vec![false; tokens.len()];
+        let mut word_start = 0;
+        while word_start < tokens.len() {
+            let mut word_end = word_start + 1;
+            while word_end < tokens.len() && masks[word_end] == Mask::Continuation {
+                word_end += 1;
+            }
+            if masks[word_start] != Mask::Special {
+                let word = tokens[word_start..word_end]
+                    .iter()
+                    .map(|token| {
+                        token
+                            .as_ref()
+                            .trim_start_matches(['\u{2581}', '\u{0120}'])
+                            .trim_start_matches("##")
+                    })
+                    .collect::<String>();
+                if self.is_stopword(&word) {
+                    is_stopword[word_start..word_end]
+                        .iter_mut()
+                        .for_each(|value| *value = true);
+                }
+            }
+            word_start = word_end;
+        }
+        is_stopword
+    }
+}
+
+impl TokenFilter for StopwordFilter {
+    fn filter(&self, mut tokens: TokensWithOffsets) -> TokensWithOffsets {
+        if self.handling == StopwordHandling::Drop {
+            let mut is_stopword = self
+                .stopword_mask(&tokens.tokens, &tokens.masks)
+                .into_iter();
+            tokens.retain(|_, _, _| !is_stopword.next().unwrap());
+        }
+        tokens
+    }
+}
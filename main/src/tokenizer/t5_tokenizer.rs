@@ -14,9 +14,13 @@ use std::path::Path;
 
 use crate::error::TokenizerError;
 use crate::tokenizer::tokenization_utils::{
-    clean_text, decompose_nfkc, is_whitespace, lowercase, split_on_special_tokens,
+    apply_unknown_piece_policy, clean_text, decompose_nfkc, is_whitespace, lowercase,
+    merge_byte_fallback_pieces, split_on_special_tokens,
+};
+use crate::tokenizer::{
+    ConfigurableTokenizer, MultiThreadedTokenizer, Tokenizer, TokenizerDescription,
+    TokenizerOptions, UnigramTokenizer, UnknownPiecePolicy,
 };
-use crate::tokenizer::{MultiThreadedTokenizer, Tokenizer};
 use crate::vocab::{SentencePieceModel, T5Vocab, Vocab};
 use crate::{Mask, Token, TokenIdsWithOffsets, TokenIdsWithSpecialTokens, TokenRef};
 
@@ -32,6 +36,7 @@ pub struct T5Tokenizer {
     vocab: T5Vocab,
     lower_case: bool,
     eos_token_id: i64,
+    options: TokenizerOptions,
 }
 
 impl T5Tokenizer {
@@ -62,6 +67,7 @@ impl T5Tokenizer {
             vocab,
             lower_case,
             eos_token_id,
+            options: TokenizerOptions::default(),
         })
     }
 
@@ -99,9 +105,36 @@ impl T5Tokenizer {
             vocab,
             lower_case,
             eos_token_id,
+            options: TokenizerOptions::default(),
         })
     }
 
+    /// Create a new instance of a `T5Tokenizer` from the bytes of a SentencePiece protobuf file,
+    /// allowing to embed the model in a self-contained binary (e.g. using `include_bytes!`).
+    ///
+    /// # Parameters
+    /// - bytes (`&[u8]`): content of the SentencePiece model file
+    /// - lower_case (`bool`): flag indicating if the text should be lower-cased as part of the tokenization
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::T5Tokenizer;
+    /// let lower_case = false;
+    /// let bytes = std::fs::read("path/to/spiece.model").unwrap();
+    /// let tokenizer = T5Tokenizer::from_protobuf_bytes(&bytes, lower_case).unwrap();
+    /// ```
+    pub fn from_protobuf_bytes(
+        bytes: &[u8],
+        lower_case: bool,
+    ) -> Result<T5Tokenizer, TokenizerError> {
+        let model = SentencePieceModel::from_protobuf_bytes(bytes)?;
+        let vocab = T5Vocab::from_protobuf_bytes(bytes)?;
+        Ok(Self::from_existing_vocab_and_model(
+            vocab, model, lower_case,
+        ))
+    }
+
     /// Create a new instance of a `T5Tokenizer` from an existing vocabulary and model
     ///
     /// # Parameters
@@ -131,6 +164,7 @@ impl T5Tokenizer {
             vocab,
             lower_case,
             eos_token_id,
+            options: TokenizerOptions::default(),
         }
     }
 
@@ -151,6 +185,17 @@ impl Tokenizer<T5Vocab> for T5Tokenizer {
         &mut self.vocab
     }
 
+    fn options(&self) -> &TokenizerOptions {
+        &self.options
+    }
+
+    fn describe(&self) -> TokenizerDescription {
+        TokenizerDescription {
+            lower_case: Some(self.lower_case),
+            ..TokenizerDescription::new("t5", &self.vocab)
+        }
+    }
+
     fn tokenize_to_tokens(&self, text: TokenRef) -> Vec<Token> {
         let mut tokens = split_on_special_tokens(text, &self.vocab)
             .into_iter()
@@ -179,10 +224,15 @@ impl Tokenizer<T5Vocab> for T5Tokenizer {
                 sub_tokens.push(token.clone());
             }
         }
-        sub_tokens
+        apply_unknown_piece_policy(sub_tokens, &self.vocab, self.unknown_piece_policy())
     }
 
     fn convert_tokens_to_string(&self, tokens: Vec<String>) -> String {
+        let tokens = if self.unknown_piece_policy() == UnknownPiecePolicy::ByteFallback {
+            merge_byte_fallback_pieces(tokens)
+        } else {
+            tokens
+        };
         tokens
             .into_iter()
             .map(|v| v.replace('\u{2581}', " "))
@@ -245,3 +295,15 @@ impl Tokenizer<T5Vocab> for T5Tokenizer {
 }
 
 impl MultiThreadedTokenizer<T5Vocab> for T5Tokenizer {}
+
+impl ConfigurableTokenizer<T5Vocab> for T5Tokenizer {
+    fn options_mut(&mut self) -> &mut TokenizerOptions {
+        &mut self.options
+    }
+}
+
+impl UnigramTokenizer<T5Vocab> for T5Tokenizer {
+    fn unigram_model(&self) -> &SentencePieceModel {
+        &self.model
+    }
+}
@@ -0,0 +1,159 @@
+// Copyright 2019 Guillaume Becquin
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//     http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tokenizer::base_tokenizer::{TokenizedInput, Tokenizer};
+use crate::tokenizer::tokenization_utils::ratio;
+use crate::vocab::Vocab;
+use std::convert::TryFrom;
+use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
+use std::time::{Duration, Instant};
+
+/// # Tokenization statistics
+/// Snapshot of the statistics aggregated by a `TokenizationStatsCollector` over the `encode_list`
+/// and `encode_pair_list` calls of a tokenizer, returned by `Tokenizer::stats`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TokenizationStats {
+    /// Number of inputs (texts or text pairs) encoded
+    pub num_inputs: u64,
+
+    /// Number of tokens in the encoded inputs, including the special tokens
+    pub num_tokens: u64,
+
+    /// Number of tokens removed from the encoded inputs by truncation
+    pub num_truncated_tokens: u64,
+
+    /// Number of tokens mapped to the unknown token
+    pub num_unknown_tokens: u64,
+
+    /// Time spent encoding the inputs
+    pub elapsed: Duration,
+
+    /// Number of BPE cache lookups (only for tokenizers relying on a `BpeCache`)
+    pub cache_lookups: Option<u64>,
+
+    /// Number of BPE cache lookups returning a cached value
+    pub cache_hits: Option<u64>,
+}
+
+impl TokenizationStats {
+    /// Returns the number of tokens produced per second of encoding (including truncated tokens)
+    pub fn tokens_per_second(&self) -> f64 {
+        let seconds = self.elapsed.as_secs_f64();
+        if seconds > 0.0 {
+            (self.num_tokens + self.num_truncated_tokens) as f64 / seconds
+        } else {
+            0.0
+        }
+    }
+
+    /// Returns the average number of tokens per input before truncation
+    pub fn average_tokens_per_input(&self) -> f64 {
+        ratio(self.num_tokens + self.num_truncated_tokens, self.num_inputs)
+    }
+
+    /// Returns the share of tokens of the encoded inputs mapped to the unknown token
+    pub fn unknown_token_rate(&self) -> f64 {
+        ratio(self.num_unknown_tokens, self.num_tokens)
+    }
+
+    /// Returns the share of BPE cache lookups returning a cached value, if the tokenizer has a cache
+    pub fn cache_hit_rate(&self) -> Option<f64> {
+        Some(ratio(self.cache_hits?, self.cache_lookups?))
+    }
+}
+
+/// # Tokenization statistics collector
+/// Thread-safe counters aggregating statistics over the `encode_list` and `encode_pair_list`
+/// calls of a tokenizer, updated by the `&self` encoding methods. Tokenizers only hold a collector
+/// once the collection is enabled with `ConfigurableTokenizer::set_collect_stats`.
+#[derive(Debug, Default)]
+pub struct TokenizationStatsCollector {
+    num_inputs: AtomicU64,
+    num_tokens: AtomicU64,
+    num_truncated_tokens: AtomicU64,
+    num_unknown_tokens: AtomicU64,
+    elapsed_nanos: AtomicU64,
+}
+
+impl TokenizationStatsCollector {
+    /// Creates a new statistics collector
+    pub fn new() -> TokenizationStatsCollector {
+        TokenizationStatsCollector::default()
+    }
+
+    /// Resets all aggregated values
+    pub fn reset(&self) {
+        self.num_inputs.store(0, AtomicOrdering::Relaxed);
+        self.num_tokens.store(0, AtomicOrdering::Relaxed);
+        self.num_truncated_tokens.store(0, AtomicOrdering::Relaxed);
+        self.num_unknown_tokens.store(0, AtomicOrdering::Relaxed);
+        self.elapsed_nanos.store(0, AtomicOrdering::Relaxed);
+    }
+
+    /// Records a batch of encoded inputs and the time spent encoding them
+    pub fn record(&self, encoded_inputs: &[TokenizedInput], unknown_id: i64, elapsed: Duration) {
+        let mut num_tokens = 0;
+        let mut num_truncated_tokens = 0;
+        let mut num_unknown_tokens = 0;
+        for encoded_input in encoded_inputs {
+            num_tokens += encoded_input.token_ids.len() as u64;
+            num_truncated_tokens += encoded_input.num_truncated_tokens as u64;
+            num_unknown_tokens += encoded_input
+                .token_ids
+                .iter()
+                .filter(|&&token_id| token_id == unknown_id)
+                .count() as u64;
+        }
+        self.num_inputs
+            .fetch_add(encoded_inputs.len() as u64, AtomicOrdering::Relaxed);
+        self.num_tokens
+            .fetch_add(num_tokens, AtomicOrdering::Relaxed);
+        self.num_truncated_tokens
+            .fetch_add(num_truncated_tokens, AtomicOrdering::Relaxed);
+        self.num_unknown_tokens
+            .fetch_add(num_unknown_tokens, AtomicOrdering::Relaxed);
+        self.elapsed_nanos.fetch_add(
+            u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX),
+            AtomicOrdering::Relaxed,
+        );
+    }
+
+    /// Returns a snapshot of the aggregated values
+    pub fn snapshot(&self) -> TokenizationStats {
+        TokenizationStats {
+            num_inputs: self.num_inputs.load(AtomicOrdering::Relaxed),
+            num_tokens: self.num_tokens.load(AtomicOrdering::Relaxed),
+            num_truncated_tokens: self.num_truncated_tokens.load(AtomicOrdering::Relaxed),
+            num_unknown_tokens: self.num_unknown_tokens.load(AtomicOrdering::Relaxed),
+            elapsed: Duration::from_nanos(self.elapsed_nanos.load(AtomicOrdering::Relaxed)),
+            cache_lookups: None,
+            cache_hits: None,
+        }
+    }
+}
+
+/// Records the inputs encoded by a tokenizer in its statistics collector, if any. `start` is the
+/// time at which the encoding started, `None` if statistics were disabled at that time.
+pub(crate) fn record_stats<T, U>(
+    tokenizer: &U,
+    encoded_inputs: &[TokenizedInput],
+    start: Option<Instant>,
+) where
+    T: Vocab,
+    U: Tokenizer<T> + ?Sized,
+{
+    if let (Some(start), Some(stats_collector)) = (start, tokenizer.stats_collector()) {
+        let unknown_id = tokenizer
+            .vocab()
+            .token_to_id(tokenizer.vocab().get_unknown_value());
+        stats_collector.record(encoded_inputs, unknown_id, start.elapsed());
+    }
+}
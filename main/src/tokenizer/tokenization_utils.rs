@@ -13,32 +13,168 @@
 // limitations under the License.
 
 use crate::error::TokenizerError;
-use crate::tokenizer::base_tokenizer::{TokenIdsWithOffsets, TruncationStrategy};
+use crate::tokenizer::base_tokenizer::{
+    BidiControlHandling, DigitNormalization, OffsetUnit, TokenIdsWithOffsets, TruncationStrategy,
+    UnknownPiecePolicy,
+};
 use crate::tokenizer::constants::{
     ACCENT_MARKERS, ADDITIONAL_WHITESPACE_CHARS, BYTES_TO_UNICODE, CONTROL_CHARS,
-    PUNCTUATION_CHARS, WHITESPACE_CHARS,
+    PUNCTUATION_CHARS, UNICODE_TO_BYTES, WHITESPACE_CHARS,
 };
 use crate::vocab::bpe_vocab::{BpePairRef, BpePairVocab};
 use crate::vocab::Vocab;
 use crate::{Mask, Offset, OffsetSize, Token, TokenRef};
 use regex::Regex;
 use std::borrow::BorrowMut;
+use std::cell::Cell;
 use std::char;
 use std::char::REPLACEMENT_CHARACTER;
 use std::cmp::{min, Ordering};
+use std::collections::hash_map::RandomState;
 use std::collections::{HashMap, HashSet};
-use std::sync::RwLock;
-use unicode_normalization::char::decompose_canonical;
+use std::hash::BuildHasher;
+use std::ops::Range;
+use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
+use std::sync::{RwLock, TryLockError};
+use unicode_normalization::char::{decompose_canonical, is_combining_mark};
 use unicode_normalization_alignments::UnicodeNormalization;
 
-pub type BpeCache = RwLock<HashMap<String, (Vec<String>, Vec<usize>)>>;
+/// Number of independently locked shards of the BPE cache
+const BPE_CACHE_SHARDS: usize = 16;
+
+type BpeCacheShard = RwLock<HashMap<String, (Vec<String>, Vec<usize>)>>;
+
+/// # BPE cache
+/// Cache of the BPE outputs (sub-tokens and their character counts), shared by the `&self`
+/// tokenization methods of the BPE tokenizers. The cache is split in shards protected by their own
+/// lock and its operations never block: a lookup is a cache miss if the shard is being updated by
+/// another thread, and an insertion is skipped if the shard is in use. Concurrent tokenization
+/// calls may duplicate some BPE computations, but never wait on each other. A lock poisoned by a
+/// panicking thread is recovered, as the cached entries are always inserted whole. The number of
+/// lookups and cache hits are counted for the tokenization statistics, once enabled with
+/// `set_count_lookups`.
+pub struct BpeCache {
+    shards: Vec<BpeCacheShard>,
+    hash_builder: RandomState,
+    count_lookups: bool,
+    num_lookups: AtomicU64,
+    num_hits: AtomicU64,
+}
+
+impl BpeCache {
+    pub fn new() -> BpeCache {
+        BpeCache {
+            shards: (0..BPE_CACHE_SHARDS)
+                .map(|_| RwLock::new(HashMap::new()))
+                .collect(),
+            hash_builder: RandomState::new(),
+            count_lookups: false,
+            num_lookups: AtomicU64::new(0),
+            num_hits: AtomicU64::new(0),
+        }
+    }
+
+    fn shard(&self, text: &str) -> &BpeCacheShard {
+        &self.shards[self.hash_builder.hash_one(text) as usize % BPE_CACHE_SHARDS]
+    }
+
+    /// Returns the cached BPE output for a text, if available without waiting
+    pub fn get(&self, text: &str) -> Option<(Vec<String>, Vec<usize>)> {
+        if self.count_lookups {
+            self.num_lookups.fetch_add(1, AtomicOrdering::Relaxed);
+        }
+        let shard = match self.shard(text).try_read() {
+            Ok(shard) => shard,
+            Err(TryLockError::Poisoned(poisoned)) => poisoned.into_inner(),
+            Err(TryLockError::WouldBlock) => return None,
+        };
+        let cached_output = shard.get(text).cloned();
+        if self.count_lookups && cached_output.is_some() {
+            self.num_hits.fetch_add(1, AtomicOrdering::Relaxed);
+        }
+        cached_output
+    }
+
+    /// Caches the BPE output for a text, unless the cache shard is in use by another thread
+    pub fn insert(&self, text: &str, bpe_output: &(Vec<String>, Vec<usize>)) {
+        let mut shard = match self.shard(text).try_write() {
+            Ok(shard) => shard,
+            Err(TryLockError::Poisoned(poisoned)) => poisoned.into_inner(),
+            Err(TryLockError::WouldBlock) => return,
+        };
+        shard.insert(text.to_owned(), bpe_output.clone());
+    }
+
+    /// Removes all the cached values, keeping the lookup and hit counters
+    pub fn clear(&mut self) {
+        for shard in self.shards.iter_mut() {
+            match shard.get_mut() {
+                Ok(shard) => shard.clear(),
+                Err(poisoned) => poisoned.into_inner().clear(),
+            }
+        }
+    }
+
+    /// Enables or disables the counting of the lookups and cache hits (disabled by default)
+    pub fn set_count_lookups(&mut self, count_lookups: bool) {
+        self.count_lookups = count_lookups;
+    }
+
+    /// Returns the number of lookups counted since the creation of the cache or the last counters
+    /// reset
+    pub fn num_lookups(&self) -> u64 {
+        self.num_lookups.load(AtomicOrdering::Relaxed)
+    }
+
+    /// Returns the number of lookups that returned a cached value
+    pub fn num_hits(&self) -> u64 {
+        self.num_hits.load(AtomicOrdering::Relaxed)
+    }
+
+    /// Resets the lookup and hit counters, keeping the cached values
+    pub fn reset_counters(&self) {
+        self.num_lookups.store(0, AtomicOrdering::Relaxed);
+        self.num_hits.store(0, AtomicOrdering::Relaxed);
+    }
+}
+
+impl Default for BpeCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the ratio of two counts, or 0 if the denominator is 0
+pub(crate) fn ratio(numerator: u64, denominator: u64) -> f64 {
+    if denominator > 0 {
+        numerator as f64 / denominator as f64
+    } else {
+        0.0
+    }
+}
 
 ///Cleans text by removing control characters and normalizing whitespace
 pub fn clean_text(token: &mut Token, strict: bool) {
+    clean_text_with_bidi_control_handling(token, strict, BidiControlHandling::Strip)
+}
+
+///Cleans text by removing control characters and normalizing whitespace. Bidirectional control
+/// characters are removed only if the handling is `BidiControlHandling::Strip`.
+pub fn clean_text_with_bidi_control_handling(
+    token: &mut Token,
+    strict: bool,
+    bidi_control_handling: BidiControlHandling,
+) {
+    let keep_bidi_controls = bidi_control_handling != BidiControlHandling::Strip;
     let capacity = token.text.capacity();
     let mut cleaned_string = String::with_capacity(capacity);
     let mut character_mapping: Vec<OffsetSize> = Vec::with_capacity(capacity);
     for (character, position) in token.text.chars().zip(token.reference_offsets.iter()) {
+        if keep_bidi_controls && is_bidi_control(&character) {
+            cleaned_string.push(character);
+            character_mapping.push(*position);
+            continue;
+        }
         if is_control(&character, strict)
             || character == '\x00'
             || character == REPLACEMENT_CHARACTER
@@ -84,27 +220,168 @@ pub fn replace_string(token: &mut Token, pattern: &str, replacement_string: &str
     }
 }
 
+thread_local! {
+    static SPECIAL_TOKEN_SPLITTING: Cell<bool> = const { Cell::new(true) };
+}
+
+/// Runs the function provided with the splitting on special tokens disabled on the current thread:
+/// special token values found in the text are tokenized as regular text. Used to tokenize
+/// untrusted content (e.g. the messages of a chat) that should not be able to inject special
+/// tokens.
+pub(crate) fn without_special_token_splitting<T>(function: impl FnOnce() -> T) -> T {
+    struct RestoreSplitting(bool);
+    impl Drop for RestoreSplitting {
+        fn drop(&mut self) {
+            SPECIAL_TOKEN_SPLITTING.with(|splitting| splitting.set(self.0));
+        }
+    }
+    let _restore =
+        RestoreSplitting(SPECIAL_TOKEN_SPLITTING.with(|splitting| splitting.replace(false)));
+    function()
+}
+
 ///Split a text on special tokens (like BOS/EOS/UNK markers), depending on the vocabulary
 pub fn split_on_special_tokens<'a>(token: TokenRef<'a>, vocab: &impl Vocab) -> Vec<TokenRef<'a>> {
+    if !SPECIAL_TOKEN_SPLITTING.with(Cell::get) {
+        return split_on_substr(token, |_| (0, 0, Mask::None), true);
+    }
     let test_substr = |s: &str| {
-        for special_value in vocab.special_values().keys() {
-            if s.starts_with(special_value.as_str()) {
-                return (
-                    special_value.len(),
-                    special_value.chars().count(),
-                    if vocab.get_unknown_value() == special_value.as_str() {
-                        Mask::Unknown
-                    } else {
-                        Mask::Special
-                    },
-                );
-            }
+        // The longest matching special token is selected so that the result does not depend on the
+        // iteration order of the special values (e.g. `<mask_1>` takes precedence over `<mask>`)
+        let longest_match = vocab
+            .special_values()
+            .keys()
+            .filter(|special_value| s.starts_with(special_value.as_str()))
+            .max_by_key(|special_value| special_value.len());
+        match longest_match {
+            Some(special_value) => (
+                special_value.len(),
+                special_value.chars().count(),
+                if vocab.get_unknown_value() == special_value.as_str() {
+                    Mask::Unknown
+                } else {
+                    Mask::Special
+                },
+            ),
+            None => (0, 0, Mask::None),
         }
-        (0, 0, Mask::None)
     };
     split_on_substr(token, test_substr, true)
 }
 
+///Split a text on emoji sequences, each sequence (including the zero-width joiners, variation
+/// selectors, skin tone modifiers and tags it contains) will be a token
+pub fn split_on_emoji_sequences(token: TokenRef) -> Vec<TokenRef> {
+    let test_substr = |s: &str| {
+        let (matched_bytes, matched_chars) = emoji_sequence_length(s);
+        (matched_bytes, matched_chars, Mask::None)
+    };
+    split_on_substr(token, test_substr, true)
+}
+
+/// Returns true if the text is made of a single emoji sequence (including the zero-width joiners,
+/// variation selectors, skin tone modifiers and tags it contains), as isolated by the tokenizers
+/// keeping emoji sequences intact (see `EmojiSequenceHandling`)
+///
+/// # Example
+///
+/// ```
+/// use rust_tokenizers::tokenizer::is_emoji_sequence;
+/// assert!(is_emoji_sequence("\u{1F469}\u{200D}\u{1F4BB}"));
+/// assert!(!is_emoji_sequence("hello"));
+/// ```
+pub fn is_emoji_sequence(text: &str) -> bool {
+    !text.is_empty() && emoji_sequence_length(text).0 == text.len()
+}
+
+/// Returns the length (in bytes and characters) of the emoji sequence at the start of the text,
+/// or (0, 0) if the text does not start with an emoji.
+fn emoji_sequence_length(text: &str) -> (usize, usize) {
+    let mut chars = text.char_indices().peekable();
+    let (mut end, mut char_count) = match chars.next() {
+        Some((_, first_char)) => (first_char.len_utf8(), 1),
+        None => return (0, 0),
+    };
+    let first_char = text.chars().next().unwrap();
+
+    if is_regional_indicator(&first_char) {
+        // flags are encoded as pairs of regional indicators
+        return match chars.next() {
+            Some((position, c)) if is_regional_indicator(&c) => (position + c.len_utf8(), 2),
+            _ => (end, char_count),
+        };
+    }
+    if matches!(first_char, '0'..='9' | '#' | '*') {
+        // keycap sequences, the base character must be followed by a combining enclosing keycap
+        if let Some(&(_, '\u{FE0F}')) = chars.peek() {
+            chars.next();
+            char_count += 1;
+        }
+        return match chars.next() {
+            Some((position, '\u{20E3}')) => (position + 3, char_count + 1),
+            _ => (0, 0),
+        };
+    }
+    if !is_emoji_char(&first_char) {
+        return (0, 0);
+    }
+
+    loop {
+        while let Some(&(position, c)) = chars.peek() {
+            if is_emoji_modifier(&c) {
+                end = position + c.len_utf8();
+                char_count += 1;
+                chars.next();
+            } else {
+                break;
+            }
+        }
+        match chars.peek() {
+            Some(&(_, '\u{200D}')) => {
+                let mut lookahead = chars.clone();
+                lookahead.next();
+                match lookahead.next() {
+                    Some((position, c)) if is_emoji_char(&c) => {
+                        end = position + c.len_utf8();
+                        char_count += 2;
+                        chars = lookahead;
+                    }
+                    _ => break,
+                }
+            }
+            _ => break,
+        }
+    }
+    (end, char_count)
+}
+
+fn is_emoji_char(character: &char) -> bool {
+    let u32_char = *character as u32;
+    (0x1F000..=0x1FAFF).contains(&u32_char)
+        | (0x2600..=0x27BF).contains(&u32_char)
+        | (0x2300..=0x23FF).contains(&u32_char)
+        | (0x2B00..=0x2BFF).contains(&u32_char)
+        | (0x2190..=0x21FF).contains(&u32_char)
+        | (0x25A0..=0x25FF).contains(&u32_char)
+        | [
+            0x00A9, 0x00AE, 0x203C, 0x2049, 0x2122, 0x2139, 0x24C2, 0x3030, 0x303D, 0x3297, 0x3299,
+        ]
+        .contains(&u32_char)
+}
+
+fn is_emoji_modifier(character: &char) -> bool {
+    let u32_char = *character as u32;
+    // variation selectors, skin tone modifiers, combining enclosing keycap and tag characters
+    (0xFE0E..=0xFE0F).contains(&u32_char)
+        | (0x1F3FB..=0x1F3FF).contains(&u32_char)
+        | (u32_char == 0x20E3)
+        | (0xE0020..=0xE007F).contains(&u32_char)
+}
+
+fn is_regional_indicator(character: &char) -> bool {
+    (0x1F1E6..=0x1F1FF).contains(&(*character as u32))
+}
+
 ///Tokenizes CJK characters, each character will be a token
 pub fn tokenize_cjk_chars(token: TokenRef) -> Vec<TokenRef> {
     split_on_char(token, is_cjk_char, true, Mask::CJK)
@@ -122,6 +399,20 @@ fn is_cjk_char(character: &char) -> bool {
         | (0x2F800..=0x2FA1F).contains(&u32_char)
 }
 
+///Split a text on bidirectional control characters, each character will be a special token
+pub fn split_on_bidi_controls(token: TokenRef) -> Vec<TokenRef> {
+    split_on_char(token, is_bidi_control, true, Mask::Special)
+}
+
+/// Checks if a character is a bidirectional text control character (Arabic letter mark,
+/// left-to-right and right-to-left marks, embeddings, overrides and isolates)
+pub fn is_bidi_control(character: &char) -> bool {
+    matches!(
+        *character as u32,
+        0x061C | 0x200E | 0x200F | 0x202A..=0x202E | 0x2066..=0x2069
+    )
+}
+
 pub fn is_whitespace(character: &char) -> bool {
     WHITESPACE_CHARS.contains(&(*character as u32))
 }
@@ -170,6 +461,150 @@ pub fn whitespace_tokenize(token: TokenRef) -> Vec<TokenRef> {
     split_on_char(token, is_whitespace, false, Mask::Whitespace)
 }
 
+///Checks if a word is an acronym (at least 2 cased characters, all upper-cased, e.g. "NASA") or a
+///mixed-case word (upper-cased characters after a lower-cased one, e.g. "iPhone" or "McDonald")
+pub fn is_acronym_or_mixed_case(text: &str) -> bool {
+    let mut num_upper = 0;
+    let mut num_lower = 0;
+    let mut has_internal_upper = false;
+    for character in text.chars() {
+        if character.is_uppercase() {
+            if num_lower > 0 {
+                has_internal_upper = true;
+            }
+            num_upper += 1;
+        } else if character.is_lowercase() {
+            num_lower += 1;
+        }
+    }
+    has_internal_upper || (num_lower == 0 && num_upper > 1)
+}
+
+///Splits the digits of a token into single-digit tokens. The leading characters of the token without
+///reference offsets (word piece continuation markers such as `##`, or word boundary markers added
+///by the tokenizer) are kept as a separate token if followed by a digit, except for `##` which is
+///removed. Tokens with more reference offsets than characters are left unchanged.
+fn split_digits(token: Token) -> Vec<Token> {
+    let num_chars = token.text.chars().count();
+    if token.mask == Mask::Special
+        || num_chars < token.reference_offsets.len()
+        || !token.text.chars().any(|c| c.is_ascii_digit())
+    {
+        return vec![token];
+    }
+    let num_markers = num_chars - token.reference_offsets.len();
+    if token
+        .text
+        .chars()
+        .take(num_markers)
+        .any(|c| c.is_alphanumeric())
+    {
+        return vec![token];
+    }
+    let mut pieces: Vec<(String, Vec<OffsetSize>)> = vec![];
+    let mut current_text = String::new();
+    let mut current_offsets = vec![];
+    for (position, character) in token.text.chars().enumerate() {
+        if character.is_ascii_digit() {
+            if !current_text.is_empty() && current_text != "##" {
+                pieces.push((current_text, current_offsets));
+            }
+            current_text = String::new();
+            current_offsets = vec![];
+            pieces.push((
+                character.to_string(),
+                vec![token.reference_offsets[position - num_markers]],
+            ));
+        } else {
+            current_text.push(character);
+            if position >= num_markers {
+                current_offsets.push(token.reference_offsets[position - num_markers]);
+            }
+        }
+    }
+    if !current_text.is_empty() {
+        pieces.push((current_text, current_offsets));
+    }
+    pieces
+        .into_iter()
+        .enumerate()
+        .map(|(index, (text, reference_offsets))| Token {
+            text,
+            offset: Offset {
+                begin: *reference_offsets.first().unwrap_or(&token.offset.begin),
+                end: reference_offsets
+                    .last()
+                    .map_or(token.offset.begin, |position| position + 1),
+            },
+            reference_offsets,
+            mask: if index == 0 {
+                token.mask
+            } else {
+                Mask::Continuation
+            },
+        })
+        .collect()
+}
+
+///Applies the digit normalization to a sequence of pre-tokenized tokens (see `DigitNormalization`).
+///Placeholders span all contiguous digits in the original text, including digits originally
+///spread over several tokens.
+pub fn normalize_digits(
+    tokens: Vec<Token>,
+    digit_normalization: &DigitNormalization,
+) -> Vec<Token> {
+    if *digit_normalization == DigitNormalization::None {
+        return tokens;
+    }
+    let mut output: Vec<Token> = Vec::with_capacity(tokens.len());
+    let mut previous_is_digit = false;
+    for piece in tokens.into_iter().flat_map(split_digits) {
+        let is_digit = piece.mask != Mask::Special
+            && piece.text.len() == 1
+            && piece.text.chars().all(|c| c.is_ascii_digit());
+        if let (DigitNormalization::Placeholder(placeholder), true) =
+            (digit_normalization, is_digit)
+        {
+            if let Some(previous) = output.last_mut().filter(|previous| {
+                previous_is_digit
+                    && previous
+                        .reference_offsets
+                        .last()
+                        .map(|position| position + 1)
+                        == piece.reference_offsets.first().copied()
+            }) {
+                previous.offset.end = piece.offset.end;
+                previous.reference_offsets.extend(piece.reference_offsets);
+                continue;
+            }
+            output.push(Token {
+                text: placeholder.clone(),
+                ..piece
+            });
+        } else {
+            output.push(piece);
+        }
+        previous_is_digit = is_digit;
+    }
+    output
+}
+
+/// Prepends a space to a token not starting with a whitespace, so that the first word of the
+/// token is encoded by byte-level BPE tokenizers as a word following a space. The space is mapped
+/// to the position of the first character of the token. Empty tokens are left unchanged.
+pub fn prefix_with_space(token: &mut Token) {
+    if token
+        .text
+        .chars()
+        .next()
+        .is_some_and(|c| !is_whitespace(&c))
+    {
+        let position = *token.reference_offsets.first().unwrap_or(&0);
+        token.text.insert(0, ' ');
+        token.reference_offsets.insert(0, position);
+    }
+}
+
 ///Lowercase
 pub fn lowercase(token: &mut Token) {
     let capacity = token.text.capacity();
@@ -309,6 +744,94 @@ where
     tokens
 }
 
+/// Splits a text into the words the merges of the byte-level BPE tokenizers (GPT2, RoBERTa) are
+/// applied to: special tokens are skipped, and the remaining text is lower-cased (if required) and
+/// split with the tokenization patterns. The bytes of each word are mapped to their unicode
+/// representation.
+pub(crate) fn byte_level_words(
+    text: &str,
+    vocab: &impl Vocab,
+    lower_case: bool,
+    add_prefix_space: bool,
+    pattern_lookahead: &Regex,
+    pattern_tokenization: &Regex,
+) -> Vec<String> {
+    let mut initial_token = Token::from(text);
+    if add_prefix_space {
+        prefix_with_space(&mut initial_token);
+    }
+    let mut words = Vec::new();
+    for token in split_on_special_tokens(initial_token.as_ref(), vocab) {
+        if token.mask == Mask::Special || token.mask == Mask::Unknown {
+            continue;
+        }
+        let mut token = token.to_owned();
+        if lower_case {
+            lowercase(&mut token);
+        }
+        for word in
+            split_on_regex_with_lookahead(token.as_ref(), pattern_lookahead, pattern_tokenization)
+        {
+            words.push(
+                word.text
+                    .as_bytes()
+                    .iter()
+                    .map(|byte| BYTES_TO_UNICODE.get(byte).unwrap())
+                    .collect(),
+            );
+        }
+    }
+    words
+}
+
+/// Continues the training of the merges of a byte-level BPE tokenizer (GPT2, RoBERTa) on a new
+/// corpus, pre-tokenized with `byte_level_words`. The merged symbols are added to the vocabulary
+/// and the BPE cache is cleared, as its entries may no longer match the updated merges.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn learn_byte_level_merges<I, S>(
+    corpus: I,
+    num_merges: usize,
+    vocab: &mut impl Vocab,
+    bpe_ranks: &mut BpePairVocab,
+    cache: &mut BpeCache,
+    lower_case: bool,
+    add_prefix_space: bool,
+    pattern_lookahead: &Regex,
+    pattern_tokenization: &Regex,
+) -> Vec<(String, String)>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut word_counts: HashMap<String, usize> = HashMap::new();
+    for text in corpus {
+        for word in byte_level_words(
+            text.as_ref(),
+            vocab,
+            lower_case,
+            add_prefix_space,
+            pattern_lookahead,
+            pattern_tokenization,
+        ) {
+            *word_counts.entry(word).or_insert(0) += 1;
+        }
+    }
+    let new_merges = bpe_ranks.learn_merges(&word_counts, num_merges);
+    let new_tokens = new_merges
+        .iter()
+        .map(|(first, second)| format!("{first}{second}"))
+        .collect::<Vec<String>>();
+    vocab.extend_vocabulary(
+        new_tokens
+            .iter()
+            .map(String::as_str)
+            .collect::<Vec<&str>>()
+            .as_slice(),
+    );
+    cache.clear();
+    new_merges
+}
+
 pub fn split_on_regex_with_lookahead<'a>(
     token: TokenRef<'a>,
     pattern_lookahead: &Regex,
@@ -382,6 +905,181 @@ pub fn split_on_regex<'a>(token: TokenRef<'a>, pattern_tokenization: &Regex) ->
     tokens
 }
 
+/// # Text pattern variants
+/// Patterns isolated into single tokens by a `PatternPreTokenizer`
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TextPattern {
+    /// URLs starting with `http://`, `https://` or `www.` (excluding trailing punctuation)
+    Url,
+    /// Email addresses (e.g. `user@example.com`)
+    Email,
+    /// Hashtags (e.g. `#rustlang`)
+    Hashtag,
+    /// @handles (e.g. `@rustlang`)
+    Handle,
+}
+
+impl TextPattern {
+    fn regex(&self) -> &'static str {
+        match self {
+            TextPattern::Url => r#"(?:https?://|www\.)[^\s<>"]*[^\s<>".,;:!?')\]}]"#,
+            TextPattern::Email => r"[\w.%+-]+@[\w-]+(?:\.[\w-]+)*\.\w{2,}",
+            TextPattern::Hashtag => r"\B#\w+",
+            TextPattern::Handle => r"\B@\w+",
+        }
+    }
+}
+
+/// # Pattern pre-tokenizer
+/// Regex-based pre-tokenizer isolating the text matching a set of patterns (such as URLs, emails,
+/// hashtags and @handles) into single tokens, with a configurable mask. Matches with a
+/// `Mask::Special` mask are protected from the normalization steps of the tokenizers (e.g. lower
+/// casing), while matches with a `Mask::None` mask go through these steps but are not split any
+/// further by the pre-tokenization. The `BaseTokenizer` and `BertTokenizer` accept a pattern
+/// pre-tokenizer, which may also be used on its own in custom pipelines.
+#[derive(Debug, Clone)]
+pub struct PatternPreTokenizer {
+    pattern: Regex,
+    mask: Mask,
+}
+
+impl PatternPreTokenizer {
+    /// Creates a new pattern pre-tokenizer for a set of predefined patterns. Patterns are matched
+    /// in the order provided when they overlap at the same position.
+    ///
+    /// # Parameters
+    /// - patterns (`&[TextPattern]`): patterns to isolate
+    /// - mask (`Mask`): mask of the tokens matching a pattern (typically `Mask::Special` or `Mask::None`)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_tokenizers::tokenizer::{PatternPreTokenizer, TextPattern};
+    /// use rust_tokenizers::{Mask, TokenRef};
+    /// let pre_tokenizer = PatternPreTokenizer::new(
+    ///     &[TextPattern::Url, TextPattern::Email, TextPattern::Handle],
+    ///     Mask::Special,
+    /// );
+    ///
+    /// let text = "Ask @maintainer at https://github.com";
+    /// let offsets = (0..text.chars().count() as u32).collect::<Vec<_>>();
+    /// let tokens = pre_tokenizer.split(TokenRef::new(text, &offsets));
+    /// assert_eq!(tokens[1].text, "@maintainer");
+    /// assert_eq!(tokens[3].text, "https://github.com");
+    /// ```
+    pub fn new(patterns: &[TextPattern], mask: Mask) -> PatternPreTokenizer {
+        let pattern = patterns
+            .iter()
+            .map(|pattern| pattern.regex())
+            .collect::<Vec<&str>>()
+            .join("|");
+        PatternPreTokenizer {
+            pattern: Regex::new(&pattern).unwrap(),
+            mask,
+        }
+    }
+
+    /// Creates a new pattern pre-tokenizer from a custom regular expression
+    ///
+    /// # Parameters
+    /// - pattern (`Regex`): regular expression matching the text to isolate
+    /// - mask (`Mask`): mask of the tokens matching the pattern (typically `Mask::Special` or `Mask::None`)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex::Regex;
+    /// use rust_tokenizers::tokenizer::PatternPreTokenizer;
+    /// use rust_tokenizers::Mask;
+    /// let ip_address_pattern = Regex::new(r"\b\d{1,3}(?:\.\d{1,3}){3}\b").unwrap();
+    /// let pre_tokenizer = PatternPreTokenizer::from_regex(ip_address_pattern, Mask::None);
+    /// ```
+    pub fn from_regex(pattern: Regex, mask: Mask) -> PatternPreTokenizer {
+        PatternPreTokenizer { pattern, mask }
+    }
+
+    /// Returns the mask of the tokens matching a pattern
+    pub fn mask(&self) -> Mask {
+        self.mask
+    }
+
+    /// Returns true if the entire text matches a pattern
+    pub fn is_match(&self, text: &str) -> bool {
+        self.pattern
+            .find(text)
+            .is_some_and(|hit| hit.start() == 0 && hit.end() == text.len())
+    }
+
+    /// Splits a token on the pattern matches. The matches are returned as single tokens with the
+    /// mask of the pre-tokenizer, and the rest of the text as tokens with the original mask.
+    /// Tokens with a mask other than `Mask::None` are returned unchanged.
+    ///
+    /// # Parameters
+    /// - token (`TokenRef`): token to split
+    ///
+    /// # Returns
+    /// `Vec<TokenRef>` with the split tokens
+    pub fn split<'a>(&self, token: TokenRef<'a>) -> Vec<TokenRef<'a>> {
+        self.split_with_matches(token)
+            .into_iter()
+            .map(|(token, _)| token)
+            .collect()
+    }
+
+    pub(crate) fn split_with_matches<'a>(&self, token: TokenRef<'a>) -> Vec<(TokenRef<'a>, bool)> {
+        if token.mask != Mask::None {
+            return vec![(token, false)];
+        }
+        let sub_token = |bytes: Range<usize>, chars: Range<usize>, mask: Mask| TokenRef {
+            text: &token.text[bytes],
+            offset: Offset::new(
+                token.offset.begin + chars.start as OffsetSize,
+                token.offset.begin + chars.end as OffsetSize,
+            ),
+            reference_offsets: &token.reference_offsets[chars],
+            mask,
+        };
+        let mut tokens = vec![];
+        let mut begin_byte = 0;
+        let mut begin_char = 0;
+        for hit in self.pattern.find_iter(token.text) {
+            if hit.start() == hit.end() {
+                continue;
+            }
+            let hit_begin_char = begin_char + token.text[begin_byte..hit.start()].chars().count();
+            let hit_end_char = hit_begin_char + hit.as_str().chars().count();
+            if hit_begin_char > begin_char {
+                tokens.push((
+                    sub_token(
+                        begin_byte..hit.start(),
+                        begin_char..hit_begin_char,
+                        token.mask,
+                    ),
+                    false,
+                ));
+            }
+            tokens.push((
+                sub_token(hit.range(), hit_begin_char..hit_end_char, self.mask),
+                true,
+            ));
+            begin_byte = hit.end();
+            begin_char = hit_end_char;
+        }
+        if begin_byte < token.text.len() {
+            let end_char = begin_char + token.text[begin_byte..].chars().count();
+            tokens.push((
+                sub_token(
+                    begin_byte..token.text.len(),
+                    begin_char..end_char,
+                    token.mask,
+                ),
+                false,
+            ));
+        }
+        tokens
+    }
+}
+
 pub fn split_at_regex<'a>(token: TokenRef<'a>, pattern_tokenization: &Regex) -> Vec<TokenRef<'a>> {
     let mut tokens: Vec<TokenRef<'a>> = Vec::new();
     let mut begin_char: usize = 0usize;
@@ -456,6 +1154,10 @@ where
         //iterate over all characters, returning the byte position with each
         for (char_idx, (bytes_idx, _)) in token.text.char_indices().enumerate() {
             char_count += 1;
+            if char_idx < char_begin {
+                //skip the characters of a multi-character match
+                continue;
+            }
             let (matched_bytes, matched_chars, set_mask): (usize, usize, Mask) =
                 test_substr(&token.text[bytes_idx..]);
             if matched_chars > 0 {
@@ -517,9 +1219,71 @@ where
     tokens
 }
 
+/// # Subword continuation marker
+/// Convention used by a vocabulary to mark the word pieces belonging to a word split into
+/// several subwords
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContinuationMarker {
+    /// Prefix added to all word pieces but the first (e.g. `##` for BERT: `una ##ffa ##ble`)
+    Prefix(String),
+    /// Suffix added to all word pieces but the last (e.g. `@@` for CTRL: `una@@ ffa@@ ble`)
+    Suffix(String),
+}
+
+impl Default for ContinuationMarker {
+    fn default() -> Self {
+        ContinuationMarker::Prefix("##".to_string())
+    }
+}
+
+impl ContinuationMarker {
+    fn mark(&self, word_piece: &str, is_first: bool, is_last: bool) -> String {
+        match self {
+            ContinuationMarker::Prefix(prefix) if !is_first => format!("{prefix}{word_piece}"),
+            ContinuationMarker::Suffix(suffix) if !is_last => format!("{word_piece}{suffix}"),
+            _ => word_piece.to_owned(),
+        }
+    }
+
+    /// Joins word pieces into a string, merging the subwords marked as belonging to the same word
+    ///
+    /// # Parameters
+    /// - tokens (`&[String]`): word pieces to join
+    ///
+    /// # Returns
+    /// - `String` with the words separated by a whitespace
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_tokenizers::tokenizer::ContinuationMarker;
+    /// let tokens = ["una@@", "ffa@@", "ble", "world"].map(String::from);
+    /// let text = ContinuationMarker::Suffix("@@".to_string()).join_word_pieces(&tokens);
+    /// assert_eq!(text, "unaffable world");
+    /// ```
+    pub fn join_word_pieces(&self, tokens: &[String]) -> String {
+        let text = tokens.join(" ");
+        let text = match self {
+            ContinuationMarker::Prefix(prefix) if !prefix.is_empty() => {
+                text.replace(&format!(" {prefix}"), "")
+            }
+            ContinuationMarker::Suffix(suffix) if !suffix.is_empty() => {
+                text.replace(&format!("{suffix} "), "")
+            }
+            _ => text,
+        };
+        text.trim().to_owned()
+    }
+}
+
 ///Tokenize a token into word pieces according to the supplied vocabulary
-///Continuation word pieces will all have the suffix `##`
-pub fn tokenize_wordpiece(token: TokenRef, vocab: &impl Vocab, max_word_len: usize) -> Vec<Token> {
+///Word pieces are marked following the `ContinuationMarker` convention of the vocabulary
+pub fn tokenize_wordpiece(
+    token: TokenRef,
+    vocab: &impl Vocab,
+    max_word_len: usize,
+    continuation_marker: &ContinuationMarker,
+) -> Vec<Token> {
     let mut tokens: Vec<Token> = Vec::new();
     if token.text.chars().count() > max_word_len {
         tokens.push(Token {
@@ -542,15 +1306,13 @@ pub fn tokenize_wordpiece(token: TokenRef, vocab: &impl Vocab, max_word_len: usi
             pos_end = char_indices.len(); //chars
             let mut is_unk: bool = true; //out of vocabulary? to be falsified
             while start < end {
-                let mut substr = token.text[start..end].to_owned();
+                let substr = &token.text[start..end];
                 let char_length = substr.chars().count();
                 let sub_offset = Offset {
                     begin: token.offset.begin + pos_begin as OffsetSize,
                     end: token.offset.begin + pos_begin as OffsetSize + char_length as OffsetSize,
                 };
-                if start > 0 {
-                    substr = format!("##{substr}");
-                }
+                let substr = continuation_marker.mark(substr, start == 0, end == max_end);
                 if vocab.values().contains_key(&substr) {
                     tokens.push(Token {
                         text: substr,
@@ -760,7 +1522,7 @@ pub fn truncate_sequences(
                     overflow_offsets,
                 ))
             }
-            TruncationStrategy::OnlySecond => Err(TokenizerError::ValueError(
+            TruncationStrategy::OnlySecond => Err(TokenizerError::UnsupportedOptionCombination(
                 "Invalid truncation strategy for single sentence truncation".into(),
             )),
             TruncationStrategy::DoNotTruncate => Err(TokenizerError::ValueError(
@@ -995,71 +1757,38 @@ where
         (token.text, token.reference_offsets)
     };
 
-    let cached: bool = if let Ok(ref mut cache) = cache.try_read() {
-        match cache.get(text) {
-            Some((cached_tokens, char_counts)) => {
-                let mut start = 0;
-                for (idx, (sub_token, &char_count)) in
-                    cached_tokens.iter().zip(char_counts.iter()).enumerate()
-                {
-                    tokens.push(Token {
-                        text: sub_token.clone(),
-                        offset: Offset {
-                            begin: reference_offsets[start],
-                            end: reference_offsets[start + char_count - 1] + 1,
-                        },
-                        reference_offsets: reference_offsets[start..start + char_count].to_vec(),
-                        mask: {
-                            if cached_tokens.len() > 1 {
-                                if idx == 0 {
-                                    Mask::Begin
-                                } else {
-                                    Mask::Continuation
-                                }
-                            } else {
-                                Mask::None
-                            }
-                        },
-                    });
-                    start += char_count;
-                }
-                true
-            }
-            None => false,
+    let (bpe_output, char_counts) = match cache.get(text) {
+        Some(cached_output) => cached_output,
+        None => {
+            let bpe_output = bpe_function(text, bpe_ranks);
+            cache.insert(text, &bpe_output);
+            bpe_output
         }
-    } else {
-        false
     };
 
-    if !cached {
-        let (bpe_output, char_counts) = bpe_function(text, bpe_ranks);
-        if let Ok(mut cache) = cache.try_write() {
-            cache.insert(text.to_owned(), (bpe_output.clone(), char_counts.clone()));
-        }
-        let mut start = 0;
-        for (idx, (sub_token, &char_count)) in bpe_output.iter().zip(char_counts.iter()).enumerate()
-        {
-            tokens.push(Token {
-                text: sub_token.clone(),
-                offset: Offset {
-                    begin: reference_offsets[start],
-                    end: reference_offsets[start + char_count - 1] + 1,
-                },
-                reference_offsets: reference_offsets[start..start + char_count].to_vec(),
-                mask: {
-                    if bpe_output.len() > 1 {
-                        if idx == 0 {
-                            Mask::Begin
-                        } else {
-                            Mask::Continuation
-                        }
+    let is_split = bpe_output.len() > 1;
+    let mut start = 0;
+    for (idx, (sub_token, char_count)) in bpe_output.into_iter().zip(char_counts).enumerate() {
+        tokens.push(Token {
+            text: sub_token,
+            offset: Offset {
+                begin: reference_offsets[start],
+                end: reference_offsets[start + char_count - 1] + 1,
+            },
+            reference_offsets: reference_offsets[start..start + char_count].to_vec(),
+            mask: {
+                if is_split {
+                    if idx == 0 {
+                        Mask::Begin
                     } else {
-                        Mask::None
+                        Mask::Continuation
                     }
-                },
-            });
-            start += char_count;
-        }
+                } else {
+                    Mask::None
+                }
+            },
+        });
+        start += char_count;
     }
     tokens
 }
@@ -1074,48 +1803,373 @@ pub fn fix_mask(tokens: &mut Vec<Token>) {
     }
 }
 
-pub(crate) fn split_on_language_code<'a>(
-    token: TokenRef<'a>,
-    code_length: usize,
-    language_codes_bytes: &HashSet<Vec<u8>>,
-) -> Vec<TokenRef<'a>> {
-    if token.text.as_bytes().len() < code_length {
-        return vec![token];
-    }
-    let mut tokens: Vec<TokenRef<'a>> = Vec::new();
-    let mut begin_char: usize = 0usize;
-    let mut start_byte: usize = 0usize;
-    let mut char_indices = token.text.char_indices();
-    for (c_start, c) in &mut char_indices {
-        if !c.is_whitespace() {
-            break;
+/// Byte-level BPE tokenization shared by the GPT2, RoBERTa and DeBERTa tokenizers: the special
+/// tokens are split out, the remaining text is (optionally) lower-cased, pre-tokenized with the
+/// provided patterns and encoded with the BPE merges.
+pub(crate) fn tokenize_byte_level_bpe(
+    token: TokenRef,
+    vocab: &impl Vocab,
+    bpe_ranks: &BpePairVocab,
+    cache: &BpeCache,
+    pattern_lookahead: &Regex,
+    pattern_tokenization: &Regex,
+    lower_case: bool,
+) -> Vec<Token> {
+    let mut tokens = split_on_special_tokens(token, vocab)
+        .into_iter()
+        .map(|token| token.to_owned())
+        .collect::<Vec<Token>>();
+
+    let mut sub_tokens = Vec::new();
+    for token in tokens.iter_mut() {
+        if token.mask != Mask::Special && token.mask != Mask::Unknown {
+            if lower_case {
+                lowercase(token);
+            }
+            for token in split_on_regex_with_lookahead(
+                token.as_ref(),
+                pattern_lookahead,
+                pattern_tokenization,
+            ) {
+                sub_tokens.extend(split_on_bpe_pairs(token, bpe, bpe_ranks, cache, true));
+            }
+        } else {
+            sub_tokens.push(token.clone());
+        }
+    }
+
+    fix_mask(&mut sub_tokens);
+    sub_tokens
+}
+
+///Split a token on punctuation following the Moses tokenizer rules:
+/// * punctuation (except periods, apostrophes, commas and hyphens) is split into separate tokens
+/// * commas are split unless they are surrounded by digits
+/// * apostrophes followed by a letter start a new token (e.g. `don't` -> `don`, `'t`)
+/// * trailing periods are split, unless they end an abbreviation (e.g. `U.S.`) or an initial
+///   within the sentence. Language-specific non-breaking prefixes are not supported.
+/// * (optional) hyphens between alphanumeric characters are split and replaced by `@-@`
+pub fn moses_tokenize(token: TokenRef, aggressive_dash_splits: bool) -> Vec<Token> {
+    let words = whitespace_tokenize(token);
+    let num_words = words.len();
+    let mut tokens: Vec<Token> = Vec::new();
+    for (word_idx, word) in words.into_iter().enumerate() {
+        let chars: Vec<char> = word.text.chars().collect();
+        if word.mask != Mask::None {
+            tokens.push(word.to_owned());
+            continue;
+        }
+        let is_alphanumeric = |position: Option<usize>| {
+            position
+                .and_then(|p| chars.get(p))
+                .is_some_and(|c| c.is_alphanumeric())
+        };
+        let is_digit = |position: Option<usize>| {
+            position
+                .and_then(|p| chars.get(p))
+                .is_some_and(|c| c.is_numeric())
+        };
+        let mut spans: Vec<(usize, usize, Mask)> = Vec::new();
+        let mut begin = 0;
+        for (position, character) in chars.iter().enumerate() {
+            let prev = position.checked_sub(1);
+            let next = Some(position + 1);
+            // (split before, split after)
+            let (split_before, split_after) = match character {
+                c if c.is_alphanumeric() || *c == '_' || *c == '.' => (false, false),
+                '-' => {
+                    let split =
+                        aggressive_dash_splits & is_alphanumeric(prev) & is_alphanumeric(next);
+                    (split, split)
+                }
+                ',' => {
+                    let split = !(is_digit(prev) & is_digit(next));
+                    (split, split)
+                }
+                '\'' | '`' => {
+                    let is_contraction = is_alphanumeric(prev)
+                        & chars.get(position + 1).is_some_and(|c| c.is_alphabetic());
+                    (true, !is_contraction)
+                }
+                _ => (true, true),
+            };
+            if split_before {
+                if begin < position {
+                    spans.push((begin, position, Mask::None));
+                }
+                begin = position;
+            }
+            if split_after {
+                spans.push((begin, position + 1, Mask::Punctuation));
+                begin = position + 1;
+            }
+        }
+        if begin < chars.len() {
+            spans.push((begin, chars.len(), Mask::None));
+        }
+
+        if let Some(&(span_begin, span_end, Mask::None)) = spans.last() {
+            let num_periods = chars[span_begin..span_end]
+                .iter()
+                .rev()
+                .take_while(|c| **c == '.')
+                .count();
+            if num_periods > 0 {
+                let stem = &chars[span_begin..span_end - num_periods];
+                let is_abbreviation = (num_periods == 1)
+                    & (word_idx + 1 < num_words)
+                    & ((stem.contains(&'.') & stem.iter().any(|c| c.is_alphabetic()))
+                        | ((stem.len() == 1) & stem.iter().all(|c| c.is_uppercase())));
+                if stem.is_empty() {
+                    spans.last_mut().unwrap().2 = Mask::Punctuation;
+                } else if !is_abbreviation {
+                    spans.pop();
+                    spans.push((span_begin, span_end - num_periods, Mask::None));
+                    spans.push((span_end - num_periods, span_end, Mask::Punctuation));
+                }
+            }
+        }
+
+        for (span_begin, span_end, mask) in spans {
+            let is_dash_split = aggressive_dash_splits
+                & (mask == Mask::Punctuation)
+                & (span_end - span_begin == 1)
+                & (chars[span_begin] == '-')
+                & is_alphanumeric(span_begin.checked_sub(1))
+                & is_alphanumeric(Some(span_end));
+            let (text, reference_offsets) = if is_dash_split {
+                (
+                    "@-@".to_string(),
+                    vec![word.reference_offsets[span_begin]; 3],
+                )
+            } else {
+                (
+                    chars[span_begin..span_end].iter().collect::<String>(),
+                    word.reference_offsets[span_begin..span_end].to_vec(),
+                )
+            };
+            tokens.push(Token {
+                text,
+                offset: Offset {
+                    begin: word.offset.begin + span_begin as OffsetSize,
+                    end: word.offset.begin + span_end as OffsetSize,
+                },
+                reference_offsets,
+                mask,
+            });
+        }
+    }
+    tokens
+}
+
+fn is_currency_symbol(character: &char) -> bool {
+    matches!(
+        character,
+        '$' | '¢' | '£' | '¤' | '¥' | '\u{20A0}'..='\u{20CF}'
+    )
+}
+
+/// Returns a mask indicating, for each character of a tokenized text, if the character is kept by
+/// the Moses detokenization rules. Detokenization only removes characters (whitespaces between words
+/// and the `@` of aggressive hyphen splits), allowing it to be applied to text split in pieces.
+fn moses_detokenization_mask(characters: &[char], language: &str) -> Vec<bool> {
+    let mut words: Vec<Range<usize>> = Vec::new();
+    let mut position = 0;
+    while position < characters.len() {
+        if is_whitespace(&characters[position]) {
+            position += 1;
+            continue;
+        }
+        let begin = position;
+        while position < characters.len() && !is_whitespace(&characters[position]) {
+            position += 1;
+        }
+        words.push(begin..position);
+    }
+
+    let is_french = language == "fr";
+    let is_elision_language = matches!(language, "fr" | "it" | "ca");
+    let is_cjk_language = matches!(language, "zh" | "ja");
+    let mut keep = vec![false; characters.len()];
+    let mut attach_next = true;
+    let mut num_double_quotes = 0;
+    let mut num_single_quotes = 0;
+    for (word_index, word_range) in words.iter().enumerate() {
+        let word = &characters[word_range.clone()];
+        let previous_word = word_index
+            .checked_sub(1)
+            .map(|index| &characters[words[index].clone()]);
+        let next_word = words
+            .get(word_index + 1)
+            .map(|range| &characters[range.clone()]);
+        keep[word_range.clone()]
+            .iter_mut()
+            .for_each(|kept| *kept = true);
+
+        let mut attach_left = attach_next;
+        attach_next = false;
+        if word == ['@', '-', '@'] {
+            keep[word_range.start] = false;
+            keep[word_range.start + 2] = false;
+            attach_left = true;
+            attach_next = true;
+        } else if word
+            .iter()
+            .all(|c| is_currency_symbol(c) || matches!(c, '(' | '[' | '{' | '¿' | '¡'))
+            || (!is_french && word == ['«'])
+        {
+            attach_next = true;
+        } else if (word.iter().all(|c| {
+            matches!(
+                c,
+                ',' | '.' | '?' | '!' | ':' | ';' | '%' | ')' | ']' | '}' | '…'
+            )
+        }) && !(is_french && matches!(word, ['?'] | ['!'] | [':'] | [';'] | ['%'])))
+            || (!is_french && word == ['»'])
+        {
+            attach_left = true;
+        } else if word == ['"'] {
+            num_double_quotes += 1;
+            if num_double_quotes % 2 == 1 {
+                attach_next = true;
+            } else {
+                attach_left = true;
+            }
+        } else if word == ['\''] {
+            num_single_quotes += 1;
+            if num_single_quotes % 2 == 1 {
+                attach_next = true;
+            } else {
+                attach_left = true;
+            }
+        } else if let Some(previous_char) = previous_word.and_then(|previous| previous.last()) {
+            let is_contraction = language == "en"
+                && word[0] == '\''
+                && word.get(1).is_some_and(|c| c.is_alphabetic())
+                && previous_char.is_alphanumeric();
+            let is_cjk_continuation =
+                is_cjk_language && is_cjk_char(&word[0]) && is_cjk_char(previous_char);
+            attach_left |= is_contraction | is_cjk_continuation;
+        }
+        if is_elision_language
+            && word.len() > 1
+            && word.last() == Some(&'\'')
+            && word[..word.len() - 1].iter().all(|c| c.is_alphabetic())
+            && next_word
+                .and_then(|next| next.first())
+                .is_some_and(|c| c.is_alphabetic())
+        {
+            attach_next = true;
+        }
+        if !attach_left {
+            keep[words[word_index - 1].end] = true;
         }
-        start_byte = c_start;
-        begin_char += 1;
     }
-    let leading_bytes = &token.text.as_bytes()[start_byte..start_byte + code_length];
-    if language_codes_bytes.contains(leading_bytes) {
+    keep
+}
+
+/// Detokenizes a text following the Moses detokenizer rules, as a language-aware alternative to the
+/// English-centric tokenization clean-up:
+/// * whitespaces are collapsed and stripped
+/// * punctuation is re-attached to the previous word (`,`, `.`, `?`, `!`, `:`, `;`, `%`, closing
+///   brackets), opening brackets and currency symbols to the next word
+/// * double and single quotes are alternately attached to the next (opening) and previous (closing)
+///   word
+/// * aggressive hyphen splits (`@-@`) are merged back into hyphens
+/// * language-specific rules: English contractions (`it 's` -> `it's`), French, Italian and
+///   Catalan elisions (`l' homme` -> `l'homme`), French typography (spaces kept before `?`, `!`,
+///   `:`, `;`, `%` and around guillemets) and Chinese and Japanese text written without spaces
+///
+/// # Parameters
+/// - text (`&str`): tokenized text, with words separated by whitespaces
+/// - language (`&str`): ISO 639-1 code of the language of the text
+///
+/// # Example
+///
+/// ```
+/// use rust_tokenizers::tokenizer::moses_detokenize;
+/// let detokenized = moses_detokenize("Il dit : \" l' homme @-@ orchestre ! \"", "fr");
+///
+/// assert_eq!(detokenized, "Il dit : \"l'homme-orchestre !\"");
+/// ```
+pub fn moses_detokenize(text: &str, language: &str) -> String {
+    let characters = text.chars().collect::<Vec<char>>();
+    let keep = moses_detokenization_mask(&characters, language);
+    characters
+        .into_iter()
+        .zip(keep)
+        .filter_map(|(character, kept)| kept.then_some(character))
+        .collect()
+}
+
+/// Applies the Moses detokenization (see `moses_detokenize`) to a text split in pieces (for example
+/// the decoded text of each token). The concatenation of the output pieces is identical to the
+/// detokenization of the concatenated input pieces, and each output piece only contains characters
+/// originating from the corresponding input piece.
+pub fn moses_detokenize_pieces(pieces: Vec<String>, language: &str) -> Vec<String> {
+    let characters = pieces
+        .iter()
+        .flat_map(|piece| piece.chars())
+        .collect::<Vec<char>>();
+    let mut keep = moses_detokenization_mask(&characters, language).into_iter();
+    pieces
+        .into_iter()
+        .map(|piece| {
+            piece
+                .chars()
+                .zip(keep.by_ref())
+                .filter_map(|(character, kept)| kept.then_some(character))
+                .collect()
+        })
+        .collect()
+}
+
+pub(crate) fn split_on_language_code<'a>(
+    token: TokenRef<'a>,
+    code_length: usize,
+    language_codes_bytes: &HashSet<Vec<u8>>,
+) -> Vec<TokenRef<'a>> {
+    // Position (in bytes and characters) of the last leading whitespace of a text, which is kept
+    // as a prefix of the text following the language code
+    fn last_leading_whitespace(text: &str) -> (usize, usize) {
+        let mut position = (0, 0);
+        for (char_idx, (byte_idx, character)) in text.char_indices().enumerate() {
+            if !character.is_whitespace() {
+                break;
+            }
+            position = (byte_idx, char_idx);
+        }
+        position
+    }
+
+    let mut tokens: Vec<TokenRef<'a>> = Vec::new();
+    let (mut start_byte, mut begin_char) = last_leading_whitespace(token.text);
+    let (code_byte, code_char) = match token
+        .text
+        .char_indices()
+        .enumerate()
+        .find(|(_, (_, character))| !character.is_whitespace())
+    {
+        Some((char_idx, (byte_idx, _))) => (byte_idx, char_idx),
+        // empty or whitespace-only input
+        None => return vec![token],
+    };
+    let code_end = code_byte + code_length;
+    if code_end <= token.text.len()
+        && language_codes_bytes.contains(&token.text.as_bytes()[code_byte..code_end])
+    {
         tokens.push(TokenRef {
-            text: &token.text[start_byte..start_byte + code_length],
+            text: &token.text[code_byte..code_end],
             offset: Offset::new(
-                token.offset.begin + begin_char as OffsetSize,
-                token.offset.begin + begin_char as OffsetSize + code_length as OffsetSize,
+                token.offset.begin + code_char as OffsetSize,
+                token.offset.begin + (code_char + code_length) as OffsetSize,
             ),
-            reference_offsets: &token.reference_offsets[begin_char..begin_char + code_length],
+            reference_offsets: &token.reference_offsets[code_char..code_char + code_length],
             mask: Mask::Special,
         });
-        start_byte += code_length;
-        begin_char += code_length;
-        for _ in 0..code_length {
-            char_indices.next();
-        }
-        for (c_start, c) in char_indices {
-            if !c.is_whitespace() {
-                break;
-            }
-            start_byte = c_start;
-            begin_char += 1;
-        }
+        let (whitespace_byte, whitespace_char) = last_leading_whitespace(&token.text[code_end..]);
+        start_byte = code_end + whitespace_byte;
+        begin_char = code_char + code_length + whitespace_char;
     }
     tokens.push(TokenRef {
         text: &token.text[start_byte..],
@@ -1129,29 +2183,374 @@ pub(crate) fn split_on_language_code<'a>(
     tokens
 }
 
+/// Checks that a language code is part of the language codes of a vocabulary. The error returned
+/// for unknown codes lists the supported language codes, sorted alphabetically.
+pub(crate) fn validate_language_code(
+    code: &str,
+    language_codes_bytes: &HashSet<Vec<u8>>,
+) -> Result<(), TokenizerError> {
+    if language_codes_bytes.contains(code.as_bytes()) {
+        Ok(())
+    } else {
+        let mut supported = language_codes_bytes
+            .iter()
+            .map(|code| String::from_utf8_lossy(code).into_owned())
+            .collect::<Vec<String>>();
+        supported.sort_unstable();
+        Err(TokenizerError::LanguageCodeNotFound {
+            code: code.to_string(),
+            supported,
+        })
+    }
+}
+
+/// Checks the language code prefixing a text, if any. `language_code_prefix` extracts the
+/// candidate language code from the start of the text (ignoring leading whitespace), which is then
+/// validated against the language codes of the vocabulary. Texts without a language code prefix
+/// are valid.
+pub(crate) fn validate_language_code_prefix<'a>(
+    text: &'a str,
+    language_codes_bytes: &HashSet<Vec<u8>>,
+    language_code_prefix: impl Fn(&'a str) -> Option<&'a str>,
+) -> Result<(), TokenizerError> {
+    match language_code_prefix(text.trim_start()) {
+        Some(code) => validate_language_code(code, language_codes_bytes),
+        None => Ok(()),
+    }
+}
+
+/// Language code formatted as `>>xx<<` (MBart50, M2M100) at the start of a text
+pub(crate) fn chevron_language_code_prefix(text: &str) -> Option<&str> {
+    let first_word = text.split_whitespace().next()?;
+    let code_end = first_word.strip_prefix(">>")?.find("<<")? + 4;
+    Some(&first_word[..code_end])
+}
+
+/// Language code formatted as `xxx_Yyyy` (NLLB, language followed by script) at the start of a text
+pub(crate) fn script_language_code_prefix(text: &str) -> Option<&str> {
+    let bytes = text.as_bytes();
+    if bytes.len() >= 8
+        && bytes[..3].iter().all(u8::is_ascii_lowercase)
+        && bytes[3] == b'_'
+        && bytes[4].is_ascii_uppercase()
+        && bytes[5..8].iter().all(u8::is_ascii_lowercase)
+        && !bytes.get(8).is_some_and(u8::is_ascii_alphanumeric)
+    {
+        Some(&text[..8])
+    } else {
+        None
+    }
+}
+
+/// Decomposes a token not found in the vocabulary into the `<0xNN>` pieces of its UTF-8 bytes, as
+/// done by SentencePiece models trained with byte fallback. Each byte piece points to the
+/// character of the original token it was generated from. Returns `None` for special tokens and
+/// tokens found in the vocabulary.
 pub(crate) fn unknown_byte_fallback<T: Vocab>(token: TokenRef, vocab: &T) -> Option<Vec<Token>> {
-    if !vocab.values().contains_key(token.text) {
-        let mut updated_tokens = Vec::new();
-        for byte in token
-            .text
-            .bytes()
-            .map(|byte| format!("<{byte:#04X?}>"))
-            .collect::<Vec<String>>()
-        {
+    if token.mask == Mask::Special || vocab.values().contains_key(token.text) {
+        return None;
+    }
+    let mut updated_tokens = Vec::new();
+    for (char_index, character) in token.text.chars().enumerate() {
+        let reference_offset = token
+            .reference_offsets
+            .get(char_index)
+            .copied()
+            .unwrap_or(token.offset.begin);
+        let mut buffer = [0; 4];
+        for byte in character.encode_utf8(&mut buffer).bytes() {
             updated_tokens.push(Token {
-                text: byte,
-                offset: Offset {
-                    begin: token.offset.end,
-                    end: token.offset.end,
-                },
-                reference_offsets: vec![*token.reference_offsets.last().unwrap()],
+                text: format!("<{byte:#04X?}>"),
+                offset: Offset::new(reference_offset, reference_offset + 1),
+                reference_offsets: vec![reference_offset],
                 mask: token.mask,
             });
         }
-        Some(updated_tokens)
-    } else {
-        None
     }
+    Some(updated_tokens)
+}
+
+/// Removes the tokens not found in the vocabulary. Special tokens are left unchanged.
+pub(crate) fn drop_unknown_tokens<T: Vocab>(tokens: Vec<Token>, vocab: &T) -> Vec<Token> {
+    tokens
+        .into_iter()
+        .filter(|token| token.mask == Mask::Special || vocab.values().contains_key(&token.text))
+        .collect()
+}
+
+/// Applies the unknown piece policy to the pieces produced by a SentencePiece model. Special tokens
+/// are left unchanged.
+pub(crate) fn apply_unknown_piece_policy<T: Vocab>(
+    tokens: Vec<Token>,
+    vocab: &T,
+    unknown_piece_policy: UnknownPiecePolicy,
+) -> Vec<Token> {
+    match unknown_piece_policy {
+        UnknownPiecePolicy::ReplaceWithUnk => tokens,
+        UnknownPiecePolicy::SkipToken => drop_unknown_tokens(tokens, vocab),
+        UnknownPiecePolicy::ByteFallback => tokens
+            .into_iter()
+            .flat_map(|token| {
+                unknown_byte_fallback(token.as_ref(), vocab).unwrap_or_else(|| vec![token])
+            })
+            .collect(),
+    }
+}
+
+/// Returns the byte encoded by a `<0xNN>` byte fallback piece, if the token is such a piece
+pub(crate) fn parse_byte_piece(token: &str) -> Option<u8> {
+    token
+        .strip_prefix("<0x")
+        .and_then(|token| token.strip_suffix('>'))
+        .filter(|hex| hex.len() == 2)
+        .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+}
+
+/// Merges consecutive `<0xNN>` byte fallback pieces back into the string they encode. Invalid UTF-8
+/// sequences are replaced by `U+FFFD`.
+pub(crate) fn merge_byte_fallback_pieces(tokens: Vec<String>) -> Vec<String> {
+    let mut output = Vec::with_capacity(tokens.len());
+    let mut bytes = Vec::new();
+    for token in tokens {
+        match parse_byte_piece(&token) {
+            Some(byte) => bytes.push(byte),
+            None => {
+                if !bytes.is_empty() {
+                    output.push(String::from_utf8_lossy(&bytes).into_owned());
+                    bytes.clear();
+                }
+                output.push(token);
+            }
+        }
+    }
+    if !bytes.is_empty() {
+        output.push(String::from_utf8_lossy(&bytes).into_owned());
+    }
+    output
+}
+
+/// Converts a byte buffer to a string, replacing invalid UTF-8 sequences with `U+FFFD` (following
+/// the same rules as `String::from_utf8_lossy`). Also returns the position in the original buffer
+/// of each character of the converted string, followed by the length of the buffer, allowing to
+/// map character offsets in the converted string back to byte offsets in the original buffer.
+pub fn decode_utf8_lossy_with_offsets(bytes: &[u8]) -> (String, Vec<usize>) {
+    let mut text = String::with_capacity(bytes.len());
+    let mut byte_positions = Vec::with_capacity(bytes.len() + 1);
+    let mut start = 0;
+    while start < bytes.len() {
+        let (valid_end, invalid_end) = match std::str::from_utf8(&bytes[start..]) {
+            Ok(_) => (bytes.len(), bytes.len()),
+            Err(error) => {
+                let valid_end = start + error.valid_up_to();
+                (
+                    valid_end,
+                    error
+                        .error_len()
+                        .map_or(bytes.len(), |error_len| valid_end + error_len),
+                )
+            }
+        };
+        if let Ok(valid) = std::str::from_utf8(&bytes[start..valid_end]) {
+            for (position, character) in valid.char_indices() {
+                text.push(character);
+                byte_positions.push(start + position);
+            }
+        }
+        if invalid_end > valid_end {
+            text.push(REPLACEMENT_CHARACTER);
+            byte_positions.push(valid_end);
+        }
+        start = invalid_end;
+    }
+    byte_positions.push(bytes.len());
+    (text, byte_positions)
+}
+
+/// Returns the length of the longest prefix of `bytes` that does not end with an incomplete
+/// multi-byte UTF-8 character. Invalid sequences are left in the prefix.
+pub(crate) fn complete_utf8_length(bytes: &[u8]) -> usize {
+    for (position, byte) in bytes.iter().enumerate().rev().take(4) {
+        let expected_length = match byte {
+            0x00..=0x7F => return bytes.len(),
+            0x80..=0xBF => continue,
+            0xC0..=0xDF => 2,
+            0xE0..=0xEF => 3,
+            _ => 4,
+        };
+        return if bytes.len() - position < expected_length {
+            position
+        } else {
+            bytes.len()
+        };
+    }
+    bytes.len()
+}
+
+/// Maps the characters of byte-level BPE tokens back to the bytes they encode. Characters that are
+/// not part of the byte-level alphabet (for example in added tokens) are kept as UTF-8.
+pub(crate) fn byte_level_tokens_to_bytes(tokens: &[String]) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for character in tokens.iter().flat_map(|token| token.chars()) {
+        match UNICODE_TO_BYTES.get(&character) {
+            Some(byte) => bytes.push(*byte),
+            None => {
+                let mut buffer = [0u8; 4];
+                bytes.extend_from_slice(character.encode_utf8(&mut buffer).as_bytes());
+            }
+        }
+    }
+    bytes
+}
+
+/// Splits a text in chunks of at most `chunk_chars` characters, returning the position (in
+/// characters) of each chunk in the text. Chunks are split before the last whitespace of the window
+/// if any, so that words are not cut across chunks. Fails with a `ValueError` if `chunk_chars` is 0.
+pub(crate) fn split_in_chunks(
+    text: &str,
+    chunk_chars: usize,
+) -> Result<Vec<(usize, &str)>, TokenizerError> {
+    if chunk_chars == 0 {
+        return Err(TokenizerError::ValueError(
+            "chunk size must be positive".to_string(),
+        ));
+    }
+    let mut chunks = Vec::new();
+    let (mut chunk_byte_start, mut chunk_char_start) = (0, 0);
+    let mut last_whitespace = None;
+    for (char_index, (byte_index, character)) in text.char_indices().enumerate() {
+        if char_index - chunk_char_start == chunk_chars {
+            let (split_byte, split_char) =
+                last_whitespace.take().unwrap_or((byte_index, char_index));
+            chunks.push((chunk_char_start, &text[chunk_byte_start..split_byte]));
+            chunk_byte_start = split_byte;
+            chunk_char_start = split_char;
+        }
+        if char_index > chunk_char_start && is_whitespace(&character) {
+            last_whitespace = Some((byte_index, char_index));
+        }
+    }
+    chunks.push((chunk_char_start, &text[chunk_byte_start..]));
+    Ok(chunks)
+}
+
+/// Returns the position of each character of a text expressed in the given offset unit, followed by
+/// the length of the text in that unit, allowing to map character offsets to the offset unit.
+pub(crate) fn offset_unit_positions(text: &str, offset_unit: OffsetUnit) -> Vec<usize> {
+    let mut positions = Vec::with_capacity(text.len() + 1);
+    let mut position = 0;
+    for character in text.chars() {
+        positions.push(position);
+        position += match offset_unit {
+            OffsetUnit::Char => 1,
+            OffsetUnit::Byte => character.len_utf8(),
+            OffsetUnit::Utf16 => character.len_utf16(),
+        };
+    }
+    positions.push(position);
+    positions
+}
+
+/// Maps the offset and reference offsets of a token to new positions. If any position can not be
+/// mapped (for example because it can not be represented as an `OffsetSize`), the token can not be
+/// related to the source text and is given a `None` offset with empty reference offsets.
+pub(crate) fn map_offsets<F>(
+    offset: Option<Offset>,
+    reference_offsets: Vec<OffsetSize>,
+    map_position: F,
+) -> (Option<Offset>, Vec<OffsetSize>)
+where
+    F: Fn(OffsetSize) -> Option<OffsetSize>,
+{
+    let mapped_offset = offset.and_then(|offset| {
+        Some(Offset::new(
+            map_position(offset.begin)?,
+            map_position(offset.end)?,
+        ))
+    });
+    let mapped_reference_offsets = reference_offsets
+        .iter()
+        .map(|position| map_position(*position))
+        .collect::<Option<Vec<OffsetSize>>>();
+    match (mapped_offset, mapped_reference_offsets) {
+        (Some(offset), Some(reference_offsets)) => (Some(offset), reference_offsets),
+        _ => (None, vec![]),
+    }
+}
+
+/// Normalized form of a text used to compare a sample with its encoded and decoded version:
+/// compatibility decomposition without combining marks, lower cased, with whitespace removed.
+pub(crate) fn normalize_for_verification(text: &str) -> String {
+    unicode_normalization::UnicodeNormalization::nfkd(text)
+        .filter(|character| !is_combining_mark(*character) && !character.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Applies a sequence of replacements (with the same semantics as chained calls to `str::replace`)
+/// to a text split in pieces (for example the decoded text of each token), returning the updated
+/// pieces. Characters of a replacement are attributed to the piece of the replaced character they
+/// correspond to, allowing the pieces to remain aligned with the tokens they originate from.
+pub fn replace_in_pieces(pieces: Vec<String>, replacements: &[(&str, &str)]) -> Vec<String> {
+    let num_pieces = pieces.len();
+    let mut characters: Vec<(char, usize)> = pieces
+        .iter()
+        .enumerate()
+        .flat_map(|(piece_index, piece)| piece.chars().map(move |c| (c, piece_index)))
+        .collect();
+    for (pattern, replacement) in replacements {
+        let pattern = pattern.chars().collect::<Vec<char>>();
+        let replacement = replacement.chars().collect::<Vec<char>>();
+        if pattern.is_empty() {
+            continue;
+        }
+        let mut output = Vec::with_capacity(characters.len());
+        let mut position = 0;
+        while position < characters.len() {
+            let matched = &characters[position..min(position + pattern.len(), characters.len())];
+            if !matched.iter().map(|(c, _)| c).eq(pattern.iter()) {
+                output.push(characters[position]);
+                position += 1;
+                continue;
+            }
+            let prefix_length = pattern
+                .iter()
+                .zip(replacement.iter())
+                .take_while(|(a, b)| a == b)
+                .count();
+            let suffix_length = pattern[prefix_length..]
+                .iter()
+                .rev()
+                .zip(replacement[prefix_length..].iter().rev())
+                .take_while(|(a, b)| a == b)
+                .count();
+            let removed = &matched[prefix_length..pattern.len() - suffix_length];
+            output.extend_from_slice(&matched[..prefix_length]);
+            let mut cursor = 0;
+            let mut piece_index = removed
+                .first()
+                .unwrap_or(&matched[min(prefix_length, matched.len() - 1)])
+                .1;
+            for character in &replacement[prefix_length..replacement.len() - suffix_length] {
+                if let Some(match_position) = removed[cursor..]
+                    .iter()
+                    .position(|(removed_char, _)| removed_char == character)
+                {
+                    cursor += match_position;
+                    piece_index = removed[cursor].1;
+                    cursor += 1;
+                }
+                output.push((*character, piece_index));
+            }
+            output.extend_from_slice(&matched[pattern.len() - suffix_length..]);
+            position += pattern.len();
+        }
+        characters = output;
+    }
+    let mut output_pieces = vec![String::new(); num_pieces];
+    for (character, piece_index) in characters {
+        output_pieces[piece_index].push(character);
+    }
+    output_pieces
 }
 
 //==============================
@@ -1162,6 +2561,10 @@ pub(crate) fn unknown_byte_fallback<T: Vocab>(token: TokenRef, vocab: &T) -> Opt
 mod tests {
     use super::*;
     use crate::error::TokenizerError;
+    use crate::tokenizer::constants::CLEAN_UP_TOKENIZATION_REPLACEMENTS;
+    use crate::tokenizer::unicode_tables::{
+        ACCENT_MARKERS_TABLE, CONTROL_TABLE, PUNCTUATION_TABLE, UNICODE_VERSION,
+    };
     use crate::vocab::base_vocab::{swap_key_values, SpecialTokenMap};
     use crate::vocab::BertVocab;
     use std::collections::HashMap;
@@ -1299,6 +2702,262 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_split_on_overlapping_special_tokens() {
+        //        Given
+        let test_tuples = [
+            ("a <mask> b", vec!["a", "<mask>", " b"]),
+            ("a <mask_1> b", vec!["a", "<mask_1>", " b"]),
+            ("a <mask_10><mask_1>", vec!["a", "<mask_10>", "<mask_1>"]),
+            ("<mask>_1>", vec!["<mask>", "_1>"]),
+        ];
+
+        //        When & Then
+        // Each vocabulary is built with a new hasher state, varying the special values iteration order
+        for _ in 0..16 {
+            let mut vocab = generate_test_vocab();
+            vocab.add_tokens(&["<mask>", "<mask_1>", "<mask_10>"]);
+            for (source_text, expected_tokens) in test_tuples.iter() {
+                let offsets =
+                    (0..source_text.chars().count() as OffsetSize).collect::<Vec<OffsetSize>>();
+                let tokens: Vec<&str> =
+                    split_on_special_tokens(TokenRef::new(source_text, offsets.as_slice()), &vocab)
+                        .into_iter()
+                        .map(|t| t.text)
+                        .collect();
+                assert_eq!(tokens, *expected_tokens);
+            }
+        }
+    }
+
+    #[test]
+    fn test_clean_text_bidi_control_handling() {
+        //        Given
+        let source_text = "a\u{202E}bc\u{202C}\u{200B}d";
+        let test_tuples = [
+            (BidiControlHandling::Strip, "abcd", vec![0, 2, 3, 6]),
+            (
+                BidiControlHandling::Keep,
+                "a\u{202E}bc\u{202C}d",
+                vec![0, 1, 2, 3, 4, 6],
+            ),
+            (
+                BidiControlHandling::Special,
+                "a\u{202E}bc\u{202C}d",
+                vec![0, 1, 2, 3, 4, 6],
+            ),
+        ];
+
+        //        When & Then
+        for (bidi_control_handling, expected_text, expected_offsets) in test_tuples.iter() {
+            let mut token = Token::new(source_text.to_string());
+            clean_text_with_bidi_control_handling(&mut token, true, *bidi_control_handling);
+            assert_eq!(token.text, *expected_text);
+            assert_eq!(token.reference_offsets, *expected_offsets);
+        }
+    }
+
+    #[test]
+    fn test_split_on_bidi_controls() {
+        //        Given
+        let source_text = "abc \u{2067}def\u{2069}";
+        let offsets = (0..source_text.chars().count() as OffsetSize).collect::<Vec<OffsetSize>>();
+
+        //        When
+        let tokens = split_on_bidi_controls(TokenRef::new(source_text, offsets.as_slice()));
+
+        //        Then
+        assert_eq!(
+            tokens.iter().map(|t| t.text).collect::<Vec<&str>>(),
+            vec!["abc ", "\u{2067}", "def", "\u{2069}"]
+        );
+        assert_eq!(
+            tokens.iter().map(|t| t.mask).collect::<Vec<Mask>>(),
+            vec![Mask::None, Mask::Special, Mask::None, Mask::Special]
+        );
+    }
+
+    #[test]
+    fn test_bpe_cache() {
+        //        Given
+        let mut cache = BpeCache::new();
+        let bpe_output = (vec!["he".to_string(), "llo".to_string()], vec![2, 3]);
+
+        //        When
+        cache.insert("hello", &bpe_output);
+        let uncounted_lookup = cache.get("hello");
+        cache.set_count_lookups(true);
+        let locked_shard = cache.shard("hello").write().unwrap();
+        let blocked_lookup = cache.get("hello");
+        cache.insert("hello", &(vec!["hello".to_string()], vec![5]));
+        drop(locked_shard);
+
+        //        Then
+        assert_eq!(uncounted_lookup, Some(bpe_output.clone()));
+        assert_eq!(blocked_lookup, None);
+        assert_eq!(cache.get("hello"), Some(bpe_output));
+        assert_eq!(cache.get("world"), None);
+        assert_eq!(cache.num_lookups(), 3);
+        assert_eq!(cache.num_hits(), 1);
+        cache.reset_counters();
+        assert_eq!(cache.num_lookups(), 0);
+        assert_eq!(cache.num_hits(), 0);
+    }
+
+    #[test]
+    fn test_split_on_language_code() {
+        //        Given
+        let language_codes_bytes: HashSet<Vec<u8>> =
+            vec![b">>en.<<".to_vec()].into_iter().collect();
+        let test_tuples = [
+            (
+                ">>en.<< hello",
+                vec![">>en.<<", " hello"],
+                vec![Offset::new(0, 7), Offset::new(7, 13)],
+            ),
+            (
+                "  >>en.<<  hello",
+                vec![">>en.<<", " hello"],
+                vec![Offset::new(2, 9), Offset::new(10, 16)],
+            ),
+            (
+                ">>en.<<",
+                vec![">>en.<<", ""],
+                vec![Offset::new(0, 7), Offset::new(7, 7)],
+            ),
+            ("  hello", vec![" hello"], vec![Offset::new(1, 7)]),
+            ("  >>", vec![" >>"], vec![Offset::new(1, 4)]),
+            ("   ", vec!["   "], vec![Offset::new(0, 3)]),
+            ("", vec![""], vec![Offset::new(0, 0)]),
+        ];
+
+        //        When & Then
+        for (source_text, expected_tokens, expected_offsets) in test_tuples.iter() {
+            let offsets =
+                (0..source_text.chars().count() as OffsetSize).collect::<Vec<OffsetSize>>();
+            let tokens = split_on_language_code(
+                TokenRef::new(source_text, offsets.as_slice()),
+                7,
+                &language_codes_bytes,
+            );
+            assert_eq!(
+                tokens.iter().map(|t| t.text).collect::<Vec<&str>>(),
+                *expected_tokens
+            );
+            assert_eq!(
+                tokens.iter().map(|t| t.offset).collect::<Vec<Offset>>(),
+                *expected_offsets
+            );
+            for token in tokens {
+                assert_eq!(token.text.chars().count(), token.reference_offsets.len());
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_language_code() {
+        //        Given
+        let language_codes_bytes: HashSet<Vec<u8>> = vec![b">>fr<<".to_vec(), b">>en<<".to_vec()]
+            .into_iter()
+            .collect();
+
+        //        When & Then
+        assert!(validate_language_code(">>en<<", &language_codes_bytes).is_ok());
+        match validate_language_code(">>xx<<", &language_codes_bytes) {
+            Err(TokenizerError::LanguageCodeNotFound { code, supported }) => {
+                assert_eq!(code, ">>xx<<");
+                assert_eq!(supported, vec![">>en<<", ">>fr<<"]);
+            }
+            other => panic!("unexpected validation result: {:?}", other),
+        }
+        let error = validate_language_code("", &language_codes_bytes).unwrap_err();
+        assert!(error.to_string().ends_with(">>en<<, >>fr<<"));
+    }
+
+    #[test]
+    fn test_validate_language_code_prefix() {
+        //        Given
+        let chevron_codes: HashSet<Vec<u8>> = vec![b">>en.<<".to_vec()].into_iter().collect();
+        let script_codes: HashSet<Vec<u8>> = vec![b"eng_Latn".to_vec()].into_iter().collect();
+        let chevron_test_tuples = [
+            (">>en.<< Hello", true),
+            ("  >>en.<<Hello", true),
+            (">>xx.<< Hello", false),
+            (" >>xx<<", false),
+            ("Hello >>xx.<<", true),
+            (">> Hello <<", true),
+            ("", true),
+        ];
+        let script_test_tuples = [
+            ("eng_Latn Hello", true),
+            ("eng_Latn", true),
+            (" fra_Latn, Bonjour", false),
+            ("fra_Latnx", true),
+            ("Hello", true),
+            ("", true),
+        ];
+
+        //        When & Then
+        for (text, is_valid) in chevron_test_tuples.iter() {
+            let result =
+                validate_language_code_prefix(text, &chevron_codes, chevron_language_code_prefix);
+            assert_eq!(result.is_ok(), *is_valid, "{}", text);
+        }
+        for (text, is_valid) in script_test_tuples.iter() {
+            let result =
+                validate_language_code_prefix(text, &script_codes, script_language_code_prefix);
+            assert_eq!(result.is_ok(), *is_valid, "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_split_on_emoji_sequences() {
+        //        Given
+        let test_tuples = [
+            (
+                "family 👨\u{200D}👩\u{200D}👧!",
+                vec!["family", "👨\u{200D}👩\u{200D}👧", "!"],
+                vec![Offset::new(0, 6), Offset::new(7, 12), Offset::new(12, 13)],
+            ),
+            (
+                "👍🏽👍",
+                vec!["👍🏽", "👍"],
+                vec![Offset::new(0, 2), Offset::new(2, 3)],
+            ),
+            (
+                "🇫🇷🇩🇪 #\u{FE0F}\u{20E3} 12",
+                vec!["🇫🇷", "🇩🇪", "#\u{FE0F}\u{20E3}", " 12"],
+                vec![
+                    Offset::new(0, 2),
+                    Offset::new(2, 4),
+                    Offset::new(5, 8),
+                    Offset::new(8, 11),
+                ],
+            ),
+            (
+                "❤\u{FE0F}\u{200D}",
+                vec!["❤\u{FE0F}", "\u{200D}"],
+                vec![Offset::new(0, 2), Offset::new(2, 3)],
+            ),
+            ("no emoji", vec!["no emoji"], vec![Offset::new(0, 8)]),
+        ];
+
+        //        When & Then
+        for (source_text, expected_tokens, expected_offsets) in test_tuples.iter() {
+            let offsets =
+                (0..source_text.chars().count() as OffsetSize).collect::<Vec<OffsetSize>>();
+            let tokens = split_on_emoji_sequences(TokenRef::new(source_text, offsets.as_slice()));
+            assert_eq!(
+                tokens.iter().map(|t| t.text).collect::<Vec<&str>>(),
+                *expected_tokens
+            );
+            assert_eq!(
+                tokens.iter().map(|t| t.offset).collect::<Vec<Offset>>(),
+                *expected_offsets
+            );
+        }
+    }
+
     #[test]
     fn test_tokenize_cjk_chars() {
         //        Given
@@ -1328,31 +2987,142 @@ mod tests {
                     Offset { begin: 12, end: 23 },
                 ],
             ),
-            ("中", vec!["中"], vec![Offset::new(0, 1)]),
+            ("中", vec!["中"], vec![Offset::new(0, 1)]),
+            (
+                "中b华",
+                vec!["中", "b", "华"],
+                vec![Offset::new(0, 1), Offset::new(1, 2), Offset::new(2, 3)],
+            ),
+            (
+                "中[PAD]华",
+                vec!["中", "[PAD]", "华"],
+                vec![Offset::new(0, 1), Offset::new(1, 6), Offset::new(6, 7)],
+            ),
+        ];
+
+        //        When & Then
+        for (source_text, expected_tokens, expected_offsets) in test_tuples.iter() {
+            let offsets =
+                (0..source_text.chars().count() as OffsetSize).collect::<Vec<OffsetSize>>();
+            let (tokens, offsets): (Vec<&str>, Vec<Offset>) =
+                tokenize_cjk_chars(TokenRef::new(source_text, offsets.as_slice()))
+                    .into_iter()
+                    .map(|t| (t.text, t.offset))
+                    .unzip();
+            assert_eq!(tokens, *expected_tokens);
+            assert_eq!(offsets, *expected_offsets);
+        }
+    }
+
+    #[test]
+    fn test_moses_tokenize() {
+        //        Given
+        let test_tuples = [
+            (
+                "Hello, world! Don't stop.",
+                false,
+                vec!["Hello", ",", "world", "!", "Don", "'t", "stop", "."],
+            ),
+            (
+                "The U.S. costs 1,000$ (est.)...",
+                false,
+                vec![
+                    "The", "U.S.", "costs", "1,000", "$", "(", "est.", ")", "...",
+                ],
+            ),
             (
-                "中b华",
-                vec!["中", "b", "华"],
-                vec![Offset::new(0, 1), Offset::new(1, 2), Offset::new(2, 3)],
+                "A well-known state-of-the-art model.",
+                false,
+                vec!["A", "well-known", "state-of-the-art", "model", "."],
             ),
             (
-                "中[PAD]华",
-                vec!["中", "[PAD]", "华"],
-                vec![Offset::new(0, 1), Offset::new(1, 6), Offset::new(6, 7)],
+                "A well-known state-of-the-art model.",
+                true,
+                vec![
+                    "A", "well", "@-@", "known", "state", "@-@", "of", "@-@", "the", "@-@", "art",
+                    "model", ".",
+                ],
             ),
+            ("", false, vec![]),
         ];
 
         //        When & Then
-        for (source_text, expected_tokens, expected_offsets) in test_tuples.iter() {
+        for (source_text, aggressive_dash_splits, expected_tokens) in test_tuples.iter() {
             let offsets =
                 (0..source_text.chars().count() as OffsetSize).collect::<Vec<OffsetSize>>();
-            let (tokens, offsets): (Vec<&str>, Vec<Offset>) =
-                tokenize_cjk_chars(TokenRef::new(source_text, offsets.as_slice()))
-                    .into_iter()
-                    .map(|t| (t.text, t.offset))
-                    .unzip();
+            let tokens: Vec<String> = moses_tokenize(
+                TokenRef::new(source_text, offsets.as_slice()),
+                *aggressive_dash_splits,
+            )
+            .into_iter()
+            .map(|t| t.text)
+            .collect();
             assert_eq!(tokens, *expected_tokens);
-            assert_eq!(offsets, *expected_offsets);
         }
+
+        let source_text = "well-known!";
+        let offsets = (0..source_text.chars().count() as OffsetSize).collect::<Vec<OffsetSize>>();
+        let offsets: Vec<Offset> = moses_tokenize(TokenRef::new(source_text, &offsets), true)
+            .into_iter()
+            .map(|t| t.offset)
+            .collect();
+        assert_eq!(
+            offsets,
+            vec![
+                Offset::new(0, 4),
+                Offset::new(4, 5),
+                Offset::new(5, 10),
+                Offset::new(10, 11)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_moses_detokenize() {
+        //        Given
+        let test_tuples = [
+            (
+                " Hello , world ! It 's a \" test \" ( really ) .",
+                "en",
+                "Hello, world! It's a \"test\" (really).",
+            ),
+            (
+                "A well @-@ known model costs $ 5 ...",
+                "en",
+                "A well-known model costs $5...",
+            ),
+            (
+                "Il dit : « l' homme @-@ orchestre ! » ",
+                "fr",
+                "Il dit : « l'homme-orchestre ! »",
+            ),
+            ("C' è un' altra cosa .", "it", "C'è un'altra cosa."),
+            ("It 's « fine »", "de", "It 's «fine»"),
+            ("我 爱 北京 , Beijing", "zh", "我爱北京, Beijing"),
+            ("", "en", ""),
+        ];
+
+        //        When & Then
+        for (source_text, language, expected_text) in test_tuples.iter() {
+            assert_eq!(moses_detokenize(source_text, language), *expected_text);
+        }
+
+        let pieces = vec![
+            " Hello".to_string(),
+            " ,".to_string(),
+            " well".to_string(),
+            " @-@".to_string(),
+            " known".to_string(),
+        ];
+        let detokenized_pieces = moses_detokenize_pieces(pieces.clone(), "en");
+        assert_eq!(
+            detokenized_pieces,
+            vec!["Hello", ",", " well", "-", "known"]
+        );
+        assert_eq!(
+            detokenized_pieces.concat(),
+            moses_detokenize(&pieces.concat(), "en")
+        );
     }
 
     #[test]
@@ -1501,73 +3271,74 @@ mod tests {
             0xf0e, 0xf0f, 0xf10, 0xf11, 0xf12, 0xf14, 0xf3a, 0xf3b, 0xf3c, 0xf3d, 0xf85, 0xfd0,
             0xfd1, 0xfd2, 0xfd3, 0xfd4, 0xfd9, 0xfda, 0x104a, 0x104b, 0x104c, 0x104d, 0x104e,
             0x104f, 0x10fb, 0x1360, 0x1361, 0x1362, 0x1363, 0x1364, 0x1365, 0x1366, 0x1367, 0x1368,
-            0x1400, 0x166d, 0x166e, 0x169b, 0x169c, 0x16eb, 0x16ec, 0x16ed, 0x1735, 0x1736, 0x17d4,
-            0x17d5, 0x17d6, 0x17d8, 0x17d9, 0x17da, 0x1800, 0x1801, 0x1802, 0x1803, 0x1804, 0x1805,
-            0x1806, 0x1807, 0x1808, 0x1809, 0x180a, 0x1944, 0x1945, 0x1a1e, 0x1a1f, 0x1aa0, 0x1aa1,
-            0x1aa2, 0x1aa3, 0x1aa4, 0x1aa5, 0x1aa6, 0x1aa8, 0x1aa9, 0x1aaa, 0x1aab, 0x1aac, 0x1aad,
-            0x1b5a, 0x1b5b, 0x1b5c, 0x1b5d, 0x1b5e, 0x1b5f, 0x1b60, 0x1bfc, 0x1bfd, 0x1bfe, 0x1bff,
-            0x1c3b, 0x1c3c, 0x1c3d, 0x1c3e, 0x1c3f, 0x1c7e, 0x1c7f, 0x1cc0, 0x1cc1, 0x1cc2, 0x1cc3,
-            0x1cc4, 0x1cc5, 0x1cc6, 0x1cc7, 0x1cd3, 0x2010, 0x2011, 0x2012, 0x2013, 0x2014, 0x2015,
-            0x2016, 0x2017, 0x2018, 0x2019, 0x201a, 0x201b, 0x201c, 0x201d, 0x201e, 0x201f, 0x2020,
-            0x2021, 0x2022, 0x2023, 0x2024, 0x2025, 0x2026, 0x2027, 0x2030, 0x2031, 0x2032, 0x2033,
-            0x2034, 0x2035, 0x2036, 0x2037, 0x2038, 0x2039, 0x203a, 0x203b, 0x203c, 0x203d, 0x203e,
-            0x203f, 0x2040, 0x2041, 0x2042, 0x2043, 0x2045, 0x2046, 0x2047, 0x2048, 0x2049, 0x204a,
-            0x204b, 0x204c, 0x204d, 0x204e, 0x204f, 0x2050, 0x2051, 0x2053, 0x2054, 0x2055, 0x2056,
-            0x2057, 0x2058, 0x2059, 0x205a, 0x205b, 0x205c, 0x205d, 0x205e, 0x207d, 0x207e, 0x208d,
-            0x208e, 0x2308, 0x2309, 0x230a, 0x230b, 0x2329, 0x232a, 0x2768, 0x2769, 0x276a, 0x276b,
-            0x276c, 0x276d, 0x276e, 0x276f, 0x2770, 0x2771, 0x2772, 0x2773, 0x2774, 0x2775, 0x27c5,
-            0x27c6, 0x27e6, 0x27e7, 0x27e8, 0x27e9, 0x27ea, 0x27eb, 0x27ec, 0x27ed, 0x27ee, 0x27ef,
-            0x2983, 0x2984, 0x2985, 0x2986, 0x2987, 0x2988, 0x2989, 0x298a, 0x298b, 0x298c, 0x298d,
-            0x298e, 0x298f, 0x2990, 0x2991, 0x2992, 0x2993, 0x2994, 0x2995, 0x2996, 0x2997, 0x2998,
-            0x29d8, 0x29d9, 0x29da, 0x29db, 0x29fc, 0x29fd, 0x2cf9, 0x2cfa, 0x2cfb, 0x2cfc, 0x2cfe,
-            0x2cff, 0x2d70, 0x2e00, 0x2e01, 0x2e02, 0x2e03, 0x2e04, 0x2e05, 0x2e06, 0x2e07, 0x2e08,
-            0x2e09, 0x2e0a, 0x2e0b, 0x2e0c, 0x2e0d, 0x2e0e, 0x2e0f, 0x2e10, 0x2e11, 0x2e12, 0x2e13,
-            0x2e14, 0x2e15, 0x2e16, 0x2e17, 0x2e18, 0x2e19, 0x2e1a, 0x2e1b, 0x2e1c, 0x2e1d, 0x2e1e,
-            0x2e1f, 0x2e20, 0x2e21, 0x2e22, 0x2e23, 0x2e24, 0x2e25, 0x2e26, 0x2e27, 0x2e28, 0x2e29,
-            0x2e2a, 0x2e2b, 0x2e2c, 0x2e2d, 0x2e2e, 0x2e30, 0x2e31, 0x2e32, 0x2e33, 0x2e34, 0x2e35,
-            0x2e36, 0x2e37, 0x2e38, 0x2e39, 0x2e3a, 0x2e3b, 0x2e3c, 0x2e3d, 0x2e3e, 0x2e3f, 0x2e40,
-            0x2e41, 0x2e42, 0x2e43, 0x2e44, 0x2e45, 0x2e46, 0x2e47, 0x2e48, 0x2e49, 0x2e4a, 0x2e4b,
-            0x2e4c, 0x2e4d, 0x2e4e, 0x3001, 0x3002, 0x3003, 0x3008, 0x3009, 0x300a, 0x300b, 0x300c,
-            0x300d, 0x300e, 0x300f, 0x3010, 0x3011, 0x3014, 0x3015, 0x3016, 0x3017, 0x3018, 0x3019,
-            0x301a, 0x301b, 0x301c, 0x301d, 0x301e, 0x301f, 0x3030, 0x303d, 0x30a0, 0x30fb, 0xa4fe,
-            0xa4ff, 0xa60d, 0xa60e, 0xa60f, 0xa673, 0xa67e, 0xa6f2, 0xa6f3, 0xa6f4, 0xa6f5, 0xa6f6,
-            0xa6f7, 0xa874, 0xa875, 0xa876, 0xa877, 0xa8ce, 0xa8cf, 0xa8f8, 0xa8f9, 0xa8fa, 0xa8fc,
-            0xa92e, 0xa92f, 0xa95f, 0xa9c1, 0xa9c2, 0xa9c3, 0xa9c4, 0xa9c5, 0xa9c6, 0xa9c7, 0xa9c8,
-            0xa9c9, 0xa9ca, 0xa9cb, 0xa9cc, 0xa9cd, 0xa9de, 0xa9df, 0xaa5c, 0xaa5d, 0xaa5e, 0xaa5f,
-            0xaade, 0xaadf, 0xaaf0, 0xaaf1, 0xabeb, 0xfd3e, 0xfd3f, 0xfe10, 0xfe11, 0xfe12, 0xfe13,
-            0xfe14, 0xfe15, 0xfe16, 0xfe17, 0xfe18, 0xfe19, 0xfe30, 0xfe31, 0xfe32, 0xfe33, 0xfe34,
-            0xfe35, 0xfe36, 0xfe37, 0xfe38, 0xfe39, 0xfe3a, 0xfe3b, 0xfe3c, 0xfe3d, 0xfe3e, 0xfe3f,
-            0xfe40, 0xfe41, 0xfe42, 0xfe43, 0xfe44, 0xfe45, 0xfe46, 0xfe47, 0xfe48, 0xfe49, 0xfe4a,
-            0xfe4b, 0xfe4c, 0xfe4d, 0xfe4e, 0xfe4f, 0xfe50, 0xfe51, 0xfe52, 0xfe54, 0xfe55, 0xfe56,
-            0xfe57, 0xfe58, 0xfe59, 0xfe5a, 0xfe5b, 0xfe5c, 0xfe5d, 0xfe5e, 0xfe5f, 0xfe60, 0xfe61,
-            0xfe63, 0xfe68, 0xfe6a, 0xfe6b, 0xff01, 0xff02, 0xff03, 0xff05, 0xff06, 0xff07, 0xff08,
-            0xff09, 0xff0a, 0xff0c, 0xff0d, 0xff0e, 0xff0f, 0xff1a, 0xff1b, 0xff1f, 0xff20, 0xff3b,
-            0xff3c, 0xff3d, 0xff3f, 0xff5b, 0xff5d, 0xff5f, 0xff60, 0xff61, 0xff62, 0xff63, 0xff64,
-            0xff65, 0x10100, 0x10101, 0x10102, 0x1039f, 0x103d0, 0x1056f, 0x10857, 0x1091f,
-            0x1093f, 0x10a50, 0x10a51, 0x10a52, 0x10a53, 0x10a54, 0x10a55, 0x10a56, 0x10a57,
-            0x10a58, 0x10a7f, 0x10af0, 0x10af1, 0x10af2, 0x10af3, 0x10af4, 0x10af5, 0x10af6,
-            0x10b39, 0x10b3a, 0x10b3b, 0x10b3c, 0x10b3d, 0x10b3e, 0x10b3f, 0x10b99, 0x10b9a,
-            0x10b9b, 0x10b9c, 0x10f55, 0x10f56, 0x10f57, 0x10f58, 0x10f59, 0x11047, 0x11048,
-            0x11049, 0x1104a, 0x1104b, 0x1104c, 0x1104d, 0x110bb, 0x110bc, 0x110be, 0x110bf,
-            0x110c0, 0x110c1, 0x11140, 0x11141, 0x11142, 0x11143, 0x11174, 0x11175, 0x111c5,
-            0x111c6, 0x111c7, 0x111c8, 0x111cd, 0x111db, 0x111dd, 0x111de, 0x111df, 0x11238,
-            0x11239, 0x1123a, 0x1123b, 0x1123c, 0x1123d, 0x112a9, 0x1144b, 0x1144c, 0x1144d,
-            0x1144e, 0x1144f, 0x1145b, 0x1145d, 0x114c6, 0x115c1, 0x115c2, 0x115c3, 0x115c4,
-            0x115c5, 0x115c6, 0x115c7, 0x115c8, 0x115c9, 0x115ca, 0x115cb, 0x115cc, 0x115cd,
-            0x115ce, 0x115cf, 0x115d0, 0x115d1, 0x115d2, 0x115d3, 0x115d4, 0x115d5, 0x115d6,
-            0x115d7, 0x11641, 0x11642, 0x11643, 0x11660, 0x11661, 0x11662, 0x11663, 0x11664,
-            0x11665, 0x11666, 0x11667, 0x11668, 0x11669, 0x1166a, 0x1166b, 0x1166c, 0x1173c,
-            0x1173d, 0x1173e, 0x1183b, 0x11a3f, 0x11a40, 0x11a41, 0x11a42, 0x11a43, 0x11a44,
-            0x11a45, 0x11a46, 0x11a9a, 0x11a9b, 0x11a9c, 0x11a9e, 0x11a9f, 0x11aa0, 0x11aa1,
-            0x11aa2, 0x11c41, 0x11c42, 0x11c43, 0x11c44, 0x11c45, 0x11c70, 0x11c71, 0x11ef7,
-            0x11ef8, 0x12470, 0x12471, 0x12472, 0x12473, 0x12474, 0x16a6e, 0x16a6f, 0x16af5,
-            0x16b37, 0x16b38, 0x16b39, 0x16b3a, 0x16b3b, 0x16b44, 0x16e97, 0x16e98, 0x16e99,
-            0x16e9a, 0x1bc9f, 0x1da87, 0x1da88, 0x1da89, 0x1da8a, 0x1da8b, 0x1e95e, 0x1e95f,
+            0x1400, 0x166e, 0x169b, 0x169c, 0x16eb, 0x16ec, 0x16ed, 0x1735, 0x1736, 0x17d4, 0x17d5,
+            0x17d6, 0x17d8, 0x17d9, 0x17da, 0x1800, 0x1801, 0x1802, 0x1803, 0x1804, 0x1805, 0x1806,
+            0x1807, 0x1808, 0x1809, 0x180a, 0x1944, 0x1945, 0x1a1e, 0x1a1f, 0x1aa0, 0x1aa1, 0x1aa2,
+            0x1aa3, 0x1aa4, 0x1aa5, 0x1aa6, 0x1aa8, 0x1aa9, 0x1aaa, 0x1aab, 0x1aac, 0x1aad, 0x1b5a,
+            0x1b5b, 0x1b5c, 0x1b5d, 0x1b5e, 0x1b5f, 0x1b60, 0x1bfc, 0x1bfd, 0x1bfe, 0x1bff, 0x1c3b,
+            0x1c3c, 0x1c3d, 0x1c3e, 0x1c3f, 0x1c7e, 0x1c7f, 0x1cc0, 0x1cc1, 0x1cc2, 0x1cc3, 0x1cc4,
+            0x1cc5, 0x1cc6, 0x1cc7, 0x1cd3, 0x2010, 0x2011, 0x2012, 0x2013, 0x2014, 0x2015, 0x2016,
+            0x2017, 0x2018, 0x2019, 0x201a, 0x201b, 0x201c, 0x201d, 0x201e, 0x201f, 0x2020, 0x2021,
+            0x2022, 0x2023, 0x2024, 0x2025, 0x2026, 0x2027, 0x2030, 0x2031, 0x2032, 0x2033, 0x2034,
+            0x2035, 0x2036, 0x2037, 0x2038, 0x2039, 0x203a, 0x203b, 0x203c, 0x203d, 0x203e, 0x203f,
+            0x2040, 0x2041, 0x2042, 0x2043, 0x2045, 0x2046, 0x2047, 0x2048, 0x2049, 0x204a, 0x204b,
+            0x204c, 0x204d, 0x204e, 0x204f, 0x2050, 0x2051, 0x2053, 0x2054, 0x2055, 0x2056, 0x2057,
+            0x2058, 0x2059, 0x205a, 0x205b, 0x205c, 0x205d, 0x205e, 0x207d, 0x207e, 0x208d, 0x208e,
+            0x2308, 0x2309, 0x230a, 0x230b, 0x2329, 0x232a, 0x2768, 0x2769, 0x276a, 0x276b, 0x276c,
+            0x276d, 0x276e, 0x276f, 0x2770, 0x2771, 0x2772, 0x2773, 0x2774, 0x2775, 0x27c5, 0x27c6,
+            0x27e6, 0x27e7, 0x27e8, 0x27e9, 0x27ea, 0x27eb, 0x27ec, 0x27ed, 0x27ee, 0x27ef, 0x2983,
+            0x2984, 0x2985, 0x2986, 0x2987, 0x2988, 0x2989, 0x298a, 0x298b, 0x298c, 0x298d, 0x298e,
+            0x298f, 0x2990, 0x2991, 0x2992, 0x2993, 0x2994, 0x2995, 0x2996, 0x2997, 0x2998, 0x29d8,
+            0x29d9, 0x29da, 0x29db, 0x29fc, 0x29fd, 0x2cf9, 0x2cfa, 0x2cfb, 0x2cfc, 0x2cfe, 0x2cff,
+            0x2d70, 0x2e00, 0x2e01, 0x2e02, 0x2e03, 0x2e04, 0x2e05, 0x2e06, 0x2e07, 0x2e08, 0x2e09,
+            0x2e0a, 0x2e0b, 0x2e0c, 0x2e0d, 0x2e0e, 0x2e0f, 0x2e10, 0x2e11, 0x2e12, 0x2e13, 0x2e14,
+            0x2e15, 0x2e16, 0x2e17, 0x2e18, 0x2e19, 0x2e1a, 0x2e1b, 0x2e1c, 0x2e1d, 0x2e1e, 0x2e1f,
+            0x2e20, 0x2e21, 0x2e22, 0x2e23, 0x2e24, 0x2e25, 0x2e26, 0x2e27, 0x2e28, 0x2e29, 0x2e2a,
+            0x2e2b, 0x2e2c, 0x2e2d, 0x2e2e, 0x2e30, 0x2e31, 0x2e32, 0x2e33, 0x2e34, 0x2e35, 0x2e36,
+            0x2e37, 0x2e38, 0x2e39, 0x2e3a, 0x2e3b, 0x2e3c, 0x2e3d, 0x2e3e, 0x2e3f, 0x2e40, 0x2e41,
+            0x2e42, 0x2e43, 0x2e44, 0x2e45, 0x2e46, 0x2e47, 0x2e48, 0x2e49, 0x2e4a, 0x2e4b, 0x2e4c,
+            0x2e4d, 0x2e4e, 0x3001, 0x3002, 0x3003, 0x3008, 0x3009, 0x300a, 0x300b, 0x300c, 0x300d,
+            0x300e, 0x300f, 0x3010, 0x3011, 0x3014, 0x3015, 0x3016, 0x3017, 0x3018, 0x3019, 0x301a,
+            0x301b, 0x301c, 0x301d, 0x301e, 0x301f, 0x3030, 0x303d, 0x30a0, 0x30fb, 0xa4fe, 0xa4ff,
+            0xa60d, 0xa60e, 0xa60f, 0xa673, 0xa67e, 0xa6f2, 0xa6f3, 0xa6f4, 0xa6f5, 0xa6f6, 0xa6f7,
+            0xa874, 0xa875, 0xa876, 0xa877, 0xa8ce, 0xa8cf, 0xa8f8, 0xa8f9, 0xa8fa, 0xa8fc, 0xa92e,
+            0xa92f, 0xa95f, 0xa9c1, 0xa9c2, 0xa9c3, 0xa9c4, 0xa9c5, 0xa9c6, 0xa9c7, 0xa9c8, 0xa9c9,
+            0xa9ca, 0xa9cb, 0xa9cc, 0xa9cd, 0xa9de, 0xa9df, 0xaa5c, 0xaa5d, 0xaa5e, 0xaa5f, 0xaade,
+            0xaadf, 0xaaf0, 0xaaf1, 0xabeb, 0xfd3e, 0xfd3f, 0xfe10, 0xfe11, 0xfe12, 0xfe13, 0xfe14,
+            0xfe15, 0xfe16, 0xfe17, 0xfe18, 0xfe19, 0xfe30, 0xfe31, 0xfe32, 0xfe33, 0xfe34, 0xfe35,
+            0xfe36, 0xfe37, 0xfe38, 0xfe39, 0xfe3a, 0xfe3b, 0xfe3c, 0xfe3d, 0xfe3e, 0xfe3f, 0xfe40,
+            0xfe41, 0xfe42, 0xfe43, 0xfe44, 0xfe45, 0xfe46, 0xfe47, 0xfe48, 0xfe49, 0xfe4a, 0xfe4b,
+            0xfe4c, 0xfe4d, 0xfe4e, 0xfe4f, 0xfe50, 0xfe51, 0xfe52, 0xfe54, 0xfe55, 0xfe56, 0xfe57,
+            0xfe58, 0xfe59, 0xfe5a, 0xfe5b, 0xfe5c, 0xfe5d, 0xfe5e, 0xfe5f, 0xfe60, 0xfe61, 0xfe63,
+            0xfe68, 0xfe6a, 0xfe6b, 0xff01, 0xff02, 0xff03, 0xff05, 0xff06, 0xff07, 0xff08, 0xff09,
+            0xff0a, 0xff0c, 0xff0d, 0xff0e, 0xff0f, 0xff1a, 0xff1b, 0xff1f, 0xff20, 0xff3b, 0xff3c,
+            0xff3d, 0xff3f, 0xff5b, 0xff5d, 0xff5f, 0xff60, 0xff61, 0xff62, 0xff63, 0xff64, 0xff65,
+            0x10100, 0x10101, 0x10102, 0x1039f, 0x103d0, 0x1056f, 0x10857, 0x1091f, 0x1093f,
+            0x10a50, 0x10a51, 0x10a52, 0x10a53, 0x10a54, 0x10a55, 0x10a56, 0x10a57, 0x10a58,
+            0x10a7f, 0x10af0, 0x10af1, 0x10af2, 0x10af3, 0x10af4, 0x10af5, 0x10af6, 0x10b39,
+            0x10b3a, 0x10b3b, 0x10b3c, 0x10b3d, 0x10b3e, 0x10b3f, 0x10b99, 0x10b9a, 0x10b9b,
+            0x10b9c, 0x10f55, 0x10f56, 0x10f57, 0x10f58, 0x10f59, 0x11047, 0x11048, 0x11049,
+            0x1104a, 0x1104b, 0x1104c, 0x1104d, 0x110bb, 0x110bc, 0x110be, 0x110bf, 0x110c0,
+            0x110c1, 0x11140, 0x11141, 0x11142, 0x11143, 0x11174, 0x11175, 0x111c5, 0x111c6,
+            0x111c7, 0x111c8, 0x111cd, 0x111db, 0x111dd, 0x111de, 0x111df, 0x11238, 0x11239,
+            0x1123a, 0x1123b, 0x1123c, 0x1123d, 0x112a9, 0x1144b, 0x1144c, 0x1144d, 0x1144e,
+            0x1144f, 0x1145b, 0x1145d, 0x114c6, 0x115c1, 0x115c2, 0x115c3, 0x115c4, 0x115c5,
+            0x115c6, 0x115c7, 0x115c8, 0x115c9, 0x115ca, 0x115cb, 0x115cc, 0x115cd, 0x115ce,
+            0x115cf, 0x115d0, 0x115d1, 0x115d2, 0x115d3, 0x115d4, 0x115d5, 0x115d6, 0x115d7,
+            0x11641, 0x11642, 0x11643, 0x11660, 0x11661, 0x11662, 0x11663, 0x11664, 0x11665,
+            0x11666, 0x11667, 0x11668, 0x11669, 0x1166a, 0x1166b, 0x1166c, 0x1173c, 0x1173d,
+            0x1173e, 0x1183b, 0x11a3f, 0x11a40, 0x11a41, 0x11a42, 0x11a43, 0x11a44, 0x11a45,
+            0x11a46, 0x11a9a, 0x11a9b, 0x11a9c, 0x11a9e, 0x11a9f, 0x11aa0, 0x11aa1, 0x11aa2,
+            0x11c41, 0x11c42, 0x11c43, 0x11c44, 0x11c45, 0x11c70, 0x11c71, 0x11ef7, 0x11ef8,
+            0x12470, 0x12471, 0x12472, 0x12473, 0x12474, 0x16a6e, 0x16a6f, 0x16af5, 0x16b37,
+            0x16b38, 0x16b39, 0x16b3a, 0x16b3b, 0x16b44, 0x16e97, 0x16e98, 0x16e99, 0x16e9a,
+            0x1bc9f, 0x1da87, 0x1da88, 0x1da89, 0x1da8a, 0x1da8b, 0x1e95e, 0x1e95f,
         ];
 
         let common_punctuation_chars = "!@$#%^&*()/?;:.,{}[]-_=+".chars();
 
-        let non_punctuation_chars: [char; 5] = ['a', '5', '♥', '\t', '越'];
+        // U+166D (Canadian Syllabics Chi Sign) is a symbol (`So`) since Unicode 12.0
+        let non_punctuation_chars: [char; 6] = ['a', '5', '♥', '\t', '越', '\u{166D}'];
 
         //        When & Then
         for character in punctuation_category_chars.iter() {
@@ -1583,6 +3354,49 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_unicode_tables() {
+        //        Given
+        let tables: [&[u32]; 4] = [
+            &WHITESPACE_CHARS,
+            &PUNCTUATION_TABLE,
+            &CONTROL_TABLE,
+            &ACCENT_MARKERS_TABLE,
+        ];
+        // Characters introduced in recent Unicode versions
+        let recent_punctuation_chars = ['\u{2E5D}', '\u{11B00}', '\u{1E5FF}'];
+        let recent_control_chars = ['\u{0890}', '\u{1343F}'];
+        let recent_accent_markers = ['\u{0898}', '\u{1E4EC}'];
+
+        //        When & Then
+        assert!(UNICODE_VERSION >= (16, 0, 0));
+        for table in tables.iter() {
+            assert!(table.windows(2).all(|pair| pair[0] < pair[1]));
+            assert!(table
+                .iter()
+                .all(|code_point| char::from_u32(*code_point).is_some()));
+        }
+        for code_point in WHITESPACE_CHARS.iter() {
+            assert!(char::from_u32(*code_point).unwrap().is_whitespace());
+        }
+        for code_point in 0..=0x10FFFF {
+            if let Some(character) = char::from_u32(code_point) {
+                if character.is_control() && !ADDITIONAL_WHITESPACE_CHARS.contains(&character) {
+                    assert!(is_control(&character, true));
+                }
+            }
+        }
+        for character in recent_punctuation_chars.iter() {
+            assert!(is_punctuation(character));
+        }
+        for character in recent_control_chars.iter() {
+            assert!(is_control(character, true));
+        }
+        for character in recent_accent_markers.iter() {
+            assert!(ACCENT_MARKERS.contains(&(*character as u32)));
+        }
+    }
+
     #[test]
     fn test_whitespace_tokenize() {
         //        Given
@@ -1667,6 +3481,146 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_pattern_pre_tokenizer() {
+        //        Given
+        let pre_tokenizer = PatternPreTokenizer::new(
+            &[
+                TextPattern::Url,
+                TextPattern::Email,
+                TextPattern::Hashtag,
+                TextPattern::Handle,
+            ],
+            Mask::Special,
+        );
+        let text = "Ask @rust_lang or me@example.com, see https://github.com/a?b=1. #ÜberRust a#b";
+        let offsets = (0..text.chars().count() as OffsetSize).collect::<Vec<OffsetSize>>();
+        let special_token = TokenRef {
+            text: "www.example.com",
+            offset: Offset::new(0, 15),
+            reference_offsets: &offsets[..15],
+            mask: Mask::Unknown,
+        };
+
+        //        When
+        let tokens = pre_tokenizer.split(TokenRef::new(text, &offsets));
+        let unchanged = pre_tokenizer.split(special_token);
+
+        //        Then
+        assert_eq!(
+            tokens
+                .iter()
+                .map(|token| (token.text, token.mask))
+                .collect::<Vec<(&str, Mask)>>(),
+            vec![
+                ("Ask ", Mask::None),
+                ("@rust_lang", Mask::Special),
+                (" or ", Mask::None),
+                ("me@example.com", Mask::Special),
+                (", see ", Mask::None),
+                ("https://github.com/a?b=1", Mask::Special),
+                (". ", Mask::None),
+                ("#ÜberRust", Mask::Special),
+                (" a#b", Mask::None),
+            ]
+        );
+        assert_eq!(tokens[7].offset, Offset::new(64, 73));
+        assert_eq!(tokens[7].reference_offsets, &offsets[64..73]);
+        assert_eq!(unchanged, vec![special_token]);
+    }
+
+    #[test]
+    fn test_normalize_digits() {
+        //        Given
+        let tokens = [
+            ("hello", vec![0, 1, 2, 3, 4], Mask::None),
+            ("\u{2581}20", vec![5, 6, 7], Mask::Begin),
+            ("24", vec![8, 9], Mask::Continuation),
+            ("##7", vec![12], Mask::Continuation),
+        ]
+        .iter()
+        .map(|(text, reference_offsets, mask)| Token {
+            text: text.to_string(),
+            offset: Offset::new(reference_offsets[0], reference_offsets.last().unwrap() + 1),
+            reference_offsets: reference_offsets.clone(),
+            mask: *mask,
+        })
+        .collect::<Vec<Token>>();
+
+        //        When
+        let unchanged = normalize_digits(tokens.clone(), &DigitNormalization::None);
+        let split = normalize_digits(tokens.clone(), &DigitNormalization::SplitDigits);
+        let placeholders = normalize_digits(
+            tokens.clone(),
+            &DigitNormalization::Placeholder("<num>".to_string()),
+        );
+
+        //        Then
+        assert_eq!(unchanged, tokens);
+        assert_eq!(
+            split.iter().map(|t| t.text.as_str()).collect::<Vec<&str>>(),
+            vec!["hello", "\u{2581}", "2", "0", "2", "4", "7"]
+        );
+        assert_eq!(
+            split
+                .iter()
+                .map(|t| t.reference_offsets.clone())
+                .collect::<Vec<Vec<OffsetSize>>>(),
+            vec![
+                vec![0, 1, 2, 3, 4],
+                vec![5],
+                vec![6],
+                vec![7],
+                vec![8],
+                vec![9],
+                vec![12]
+            ]
+        );
+        assert_eq!(
+            split.iter().map(|t| t.mask).collect::<Vec<Mask>>(),
+            vec![
+                Mask::None,
+                Mask::Begin,
+                Mask::Continuation,
+                Mask::Continuation,
+                Mask::Continuation,
+                Mask::Continuation,
+                Mask::Continuation
+            ]
+        );
+        assert_eq!(
+            placeholders
+                .iter()
+                .map(|t| (t.text.as_str(), t.offset))
+                .collect::<Vec<(&str, Offset)>>(),
+            vec![
+                ("hello", Offset::new(0, 5)),
+                ("\u{2581}", Offset::new(5, 6)),
+                ("<num>", Offset::new(6, 10)),
+                ("<num>", Offset::new(12, 13)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_is_acronym_or_mixed_case() {
+        let test_tuples = [
+            ("NASA", true),
+            ("iPhone", true),
+            ("McDonald", true),
+            ("U.S.", true),
+            ("Hello", false),
+            ("hello", false),
+            ("A", false),
+            ("123", false),
+        ];
+
+        //        When & Then
+        for (source_text, expected_result) in test_tuples.iter() {
+            assert_eq!(is_acronym_or_mixed_case(source_text), *expected_result);
+        }
+    }
+
     #[test]
     fn test_strip_accents() {
         let test_tuples = [
@@ -1762,6 +3716,7 @@ mod tests {
                 ),
                 &vocab,
                 100,
+                &ContinuationMarker::default(),
             )
             .into_iter()
             .map(|t| (t.text, t.offset))
@@ -1777,6 +3732,42 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_wordpiece_tokenizer_continuation_marker() {
+        //        Given
+        let mut vocab = generate_test_vocab();
+        vocab.extend_vocabulary(&["una@@", "ffa@@", "ble", "++ffa", "++ble"]);
+        let source_text = "unaffable";
+        let reference_offsets = (0..9).collect::<Vec<OffsetSize>>();
+        let tokenize = |continuation_marker| {
+            tokenize_wordpiece(
+                TokenRef::new(source_text, &reference_offsets),
+                &vocab,
+                100,
+                &continuation_marker,
+            )
+            .into_iter()
+            .map(|token| token.text)
+            .collect::<Vec<String>>()
+        };
+
+        //        When
+        let suffix_tokens = tokenize(ContinuationMarker::Suffix("@@".to_string()));
+        let prefix_tokens = tokenize(ContinuationMarker::Prefix("++".to_string()));
+
+        //        Then
+        assert_eq!(suffix_tokens, vec!["una@@", "ffa@@", "ble"]);
+        assert_eq!(prefix_tokens, vec!["una", "++ffa", "++ble"]);
+        assert_eq!(
+            ContinuationMarker::Suffix("@@".to_string()).join_word_pieces(&suffix_tokens),
+            "unaffable"
+        );
+        assert_eq!(
+            ContinuationMarker::Prefix("++".to_string()).join_word_pieces(&prefix_tokens),
+            "unaffable"
+        );
+    }
+
     #[test]
     fn test_truncate_single_sentence() {
         //        Given
@@ -1945,7 +3936,7 @@ mod tests {
             //            Invalid truncation requested
             (
                 (1, &TruncationStrategy::OnlySecond, 0),
-                Err(TokenizerError::ValueError(
+                Err(TokenizerError::UnsupportedOptionCombination(
                     "Invalid truncation strategy for single sentence truncation".into(),
                 )),
             ),
@@ -2698,4 +4689,342 @@ mod tests {
             assert_eq!(ctrl_bpe(input, &bpe_pairs), *expected_output);
         }
     }
+
+    #[test]
+    fn test_decode_utf8_lossy_with_offsets() {
+        //        Given
+        let test_tuples: [(&[u8], &str, Vec<usize>); 5] = [
+            (b"", "", vec![0]),
+            (b"hello", "hello", vec![0, 1, 2, 3, 4, 5]),
+            (
+                "h\u{e9}llo".as_bytes(),
+                "h\u{e9}llo",
+                vec![0, 1, 3, 4, 5, 6],
+            ),
+            (
+                b"a\xF0\x90\x80b\xFFc",
+                "a\u{FFFD}b\u{FFFD}c",
+                vec![0, 1, 4, 5, 6, 7],
+            ),
+            (b"ab\xE2\x82", "ab\u{FFFD}", vec![0, 1, 2, 4]),
+        ];
+
+        //        When & Then
+        for (bytes, expected_text, expected_positions) in test_tuples.iter() {
+            let (text, byte_positions) = decode_utf8_lossy_with_offsets(bytes);
+            assert_eq!(text, String::from_utf8_lossy(bytes));
+            assert_eq!(text, *expected_text);
+            assert_eq!(byte_positions, *expected_positions);
+        }
+    }
+
+    #[test]
+    fn test_complete_utf8_length() {
+        //        Given
+        let test_tuples: [(&[u8], usize); 7] = [
+            (b"", 0),
+            (b"hello", 5),
+            ("h\u{e9}".as_bytes(), 3),
+            (b"h\xC3", 1),
+            (b"ab\xE2\x82", 2),
+            (b"a\xF0\x90\x80", 1),
+            (b"a\xF0\x90\x80\x80", 5),
+        ];
+
+        //        When & Then
+        for (bytes, expected_length) in test_tuples.iter() {
+            assert_eq!(complete_utf8_length(bytes), *expected_length);
+        }
+    }
+
+    #[test]
+    fn test_offset_unit_positions() {
+        //        Given
+        let test_tuples = [
+            ("", [vec![0], vec![0], vec![0]]),
+            (
+                "hello",
+                [
+                    vec![0, 1, 2, 3, 4, 5],
+                    vec![0, 1, 2, 3, 4, 5],
+                    vec![0, 1, 2, 3, 4, 5],
+                ],
+            ),
+            (
+                "h\u{e9}\u{1F914}!",
+                [
+                    vec![0, 1, 2, 3, 4],
+                    vec![0, 1, 3, 7, 8],
+                    vec![0, 1, 2, 4, 5],
+                ],
+            ),
+        ];
+
+        //        When & Then
+        for (text, expected_positions) in test_tuples.iter() {
+            for (offset_unit, expected) in [OffsetUnit::Char, OffsetUnit::Byte, OffsetUnit::Utf16]
+                .iter()
+                .zip(expected_positions.iter())
+            {
+                assert_eq!(offset_unit_positions(text, *offset_unit), *expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_split_in_chunks() {
+        //        Given
+        let test_tuples = [
+            ("", 4, vec![(0, "")]),
+            ("hello", 8, vec![(0, "hello")]),
+            ("hello", 2, vec![(0, "he"), (2, "ll"), (4, "o")]),
+            ("hé world", 4, vec![(0, "hé"), (2, " wor"), (6, "ld")]),
+            ("a b c d", 4, vec![(0, "a b"), (3, " c d")]),
+            ("a  b", 2, vec![(0, "a"), (1, " "), (2, " b")]),
+        ];
+
+        //        When & Then
+        for (text, chunk_chars, expected) in test_tuples.iter() {
+            assert_eq!(split_in_chunks(text, *chunk_chars).unwrap(), *expected);
+        }
+        assert!(split_in_chunks("hello", 0).is_err());
+    }
+
+    #[test]
+    fn test_map_offsets() {
+        //        Given
+        let shift = |position: OffsetSize| position.checked_add(OffsetSize::MAX - 5);
+        let test_tuples = [
+            (
+                Some(Offset::new(0, 3)),
+                vec![0, 1, 2],
+                (
+                    Some(Offset::new(OffsetSize::MAX - 5, OffsetSize::MAX - 2)),
+                    vec![
+                        OffsetSize::MAX - 5,
+                        OffsetSize::MAX - 4,
+                        OffsetSize::MAX - 3,
+                    ],
+                ),
+            ),
+            (
+                Some(Offset::new(3, 5)),
+                vec![3, 4],
+                (
+                    Some(Offset::new(OffsetSize::MAX - 2, OffsetSize::MAX)),
+                    vec![OffsetSize::MAX - 2, OffsetSize::MAX - 1],
+                ),
+            ),
+            (Some(Offset::new(4, 6)), vec![4, 5], (None, vec![])),
+            (Some(Offset::new(6, 8)), vec![6, 7], (None, vec![])),
+            (None, vec![], (None, vec![])),
+        ];
+
+        //        When & Then
+        for (offset, reference_offsets, expected) in test_tuples.iter() {
+            assert_eq!(
+                map_offsets(*offset, reference_offsets.clone(), shift),
+                *expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_unknown_byte_fallback() {
+        //        Given
+        let mut vocab = generate_test_vocab();
+        for (index, byte) in [0xF0u8, 0x9F, 0x98, 0x80].iter().enumerate() {
+            vocab
+                .values
+                .insert(format!("<{byte:#04X?}>"), 11 + index as i64);
+        }
+        let tokens = vec![
+            Token {
+                text: "hello".to_owned(),
+                offset: Offset::new(0, 5),
+                reference_offsets: vec![0, 1, 2, 3, 4],
+                mask: Mask::None,
+            },
+            Token {
+                text: "a\u{1F600}".to_owned(),
+                offset: Offset::new(6, 8),
+                reference_offsets: vec![6, 7],
+                mask: Mask::None,
+            },
+            Token {
+                text: "[UNK]".to_owned(),
+                offset: Offset::new(9, 10),
+                reference_offsets: vec![9],
+                mask: Mask::Special,
+            },
+        ];
+
+        //        When
+        let output = tokens
+            .into_iter()
+            .flat_map(|token| {
+                unknown_byte_fallback(token.as_ref(), &vocab).unwrap_or_else(|| vec![token])
+            })
+            .collect::<Vec<Token>>();
+
+        //        Then
+        assert_eq!(
+            output
+                .iter()
+                .map(|token| token.text.as_str())
+                .collect::<Vec<_>>(),
+            vec!["hello", "<0x61>", "<0xF0>", "<0x9F>", "<0x98>", "<0x80>", "[UNK]"]
+        );
+        assert_eq!(
+            output.iter().map(|token| token.offset).collect::<Vec<_>>(),
+            vec![
+                Offset::new(0, 5),
+                Offset::new(6, 7),
+                Offset::new(7, 8),
+                Offset::new(7, 8),
+                Offset::new(7, 8),
+                Offset::new(7, 8),
+                Offset::new(9, 10),
+            ]
+        );
+        assert_eq!(
+            output
+                .iter()
+                .map(|token| vocab.token_to_id(&token.text))
+                .collect::<Vec<_>>(),
+            vec![0, 2, 11, 12, 13, 14, 2]
+        );
+    }
+
+    #[test]
+    fn test_apply_unknown_piece_policy() {
+        //        Given
+        let vocab = generate_test_vocab();
+        let tokens = vec![
+            Token {
+                text: "hello".to_owned(),
+                offset: Offset::new(0, 5),
+                reference_offsets: vec![0, 1, 2, 3, 4],
+                mask: Mask::None,
+            },
+            Token {
+                text: "a\u{1F600}".to_owned(),
+                offset: Offset::new(6, 8),
+                reference_offsets: vec![6, 7],
+                mask: Mask::None,
+            },
+            Token {
+                text: "<lang>".to_owned(),
+                offset: Offset::new(9, 10),
+                reference_offsets: vec![9],
+                mask: Mask::Special,
+            },
+        ];
+
+        let test_tuples = [
+            (
+                UnknownPiecePolicy::ReplaceWithUnk,
+                vec!["hello", "a\u{1F600}", "<lang>"],
+            ),
+            (UnknownPiecePolicy::SkipToken, vec!["hello", "<lang>"]),
+            (
+                UnknownPiecePolicy::ByteFallback,
+                vec![
+                    "hello", "<0x61>", "<0xF0>", "<0x9F>", "<0x98>", "<0x80>", "<lang>",
+                ],
+            ),
+        ];
+
+        //        When & Then
+        for (unknown_piece_policy, expected_tokens) in test_tuples {
+            let output = apply_unknown_piece_policy(tokens.clone(), &vocab, unknown_piece_policy);
+            assert_eq!(
+                output
+                    .iter()
+                    .map(|token| token.text.as_str())
+                    .collect::<Vec<_>>(),
+                expected_tokens
+            );
+        }
+    }
+
+    #[test]
+    fn test_merge_byte_fallback_pieces() {
+        //        Given
+        let test_tuples = [
+            (
+                vec!["\u{2581}a", "<0xF0>", "<0x9F>", "<0x98>", "<0x80>"],
+                vec!["\u{2581}a", "\u{1F600}"],
+            ),
+            (
+                vec!["<0xC3>", "<0xA9>", "t", "<0xC3>"],
+                vec!["\u{e9}", "t", "\u{FFFD}"],
+            ),
+            (
+                vec!["<0x>", "<0x41>", "<0xZZ>"],
+                vec!["<0x>", "A", "<0xZZ>"],
+            ),
+        ];
+
+        //        When & Then
+        for (pieces, expected_pieces) in test_tuples.iter() {
+            let pieces = pieces
+                .iter()
+                .map(|piece| piece.to_string())
+                .collect::<Vec<_>>();
+            assert_eq!(&merge_byte_fallback_pieces(pieces), expected_pieces);
+        }
+    }
+
+    #[test]
+    fn test_replace_in_pieces() {
+        //        Given
+        let test_tuples = [
+            (
+                vec!["Hello", " .", " Do", " n't"],
+                vec!["Hello", ".", " Do", "n't"],
+            ),
+            (
+                vec!["I", " do", " not", " know", " ' ", "x"],
+                vec!["I", " do", "n't", " know", "'", "x"],
+            ),
+            (vec!["a", " ", "!", " ?"], vec!["a", "", "!", "?"]),
+            (vec!["", "it", " 's"], vec!["", "it", "'s"]),
+        ];
+
+        //        When & Then
+        for (pieces, expected_pieces) in test_tuples.iter() {
+            let pieces = pieces
+                .iter()
+                .map(|piece| piece.to_string())
+                .collect::<Vec<_>>();
+            let joined = pieces.concat();
+            let cleaned_pieces = replace_in_pieces(pieces, &CLEAN_UP_TOKENIZATION_REPLACEMENTS);
+            assert_eq!(&cleaned_pieces, expected_pieces);
+            assert_eq!(
+                cleaned_pieces.concat(),
+                CLEAN_UP_TOKENIZATION_REPLACEMENTS
+                    .iter()
+                    .fold(joined, |text, (pattern, replacement)| text
+                        .replace(pattern, replacement))
+            );
+        }
+    }
+
+    #[test]
+    fn test_normalize_for_verification() {
+        //        Given
+        let test_tuples = [
+            ("", ""),
+            ("Hello, World!", "hello,world!"),
+            ("  Café \t\r\n crème ", "cafecreme"),
+            ("ﬁne ½", "fine1⁄2"),
+            ("中华 人", "中华人"),
+        ];
+
+        //        When & Then
+        for (input, expected) in test_tuples.iter() {
+            assert_eq!(normalize_for_verification(input), *expected);
+        }
+    }
 }
@@ -0,0 +1,176 @@
+// Copyright 2019 Guillaume Becquin
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//     http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tokenizer::base_tokenizer::Tokenizer;
+use crate::vocab::Vocab;
+use std::collections::HashMap;
+
+/// # Vocabulary trie
+/// Trie over the surface text of the (non-special) tokens of a vocabulary, allowing constrained
+/// decoding implementations (for example grammar-guided generation) to efficiently query the tokens
+/// that may be generated next. The surface text of a token is the text it contributes to a decoded
+/// sequence, including the leading space of word-initial tokens (e.g. `" world"` for `"Ġworld"`,
+/// `"▁world"` or `"world"` in a WordPiece vocabulary, `"ffa"` for `"##ffa"`). Tokens with an empty
+/// surface text are not indexed.
+#[derive(Debug, Clone, Default)]
+pub struct VocabTrie {
+    root: VocabTrieNode,
+    num_tokens: usize,
+}
+
+#[derive(Debug, Clone, Default)]
+struct VocabTrieNode {
+    token_ids: Vec<i64>,
+    children: HashMap<char, VocabTrieNode>,
+}
+
+impl VocabTrieNode {
+    fn collect_token_ids(&self, token_ids: &mut Vec<i64>) {
+        token_ids.extend_from_slice(&self.token_ids);
+        for child in self.children.values() {
+            child.collect_token_ids(token_ids);
+        }
+    }
+}
+
+impl VocabTrie {
+    /// Builds a trie from pairs of surface text and token id
+    ///
+    /// # Parameters
+    /// - surface_texts (`IntoIterator<Item = (S, i64)>`): surface text and id of the tokens to index
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_tokenizers::tokenizer::VocabTrie;
+    /// let trie = VocabTrie::from_surface_texts(vec![("hello", 0), (" world", 1), ("he", 2)]);
+    ///
+    /// assert_eq!(trie.allowed_token_ids("hel"), vec![0, 2]);
+    /// ```
+    pub fn from_surface_texts<I, S>(surface_texts: I) -> VocabTrie
+    where
+        I: IntoIterator<Item = (S, i64)>,
+        S: AsRef<str>,
+    {
+        let mut trie = VocabTrie::default();
+        for (surface_text, token_id) in surface_texts {
+            trie.insert(surface_text.as_ref(), token_id);
+        }
+        trie
+    }
+
+    /// Builds a trie over the vocabulary of a tokenizer. The surface text of each token is obtained
+    /// with `Tokenizer::convert_tokens_to_string`, decoding the token between two unknown tokens
+    /// so that the whitespace handling of the tokenizer is preserved. Special tokens are not indexed.
+    ///
+    /// # Parameters
+    /// - tokenizer (`&Tokenizer`): tokenizer whose vocabulary is indexed
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::{Gpt2Tokenizer, VocabTrie};
+    /// let tokenizer =
+    ///     Gpt2Tokenizer::from_file("path/to/vocab/file", "path/to/merges/file", false, false)
+    ///         .unwrap();
+    ///
+    /// let trie = VocabTrie::from_tokenizer(&tokenizer);
+    /// let allowed_token_ids = trie.allowed_token_ids(" {\"name\":");
+    /// ```
+    pub fn from_tokenizer<T, V>(tokenizer: &T) -> VocabTrie
+    where
+        T: Tokenizer<V> + ?Sized,
+        V: Vocab,
+    {
+        let vocab = tokenizer.vocab();
+        let unknown_token = vocab.get_unknown_value().to_owned();
+        let decode = |tokens: &[&str]| {
+            tokenizer
+                .convert_tokens_to_string(tokens.iter().map(|token| token.to_string()).collect())
+        };
+        let prefix = decode(&[&unknown_token]);
+        let pair = decode(&[&unknown_token, &unknown_token]);
+        let suffix = pair
+            .strip_prefix(prefix.as_str())
+            .unwrap_or(&prefix)
+            .to_owned();
+
+        let mut trie = VocabTrie::default();
+        for (token, &token_id) in vocab.values() {
+            if vocab.special_values().contains_key(token) {
+                continue;
+            }
+            let surrounded = decode(&[&unknown_token, token, &unknown_token]);
+            match surrounded
+                .strip_prefix(prefix.as_str())
+                .and_then(|text| text.strip_suffix(suffix.as_str()))
+            {
+                Some(surface_text) => trie.insert(surface_text, token_id),
+                None => trie.insert(&decode(&[token]), token_id),
+            }
+        }
+        trie
+    }
+
+    /// Indexes a token under its surface text. Empty surface texts are ignored.
+    pub fn insert(&mut self, surface_text: &str, token_id: i64) {
+        if surface_text.is_empty() {
+            return;
+        }
+        let mut node = &mut self.root;
+        for character in surface_text.chars() {
+            node = node.children.entry(character).or_default();
+        }
+        node.token_ids.push(token_id);
+        self.num_tokens += 1;
+    }
+
+    /// Returns the number of tokens indexed
+    pub fn len(&self) -> usize {
+        self.num_tokens
+    }
+
+    /// Returns true if no token is indexed
+    pub fn is_empty(&self) -> bool {
+        self.num_tokens == 0
+    }
+
+    /// Returns the ids of the tokens that may be generated next for a text continuation starting
+    /// with `prefix`: tokens whose surface text is a prefix of `prefix` (the continuation continues
+    /// after the token) and tokens whose surface text starts with `prefix` (the token completes the
+    /// continuation). An empty prefix allows all indexed tokens. The ids are sorted.
+    ///
+    /// # Parameters
+    /// - prefix (`&str`): text the continuation has to start with
+    ///
+    /// # Returns
+    /// `Vec<i64>` ids of the allowed tokens
+    pub fn allowed_token_ids(&self, prefix: &str) -> Vec<i64> {
+        let mut token_ids = vec![];
+        let mut node = &self.root;
+        for character in prefix.chars() {
+            match node.children.get(&character) {
+                Some(child) => {
+                    node = child;
+                    token_ids.extend_from_slice(&node.token_ids);
+                }
+                None => {
+                    token_ids.sort_unstable();
+                    return token_ids;
+                }
+            }
+        }
+        token_ids.truncate(token_ids.len() - node.token_ids.len());
+        node.collect_token_ids(&mut token_ids);
+        token_ids.sort_unstable();
+        token_ids
+    }
+}
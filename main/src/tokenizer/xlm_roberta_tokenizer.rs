@@ -17,9 +17,13 @@ use crate::tokenizer::base_tokenizer::{
     Mask, Offset, OffsetSize, Token, TokenIdsWithOffsets, TokenIdsWithSpecialTokens, TokenRef,
 };
 use crate::tokenizer::tokenization_utils::{
-    clean_text, decompose_nfkc, is_whitespace, lowercase, split_on_special_tokens,
+    apply_unknown_piece_policy, clean_text, decompose_nfkc, is_whitespace, lowercase,
+    merge_byte_fallback_pieces, split_on_special_tokens,
+};
+use crate::tokenizer::{
+    ConfigurableTokenizer, MultiThreadedTokenizer, Tokenizer, TokenizerDescription,
+    TokenizerOptions, UnigramTokenizer, UnknownPiecePolicy,
 };
-use crate::tokenizer::{MultiThreadedTokenizer, Tokenizer};
 use crate::vocab::{SentencePieceModel, Vocab, XLMRobertaVocab};
 
 /// # XLM RoBERTa tokenizer
@@ -34,6 +38,7 @@ pub struct XLMRobertaTokenizer {
     model: SentencePieceModel,
     vocab: XLMRobertaVocab,
     lower_case: bool,
+    options: TokenizerOptions,
 }
 
 impl XLMRobertaTokenizer {
@@ -61,6 +66,7 @@ impl XLMRobertaTokenizer {
             model,
             vocab,
             lower_case,
+            options: TokenizerOptions::default(),
         })
     }
 
@@ -98,9 +104,36 @@ impl XLMRobertaTokenizer {
             model,
             vocab,
             lower_case,
+            options: TokenizerOptions::default(),
         })
     }
 
+    /// Create a new instance of a `XLMRobertaTokenizer` from the bytes of a SentencePiece protobuf
+    /// file, allowing to embed the model in a self-contained binary (e.g. using `include_bytes!`).
+    ///
+    /// # Parameters
+    /// - bytes (`&[u8]`): content of the SentencePiece model file
+    /// - lower_case (`bool`): flag indicating if the text should be lower-cased as part of the tokenization
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::XLMRobertaTokenizer;
+    /// let lower_case = false;
+    /// let bytes = std::fs::read("path/to/sentencepiece.bpe.model").unwrap();
+    /// let tokenizer = XLMRobertaTokenizer::from_protobuf_bytes(&bytes, lower_case).unwrap();
+    /// ```
+    pub fn from_protobuf_bytes(
+        bytes: &[u8],
+        lower_case: bool,
+    ) -> Result<XLMRobertaTokenizer, TokenizerError> {
+        let model = SentencePieceModel::from_protobuf_bytes(bytes)?;
+        let vocab = XLMRobertaVocab::from_protobuf_bytes(bytes)?;
+        Ok(Self::from_existing_vocab_and_model(
+            vocab, model, lower_case,
+        ))
+    }
+
     /// Create a new instance of a `MarianTokenizer` from an existing vocabulary and model
     ///
     /// # Parameters
@@ -128,6 +161,7 @@ impl XLMRobertaTokenizer {
             model,
             vocab,
             lower_case,
+            options: TokenizerOptions::default(),
         }
     }
 }
@@ -140,6 +174,17 @@ impl Tokenizer<XLMRobertaVocab> for XLMRobertaTokenizer {
         &mut self.vocab
     }
 
+    fn options(&self) -> &TokenizerOptions {
+        &self.options
+    }
+
+    fn describe(&self) -> TokenizerDescription {
+        TokenizerDescription {
+            lower_case: Some(self.lower_case),
+            ..TokenizerDescription::new("xlm_roberta", &self.vocab)
+        }
+    }
+
     fn tokenize_to_tokens(&self, text: TokenRef) -> Vec<Token> {
         let mut tokens = split_on_special_tokens(text, &self.vocab)
             .into_iter()
@@ -168,10 +213,15 @@ impl Tokenizer<XLMRobertaVocab> for XLMRobertaTokenizer {
                 sub_tokens.push(token.clone());
             }
         }
-        sub_tokens
+        apply_unknown_piece_policy(sub_tokens, &self.vocab, self.unknown_piece_policy())
     }
 
     fn convert_tokens_to_string(&self, tokens: Vec<String>) -> String {
+        let tokens = if self.unknown_piece_policy() == UnknownPiecePolicy::ByteFallback {
+            merge_byte_fallback_pieces(tokens)
+        } else {
+            tokens
+        };
         tokens
             .into_iter()
             .map(|v| v.replace('\u{2581}', " "))
@@ -237,3 +287,15 @@ impl Tokenizer<XLMRobertaVocab> for XLMRobertaTokenizer {
 }
 
 impl MultiThreadedTokenizer<XLMRobertaVocab> for XLMRobertaTokenizer {}
+
+impl ConfigurableTokenizer<XLMRobertaVocab> for XLMRobertaTokenizer {
+    fn options_mut(&mut self) -> &mut TokenizerOptions {
+        &mut self.options
+    }
+}
+
+impl UnigramTokenizer<XLMRobertaVocab> for XLMRobertaTokenizer {
+    fn unigram_model(&self) -> &SentencePieceModel {
+        &self.model
+    }
+}
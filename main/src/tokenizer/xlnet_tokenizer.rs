@@ -16,9 +16,13 @@ use crate::error::TokenizerError;
 use crate::tokenizer::base_tokenizer::{TokenIdsWithOffsets, TokenIdsWithSpecialTokens};
 use crate::tokenizer::tokenization_utils::strip_accents;
 use crate::tokenizer::tokenization_utils::{
-    clean_text, decompose_nfkc, is_whitespace, lowercase, replace_string, split_on_special_tokens,
+    apply_unknown_piece_policy, clean_text, decompose_nfkc, is_whitespace, lowercase,
+    merge_byte_fallback_pieces, replace_string, split_on_special_tokens,
+};
+use crate::tokenizer::{
+    ConfigurableTokenizer, MultiThreadedTokenizer, Tokenizer, TokenizerDescription,
+    TokenizerOptions, UnigramTokenizer, UnknownPiecePolicy,
 };
-use crate::tokenizer::{MultiThreadedTokenizer, Tokenizer};
 use crate::vocab::{SentencePieceModel, Vocab, XLNetVocab};
 use crate::{Mask, Offset, OffsetSize, Token, TokenRef};
 
@@ -26,7 +30,7 @@ use crate::{Mask, Offset, OffsetSize, Token, TokenRef};
 /// XLNet tokenizer performing:
 /// - Splitting on special tokens
 /// - Text cleaning
-/// - NFKC decomposition
+/// - (optional) NFKC decomposition
 /// - (optional) lower casing
 /// - (optional) accents stripping
 /// - SentencePiece decomposition
@@ -36,6 +40,8 @@ pub struct XLNetTokenizer {
     vocab: XLNetVocab,
     lower_case: bool,
     strip_accents: bool,
+    nfkc_normalization: bool,
+    options: TokenizerOptions,
 }
 
 impl XLNetTokenizer {
@@ -68,6 +74,8 @@ impl XLNetTokenizer {
             vocab,
             lower_case,
             strip_accents,
+            nfkc_normalization: true,
+            options: TokenizerOptions::default(),
         })
     }
 
@@ -108,9 +116,44 @@ impl XLNetTokenizer {
             vocab,
             lower_case,
             strip_accents,
+            nfkc_normalization: true,
+            options: TokenizerOptions::default(),
         })
     }
 
+    /// Create a new instance of a `XLNetTokenizer` from the bytes of a SentencePiece protobuf file,
+    /// allowing to embed the model in a self-contained binary (e.g. using `include_bytes!`).
+    ///
+    /// # Parameters
+    /// - bytes (`&[u8]`): content of the SentencePiece model file
+    /// - lower_case (`bool`): flag indicating if the text should be lower-cased as part of the tokenization
+    /// - strip_accents (`bool`): flag indicating if accents should be stripped from the text
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::XLNetTokenizer;
+    /// let lower_case = false;
+    /// let strip_accents = false;
+    /// let bytes = std::fs::read("path/to/spiece.model").unwrap();
+    /// let tokenizer =
+    ///     XLNetTokenizer::from_protobuf_bytes(&bytes, lower_case, strip_accents).unwrap();
+    /// ```
+    pub fn from_protobuf_bytes(
+        bytes: &[u8],
+        lower_case: bool,
+        strip_accents: bool,
+    ) -> Result<XLNetTokenizer, TokenizerError> {
+        let model = SentencePieceModel::from_protobuf_bytes(bytes)?;
+        let vocab = XLNetVocab::from_protobuf_bytes(bytes)?;
+        Ok(Self::from_existing_vocab_and_model(
+            vocab,
+            model,
+            lower_case,
+            strip_accents,
+        ))
+    }
+
     /// Create a new instance of a `XLNetTokenizer` from an existing vocabulary and model
     ///
     /// # Parameters
@@ -143,9 +186,31 @@ impl XLNetTokenizer {
             vocab,
             lower_case,
             strip_accents,
+            nfkc_normalization: true,
+            options: TokenizerOptions::default(),
         }
     }
 
+    /// Enables or disables the NFKC normalization of the text before tokenization. NFKC
+    /// normalization is enabled by default, matching the reference XLNet tokenizer.
+    ///
+    /// # Parameters
+    /// - nfkc_normalization (`bool`): flag indicating if the text should be NFKC-normalized before tokenization
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::XLNetTokenizer;
+    /// let strip_accents = false;
+    /// let lower_case = false;
+    /// let mut tokenizer =
+    ///     XLNetTokenizer::from_file("path/to/vocab/file", lower_case, strip_accents).unwrap();
+    /// tokenizer.set_nfkc_normalization(false);
+    /// ```
+    pub fn set_nfkc_normalization(&mut self, nfkc_normalization: bool) {
+        self.nfkc_normalization = nfkc_normalization;
+    }
+
     fn post_process_pieces<'a>(&self, tokens: &'a mut Vec<Token>) -> &'a Vec<Token> {
         let mut positions_to_update: Vec<(usize, Vec<Token>)> = vec![];
         for (token_idx, token) in tokens.iter().enumerate() {
@@ -200,6 +265,18 @@ impl Tokenizer<XLNetVocab> for XLNetTokenizer {
         &mut self.vocab
     }
 
+    fn options(&self) -> &TokenizerOptions {
+        &self.options
+    }
+
+    fn describe(&self) -> TokenizerDescription {
+        TokenizerDescription {
+            lower_case: Some(self.lower_case),
+            strip_accents: Some(self.strip_accents),
+            ..TokenizerDescription::new("xlnet", &self.vocab)
+        }
+    }
+
     fn tokenize_to_tokens(&self, text: TokenRef) -> Vec<Token> {
         let mut tokens = split_on_special_tokens(text, &self.vocab)
             .into_iter()
@@ -212,7 +289,9 @@ impl Tokenizer<XLNetVocab> for XLNetTokenizer {
                 replace_string(token, "``", "\"");
                 replace_string(token, "\'\'", "\"");
                 clean_text(token, true);
-                decompose_nfkc(token);
+                if self.nfkc_normalization {
+                    decompose_nfkc(token);
+                }
                 if self.lower_case {
                     lowercase(token);
                 }
@@ -234,10 +313,15 @@ impl Tokenizer<XLNetVocab> for XLNetTokenizer {
                 sub_tokens.push(token.clone());
             }
         }
-        sub_tokens
+        apply_unknown_piece_policy(sub_tokens, &self.vocab, self.unknown_piece_policy())
     }
 
     fn convert_tokens_to_string(&self, tokens: Vec<String>) -> String {
+        let tokens = if self.unknown_piece_policy() == UnknownPiecePolicy::ByteFallback {
+            merge_byte_fallback_pieces(tokens)
+        } else {
+            tokens
+        };
         tokens
             .into_iter()
             .map(|v| v.replace('\u{2581}', " "))
@@ -301,3 +385,15 @@ impl Tokenizer<XLNetVocab> for XLNetTokenizer {
 }
 
 impl MultiThreadedTokenizer<XLNetVocab> for XLNetTokenizer {}
+
+impl ConfigurableTokenizer<XLNetVocab> for XLNetTokenizer {
+    fn options_mut(&mut self) -> &mut TokenizerOptions {
+        &mut self.options
+    }
+}
+
+impl UnigramTokenizer<XLNetVocab> for XLNetTokenizer {
+    fn unigram_model(&self) -> &SentencePieceModel {
+        &self.model
+    }
+}
@@ -14,7 +14,8 @@
 
 use crate::error::TokenizerError;
 use crate::vocab::base_vocab::{
-    read_protobuf_file, read_special_token_mapping_file, swap_key_values, SpecialTokenMap,
+    read_protobuf_bytes, read_protobuf_file, read_special_token_mapping_file, swap_key_values,
+    SpecialTokenMap,
 };
 use crate::vocab::Vocab;
 use std::collections::HashMap;
@@ -100,6 +101,34 @@ impl AlbertVocab {
             .as_deref()
             .unwrap_or(DEFAULT_MASK_TOKEN)
     }
+
+    fn default_special_token_map() -> SpecialTokenMap {
+        SpecialTokenMap {
+            unk_token: DEFAULT_UNK_TOKEN.to_string(),
+            pad_token: Some(DEFAULT_PAD_TOKEN.to_string()),
+            bos_token: Some(DEFAULT_BOS_TOKEN.to_string()),
+            sep_token: Some(DEFAULT_SEP_TOKEN.to_string()),
+            cls_token: Some(DEFAULT_CLS_TOKEN.to_string()),
+            eos_token: Some(DEFAULT_EOS_TOKEN.to_string()),
+            mask_token: Some(DEFAULT_MASK_TOKEN.to_string()),
+            additional_special_tokens: None,
+        }
+    }
+
+    /// Creates a vocabulary from the bytes of a SentencePiece protobuf file, allowing to embed
+    /// the model in a binary (e.g. using `include_bytes!`).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use rust_tokenizers::vocab::AlbertVocab;
+    ///
+    /// let bytes = std::fs::read("path/to/spiece.model").unwrap();
+    /// let vocab = AlbertVocab::from_protobuf_bytes(&bytes).unwrap();
+    /// ```
+    pub fn from_protobuf_bytes(bytes: &[u8]) -> Result<AlbertVocab, TokenizerError> {
+        let values = read_protobuf_bytes(bytes)?;
+        Self::from_values_and_special_token_map(values, Self::default_special_token_map())
+    }
 }
 
 impl Vocab for AlbertVocab {
@@ -123,6 +152,10 @@ impl Vocab for AlbertVocab {
         &self.special_indices
     }
 
+    fn special_token_map(&self) -> &SpecialTokenMap {
+        &self.special_token_map
+    }
+
     fn values_mut(&mut self) -> &mut HashMap<String, i64> {
         &mut self.values
     }
@@ -142,16 +175,7 @@ impl Vocab for AlbertVocab {
     fn from_file<P: AsRef<Path>>(path: P) -> Result<AlbertVocab, TokenizerError> {
         let values = read_protobuf_file(path.as_ref())?;
 
-        let special_token_map = SpecialTokenMap {
-            unk_token: DEFAULT_UNK_TOKEN.to_string(),
-            pad_token: Some(DEFAULT_PAD_TOKEN.to_string()),
-            bos_token: Some(DEFAULT_BOS_TOKEN.to_string()),
-            sep_token: Some(DEFAULT_SEP_TOKEN.to_string()),
-            cls_token: Some(DEFAULT_CLS_TOKEN.to_string()),
-            eos_token: Some(DEFAULT_EOS_TOKEN.to_string()),
-            mask_token: Some(DEFAULT_MASK_TOKEN.to_string()),
-            additional_special_tokens: None,
-        };
+        let special_token_map = Self::default_special_token_map();
         Self::from_values_and_special_token_map(values, special_token_map)
     }
 
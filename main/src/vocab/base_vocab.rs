@@ -17,6 +17,7 @@ use std::collections::{HashMap, HashSet};
 use std::fs::File;
 use std::hash::Hash;
 use std::io::{BufRead, BufReader, Read};
+use std::ops::Range;
 use std::path::Path;
 
 pub(crate) fn swap_key_values<T: Clone, U: Hash + Eq + Copy>(
@@ -28,29 +29,91 @@ pub(crate) fn swap_key_values<T: Clone, U: Hash + Eq + Copy>(
         .collect()
 }
 
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// Path reported in the parsing errors of vocabularies and models read from bytes
+pub(crate) const IN_MEMORY_FILE: &str = "<in-memory file>";
+
+/// Open a text file for buffered reading, skipping the UTF-8 byte order mark (BOM) that some
+/// editors (e.g. Notepad on Windows) write at the start of the file.
+pub(crate) fn open_text_file<P: AsRef<Path>>(path: P) -> Result<BufReader<File>, TokenizerError> {
+    let f = File::open(&path).map_err(|e| TokenizerError::file_not_found(&path, e))?;
+    let mut br = BufReader::new(f);
+    let has_bom = br
+        .fill_buf()
+        .map_err(|e| TokenizerError::io_error(&path, e))?
+        .starts_with(UTF8_BOM);
+    if has_bom {
+        br.consume(UTF8_BOM.len());
+    }
+    Ok(br)
+}
+
 /// Read a flat vocab.txt file (single column, one token per line)
-/// Indices are inferred based on their position in this flat file.
+/// Indices are inferred based on their position in this flat file. Leading and trailing
+/// whitespace (including the carriage returns of Windows line endings) is removed from the tokens
+/// and empty lines are skipped.
 pub(crate) fn read_flat_file<P: AsRef<Path>>(
     path: P,
 ) -> Result<HashMap<String, i64>, TokenizerError> {
-    let f = File::open(&path).map_err(|e| {
-        TokenizerError::FileNotFound(format!(
-            "{} vocabulary file not found :{}",
-            path.as_ref().display(),
-            e
-        ))
-    })?;
-    let br = BufReader::new(f);
+    let br = open_text_file(&path)?;
     let mut data = HashMap::new();
 
     for (index, line) in br.lines().enumerate() {
         let line = match line {
             Ok(value) => value,
             Err(e) => {
-                return Err(TokenizerError::VocabularyParsingError(e.to_string()));
+                return Err(TokenizerError::vocabulary_parsing_error(&path, e));
+            }
+        };
+        let token = line.trim();
+        if token.is_empty() {
+            continue;
+        }
+        data.insert(token.to_owned(), index as i64);
+    }
+    Ok(data)
+}
+
+/// Read a token list file (one token per line, with an optional whitespace-separated frequency column).
+/// Indices are inferred based on the line position of each token, empty lines are skipped and
+/// their index is left unused.
+pub(crate) fn read_token_list_file<P: AsRef<Path>>(
+    path: P,
+) -> Result<HashMap<String, i64>, TokenizerError> {
+    let br = open_text_file(&path)?;
+    let mut data = HashMap::new();
+
+    for (line_index, line) in br.lines().enumerate() {
+        let line = match line {
+            Ok(value) => value,
+            Err(e) => {
+                return Err(TokenizerError::vocabulary_parsing_error(&path, e));
+            }
+        };
+        let mut fields = line.split_whitespace();
+        let token = match (fields.next(), fields.next(), fields.next()) {
+            (None, _, _) => continue,
+            (Some(token), None, _) => token,
+            (Some(token), Some(frequency), None) if frequency.parse::<u64>().is_ok() => token,
+            _ => {
+                return Err(TokenizerError::vocabulary_parsing_error(
+                    &path,
+                    format!(
+                        "Incorrect token list format at line {}, expected '<token>' or '<token> <frequency>': {}",
+                        line_index + 1,
+                        line
+                    ),
+                ));
             }
         };
-        data.insert(line.trim().to_owned(), index as i64);
+        if data.contains_key(token) {
+            return Err(TokenizerError::vocabulary_parsing_error(
+                &path,
+                format!("Duplicate token {} at line {}", token, line_index + 1),
+            ));
+        }
+        data.insert(token.to_owned(), line_index as i64);
     }
     Ok(data)
 }
@@ -59,41 +122,109 @@ pub(crate) fn read_flat_file<P: AsRef<Path>>(
 pub(crate) fn read_json_file<P: AsRef<Path>>(
     path: P,
 ) -> Result<HashMap<String, i64>, TokenizerError> {
-    let f = File::open(&path).map_err(|e| {
-        TokenizerError::FileNotFound(format!(
-            "{} vocabulary file not found :{}",
-            path.as_ref().display(),
-            e
-        ))
-    })?;
-    let br = BufReader::new(f);
+    let br = open_text_file(&path)?;
     let values: HashMap<String, i64> = match serde_json::from_reader(br) {
         Ok(value) => value,
         Err(e) => {
-            return Err(TokenizerError::VocabularyParsingError(e.to_string()));
+            return Err(TokenizerError::vocabulary_parsing_error(&path, e));
         }
     };
     Ok(values)
 }
 
+/// Read the bytes of a json file (mapping of vocabulary to indices).
+pub(crate) fn read_json_bytes(bytes: &[u8]) -> Result<HashMap<String, i64>, TokenizerError> {
+    serde_json::from_slice(bytes)
+        .map_err(|e| TokenizerError::vocabulary_parsing_error(IN_MEMORY_FILE, e))
+}
+
+/// Read a fairseq dictionary file (`dict.txt`, one `token count` pair per line).
+/// Following fairseq, the BOS, padding, EOS and unknown tokens are prepended to the dictionary
+/// entries (in this order) and the mask token is appended if it is not already in the dictionary.
+/// The special token strings are read from the `special_token_map` provided, falling back to the
+/// fairseq defaults (`<s>`, `<pad>`, `</s>`) when not set.
+pub(crate) fn read_fairseq_dict_file<P: AsRef<Path>>(
+    path: P,
+    special_token_map: &SpecialTokenMap,
+) -> Result<HashMap<String, i64>, TokenizerError> {
+    let br = open_text_file(&path)?;
+    let mut data = HashMap::new();
+
+    for token in [
+        special_token_map.bos_token.as_deref().unwrap_or("<s>"),
+        special_token_map.pad_token.as_deref().unwrap_or("<pad>"),
+        special_token_map.eos_token.as_deref().unwrap_or("</s>"),
+        special_token_map.unk_token.as_str(),
+    ] {
+        if !data.contains_key(token) {
+            data.insert(token.to_owned(), data.len() as i64);
+        }
+    }
+
+    for (line_index, line) in br.lines().enumerate() {
+        let line = match line {
+            Ok(value) => value,
+            Err(e) => {
+                return Err(TokenizerError::vocabulary_parsing_error(&path, e));
+            }
+        };
+        let line = line.trim_end();
+        if line.is_empty() {
+            continue;
+        }
+        let (line, overwrite) = match line.strip_suffix(" #fairseq:overwrite") {
+            Some(stripped) => (stripped, true),
+            None => (line, false),
+        };
+        let token = match line.rsplit_once(' ') {
+            Some((token, count)) if count.parse::<i64>().is_ok() => token,
+            _ => {
+                return Err(TokenizerError::vocabulary_parsing_error(
+                    &path,
+                    format!(
+                        "Incorrect dictionary format at line {}, expected '<token> <count>': {}",
+                        line_index + 1,
+                        line
+                    ),
+                ));
+            }
+        };
+        if data.contains_key(token) {
+            if overwrite {
+                continue;
+            }
+            return Err(TokenizerError::vocabulary_parsing_error(
+                &path,
+                format!(
+                    "Duplicate token {} at line {} (add '#fairseq:overwrite' to allow duplicates)",
+                    token,
+                    line_index + 1
+                ),
+            ));
+        }
+        data.insert(token.to_owned(), data.len() as i64);
+    }
+
+    if let Some(mask_token) = &special_token_map.mask_token {
+        if !data.contains_key(mask_token) {
+            data.insert(mask_token.clone(), data.len() as i64);
+        }
+    }
+    Ok(data)
+}
+
 pub(crate) fn open_protobuf_file<P: AsRef<Path>>(path: P) -> Result<ModelProto, TokenizerError> {
-    let mut f = File::open(&path).map_err(|e| {
-        TokenizerError::FileNotFound(format!(
-            "{} vocabulary file not found :{}",
-            path.as_ref().display(),
-            e
-        ))
-    })?;
+    let mut f = File::open(&path).map_err(|e| TokenizerError::file_not_found(&path, e))?;
     let mut contents = Vec::new();
     let proto = match f.read_to_end(&mut contents) {
         Ok(_) => match ModelProto::parse_from_bytes(contents.as_slice()) {
             Ok(proto_value) => proto_value,
             Err(e) => {
-                return Err(TokenizerError::VocabularyParsingError(e.to_string()));
+                return Err(TokenizerError::vocabulary_parsing_error(&path, e));
             }
         },
         Err(e) => {
-            return Err(TokenizerError::VocabularyParsingError(e.to_string()));
+            return Err(TokenizerError::vocabulary_parsing_error(&path, e));
         }
     };
     Ok(proto)
@@ -104,12 +235,27 @@ pub(crate) fn read_protobuf_file<P: AsRef<Path>>(
     path: P,
 ) -> Result<HashMap<String, i64>, TokenizerError> {
     let proto = open_protobuf_file(path)?;
+    Ok(read_protobuf_values(&proto))
+}
+
+/// Parse SentencePiece protobuf bytes (for example embedded in a binary using `include_bytes!`)
+pub(crate) fn parse_protobuf_bytes(bytes: &[u8]) -> Result<ModelProto, TokenizerError> {
+    ModelProto::parse_from_bytes(bytes)
+        .map_err(|e| TokenizerError::vocabulary_parsing_error(IN_MEMORY_FILE, e))
+}
+
+/// Read SentencePiece protobuf bytes and extract vocabulary from it.
+pub(crate) fn read_protobuf_bytes(bytes: &[u8]) -> Result<HashMap<String, i64>, TokenizerError> {
+    let proto = parse_protobuf_bytes(bytes)?;
+    Ok(read_protobuf_values(&proto))
+}
 
+fn read_protobuf_values(proto: &ModelProto) -> HashMap<String, i64> {
     let mut values = HashMap::new();
     for (idx, piece) in proto.get_pieces().iter().enumerate() {
         values.insert(piece.get_piece().to_owned(), idx as i64);
     }
-    Ok(values)
+    values
 }
 
 /// Read a special token mapping file (expects a JSON-like file with key-value pairs
@@ -117,16 +263,12 @@ pub(crate) fn read_protobuf_file<P: AsRef<Path>>(
 pub(crate) fn read_special_token_mapping_file<P: AsRef<Path>>(
     path: P,
 ) -> Result<SpecialTokenMap, TokenizerError> {
-    let f = File::open(&path).map_err(|e| {
-        TokenizerError::FileNotFound(format!(
-            "{} vocabulary file not found :{}",
-            path.as_ref().display(),
-            e
-        ))
-    })?;
-    let br = BufReader::new(f);
+    let br = open_text_file(&path)?;
     serde_json::from_reader(br).map_err(|e| {
-        TokenizerError::FileNotFound(format!("Invalid special token mapping file {e}"))
+        TokenizerError::vocabulary_parsing_error(
+            &path,
+            format!("Invalid special token mapping file: {e}"),
+        )
     })
 }
 
@@ -144,7 +286,7 @@ pub(crate) fn register_as_special_value(
     let token_id = match values.get(token) {
         Some(index) => *index,
         None => {
-            return Err(TokenizerError::TokenNotFound(format!(
+            return Err(TokenizerError::SpecialTokenNotFound(format!(
                 "The special value {token} could not be found in the vocabulary"
             )));
         }
@@ -153,6 +295,22 @@ pub(crate) fn register_as_special_value(
     Ok(())
 }
 
+/// Returns the value of an optional special token, failing if it is not defined
+///
+/// # Parameters
+/// - token (`&Option<String>`): optional special token value read from a special token map
+/// - token_name (`&str`): name of the special token (e.g. `mask_token`), used for error reporting
+pub(crate) fn required_special_token<'a>(
+    token: &'a Option<String>,
+    token_name: &str,
+) -> Result<&'a String, TokenizerError> {
+    token.as_ref().ok_or_else(|| {
+        TokenizerError::SpecialTokenNotFound(format!(
+            "The special token map does not define the required {token_name}"
+        ))
+    })
+}
+
 #[derive(Debug, Default, Clone, Deserialize)]
 pub struct SpecialTokenMap {
     pub unk_token: String,
@@ -199,6 +357,70 @@ impl SpecialTokenMap {
         }
         Ok(())
     }
+
+    /// Returns the special token map with its token strings replaced following the provided
+    /// remapping (from original to new token string). Tokens absent from the remapping are kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_tokenizers::vocab::SpecialTokenMap;
+    /// use std::collections::HashMap;
+    /// let special_token_map = SpecialTokenMap {
+    ///     unk_token: "[UNK]".to_string(),
+    ///     cls_token: Some("[CLS]".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// let remapping = HashMap::from([("[CLS]".to_string(), "<cls>".to_string())]);
+    ///
+    /// let remapped = special_token_map.remap(&remapping);
+    /// assert_eq!(remapped.cls_token.as_deref(), Some("<cls>"));
+    /// assert_eq!(remapped.unk_token, "[UNK]");
+    /// ```
+    pub fn remap(self, remapping: &HashMap<String, String>) -> SpecialTokenMap {
+        let remap_token = |token: String| remapping.get(&token).cloned().unwrap_or(token);
+        SpecialTokenMap {
+            unk_token: remap_token(self.unk_token),
+            pad_token: self.pad_token.map(remap_token),
+            bos_token: self.bos_token.map(remap_token),
+            sep_token: self.sep_token.map(remap_token),
+            cls_token: self.cls_token.map(remap_token),
+            eos_token: self.eos_token.map(remap_token),
+            mask_token: self.mask_token.map(remap_token),
+            additional_special_tokens: self
+                .additional_special_tokens
+                .map(|tokens| tokens.into_iter().map(remap_token).collect()),
+        }
+    }
+}
+
+/// # Vocabulary validation report
+/// Integrity issues found in a vocabulary by `Vocab::validate`. All fields are sorted to allow
+/// comparing reports across runs.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct VocabValidationReport {
+    /// Ids shared by several tokens, with the tokens mapped to each of them
+    pub duplicate_ids: Vec<(i64, Vec<String>)>,
+
+    /// Ranges of ids missing between 0 and the largest id of the vocabulary
+    pub id_gaps: Vec<Range<i64>>,
+
+    /// Special ids not directly following the previous special id, i.e. the start of each block
+    /// of special ids after the first one
+    pub non_contiguous_special_ids: Vec<i64>,
+
+    /// Tokens containing raw control characters (e.g. line feeds or null characters)
+    pub control_character_tokens: Vec<String>,
+}
+
+impl VocabValidationReport {
+    /// Returns true if no issue was found in the vocabulary
+    pub fn is_valid(&self) -> bool {
+        self.duplicate_ids.is_empty()
+            && self.id_gaps.is_empty()
+            && self.non_contiguous_special_ids.is_empty()
+            && self.control_character_tokens.is_empty()
+    }
 }
 
 /// # Base Vocab trait
@@ -219,6 +441,9 @@ pub trait Vocab {
     /// Return the map of token IDs to strings for special values
     fn special_indices(&self) -> &HashMap<i64, String>;
 
+    /// Return the special token map of the vocabulary
+    fn special_token_map(&self) -> &SpecialTokenMap;
+
     /// Return a mutable reference to the map of token strings to IDs
     fn values_mut(&mut self) -> &mut HashMap<String, i64>;
 
@@ -245,6 +470,44 @@ pub trait Vocab {
     where
         Self: Sized;
 
+    /// Read a vocabulary from file with `from_file`, then replace its special token strings
+    /// following the provided remapping (e.g. to use `<cls>` instead of `[CLS]`). Special tokens
+    /// absent from the remapping keep their default value. The default special tokens must be
+    /// present in the file, as well as their replacements.
+    ///
+    /// # Parameters
+    /// - path: path to the vocabulary file
+    /// - remapping (`&HashMap<String, String>`): map from default special token strings to the
+    ///   strings used by the vocabulary
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::tokenizer::BertTokenizer;
+    /// use rust_tokenizers::vocab::{BertVocab, Vocab};
+    /// use std::collections::HashMap;
+    /// let path = "path/to/file";
+    /// let remapping = HashMap::from([
+    ///     ("[CLS]".to_string(), "<cls>".to_string()),
+    ///     ("[SEP]".to_string(), "<sep>".to_string()),
+    /// ]);
+    ///
+    /// let vocab = BertVocab::from_file_with_special_token_remapping(path, &remapping).unwrap();
+    /// let tokenizer = BertTokenizer::from_existing_vocab(vocab, true, true);
+    /// ```
+    fn from_file_with_special_token_remapping<P: AsRef<Path>>(
+        path: P,
+        remapping: &HashMap<String, String>,
+    ) -> Result<Self, TokenizerError>
+    where
+        Self: Sized,
+    {
+        let mut vocab = Self::from_file(path)?;
+        let special_token_map = vocab.special_token_map().clone().remap(remapping);
+        let values = std::mem::take(vocab.values_mut());
+        Self::from_values_and_special_token_map(values, special_token_map)
+    }
+
     /// Read a vocabulary from file with special token mapping
     ///
     /// # Example
@@ -270,6 +533,40 @@ pub trait Vocab {
     where
         Self: Sized;
 
+    /// Read a vocabulary from a fairseq dictionary file (`dict.txt`) with a special token mapping.
+    /// The BOS, padding, EOS and unknown tokens are assigned the first indices, followed by the
+    /// dictionary entries in file order. The mask token (if any) is appended at the end of the
+    /// vocabulary if it is not part of the dictionary.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::vocab::{RobertaVocab, SpecialTokenMap, Vocab};
+    /// let path = "path/to/dict.txt";
+    /// let special_token_map = SpecialTokenMap {
+    ///     unk_token: "<unk>".to_string(),
+    ///     pad_token: Some("<pad>".to_string()),
+    ///     bos_token: Some("<s>".to_string()),
+    ///     sep_token: Some("</s>".to_string()),
+    ///     cls_token: Some("<s>".to_string()),
+    ///     eos_token: Some("</s>".to_string()),
+    ///     mask_token: Some("<mask>".to_string()),
+    ///     additional_special_tokens: None,
+    /// };
+    ///
+    /// let vocab = RobertaVocab::from_fairseq_dict_file(path, special_token_map);
+    /// ```
+    fn from_fairseq_dict_file<P: AsRef<Path>>(
+        path: P,
+        special_token_map: SpecialTokenMap,
+    ) -> Result<Self, TokenizerError>
+    where
+        Self: Sized,
+    {
+        let values = read_fairseq_dict_file(path, &special_token_map)?;
+        Self::from_values_and_special_token_map(values, special_token_map)
+    }
+
     /// Converts a token to an id, provided a `HashMap` of values, a `HashMap` of special values and
     /// the unknown value token string representation. This is not meant to be directly used, the method
     /// `token_to_id` offers a more convenient interface for most vocabularies, but needs to be implemented
@@ -346,6 +643,82 @@ pub trait Vocab {
     /// - `String`: token value for the index provided. If not found in the indices, returns the unknown token value
     fn id_to_token(&self, id: &i64) -> String;
 
+    /// Checks the integrity of the vocabulary, looking for ids shared by several tokens, gaps in
+    /// the ids, special ids that do not form a contiguous block and tokens containing raw control
+    /// characters. These usually indicate a broken vocabulary conversion.
+    ///
+    /// # Returns
+    /// - `VocabValidationReport`: integrity issues found in the vocabulary
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::vocab::{BertVocab, Vocab};
+    /// let path = "path/to/file";
+    ///
+    /// let bert_vocab = BertVocab::from_file(path).unwrap();
+    /// let report = bert_vocab.validate();
+    /// assert!(report.is_valid(), "{:?}", report);
+    /// ```
+    fn validate(&self) -> VocabValidationReport {
+        let mut tokens_by_id: HashMap<i64, Vec<String>> = HashMap::new();
+        for (token, id) in self.values().iter().chain(self.special_values().iter()) {
+            let tokens = tokens_by_id.entry(*id).or_default();
+            if !tokens.contains(token) {
+                tokens.push(token.clone());
+            }
+        }
+
+        let mut duplicate_ids = tokens_by_id
+            .iter()
+            .filter(|(_, tokens)| tokens.len() > 1)
+            .map(|(id, tokens)| {
+                let mut tokens = tokens.clone();
+                tokens.sort();
+                (*id, tokens)
+            })
+            .collect::<Vec<(i64, Vec<String>)>>();
+        duplicate_ids.sort();
+
+        let mut ids = tokens_by_id.keys().copied().collect::<Vec<i64>>();
+        ids.sort_unstable();
+        let mut id_gaps = Vec::new();
+        let mut expected_id = 0;
+        for &id in ids.iter().filter(|id| **id >= 0) {
+            if id > expected_id {
+                id_gaps.push(expected_id..id);
+            }
+            expected_id = id + 1;
+        }
+
+        let mut special_ids = self
+            .special_values()
+            .values()
+            .copied()
+            .collect::<Vec<i64>>();
+        special_ids.sort_unstable();
+        special_ids.dedup();
+        let non_contiguous_special_ids = special_ids
+            .windows(2)
+            .filter(|pair| pair[1] != pair[0] + 1)
+            .map(|pair| pair[1])
+            .collect();
+
+        let mut control_character_tokens = tokens_by_id
+            .into_values()
+            .flatten()
+            .filter(|token| token.chars().any(char::is_control))
+            .collect::<Vec<String>>();
+        control_character_tokens.sort();
+
+        VocabValidationReport {
+            duplicate_ids,
+            id_gaps,
+            non_contiguous_special_ids,
+            control_character_tokens,
+        }
+    }
+
     /// Converts a list of tokens to a list of indices.
     ///
     /// # Parameters
@@ -380,6 +753,27 @@ pub trait Vocab {
         );
     }
 
+    /// Add regular tokens to the vocabulary, for example the symbols resulting from merges learned
+    /// on a new domain (`BpePairVocab::learn_merges`).
+    ///
+    /// In contrast with `add_tokens`, these tokens are not registered as special tokens: they are
+    /// produced by the tokenization algorithm like the other entries of the vocabulary. Tokens
+    /// already in the vocabulary are skipped.
+    ///
+    /// # Parameters
+    /// - tokens (`&[&str]`): list of tokens to add to the vocabulary
+    fn extend_vocabulary(&mut self, tokens: &[&str]) {
+        let mut current_index = self.values().len() as i64;
+        for token in tokens {
+            if self.values().contains_key(*token) {
+                continue;
+            }
+            self.values_mut().insert(token.to_string(), current_index);
+            self.indices_mut().insert(current_index, token.to_string());
+            current_index += 1;
+        }
+    }
+
     /// Add arbitrary tokens to the vocabulary.
     ///
     /// These tokens are added to the special token map and are ignored from the tokenization
@@ -432,6 +826,44 @@ pub struct BaseVocab {
 
 const DEFAULT_UNK_TOKEN: &str = "[UNK]";
 
+impl BaseVocab {
+    /// Read a vocabulary from a plain token list (one token per line, the token id being its line
+    /// number). Each line may optionally contain a frequency column separated from the token by
+    /// whitespace (e.g. `the 23135851162`), as commonly found in word embedding vocabularies. The
+    /// frequencies are validated but do not affect the token ids. Empty lines are skipped, their
+    /// line number is not assigned to any token.
+    /// If the unknown token provided is missing from the list, it is appended at the end of the
+    /// vocabulary.
+    ///
+    /// # Parameters
+    /// - path: path to the token list file
+    /// - unk_token (`&str`): unknown token string representation
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::vocab::BaseVocab;
+    /// let path = "path/to/vocab.txt";
+    ///
+    /// let base_vocab = BaseVocab::from_token_list_file(path, "<unk>");
+    /// ```
+    pub fn from_token_list_file<P: AsRef<Path>>(
+        path: P,
+        unk_token: &str,
+    ) -> Result<BaseVocab, TokenizerError> {
+        let mut values = read_token_list_file(path)?;
+        if !values.contains_key(unk_token) {
+            let unk_id = values.values().max().map_or(0, |max_id| max_id + 1);
+            values.insert(unk_token.to_owned(), unk_id);
+        }
+        let special_token_map = SpecialTokenMap {
+            unk_token: unk_token.to_owned(),
+            ..Default::default()
+        };
+        Self::from_values_and_special_token_map(values, special_token_map)
+    }
+}
+
 impl Vocab for BaseVocab {
     fn get_unknown_value(&self) -> &str {
         &self.special_token_map.unk_token
@@ -453,6 +885,10 @@ impl Vocab for BaseVocab {
         &self.special_indices
     }
 
+    fn special_token_map(&self) -> &SpecialTokenMap {
+        &self.special_token_map
+    }
+
     fn values_mut(&mut self) -> &mut HashMap<String, i64> {
         &mut self.values
     }
@@ -618,6 +1054,128 @@ mod tests {
         let _base_vocab = BaseVocab::from_file(&path).unwrap();
     }
 
+    #[test]
+    fn test_create_object_from_file_without_unknown_token_error() -> anyhow::Result<()> {
+        //        Given
+        let mut vocab_file = tempfile::NamedTempFile::new()?;
+        write!(vocab_file, "hello \n world \n !")?;
+        let path = vocab_file.into_temp_path();
+
+        //        When
+        let base_vocab = BaseVocab::from_file(&path);
+
+        //        Then
+        match base_vocab {
+            Err(TokenizerError::SpecialTokenNotFound(message)) => {
+                assert!(message.contains("[UNK]"))
+            }
+            _ => panic!("expected a special token not found error"),
+        }
+        drop(path);
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_object_from_token_list_file() -> anyhow::Result<()> {
+        //        Given
+        let mut vocab_file = tempfile::NamedTempFile::new()?;
+        write!(vocab_file, "the 1061396\nof\t593677\nand 416629\n\nin 2154")?;
+        let path = vocab_file.into_temp_path();
+        let target_values: HashMap<String, i64> = [
+            ("the".to_owned(), 0),
+            ("of".to_owned(), 1),
+            ("and".to_owned(), 2),
+            ("in".to_owned(), 4),
+            ("<unk>".to_owned(), 5),
+        ]
+        .iter()
+        .cloned()
+        .collect();
+
+        //        When
+        let base_vocab = BaseVocab::from_token_list_file(&path, "<unk>")?;
+
+        //        Then
+        assert_eq!(base_vocab.get_unknown_value(), "<unk>");
+        assert_eq!(base_vocab.values, target_values);
+        assert_eq!(base_vocab.token_to_id("oov_value"), 5);
+        drop(path);
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_object_from_fairseq_dict_file() -> anyhow::Result<()> {
+        //        Given
+        let mut vocab_file = tempfile::NamedTempFile::new()?;
+        write!(
+            vocab_file,
+            "hello 120\nworld 80\n! 12\n<pad> 3 #fairseq:overwrite\n"
+        )?;
+        let path = vocab_file.into_temp_path();
+        let special_token_map = SpecialTokenMap {
+            unk_token: "<unk>".to_string(),
+            pad_token: Some("<pad>".to_string()),
+            bos_token: Some("<s>".to_string()),
+            sep_token: None,
+            cls_token: None,
+            eos_token: Some("</s>".to_string()),
+            mask_token: Some("<mask>".to_string()),
+            additional_special_tokens: None,
+        };
+        let target_values: HashMap<String, i64> = [
+            ("<s>".to_owned(), 0),
+            ("<pad>".to_owned(), 1),
+            ("</s>".to_owned(), 2),
+            ("<unk>".to_owned(), 3),
+            ("hello".to_owned(), 4),
+            ("world".to_owned(), 5),
+            ("!".to_owned(), 6),
+            ("<mask>".to_owned(), 7),
+        ]
+        .iter()
+        .cloned()
+        .collect();
+
+        //        When
+        let base_vocab = BaseVocab::from_fairseq_dict_file(&path, special_token_map)?;
+
+        //        Then
+        assert_eq!(base_vocab.values, target_values);
+        assert_eq!(base_vocab.token_to_id("<mask>"), 7);
+        assert_eq!(base_vocab.token_to_id("oov_value"), 3);
+        drop(path);
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_object_from_malformed_fairseq_dict_file() -> anyhow::Result<()> {
+        //        Given
+        let mut vocab_file = tempfile::NamedTempFile::new()?;
+        write!(vocab_file, "hello 120\nworld\n")?;
+        let path = vocab_file.into_temp_path();
+        let special_token_map = SpecialTokenMap {
+            unk_token: "<unk>".to_string(),
+            ..Default::default()
+        };
+
+        //        When
+        let base_vocab = BaseVocab::from_fairseq_dict_file(&path, special_token_map);
+
+        //        Then
+        match base_vocab {
+            Err(TokenizerError::VocabularyParsingError {
+                path: error_path,
+                message,
+            }) => {
+                assert_eq!(error_path, path.to_path_buf());
+                assert!(message.contains("line 2"));
+            }
+            _ => panic!("expected a vocabulary parsing error"),
+        }
+        drop(path);
+        Ok(())
+    }
+
     #[test]
     fn test_encode_tokens() -> anyhow::Result<()> {
         //        Given
@@ -654,4 +1212,54 @@ mod tests {
         drop(path);
         Ok(())
     }
+
+    #[test]
+    fn test_validate_vocab() -> anyhow::Result<()> {
+        //        Given
+        let valid_values: HashMap<String, i64> = [("[UNK]", 0), ("[PAD]", 1), ("hello", 2)]
+            .iter()
+            .map(|(token, id)| (token.to_string(), *id))
+            .collect();
+        let invalid_values: HashMap<String, i64> = [
+            ("[UNK]", 0),
+            ("hello", 1),
+            ("hel\u{0}lo", 1),
+            ("world", 2),
+            ("!\n", 5),
+            ("[PAD]", 6),
+            ("?", 9),
+        ]
+        .iter()
+        .map(|(token, id)| (token.to_string(), *id))
+        .collect();
+        let special_token_map = SpecialTokenMap {
+            unk_token: "[UNK]".to_string(),
+            pad_token: Some("[PAD]".to_string()),
+            ..Default::default()
+        };
+        let valid_vocab =
+            BaseVocab::from_values_and_special_token_map(valid_values, special_token_map.clone())?;
+        let invalid_vocab =
+            BaseVocab::from_values_and_special_token_map(invalid_values, special_token_map)?;
+
+        //        When
+        let valid_report = valid_vocab.validate();
+        let invalid_report = invalid_vocab.validate();
+
+        //        Then
+        assert!(valid_report.is_valid());
+        assert_eq!(valid_report, VocabValidationReport::default());
+        assert!(!invalid_report.is_valid());
+        assert_eq!(
+            invalid_report.duplicate_ids,
+            vec![(1, vec!["hel\u{0}lo".to_string(), "hello".to_string()])]
+        );
+        assert_eq!(invalid_report.id_gaps, vec![3..5, 7..9]);
+        assert_eq!(invalid_report.non_contiguous_special_ids, vec![6]);
+        assert_eq!(
+            invalid_report.control_character_tokens,
+            vec!["!\n".to_string(), "hel\u{0}lo".to_string()]
+        );
+        Ok(())
+    }
 }
@@ -103,6 +103,10 @@ impl Vocab for BertVocab {
         &self.special_indices
     }
 
+    fn special_token_map(&self) -> &SpecialTokenMap {
+        &self.special_token_map
+    }
+
     fn values_mut(&mut self) -> &mut HashMap<String, i64> {
         &mut self.values
     }
@@ -269,6 +273,38 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_create_object_from_file_with_special_token_remapping() -> anyhow::Result<()> {
+        //        Given
+        let mut vocab_file = tempfile::NamedTempFile::new()?;
+        write!(
+            vocab_file,
+            "hello \n world \n [UNK] \n ! \n [CLS] \n [SEP] \n [MASK] \n [PAD] \n <unk> \n <cls> \n <sep>"
+        )?;
+        let path = vocab_file.into_temp_path();
+        let remapping: HashMap<String, String> = [
+            ("[UNK]".to_owned(), "<unk>".to_owned()),
+            ("[CLS]".to_owned(), "<cls>".to_owned()),
+            ("[SEP]".to_owned(), "<sep>".to_owned()),
+        ]
+        .iter()
+        .cloned()
+        .collect();
+
+        //        When
+        let bert_vocab = BertVocab::from_file_with_special_token_remapping(&path, &remapping)?;
+
+        //        Then
+        assert_eq!(bert_vocab.get_unknown_value(), "<unk>");
+        assert_eq!(bert_vocab.get_cls_value(), "<cls>");
+        assert_eq!(bert_vocab.get_sep_value(), "<sep>");
+        assert_eq!(bert_vocab.get_mask_value(), "[MASK]");
+        assert_eq!(bert_vocab.token_to_id("<cls>"), 9);
+        assert_eq!(bert_vocab.special_values.len(), 5);
+        drop(path);
+        Ok(())
+    }
+
     #[test]
     #[should_panic]
     fn test_create_object_from_file_without_unknown_token() {
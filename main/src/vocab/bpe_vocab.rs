@@ -11,11 +11,11 @@
 // limitations under the License.
 
 use crate::error::TokenizerError;
+use crate::tokenizer::tokenization_utils::bpe;
+use crate::vocab::base_vocab::{open_protobuf_file, open_text_file, parse_protobuf_bytes};
 use crate::vocab::sentencepiece_proto::sentencepiece_model::ModelProto;
-use protobuf::Message;
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufRead, BufReader, Read};
+use std::io::BufRead;
 use std::mem::ManuallyDrop;
 use std::path::Path;
 use std::ptr;
@@ -40,7 +40,8 @@ pub struct BpePairVocab {
 impl BpePairVocab {
     /// Create a new `BpePairVocab` from a flat file containing merges in the format `first_element second_element`)
     /// The indices are implied by the lien position of each pair in the merges file. The first line needs to be a
-    /// header and is skipped.
+    /// header and is skipped. Empty lines are ignored, other lines that do not contain exactly two tokens
+    /// separated by a single space return a `VocabularyParsingError` with the offending line number.
     ///
     /// # Example
     ///
@@ -51,27 +52,38 @@ impl BpePairVocab {
     /// let bpe_vocab = BpePairVocab::from_file(path);
     /// ```
     pub fn from_file<P: AsRef<Path>>(path: P) -> Result<BpePairVocab, TokenizerError> {
-        let f = File::open(&path).map_err(|e| {
-            TokenizerError::FileNotFound(format!(
-                "{} vocabulary file not found :{}",
-                path.as_ref().display(),
-                e
-            ))
-        })?;
-        let br = BufReader::new(f);
+        let br = open_text_file(&path)?;
         let mut data = HashMap::new();
         let mut index = 0;
-        for line in br.lines().skip(1) {
+        for (line_index, line) in br.lines().enumerate().skip(1) {
             let line = match line {
                 Ok(value) => value,
                 Err(e) => {
-                    return Err(TokenizerError::VocabularyParsingError(e.to_string()));
+                    return Err(TokenizerError::vocabulary_parsing_error(
+                        &path,
+                        format!("line {}: {}", line_index + 1, e),
+                    ));
                 }
             };
-            let tuple: Vec<String> = line.trim().split(' ').map(|v| v.to_owned()).collect();
-            if tuple.len() > 1 {
-                data.insert((tuple[0].clone(), tuple[1].clone()), index);
-                index += 1;
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            match line.split(' ').collect::<Vec<&str>>().as_slice() {
+                [first, second] if !first.is_empty() && !second.is_empty() => {
+                    data.insert((first.to_string(), second.to_string()), index);
+                    index += 1;
+                }
+                _ => {
+                    return Err(TokenizerError::vocabulary_parsing_error(
+                        &path,
+                        format!(
+                            "line {}: invalid merge {:?}, expected two tokens separated by a single space",
+                            line_index + 1,
+                            line
+                        ),
+                    ));
+                }
             }
         }
 
@@ -91,25 +103,27 @@ impl BpePairVocab {
     pub fn from_sentencepiece_file<P: AsRef<Path>>(
         path: P,
     ) -> Result<BpePairVocab, TokenizerError> {
-        let mut f = File::open(&path).map_err(|e| {
-            TokenizerError::FileNotFound(format!(
-                "{} vocabulary file not found :{}",
-                path.as_ref().display(),
-                e
-            ))
-        })?;
-        let mut contents = Vec::new();
-        let proto = match f.read_to_end(&mut contents) {
-            Ok(_) => match ModelProto::parse_from_bytes(contents.as_slice()) {
-                Ok(proto_value) => proto_value,
-                Err(e) => {
-                    return Err(TokenizerError::VocabularyParsingError(e.to_string()));
-                }
-            },
-            Err(e) => {
-                return Err(TokenizerError::VocabularyParsingError(e.to_string()));
-            }
-        };
+        let proto = open_protobuf_file(path)?;
+        Ok(Self::from_sentencepiece_proto(&proto))
+    }
+
+    /// Create a new `BpePairVocab` from the bytes of a SentencePiece file containing a BPE model,
+    /// allowing to embed the model in a binary (e.g. using `include_bytes!`).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::vocab::BpePairVocab;
+    /// let bytes = std::fs::read("path/to/spiece.model").unwrap();
+    ///
+    /// let bpe_vocab = BpePairVocab::from_sentencepiece_bytes(&bytes);
+    /// ```
+    pub fn from_sentencepiece_bytes(bytes: &[u8]) -> Result<BpePairVocab, TokenizerError> {
+        let proto = parse_protobuf_bytes(bytes)?;
+        Ok(Self::from_sentencepiece_proto(&proto))
+    }
+
+    fn from_sentencepiece_proto(proto: &ModelProto) -> BpePairVocab {
         let mut values = HashMap::new();
         for (idx, piece) in proto.get_pieces().iter().enumerate() {
             values.insert(piece.get_piece().to_owned(), idx as i64);
@@ -124,7 +138,7 @@ impl BpePairVocab {
             }
         }
 
-        Ok(BpePairVocab { values: data })
+        BpePairVocab { values: data }
     }
 
     /// Gets the id of a "byte pair" in the merges vocab. Returns an optional index for the pair if
@@ -153,6 +167,161 @@ impl BpePairVocab {
             self.values.get(&k)
         }
     }
+
+    /// Gets the rank (priority) of the merge of two symbols. Lower ranks are merged first. Returns
+    /// `None` if the pair is not part of the merges.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::vocab::BpePairVocab;
+    /// let bpe_vocab = BpePairVocab::from_file("path/to/file").unwrap();
+    ///
+    /// let rank = bpe_vocab.rank("won", "derful");
+    /// ```
+    pub fn rank(&self, first: &str, second: &str) -> Option<i64> {
+        self.values
+            .get(&(first.to_owned(), second.to_owned()))
+            .copied()
+    }
+
+    /// Returns the number of merges in the vocabulary
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns true if the vocabulary does not contain any merge
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns the merges with their rank, sorted by increasing rank (order of application)
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::vocab::BpePairVocab;
+    /// let bpe_vocab = BpePairVocab::from_file("path/to/file").unwrap();
+    ///
+    /// for (first, second, rank) in bpe_vocab.ranked_merges().iter().take(10) {
+    ///     println!("{rank}: {first} + {second}");
+    /// }
+    /// ```
+    pub fn ranked_merges(&self) -> Vec<(&str, &str, i64)> {
+        let mut merges = self
+            .values
+            .iter()
+            .map(|((first, second), rank)| (first.as_str(), second.as_str(), *rank))
+            .collect::<Vec<(&str, &str, i64)>>();
+        merges.sort_by_key(|(_, _, rank)| *rank);
+        merges
+    }
+
+    /// Splits a word in characters and applies the merges, lowest rank first, until no adjacent
+    /// pair of symbols can be merged. This is the BPE algorithm used by the GPT2 and RoBERTa
+    /// tokenizers, without their byte-level encoding and end-of-word markers: callers building
+    /// custom variants are expected to prepare the word accordingly.
+    ///
+    /// # Parameters
+    /// - word (`&str`): word to split in sub-tokens
+    ///
+    /// # Returns
+    /// `Vec<String>` with the sub-tokens resulting from the merges
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::vocab::BpePairVocab;
+    /// let bpe_vocab = BpePairVocab::from_file("path/to/file").unwrap();
+    ///
+    /// let sub_tokens = bpe_vocab.apply_merges("wonderful");
+    /// ```
+    pub fn apply_merges(&self, word: &str) -> Vec<String> {
+        bpe(word, self).0
+    }
+
+    /// Continues the training of the merges on a new corpus: the words of the corpus are split
+    /// with the existing merges, and the most frequent pairs of adjacent symbols are iteratively
+    /// merged and appended to the merges (with a rank following the existing ones). Ties are broken
+    /// by the lexicographic order of the pairs. The training stops after `num_merges` merges, or
+    /// earlier if no pair occurs at least twice in the corpus.
+    ///
+    /// As for `apply_merges`, the words are expected to be prepared for the tokenizer using the
+    /// merges (e.g. byte-level encoding or end-of-word markers). The merged symbols should be added
+    /// to the vocabulary of the tokenizer (for example with `Vocab::extend_vocabulary`).
+    ///
+    /// # Parameters
+    /// - word_counts (`&HashMap<String, usize>`): number of occurrences of each word of the corpus
+    /// - num_merges (`usize`): maximum number of merges to learn
+    ///
+    /// # Returns
+    /// `Vec<(String, String)>` with the pairs merged, in order of their rank
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rust_tokenizers::vocab::BpePairVocab;
+    /// use std::collections::HashMap;
+    /// let mut bpe_vocab = BpePairVocab::from_file("path/to/file").unwrap();
+    ///
+    /// let word_counts: HashMap<String, usize> =
+    ///     [("acetaminophen".to_owned(), 12), ("ibuprofen".to_owned(), 7)]
+    ///         .iter()
+    ///         .cloned()
+    ///         .collect();
+    /// let new_merges = bpe_vocab.learn_merges(&word_counts, 100);
+    /// ```
+    pub fn learn_merges(
+        &mut self,
+        word_counts: &HashMap<String, usize>,
+        num_merges: usize,
+    ) -> Vec<(String, String)> {
+        let mut words: Vec<(Vec<String>, usize)> = word_counts
+            .iter()
+            .filter(|(_, count)| **count > 0)
+            .map(|(word, count)| (self.apply_merges(word), *count))
+            .collect();
+        let mut next_rank = self.values.values().max().map_or(0, |rank| rank + 1);
+        let mut new_merges = Vec::new();
+        while new_merges.len() < num_merges {
+            let mut pair_counts: HashMap<(&str, &str), usize> = HashMap::new();
+            for (symbols, count) in words.iter() {
+                for pair in symbols.windows(2) {
+                    *pair_counts
+                        .entry((pair[0].as_str(), pair[1].as_str()))
+                        .or_insert(0) += count;
+                }
+            }
+            let best_pair = pair_counts
+                .into_iter()
+                .filter(|(_, count)| *count >= 2)
+                .max_by(|(pair_1, count_1), (pair_2, count_2)| {
+                    count_1.cmp(count_2).then_with(|| pair_2.cmp(pair_1))
+                })
+                .map(|((first, second), _)| (first.to_owned(), second.to_owned()));
+            let (first, second) = match best_pair {
+                Some(best_pair) => best_pair,
+                None => break,
+            };
+
+            let merged = format!("{first}{second}");
+            for (symbols, _) in words.iter_mut() {
+                let mut position = 0;
+                while position + 1 < symbols.len() {
+                    if symbols[position] == first && symbols[position + 1] == second {
+                        symbols[position] = merged.clone();
+                        symbols.remove(position + 1);
+                    }
+                    position += 1;
+                }
+            }
+            self.values
+                .insert((first.clone(), second.clone()), next_rank);
+            next_rank += 1;
+            new_merges.push((first, second));
+        }
+        new_merges
+    }
 }
 
 //==============================
@@ -163,6 +332,8 @@ impl BpePairVocab {
 mod tests {
     extern crate anyhow;
     use super::*;
+    use crate::vocab::sentencepiece_proto::sentencepiece_model::ModelProto_SentencePiece;
+    use protobuf::Message;
     use std::io::Write;
 
     #[test]
@@ -204,6 +375,153 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_create_pair_vocab_from_sentencepiece_bytes() -> anyhow::Result<()> {
+        //        Given
+        let mut proto = ModelProto::new();
+        for piece in ["<unk>", "▁", "h", "e", "▁h", "▁he"] {
+            let mut sentence_piece = ModelProto_SentencePiece::new();
+            sentence_piece.set_piece(piece.to_string());
+            proto.mut_pieces().push(sentence_piece);
+        }
+        let bytes = proto.write_to_bytes()?;
+        let target_values: HashMap<(String, String), i64> = [
+            (("▁".to_owned(), "h".to_owned()), 4),
+            (("▁h".to_owned(), "e".to_owned()), 5),
+        ]
+        .iter()
+        .cloned()
+        .collect();
+
+        //        When
+        let pair_vocab = BpePairVocab::from_sentencepiece_bytes(&bytes)?;
+
+        //        Then
+        assert_eq!(pair_vocab.values, target_values);
+        assert!(matches!(
+            BpePairVocab::from_sentencepiece_bytes(&[0xFF, 0xFF]),
+            Err(TokenizerError::VocabularyParsingError { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_inspection() {
+        //        Given
+        let pair_vocab = BpePairVocab {
+            values: [
+                (("t".to_owned(), "h".to_owned()), 0),
+                (("a".to_owned(), "n".to_owned()), 1),
+                (("th".to_owned(), "e".to_owned()), 2),
+                (("e".to_owned(), "r".to_owned()), 3),
+            ]
+            .iter()
+            .cloned()
+            .collect(),
+        };
+
+        //        When
+        let ranked_merges = pair_vocab.ranked_merges();
+
+        //        Then
+        assert_eq!(pair_vocab.len(), 4);
+        assert!(!pair_vocab.is_empty());
+        assert_eq!(pair_vocab.rank("th", "e"), Some(2));
+        assert_eq!(pair_vocab.rank("h", "t"), None);
+        assert_eq!(
+            ranked_merges,
+            vec![("t", "h", 0), ("a", "n", 1), ("th", "e", 2), ("e", "r", 3)]
+        );
+        assert_eq!(pair_vocab.apply_merges("there"), vec!["the", "r", "e"]);
+        assert_eq!(pair_vocab.apply_merges("anther"), vec!["an", "the", "r"]);
+        assert_eq!(pair_vocab.apply_merges("x"), vec!["x"]);
+        assert!(pair_vocab.apply_merges("").is_empty());
+    }
+
+    #[test]
+    fn test_learn_merges() {
+        //        Given
+        let mut pair_vocab = BpePairVocab {
+            values: [
+                (("t".to_owned(), "h".to_owned()), 0),
+                (("th".to_owned(), "e".to_owned()), 1),
+            ]
+            .iter()
+            .cloned()
+            .collect(),
+        };
+        let word_counts: HashMap<String, usize> = [
+            ("these".to_owned(), 3),
+            ("theses".to_owned(), 1),
+            ("sees".to_owned(), 2),
+            ("x".to_owned(), 5),
+        ]
+        .iter()
+        .cloned()
+        .collect();
+
+        //        When
+        let new_merges = pair_vocab.learn_merges(&word_counts, 3);
+
+        //        Then
+        assert_eq!(
+            new_merges,
+            vec![
+                ("s".to_owned(), "e".to_owned()),
+                ("the".to_owned(), "se".to_owned()),
+                ("e".to_owned(), "s".to_owned()),
+            ]
+        );
+        assert_eq!(pair_vocab.len(), 5);
+        assert_eq!(pair_vocab.rank("s", "e"), Some(2));
+        assert_eq!(pair_vocab.rank("e", "s"), Some(4));
+        assert_eq!(pair_vocab.apply_merges("these"), vec!["these"]);
+        assert_eq!(pair_vocab.apply_merges("sees"), vec!["se", "es"]);
+        assert!(pair_vocab.learn_merges(&word_counts, 10).len() < 10);
+    }
+
+    #[test]
+    fn test_create_pair_vocab_from_malformed_file() -> anyhow::Result<()> {
+        //        Given
+        let test_tuples = [
+            (
+                "#version: 0.1\nt h\nan\ni n",
+                "line 3: invalid merge \"an\"",
+            ),
+            (
+                "#version: 0.1\nt h\na n b\ni n",
+                "line 3: invalid merge \"a n b\"",
+            ),
+            (
+                "#version: 0.1\nt h\n\ni  n",
+                "line 4: invalid merge \"i  n\"",
+            ),
+        ];
+
+        for (contents, expected_error) in test_tuples.iter() {
+            let mut merges_file = tempfile::NamedTempFile::new()?;
+            write!(merges_file, "{contents}")?;
+            let path = merges_file.into_temp_path();
+
+            //        When
+            let error = BpePairVocab::from_file(&path).unwrap_err();
+
+            //        Then
+            assert!(matches!(
+                error,
+                TokenizerError::VocabularyParsingError { .. }
+            ));
+            assert!(
+                error.to_string().contains(expected_error),
+                "{} does not contain {}",
+                error,
+                expected_error
+            );
+            drop(path);
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_encode_byte_pairs() -> anyhow::Result<()> {
         //        Given
@@ -12,7 +12,8 @@
 
 use crate::error::TokenizerError;
 use crate::vocab::base_vocab::{
-    read_protobuf_file, read_special_token_mapping_file, swap_key_values, SpecialTokenMap,
+    read_protobuf_bytes, read_protobuf_file, read_special_token_mapping_file, swap_key_values,
+    SpecialTokenMap,
 };
 use crate::vocab::Vocab;
 use std::collections::HashMap;
@@ -99,6 +100,43 @@ impl DeBERTaV2Vocab {
             .as_deref()
             .unwrap_or(DEFAULT_MASK_TOKEN)
     }
+
+    /// Creates a vocabulary from the bytes of a SentencePiece protobuf file, allowing to embed
+    /// the model in a binary (e.g. using `include_bytes!`).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use rust_tokenizers::vocab::DeBERTaV2Vocab;
+    ///
+    /// let bytes = std::fs::read("path/to/spm.model").unwrap();
+    /// let vocab = DeBERTaV2Vocab::from_protobuf_bytes(&bytes).unwrap();
+    /// ```
+    pub fn from_protobuf_bytes(bytes: &[u8]) -> Result<DeBERTaV2Vocab, TokenizerError> {
+        let values = read_protobuf_bytes(bytes)?;
+        Self::from_default_values(values)
+    }
+
+    fn from_default_values(
+        mut values: HashMap<String, i64>,
+    ) -> Result<DeBERTaV2Vocab, TokenizerError> {
+        let special_token_map = SpecialTokenMap {
+            unk_token: DEFAULT_UNK_TOKEN.to_string(),
+            pad_token: Some(DEFAULT_PAD_TOKEN.to_string()),
+            bos_token: Some(DEFAULT_BOS_TOKEN.to_string()),
+            sep_token: Some(DEFAULT_SEP_TOKEN.to_string()),
+            cls_token: Some(DEFAULT_CLS_TOKEN.to_string()),
+            eos_token: Some(DEFAULT_EOS_TOKEN.to_string()),
+            mask_token: Some(DEFAULT_MASK_TOKEN.to_string()),
+            additional_special_tokens: None,
+        };
+        if !values.contains_key(special_token_map.mask_token.as_ref().unwrap()) {
+            values.insert(
+                special_token_map.mask_token.as_ref().unwrap().clone(),
+                values.len() as i64,
+            );
+        }
+        Self::from_values_and_special_token_map(values, special_token_map)
+    }
 }
 
 impl Vocab for DeBERTaV2Vocab {
@@ -122,6 +160,10 @@ impl Vocab for DeBERTaV2Vocab {
         &self.special_indices
     }
 
+    fn special_token_map(&self) -> &SpecialTokenMap {
+        &self.special_token_map
+    }
+
     fn values_mut(&mut self) -> &mut HashMap<String, i64> {
         &mut self.values
     }
@@ -139,25 +181,8 @@ impl Vocab for DeBERTaV2Vocab {
     }
 
     fn from_file<P: AsRef<Path>>(path: P) -> Result<DeBERTaV2Vocab, TokenizerError> {
-        let mut values = read_protobuf_file(path)?;
-
-        let special_token_map = SpecialTokenMap {
-            unk_token: DEFAULT_UNK_TOKEN.to_string(),
-            pad_token: Some(DEFAULT_PAD_TOKEN.to_string()),
-            bos_token: Some(DEFAULT_BOS_TOKEN.to_string()),
-            sep_token: Some(DEFAULT_SEP_TOKEN.to_string()),
-            cls_token: Some(DEFAULT_CLS_TOKEN.to_string()),
-            eos_token: Some(DEFAULT_EOS_TOKEN.to_string()),
-            mask_token: Some(DEFAULT_MASK_TOKEN.to_string()),
-            additional_special_tokens: None,
-        };
-        if !values.contains_key(special_token_map.mask_token.as_ref().unwrap()) {
-            values.insert(
-                special_token_map.mask_token.as_ref().unwrap().clone(),
-                values.len() as i64,
-            );
-        }
-        Self::from_values_and_special_token_map(values, special_token_map)
+        let values = read_protobuf_file(path)?;
+        Self::from_default_values(values)
     }
 
     fn from_file_with_special_token_mapping<P: AsRef<Path>, S: AsRef<Path>>(
@@ -122,6 +122,10 @@ impl Vocab for DeBERTaVocab {
         &self.special_indices
     }
 
+    fn special_token_map(&self) -> &SpecialTokenMap {
+        &self.special_token_map
+    }
+
     fn values_mut(&mut self) -> &mut HashMap<String, i64> {
         &mut self.values
     }
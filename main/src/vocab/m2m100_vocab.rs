@@ -12,8 +12,8 @@
 
 use crate::error::TokenizerError;
 use crate::vocab::base_vocab::{
-    read_json_file, read_special_token_mapping_file, register_as_special_value, swap_key_values,
-    SpecialTokenMap,
+    read_json_bytes, read_json_file, read_special_token_mapping_file, register_as_special_value,
+    swap_key_values, SpecialTokenMap,
 };
 use crate::vocab::Vocab;
 use std::collections::{HashMap, HashSet};
@@ -95,6 +95,35 @@ impl M2M100Vocab {
             .as_deref()
             .unwrap_or(DEFAULT_EOS_TOKEN)
     }
+
+    /// Creates a vocabulary from the bytes of a JSON vocabulary file, allowing to embed the
+    /// vocabulary in a binary (e.g. using `include_bytes!`).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use rust_tokenizers::vocab::M2M100Vocab;
+    ///
+    /// let bytes = std::fs::read("path/to/vocab.json").unwrap();
+    /// let vocab = M2M100Vocab::from_json_bytes(&bytes).unwrap();
+    /// ```
+    pub fn from_json_bytes(bytes: &[u8]) -> Result<M2M100Vocab, TokenizerError> {
+        let values = read_json_bytes(bytes)?;
+        Self::from_default_values(values)
+    }
+
+    fn from_default_values(values: HashMap<String, i64>) -> Result<M2M100Vocab, TokenizerError> {
+        let special_token_map = SpecialTokenMap {
+            unk_token: DEFAULT_UNK_TOKEN.to_string(),
+            pad_token: Some(DEFAULT_PAD_TOKEN.to_string()),
+            bos_token: Some(DEFAULT_BOS_TOKEN.to_string()),
+            sep_token: Some(DEFAULT_SEP_TOKEN.to_string()),
+            cls_token: None,
+            eos_token: Some(DEFAULT_EOS_TOKEN.to_string()),
+            mask_token: None,
+            additional_special_tokens: None,
+        };
+        Self::from_values_and_special_token_map(values, special_token_map)
+    }
 }
 
 impl Vocab for M2M100Vocab {
@@ -118,6 +147,10 @@ impl Vocab for M2M100Vocab {
         &self.special_indices
     }
 
+    fn special_token_map(&self) -> &SpecialTokenMap {
+        &self.special_token_map
+    }
+
     fn values_mut(&mut self) -> &mut HashMap<String, i64> {
         &mut self.values
     }
@@ -136,18 +169,7 @@ impl Vocab for M2M100Vocab {
 
     fn from_file<P: AsRef<Path>>(path: P) -> Result<M2M100Vocab, TokenizerError> {
         let values = read_json_file(path)?;
-
-        let special_token_map = SpecialTokenMap {
-            unk_token: DEFAULT_UNK_TOKEN.to_string(),
-            pad_token: Some(DEFAULT_PAD_TOKEN.to_string()),
-            bos_token: Some(DEFAULT_BOS_TOKEN.to_string()),
-            sep_token: Some(DEFAULT_SEP_TOKEN.to_string()),
-            cls_token: None,
-            eos_token: Some(DEFAULT_EOS_TOKEN.to_string()),
-            mask_token: None,
-            additional_special_tokens: None,
-        };
-        Self::from_values_and_special_token_map(values, special_token_map)
+        Self::from_default_values(values)
     }
 
     fn from_file_with_special_token_mapping<P: AsRef<Path>, S: AsRef<Path>>(
@@ -170,7 +192,7 @@ impl Vocab for M2M100Vocab {
             } else if language_code.len() == 3 {
                 format!(">>{language_code}<<")
             } else {
-                return Err(TokenizerError::VocabularyParsingError(
+                return Err(TokenizerError::ValueError(
                     "M2M100 Vocab only supports language code of length 2 or 3".to_string(),
                 ));
             };
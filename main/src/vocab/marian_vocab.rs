@@ -14,7 +14,8 @@
 
 use crate::error::TokenizerError;
 use crate::vocab::base_vocab::{
-    read_json_file, read_special_token_mapping_file, swap_key_values, SpecialTokenMap,
+    read_json_bytes, read_json_file, read_special_token_mapping_file, swap_key_values,
+    SpecialTokenMap,
 };
 use crate::vocab::Vocab;
 use std::collections::HashMap;
@@ -64,6 +65,35 @@ impl MarianVocab {
             .as_deref()
             .unwrap_or(DEFAULT_EOS_TOKEN)
     }
+
+    /// Creates a vocabulary from the bytes of a JSON vocabulary file, allowing to embed the
+    /// vocabulary in a binary (e.g. using `include_bytes!`).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use rust_tokenizers::vocab::MarianVocab;
+    ///
+    /// let bytes = std::fs::read("path/to/vocab.json").unwrap();
+    /// let vocab = MarianVocab::from_json_bytes(&bytes).unwrap();
+    /// ```
+    pub fn from_json_bytes(bytes: &[u8]) -> Result<MarianVocab, TokenizerError> {
+        let values = read_json_bytes(bytes)?;
+        Self::from_default_values(values)
+    }
+
+    fn from_default_values(values: HashMap<String, i64>) -> Result<MarianVocab, TokenizerError> {
+        let special_token_map = SpecialTokenMap {
+            unk_token: DEFAULT_UNK_TOKEN.to_string(),
+            pad_token: Some(DEFAULT_PAD_TOKEN.to_string()),
+            bos_token: None,
+            sep_token: None,
+            cls_token: None,
+            eos_token: Some(DEFAULT_EOS_TOKEN.to_string()),
+            mask_token: None,
+            additional_special_tokens: None,
+        };
+        Self::from_values_and_special_token_map(values, special_token_map)
+    }
 }
 
 impl Vocab for MarianVocab {
@@ -87,6 +117,10 @@ impl Vocab for MarianVocab {
         &self.special_indices
     }
 
+    fn special_token_map(&self) -> &SpecialTokenMap {
+        &self.special_token_map
+    }
+
     fn values_mut(&mut self) -> &mut HashMap<String, i64> {
         &mut self.values
     }
@@ -105,18 +139,7 @@ impl Vocab for MarianVocab {
 
     fn from_file<P: AsRef<Path>>(path: P) -> Result<MarianVocab, TokenizerError> {
         let values = read_json_file(path)?;
-
-        let special_token_map = SpecialTokenMap {
-            unk_token: DEFAULT_UNK_TOKEN.to_string(),
-            pad_token: Some(DEFAULT_PAD_TOKEN.to_string()),
-            bos_token: None,
-            sep_token: None,
-            cls_token: None,
-            eos_token: Some(DEFAULT_EOS_TOKEN.to_string()),
-            mask_token: None,
-            additional_special_tokens: None,
-        };
-        Self::from_values_and_special_token_map(values, special_token_map)
+        Self::from_default_values(values)
     }
 
     fn from_file_with_special_token_mapping<P: AsRef<Path>, S: AsRef<Path>>(
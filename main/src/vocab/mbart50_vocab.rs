@@ -12,9 +12,10 @@
 
 use crate::error::TokenizerError;
 use crate::vocab::base_vocab::{
-    open_protobuf_file, read_special_token_mapping_file, register_as_special_value,
-    swap_key_values, SpecialTokenMap,
+    open_protobuf_file, parse_protobuf_bytes, read_special_token_mapping_file,
+    register_as_special_value, required_special_token, swap_key_values, SpecialTokenMap,
 };
+use crate::vocab::sentencepiece_proto::sentencepiece_model::ModelProto;
 use crate::vocab::Vocab;
 use std::collections::{HashMap, HashSet};
 use std::path::Path;
@@ -103,46 +104,23 @@ impl MBart50Vocab {
             .as_deref()
             .unwrap_or(DEFAULT_MASK_TOKEN)
     }
-}
-
-impl Vocab for MBart50Vocab {
-    fn get_unknown_value(&self) -> &str {
-        &self.special_token_map.unk_token
-    }
-
-    fn values(&self) -> &HashMap<String, i64> {
-        &self.values
-    }
-
-    fn indices(&self) -> &HashMap<i64, String> {
-        &self.indices
-    }
 
-    fn special_values(&self) -> &HashMap<String, i64> {
-        &self.special_values
+    /// Creates a vocabulary from the bytes of a SentencePiece protobuf file, allowing to embed
+    /// the model in a binary (e.g. using `include_bytes!`).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use rust_tokenizers::vocab::MBart50Vocab;
+    ///
+    /// let bytes = std::fs::read("path/to/sentencepiece.bpe.model").unwrap();
+    /// let vocab = MBart50Vocab::from_protobuf_bytes(&bytes).unwrap();
+    /// ```
+    pub fn from_protobuf_bytes(bytes: &[u8]) -> Result<MBart50Vocab, TokenizerError> {
+        let proto = parse_protobuf_bytes(bytes)?;
+        Self::from_proto(&proto)
     }
 
-    fn special_indices(&self) -> &HashMap<i64, String> {
-        &self.special_indices
-    }
-
-    fn values_mut(&mut self) -> &mut HashMap<String, i64> {
-        &mut self.values
-    }
-
-    fn indices_mut(&mut self) -> &mut HashMap<i64, String> {
-        &mut self.indices
-    }
-
-    fn special_values_mut(&mut self) -> &mut HashMap<String, i64> {
-        &mut self.special_values
-    }
-
-    fn special_indices_mut(&mut self) -> &mut HashMap<i64, String> {
-        &mut self.special_indices
-    }
-
-    fn from_file<P: AsRef<Path>>(path: P) -> Result<MBart50Vocab, TokenizerError> {
+    fn from_proto(proto: &ModelProto) -> Result<MBart50Vocab, TokenizerError> {
         let mut values = HashMap::new();
         let mut special_values = HashMap::new();
 
@@ -170,7 +148,6 @@ impl Vocab for MBart50Vocab {
         );
         values.insert(special_token_map.unk_token.clone(), values.len() as i64);
 
-        let proto = open_protobuf_file(path)?;
         for piece in proto.get_pieces().iter().skip(3) {
             values.insert(piece.get_piece().to_owned(), values.len() as i64);
         }
@@ -185,7 +162,7 @@ impl Vocab for MBart50Vocab {
             values.len() as i64,
         );
 
-        let _ = special_token_map.register_special_values(&values, &mut special_values);
+        special_token_map.register_special_values(&values, &mut special_values)?;
 
         let indices = swap_key_values(&values);
         let special_indices = swap_key_values(&special_values);
@@ -203,6 +180,53 @@ impl Vocab for MBart50Vocab {
             language_codes_bytes,
         })
     }
+}
+
+impl Vocab for MBart50Vocab {
+    fn get_unknown_value(&self) -> &str {
+        &self.special_token_map.unk_token
+    }
+
+    fn values(&self) -> &HashMap<String, i64> {
+        &self.values
+    }
+
+    fn indices(&self) -> &HashMap<i64, String> {
+        &self.indices
+    }
+
+    fn special_values(&self) -> &HashMap<String, i64> {
+        &self.special_values
+    }
+
+    fn special_indices(&self) -> &HashMap<i64, String> {
+        &self.special_indices
+    }
+
+    fn special_token_map(&self) -> &SpecialTokenMap {
+        &self.special_token_map
+    }
+
+    fn values_mut(&mut self) -> &mut HashMap<String, i64> {
+        &mut self.values
+    }
+
+    fn indices_mut(&mut self) -> &mut HashMap<i64, String> {
+        &mut self.indices
+    }
+
+    fn special_values_mut(&mut self) -> &mut HashMap<String, i64> {
+        &mut self.special_values
+    }
+
+    fn special_indices_mut(&mut self) -> &mut HashMap<i64, String> {
+        &mut self.special_indices
+    }
+
+    fn from_file<P: AsRef<Path>>(path: P) -> Result<MBart50Vocab, TokenizerError> {
+        let proto = open_protobuf_file(path)?;
+        Self::from_proto(&proto)
+    }
 
     fn from_file_with_special_token_mapping<P: AsRef<Path>, S: AsRef<Path>>(
         path: P,
@@ -238,11 +262,11 @@ impl Vocab for MBart50Vocab {
         }
 
         values.insert(
-            special_token_map.mask_token.as_ref().unwrap().to_owned(),
+            required_special_token(&special_token_map.mask_token, "mask_token")?.to_owned(),
             values.len() as i64,
         );
 
-        let _ = special_token_map.register_special_values(&values, &mut special_values);
+        special_token_map.register_special_values(&values, &mut special_values)?;
 
         let indices = swap_key_values(&values);
         let special_indices = swap_key_values(&special_values);
@@ -58,7 +58,7 @@ mod xlm_roberta_vocab;
 mod xlnet_vocab;
 
 pub use albert_vocab::AlbertVocab;
-pub use base_vocab::{BaseVocab, Vocab};
+pub use base_vocab::{BaseVocab, SpecialTokenMap, Vocab, VocabValidationReport};
 pub use bert_vocab::BertVocab;
 pub use bpe_vocab::{BpePairRef, BpePairVocab};
 pub use deberta_v2_vocab::DeBERTaV2Vocab;
@@ -69,14 +69,15 @@ pub use m2m100_vocab::M2M100Vocab;
 pub use marian_vocab::MarianVocab;
 pub use mbart50_vocab::MBart50Vocab;
 pub use nllb_vocab::NLLBVocab;
-pub(crate) use nllb_vocab::EXTENDED_FAIRSEQ_LANGUAGE_CODES;
 pub use openai_gpt_vocab::OpenAiGptVocab;
 pub use pegasus_vocab::PegasusVocab;
 pub use prophetnet_vocab::ProphetNetVocab;
 pub use reformer_vocab::ReformerVocab;
 pub use roberta_vocab::RobertaVocab;
 pub use sentence_piece_bpe_model::SentencePieceBpeModel;
-pub use sentence_piece_unigram_model::SentencePieceModel;
+pub use sentence_piece_unigram_model::{
+    Lattice, LatticeNode, PieceMatch, SentencePieceModel, TrieNode,
+};
 pub use sentence_piece_vocab::SentencePieceVocab;
 pub use t5_vocab::T5Vocab;
 pub use xlm_roberta_vocab::XLMRobertaVocab;
@@ -12,8 +12,6 @@
 
 use std::{
     collections::{HashMap, HashSet},
-    fs::File,
-    io::BufReader,
     path::Path,
 };
 
@@ -22,7 +20,9 @@ use serde::{Deserialize, Deserializer};
 use crate::error::*;
 
 use super::{
-    base_vocab::{register_as_special_value, swap_key_values, SpecialTokenMap},
+    base_vocab::{
+        open_text_file, register_as_special_value, swap_key_values, SpecialTokenMap, IN_MEMORY_FILE,
+    },
     Vocab,
 };
 
@@ -159,6 +159,36 @@ impl NLLBVocab {
             .as_deref()
             .unwrap_or(DEFAULT_PAD_TOKEN)
     }
+
+    /// Creates a vocabulary from the bytes of a `tokenizer.json` file, allowing to embed the
+    /// vocabulary in a binary (e.g. using `include_bytes!`).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use rust_tokenizers::vocab::NLLBVocab;
+    ///
+    /// let bytes = std::fs::read("path/to/tokenizer.json").unwrap();
+    /// let vocab = NLLBVocab::from_json_bytes(&bytes).unwrap();
+    /// ```
+    pub fn from_json_bytes(bytes: &[u8]) -> Result<Self, TokenizerError> {
+        let values = Tokenizer::from_bytes(bytes)?.model.vocab;
+        Self::from_default_values(values)
+    }
+
+    fn from_default_values(values: HashMap<String, i64>) -> Result<Self, TokenizerError> {
+        let special_token_map = SpecialTokenMap {
+            unk_token: DEFAULT_UNK_TOKEN.to_string(),
+            pad_token: Some(DEFAULT_PAD_TOKEN.to_string()),
+            bos_token: Some(DEFAULT_BOS_TOKEN.to_string()),
+            sep_token: Some(DEFAULT_SEP_TOKEN.to_string()),
+            cls_token: None,
+            eos_token: Some(DEFAULT_EOS_TOKEN.to_string()),
+            mask_token: None,
+            additional_special_tokens: None,
+        };
+
+        Self::from_values_and_special_token_map(values, special_token_map)
+    }
 }
 
 impl Vocab for NLLBVocab {
@@ -182,6 +212,10 @@ impl Vocab for NLLBVocab {
         &self.special_indices
     }
 
+    fn special_token_map(&self) -> &SpecialTokenMap {
+        &self.special_token_map
+    }
+
     fn values_mut(&mut self) -> &mut HashMap<String, i64> {
         &mut self.values
     }
@@ -200,19 +234,7 @@ impl Vocab for NLLBVocab {
 
     fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, TokenizerError> {
         let values = Tokenizer::deserialize(path)?.model.vocab;
-
-        let special_token_map = SpecialTokenMap {
-            unk_token: DEFAULT_UNK_TOKEN.to_string(),
-            pad_token: Some(DEFAULT_PAD_TOKEN.to_string()),
-            bos_token: Some(DEFAULT_BOS_TOKEN.to_string()),
-            sep_token: Some(DEFAULT_SEP_TOKEN.to_string()),
-            cls_token: None,
-            eos_token: Some(DEFAULT_EOS_TOKEN.to_string()),
-            mask_token: None,
-            additional_special_tokens: None,
-        };
-
-        Self::from_values_and_special_token_map(values, special_token_map)
+        Self::from_default_values(values)
     }
 
     fn token_to_id(&self, token: &str) -> i64 {
@@ -241,16 +263,12 @@ impl Vocab for NLLBVocab {
         Self: Sized,
     {
         let values = Tokenizer::deserialize(path)?.model.vocab;
-        let f = File::open(&special_token_mapping_path).map_err(|e| {
-            TokenizerError::FileNotFound(format!(
-                "{} vocabulary file not found :{}",
-                special_token_mapping_path.as_ref().display(),
-                e
-            ))
-        })?;
-        let br = BufReader::new(f);
+        let br = open_text_file(&special_token_mapping_path)?;
         let special_config: NLLBSpecialTokenMap = serde_json::from_reader(br).map_err(|e| {
-            TokenizerError::FileNotFound(format!("Invalid special token mapping file {e}"))
+            TokenizerError::vocabulary_parsing_error(
+                &special_token_mapping_path,
+                format!("Invalid special token mapping file: {e}"),
+            )
         })?;
 
         Self::from_values_and_special_token_map(values, special_config.into())
@@ -318,17 +336,14 @@ struct Tokenizer {
 
 impl Tokenizer {
     fn deserialize<P: AsRef<Path>>(path: P) -> Result<Self, TokenizerError> {
-        let file = File::open(&path).map_err(|e| {
-            TokenizerError::FileNotFound(format!(
-                "{} tokenizer file not found: {}",
-                path.as_ref().display(),
-                e
-            ))
-        })?;
-
-        let reader = BufReader::new(file);
+        let reader = open_text_file(&path)?;
 
         serde_json::from_reader(reader)
-            .map_err(|e| TokenizerError::VocabularyParsingError(e.to_string()))
+            .map_err(|e| TokenizerError::vocabulary_parsing_error(&path, e))
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, TokenizerError> {
+        serde_json::from_slice(bytes)
+            .map_err(|e| TokenizerError::vocabulary_parsing_error(IN_MEMORY_FILE, e))
     }
 }
@@ -12,9 +12,10 @@
 
 use crate::error::TokenizerError;
 use crate::vocab::base_vocab::{
-    open_protobuf_file, read_special_token_mapping_file, register_as_special_value,
-    swap_key_values, SpecialTokenMap,
+    open_protobuf_file, parse_protobuf_bytes, read_special_token_mapping_file,
+    register_as_special_value, swap_key_values, SpecialTokenMap,
 };
+use crate::vocab::sentencepiece_proto::sentencepiece_model::ModelProto;
 use crate::vocab::Vocab;
 use std::collections::hash_map::Entry;
 use std::collections::{HashMap, HashSet};
@@ -76,6 +77,10 @@ impl PegasusVocab {
             .unwrap_or(DEFAULT_MASK_TOKEN)
     }
 
+    pub fn get_sentence_mask_value(&self) -> &str {
+        DEFAULT_SENTENCE_MASK_TOKEN
+    }
+
     fn _add_and_register_special_value(
         values: &mut HashMap<String, i64>,
         special_values: &mut HashMap<String, i64>,
@@ -86,48 +91,23 @@ impl PegasusVocab {
         register_as_special_value(value, values, special_values)?;
         Ok(offset + 1)
     }
-}
-
-impl Vocab for PegasusVocab {
-    fn get_unknown_value(&self) -> &str {
-        &self.special_token_map.unk_token
-    }
-
-    fn values(&self) -> &HashMap<String, i64> {
-        &self.values
-    }
-
-    fn indices(&self) -> &HashMap<i64, String> {
-        &self.indices
-    }
 
-    fn special_values(&self) -> &HashMap<String, i64> {
-        &self.special_values
-    }
-
-    fn special_indices(&self) -> &HashMap<i64, String> {
-        &self.special_indices
+    /// Creates a vocabulary from the bytes of a SentencePiece protobuf file, allowing to embed
+    /// the model in a binary (e.g. using `include_bytes!`).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use rust_tokenizers::vocab::PegasusVocab;
+    ///
+    /// let bytes = std::fs::read("path/to/spiece.model").unwrap();
+    /// let vocab = PegasusVocab::from_protobuf_bytes(&bytes).unwrap();
+    /// ```
+    pub fn from_protobuf_bytes(bytes: &[u8]) -> Result<PegasusVocab, TokenizerError> {
+        let proto = parse_protobuf_bytes(bytes)?;
+        Self::from_proto(&proto)
     }
 
-    fn values_mut(&mut self) -> &mut HashMap<String, i64> {
-        &mut self.values
-    }
-
-    fn indices_mut(&mut self) -> &mut HashMap<i64, String> {
-        &mut self.indices
-    }
-
-    fn special_values_mut(&mut self) -> &mut HashMap<String, i64> {
-        &mut self.special_values
-    }
-
-    fn special_indices_mut(&mut self) -> &mut HashMap<i64, String> {
-        &mut self.special_indices
-    }
-
-    fn from_file<P: AsRef<Path>>(path: P) -> Result<PegasusVocab, TokenizerError> {
-        let proto = open_protobuf_file(path)?;
-
+    fn from_proto(proto: &ModelProto) -> Result<PegasusVocab, TokenizerError> {
         let mut values = HashMap::new();
         let mut special_values = HashMap::new();
 
@@ -217,6 +197,53 @@ impl Vocab for PegasusVocab {
             special_indices,
         })
     }
+}
+
+impl Vocab for PegasusVocab {
+    fn get_unknown_value(&self) -> &str {
+        &self.special_token_map.unk_token
+    }
+
+    fn values(&self) -> &HashMap<String, i64> {
+        &self.values
+    }
+
+    fn indices(&self) -> &HashMap<i64, String> {
+        &self.indices
+    }
+
+    fn special_values(&self) -> &HashMap<String, i64> {
+        &self.special_values
+    }
+
+    fn special_indices(&self) -> &HashMap<i64, String> {
+        &self.special_indices
+    }
+
+    fn special_token_map(&self) -> &SpecialTokenMap {
+        &self.special_token_map
+    }
+
+    fn values_mut(&mut self) -> &mut HashMap<String, i64> {
+        &mut self.values
+    }
+
+    fn indices_mut(&mut self) -> &mut HashMap<i64, String> {
+        &mut self.indices
+    }
+
+    fn special_values_mut(&mut self) -> &mut HashMap<String, i64> {
+        &mut self.special_values
+    }
+
+    fn special_indices_mut(&mut self) -> &mut HashMap<i64, String> {
+        &mut self.special_indices
+    }
+
+    fn from_file<P: AsRef<Path>>(path: P) -> Result<PegasusVocab, TokenizerError> {
+        let proto = open_protobuf_file(path)?;
+        Self::from_proto(&proto)
+    }
 
     fn from_file_with_special_token_mapping<P: AsRef<Path>, S: AsRef<Path>>(
         path: P,
@@ -227,7 +254,7 @@ impl Vocab for PegasusVocab {
         let mut values = HashMap::new();
         let mut special_values = HashMap::new();
 
-        let mut additional_special_tokens = HashSet::from(["<mask_1>".into()]);
+        let mut additional_special_tokens = HashSet::from([DEFAULT_SENTENCE_MASK_TOKEN.into()]);
         for idx in 2..103 {
             let _ = additional_special_tokens.insert(format!("<unk_{idx}>"));
         }
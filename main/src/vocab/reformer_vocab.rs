@@ -13,7 +13,8 @@
 
 use crate::error::TokenizerError;
 use crate::vocab::base_vocab::{
-    read_protobuf_file, read_special_token_mapping_file, swap_key_values, SpecialTokenMap,
+    read_protobuf_bytes, read_protobuf_file, read_special_token_mapping_file, swap_key_values,
+    SpecialTokenMap,
 };
 use crate::vocab::Vocab;
 use std::collections::HashMap;
@@ -54,6 +55,35 @@ impl ReformerVocab {
             .as_deref()
             .unwrap_or(DEFAULT_EOS_TOKEN)
     }
+
+    /// Creates a vocabulary from the bytes of a SentencePiece protobuf file, allowing to embed
+    /// the model in a binary (e.g. using `include_bytes!`).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use rust_tokenizers::vocab::ReformerVocab;
+    ///
+    /// let bytes = std::fs::read("path/to/spiece.model").unwrap();
+    /// let vocab = ReformerVocab::from_protobuf_bytes(&bytes).unwrap();
+    /// ```
+    pub fn from_protobuf_bytes(bytes: &[u8]) -> Result<ReformerVocab, TokenizerError> {
+        let values = read_protobuf_bytes(bytes)?;
+        Self::from_default_values(values)
+    }
+
+    fn from_default_values(values: HashMap<String, i64>) -> Result<ReformerVocab, TokenizerError> {
+        let special_token_map = SpecialTokenMap {
+            unk_token: DEFAULT_UNK_TOKEN.to_string(),
+            pad_token: None,
+            bos_token: None,
+            sep_token: None,
+            cls_token: None,
+            eos_token: Some(DEFAULT_EOS_TOKEN.to_string()),
+            mask_token: None,
+            additional_special_tokens: None,
+        };
+        Self::from_values_and_special_token_map(values, special_token_map)
+    }
 }
 
 impl Vocab for ReformerVocab {
@@ -77,6 +107,10 @@ impl Vocab for ReformerVocab {
         &self.special_indices
     }
 
+    fn special_token_map(&self) -> &SpecialTokenMap {
+        &self.special_token_map
+    }
+
     fn values_mut(&mut self) -> &mut HashMap<String, i64> {
         &mut self.values
     }
@@ -95,18 +129,7 @@ impl Vocab for ReformerVocab {
 
     fn from_file<P: AsRef<Path>>(path: P) -> Result<ReformerVocab, TokenizerError> {
         let values = read_protobuf_file(path)?;
-
-        let special_token_map = SpecialTokenMap {
-            unk_token: DEFAULT_UNK_TOKEN.to_string(),
-            pad_token: None,
-            bos_token: None,
-            sep_token: None,
-            cls_token: None,
-            eos_token: Some(DEFAULT_EOS_TOKEN.to_string()),
-            mask_token: None,
-            additional_special_tokens: None,
-        };
-        Self::from_values_and_special_token_map(values, special_token_map)
+        Self::from_default_values(values)
     }
 
     fn from_file_with_special_token_mapping<P: AsRef<Path>, S: AsRef<Path>>(
@@ -14,14 +14,12 @@
 use crate::error::TokenizerError;
 use crate::tokenizer::base_tokenizer::{Token, TokenRef};
 use crate::tokenizer::tokenization_utils::{is_punctuation, is_whitespace};
+use crate::vocab::base_vocab::{open_protobuf_file, parse_protobuf_bytes};
 use crate::vocab::sentencepiece_proto::sentencepiece_model::ModelProto;
 use crate::{Mask, Offset, OffsetSize};
 use hashbrown::HashMap;
-use protobuf::Message;
 use std::cmp::Ordering;
 use std::collections::BinaryHeap;
-use std::fs::File;
-use std::io::Read;
 use std::ops::Index;
 use std::path::Path;
 
@@ -50,32 +48,32 @@ impl SentencePieceBpeModel {
     /// let sentence_piece_model = SentencePieceBpeModel::from_file(path);
     /// ```
     pub fn from_file<P: AsRef<Path>>(path: P) -> Result<SentencePieceBpeModel, TokenizerError> {
-        let mut f = File::open(&path).map_err(|e| {
-            TokenizerError::FileNotFound(format!(
-                "{} vocabulary file not found :{}",
-                path.as_ref().display(),
-                e
-            ))
-        })?;
-        let mut contents = Vec::new();
-        let proto = match f.read_to_end(&mut contents) {
-            Ok(_) => match ModelProto::parse_from_bytes(contents.as_slice()) {
-                Ok(proto_value) => proto_value,
-                Err(e) => {
-                    return Err(TokenizerError::VocabularyParsingError(e.to_string()));
-                }
-            },
-            Err(e) => {
-                return Err(TokenizerError::VocabularyParsingError(e.to_string()));
-            }
-        };
+        let proto = open_protobuf_file(path)?;
+        Ok(Self::from_proto(&proto))
+    }
+
+    /// Creates a SentencePiece BPE Model from the bytes of a protobuf file, allowing to embed the
+    /// model in a binary (e.g. using `include_bytes!`).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use rust_tokenizers::vocab::SentencePieceBpeModel;
+    ///
+    /// let bytes = std::fs::read("path/to/spiece.model").unwrap();
+    /// let sentence_piece_model = SentencePieceBpeModel::from_protobuf_bytes(&bytes);
+    /// ```
+    pub fn from_protobuf_bytes(bytes: &[u8]) -> Result<SentencePieceBpeModel, TokenizerError> {
+        let proto = parse_protobuf_bytes(bytes)?;
+        Ok(Self::from_proto(&proto))
+    }
 
+    fn from_proto(proto: &ModelProto) -> SentencePieceBpeModel {
         let mut values = HashMap::new();
         for (idx, piece) in proto.get_pieces().iter().enumerate() {
             values.insert(piece.get_piece().to_owned(), idx as i64);
         }
         let bpe_ranks = BpeMergeVocab { values };
-        Ok(SentencePieceBpeModel { bpe_ranks })
+        SentencePieceBpeModel { bpe_ranks }
     }
 
     /// Tokenizes an input sequence into an array of Tokens by merging adjacent symbols present
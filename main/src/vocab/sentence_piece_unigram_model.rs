@@ -12,13 +12,11 @@
 
 use crate::error::TokenizerError;
 use crate::tokenizer::tokenization_utils::{is_punctuation, is_whitespace};
+use crate::vocab::base_vocab::{open_protobuf_file, parse_protobuf_bytes};
 use crate::vocab::sentencepiece_proto::sentencepiece_model::ModelProto;
 use crate::{Mask, Offset, OffsetSize, Token, TokenRef};
 use hashbrown::HashMap as BrownHashMap;
 use itertools::Itertools;
-use protobuf::Message;
-use std::fs::File;
-use std::io::Read;
 use std::path::Path;
 
 #[derive(Debug, Clone, Copy)]
@@ -55,6 +53,91 @@ impl TrieNode {
     }
 }
 
+/// # Piece match
+/// Vocabulary piece matching a prefix of a query, returned by `SentencePieceModel::common_prefix_search`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PieceMatch<'a> {
+    /// Prefix of the query matching the piece
+    pub text: &'a str,
+    /// Index of the piece in the vocabulary
+    pub index: i64,
+    /// Unigram log-probability of the piece
+    pub score: f32,
+    /// Length of the piece in characters
+    pub char_len: usize,
+}
+
+/// # Lattice node
+/// Candidate piece of a segmentation lattice, returned as part of a `Lattice` by
+/// `SentencePieceModel::lattice`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LatticeNode<'a> {
+    /// Text of the piece
+    pub text: &'a str,
+    /// Index of the piece in the vocabulary (0 for unknown characters)
+    pub index: i64,
+    /// Unigram log-probability of the piece (`f32::MIN` for unknown characters)
+    pub score: f32,
+    /// Start position (in characters) of the piece in the input
+    pub start: usize,
+    /// End position (in characters, exclusive) of the piece in the input
+    pub end: usize,
+    /// Score of the best segmentation of the input up to the end of the piece, going through the piece
+    pub path_score: f32,
+    /// Position in `Lattice::nodes` of the best node ending at the start of the piece (`None` for
+    /// pieces starting the input)
+    pub back_pointer: Option<usize>,
+    /// Flag indicating the piece is a character not covered by the vocabulary
+    pub is_unknown: bool,
+}
+
+/// # Segmentation lattice
+/// All candidate segmentations of an input under a SentencePiece unigram model. The nodes are
+/// sorted by start position and length. A character not covered by any piece of the vocabulary is
+/// added as an unknown node, after which the path scores restart from 0 (as in the tokenizer
+/// decoding).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lattice<'a> {
+    /// Input segmented
+    pub text: &'a str,
+    /// Candidate pieces
+    pub nodes: Vec<LatticeNode<'a>>,
+    /// Position in `nodes` of the best node ending at each character position of the input (the
+    /// first element, for the start of the input, is always `None`)
+    pub best_nodes: Vec<Option<usize>>,
+}
+
+impl<'a> Lattice<'a> {
+    /// Returns the number of characters of the input
+    pub fn num_chars(&self) -> usize {
+        self.best_nodes.len() - 1
+    }
+
+    /// Returns the nodes starting at a character position
+    pub fn nodes_starting_at(&self, position: usize) -> impl Iterator<Item = &LatticeNode<'a>> {
+        self.nodes.iter().filter(move |node| node.start == position)
+    }
+
+    /// Returns the nodes ending at a character position
+    pub fn nodes_ending_at(&self, position: usize) -> impl Iterator<Item = &LatticeNode<'a>> {
+        self.nodes.iter().filter(move |node| node.end == position)
+    }
+
+    /// Returns the most likely segmentation, following the back-pointers from the end of the input.
+    /// This is the segmentation used by the tokenizers.
+    pub fn best_path(&self) -> Vec<&LatticeNode<'a>> {
+        let mut best_path = vec![];
+        let mut next_node = self.best_nodes.last().copied().flatten();
+        while let Some(node_position) = next_node {
+            let node = &self.nodes[node_position];
+            best_path.push(node);
+            next_node = node.back_pointer;
+        }
+        best_path.reverse();
+        best_path
+    }
+}
+
 /// # SentencePiece Model
 /// Model for SentencePiece tokenizer. Contains the following special values. This model performs
 /// the SentencePiece unigram decomposition. As such, it contains a `Trie` data structure for efficient
@@ -65,9 +148,37 @@ impl TrieNode {
 pub struct SentencePieceModel {
     /// Trie data structure containing the vocabulary elements and their unigram log-probabilities
     pub root: TrieNode,
+    /// Lowest unigram log-probability of the vocabulary pieces, computed when the model is created
+    min_score: f32,
 }
 
 impl SentencePieceModel {
+    /// Penalty applied (below the lowest piece score) to pieces that are not part of the vocabulary
+    /// when scoring a sequence
+    pub const UNKNOWN_PIECE_PENALTY: f32 = 10.0;
+
+    /// Creates a SentencePiece Model from a Trie containing the vocabulary pieces. The lowest score
+    /// of the pieces is computed on creation: pieces later added to the Trie directly are not
+    /// taken into account by `min_score`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use rust_tokenizers::vocab::{SentencePieceModel, TrieNode};
+    ///
+    /// let sentence_piece_model = SentencePieceModel::new(TrieNode::new("".to_string()));
+    /// ```
+    pub fn new(root: TrieNode) -> SentencePieceModel {
+        let mut min_score = f32::INFINITY;
+        let mut nodes = vec![&root];
+        while let Some(node) = nodes.pop() {
+            if node.end {
+                min_score = min_score.min(node.score);
+            }
+            nodes.extend(node.children.values());
+        }
+        SentencePieceModel { root, min_score }
+    }
+
     /// Creates a SentencePiece Model from a protobuf file.
     ///
     /// # Example
@@ -78,31 +189,31 @@ impl SentencePieceModel {
     /// let sentence_piece_model = SentencePieceModel::from_file(&path).unwrap();
     /// ```
     pub fn from_file<P: AsRef<Path>>(path: P) -> Result<SentencePieceModel, TokenizerError> {
-        let mut f = File::open(&path).map_err(|e| {
-            TokenizerError::FileNotFound(format!(
-                "{} vocabulary file not found :{}",
-                path.as_ref().display(),
-                e
-            ))
-        })?;
-        let mut contents = Vec::new();
-        let proto = match f.read_to_end(&mut contents) {
-            Ok(_) => match ModelProto::parse_from_bytes(contents.as_slice()) {
-                Ok(proto_value) => proto_value,
-                Err(e) => {
-                    return Err(TokenizerError::VocabularyParsingError(e.to_string()));
-                }
-            },
-            Err(e) => {
-                return Err(TokenizerError::VocabularyParsingError(e.to_string()));
-            }
-        };
-        let root = TrieNode::new("".to_string());
-        let mut vocab = SentencePieceModel { root };
+        let proto = open_protobuf_file(path)?;
+        Ok(Self::from_proto(&proto))
+    }
+
+    /// Creates a SentencePiece Model from the bytes of a protobuf file, allowing to embed the
+    /// model in a binary (e.g. using `include_bytes!`).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use rust_tokenizers::vocab::SentencePieceModel;
+    ///
+    /// let bytes = std::fs::read("path/to/spiece.model").unwrap();
+    /// let sentence_piece_model = SentencePieceModel::from_protobuf_bytes(&bytes).unwrap();
+    /// ```
+    pub fn from_protobuf_bytes(bytes: &[u8]) -> Result<SentencePieceModel, TokenizerError> {
+        let proto = parse_protobuf_bytes(bytes)?;
+        Ok(Self::from_proto(&proto))
+    }
+
+    fn from_proto(proto: &ModelProto) -> SentencePieceModel {
+        let mut vocab = SentencePieceModel::new(TrieNode::new("".to_string()));
         for (idx, piece) in proto.get_pieces().iter().enumerate() {
             vocab.insert(piece.get_piece(), piece.get_score(), idx as i64);
         }
-        Ok(vocab)
+        vocab
     }
 
     fn insert(&mut self, word: &str, score: f32, index: i64) {
@@ -123,15 +234,17 @@ impl SentencePieceModel {
                 node.index = index;
             }
         }
+        self.min_score = self.min_score.min(score);
     }
 
-    /// Performs a common prefix search for a given query on the model Trie structure
+    /// Performs a common prefix search for a given query on the model Trie structure, returning
+    /// the vocabulary pieces that are a prefix of the query (by increasing length).
     ///
     /// # Arguments
     /// - text (`&str`): query to find common prefixes from
     ///
     /// # Returns
-    /// - `Vec<&TrieNode>` containing references to the Trie nodes with a common (character based) prefix with the query
+    /// - `Vec<PieceMatch>` containing the matching pieces, with their index and score
     ///
     /// # Example
     /// ```no_run
@@ -142,33 +255,23 @@ impl SentencePieceModel {
     /// let query = "hello";
     /// let common_prefixes = sentence_piece_model.common_prefix_search(query);
     /// ```
-    pub fn common_prefix_search<'a>(&'a self, text: &'a str) -> Vec<&'a TrieNode> {
+    pub fn common_prefix_search<'a>(&self, text: &'a str) -> Vec<PieceMatch<'a>> {
         let mut results = vec![];
-        let mut characters = text.chars();
-        let mut node = self.root.children.get(match &characters.next() {
-            Some(character) => character,
-            None => {
-                return vec![];
-            }
-        });
-        if let Some(node_value) = node {
-            if node_value.end {
-                results.push(node_value);
-            }
-        } else {
-            return vec![];
-        }
-        for character in characters {
-            node = node.unwrap().children.get(&character);
-            if let Some(node_value) = node {
-                if node_value.end {
-                    results.push(node_value);
-                }
-            } else {
-                break;
+        let mut node = &self.root;
+        for (char_index, (position, character)) in text.char_indices().enumerate() {
+            node = match node.children.get(&character) {
+                Some(child) => child,
+                None => break,
+            };
+            if node.end {
+                results.push(PieceMatch {
+                    text: &text[..position + character.len_utf8()],
+                    index: node.index,
+                    score: node.score,
+                    char_len: char_index + 1,
+                });
             }
         }
-
         results
     }
 
@@ -200,14 +303,14 @@ impl SentencePieceModel {
 
         for char_start in 0..char_positions.len() - 1 {
             let matches = self.common_prefix_search(&token.text[char_positions[char_start]..]);
-            for node in matches {
-                let local_score = scores[char_start] + node.score;
-                let char_end = char_start + node.len;
+            for piece_match in matches {
+                let local_score = scores[char_start] + piece_match.score;
+                let char_end = char_start + piece_match.char_len;
                 if local_score > scores[char_end] {
                     results[char_end] = Some(Node {
-                        text: &token.text[char_positions[char_start]..char_positions[char_end]],
+                        text: piece_match.text,
                         score: local_score,
-                        index: node.index,
+                        index: piece_match.index,
                         start: char_start,
                         end: char_end,
                         reference_offsets: &token.reference_offsets[char_start..char_end],
@@ -230,6 +333,137 @@ impl SentencePieceModel {
         results
     }
 
+    /// Returns the unigram log-probability of a piece, or `None` if the piece is not part of the
+    /// vocabulary
+    ///
+    /// # Example
+    /// ```no_run
+    /// use rust_tokenizers::vocab::SentencePieceModel;
+    /// let path = "path/to/spiece.model";
+    /// let sentence_piece_model = SentencePieceModel::from_file(path).unwrap();
+    ///
+    /// let score = sentence_piece_model.piece_score("▁hello");
+    /// ```
+    pub fn piece_score(&self, piece: &str) -> Option<f32> {
+        let mut node = &self.root;
+        for character in piece.chars() {
+            node = node.children.get(&character)?;
+        }
+        if node.end {
+            Some(node.score)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the lowest unigram log-probability of the vocabulary pieces (0 for an empty vocabulary)
+    pub fn min_score(&self) -> f32 {
+        if self.min_score.is_finite() {
+            self.min_score
+        } else {
+            0f32
+        }
+    }
+
+    /// Returns the log-probability of a sequence of pieces under the unigram model (sum of the
+    /// pieces log-probabilities). As in SentencePiece, pieces that are not part of the vocabulary
+    /// are scored with the lowest score of the vocabulary minus `UNKNOWN_PIECE_PENALTY`.
+    ///
+    /// # Arguments
+    /// - pieces (`&[S]`): pieces to score
+    ///
+    /// # Returns
+    /// - `f32` log-probability of the sequence
+    ///
+    /// # Example
+    /// ```no_run
+    /// use rust_tokenizers::vocab::SentencePieceModel;
+    /// let path = "path/to/spiece.model";
+    /// let sentence_piece_model = SentencePieceModel::from_file(path).unwrap();
+    ///
+    /// let score = sentence_piece_model.score_pieces(&["▁hello", "▁world"]);
+    /// ```
+    pub fn score_pieces<S: AsRef<str>>(&self, pieces: &[S]) -> f32 {
+        let unknown_score = self.min_score() - Self::UNKNOWN_PIECE_PENALTY;
+        pieces
+            .iter()
+            .map(|piece| self.piece_score(piece.as_ref()).unwrap_or(unknown_score))
+            .sum()
+    }
+
+    /// Builds the full segmentation lattice of a text, with all the vocabulary pieces matching the
+    /// text and, for each of them, the score of the best segmentation going through the piece and a
+    /// back-pointer to the previous piece on this segmentation. The text is expected to be normalized
+    /// as done by the tokenizers (e.g. with whitespaces replaced by `▁`).
+    ///
+    /// # Arguments
+    /// - text (`&str`): text to segment
+    ///
+    /// # Returns
+    /// - `Lattice` with the candidate pieces
+    ///
+    /// # Example
+    /// ```no_run
+    /// use rust_tokenizers::vocab::SentencePieceModel;
+    /// let path = "path/to/spiece.model";
+    /// let sentence_piece_model = SentencePieceModel::from_file(path).unwrap();
+    ///
+    /// let lattice = sentence_piece_model.lattice("▁hello▁world");
+    /// for node in lattice.nodes_starting_at(0) {
+    ///     println!("{} ({})", node.text, node.score);
+    /// }
+    /// let best_segmentation = lattice.best_path();
+    /// ```
+    pub fn lattice<'a>(&self, text: &'a str) -> Lattice<'a> {
+        let mut char_positions = text.char_indices().map(|(pos, _)| pos).collect_vec();
+        char_positions.push(text.len());
+        let num_chars = char_positions.len() - 1;
+        let mut nodes = vec![];
+        let mut best_nodes = vec![None; num_chars + 1];
+        let mut scores = vec![f32::NEG_INFINITY; num_chars + 1];
+        scores[0] = 0f32;
+
+        for char_start in 0..num_chars {
+            for piece_match in self.common_prefix_search(&text[char_positions[char_start]..]) {
+                let char_end = char_start + piece_match.char_len;
+                let path_score = scores[char_start] + piece_match.score;
+                if path_score > scores[char_end] {
+                    best_nodes[char_end] = Some(nodes.len());
+                    scores[char_end] = path_score;
+                }
+                nodes.push(LatticeNode {
+                    text: piece_match.text,
+                    index: piece_match.index,
+                    score: piece_match.score,
+                    start: char_start,
+                    end: char_end,
+                    path_score,
+                    back_pointer: best_nodes[char_start],
+                    is_unknown: false,
+                });
+            }
+            if scores[char_start + 1] <= f32::MIN {
+                best_nodes[char_start + 1] = Some(nodes.len());
+                scores[char_start + 1] = 0f32;
+                nodes.push(LatticeNode {
+                    text: &text[char_positions[char_start]..char_positions[char_start + 1]],
+                    index: 0,
+                    score: f32::MIN,
+                    start: char_start,
+                    end: char_start + 1,
+                    path_score: 0f32,
+                    back_pointer: best_nodes[char_start],
+                    is_unknown: true,
+                });
+            }
+        }
+        Lattice {
+            text,
+            nodes,
+            best_nodes,
+        }
+    }
+
     /// Backward pass through an array of nodes (generated as a result of the forward pass), returning
     /// the most likely sequence of nodes. These are usually converted back to tokens in a last step
     ///
@@ -378,3 +612,175 @@ impl SentencePieceModel {
         }
     }
 }
+
+//==============================
+// Unit tests
+//==============================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vocab::sentencepiece_proto::sentencepiece_model::ModelProto_SentencePiece;
+    use crate::vocab::{SentencePieceVocab, Vocab};
+    use protobuf::Message;
+
+    #[test]
+    fn test_common_prefix_search() {
+        //        Given
+        let mut model = SentencePieceModel::new(TrieNode::new("".to_string()));
+        for (index, (piece, score)) in [("▁", -1.0), ("▁he", -2.0), ("▁hello", -3.0), ("é", -4.0)]
+            .iter()
+            .enumerate()
+        {
+            model.insert(piece, *score, index as i64);
+        }
+
+        //        When
+        let matches = model.common_prefix_search("▁hello world");
+
+        //        Then
+        assert_eq!(
+            matches,
+            vec![
+                PieceMatch {
+                    text: "▁",
+                    index: 0,
+                    score: -1.0,
+                    char_len: 1,
+                },
+                PieceMatch {
+                    text: "▁he",
+                    index: 1,
+                    score: -2.0,
+                    char_len: 3,
+                },
+                PieceMatch {
+                    text: "▁hello",
+                    index: 2,
+                    score: -3.0,
+                    char_len: 6,
+                },
+            ]
+        );
+        assert_eq!(model.common_prefix_search("éa")[0].text, "é");
+        assert!(model.common_prefix_search("hello").is_empty());
+        assert!(model.common_prefix_search("").is_empty());
+    }
+
+    #[test]
+    fn test_from_protobuf_bytes() {
+        //        Given
+        let mut proto = ModelProto::new();
+        for (piece, score) in [("<unk>", 0.0), ("▁", -1.0), ("▁he", -2.0), ("▁hello", -3.0)] {
+            let mut sentence_piece = ModelProto_SentencePiece::new();
+            sentence_piece.set_piece(piece.to_string());
+            sentence_piece.set_score(score);
+            proto.mut_pieces().push(sentence_piece);
+        }
+        let bytes = proto.write_to_bytes().unwrap();
+
+        //        When
+        let model = SentencePieceModel::from_protobuf_bytes(&bytes).unwrap();
+        let vocab = SentencePieceVocab::from_protobuf_bytes(&bytes).unwrap();
+
+        //        Then
+        let matches = model.common_prefix_search("▁hello");
+        assert_eq!(
+            matches.iter().map(|m| (m.text, m.index)).collect_vec(),
+            vec![("▁", 1), ("▁he", 2), ("▁hello", 3)]
+        );
+        assert_eq!(matches[2].score, -3.0);
+        assert_eq!(vocab.token_to_id("▁hello"), 3);
+        assert_eq!(vocab.token_to_id("world"), 0);
+        assert!(matches!(
+            SentencePieceModel::from_protobuf_bytes(&[0xFF, 0xFF]),
+            Err(TokenizerError::VocabularyParsingError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_lattice() {
+        //        Given
+        let mut model = SentencePieceModel::new(TrieNode::new("".to_string()));
+        for (index, (piece, score)) in [
+            ("<unk>", 0.0),
+            ("▁", -1.0),
+            ("▁he", -2.0),
+            ("▁hello", -6.0),
+            ("l", -1.5),
+            ("lo", -2.0),
+            ("llo", -2.5),
+        ]
+        .iter()
+        .enumerate()
+        {
+            model.insert(piece, *score, index as i64);
+        }
+        let text = "▁hello▁x";
+        let offsets = (0..8).collect_vec();
+
+        //        When
+        let lattice = model.lattice(text);
+        let best_path = lattice.best_path();
+        let forward_nodes = model.decode_forward_token_ref(TokenRef::new(text, &offsets));
+        let decoded_nodes = model.decode_backward(&forward_nodes);
+
+        //        Then
+        assert_eq!(lattice.num_chars(), 8);
+        assert_eq!(
+            lattice
+                .nodes_starting_at(0)
+                .map(|node| node.text)
+                .collect_vec(),
+            vec!["▁", "▁he", "▁hello"]
+        );
+        assert_eq!(
+            lattice
+                .nodes_ending_at(6)
+                .map(|node| node.text)
+                .collect_vec(),
+            vec!["▁hello", "llo", "lo"]
+        );
+        assert_eq!(
+            best_path.iter().map(|node| node.text).collect_vec(),
+            vec!["▁he", "llo", "▁", "x"]
+        );
+        assert_eq!(best_path[1].path_score, -4.5);
+        assert_eq!(best_path[1].back_pointer, lattice.best_nodes[3]);
+        assert!(best_path[3].is_unknown);
+        assert_eq!(
+            best_path
+                .iter()
+                .map(|node| (node.text, node.start, node.end))
+                .collect_vec(),
+            decoded_nodes
+                .iter()
+                .map(|node| (node.text, node.start, node.end))
+                .collect_vec()
+        );
+        assert!(model.lattice("").best_path().is_empty());
+    }
+
+    #[test]
+    fn test_score_pieces() {
+        //        Given
+        let mut model = SentencePieceModel::new(TrieNode::new("".to_string()));
+        for (index, (piece, score)) in [("▁he", -2.0), ("llo", -2.5), ("▁hello", -6.0)]
+            .iter()
+            .enumerate()
+        {
+            model.insert(piece, *score, index as i64);
+        }
+
+        //        When
+        let known_score = model.score_pieces(&["▁he", "llo"]);
+        let unknown_score = model.score_pieces(&["▁hello".to_string(), "x".to_string()]);
+
+        //        Then
+        assert_eq!(model.piece_score("llo"), Some(-2.5));
+        assert_eq!(model.piece_score("ll"), None);
+        assert_eq!(model.min_score(), -6.0);
+        assert_eq!(known_score, -4.5);
+        assert_eq!(unknown_score, -22.0);
+        assert_eq!(model.score_pieces::<&str>(&[]), 0.0);
+    }
+}
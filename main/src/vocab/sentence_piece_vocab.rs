@@ -12,7 +12,8 @@
 
 use crate::error::TokenizerError;
 use crate::vocab::base_vocab::{
-    read_protobuf_file, read_special_token_mapping_file, swap_key_values, SpecialTokenMap,
+    read_protobuf_bytes, read_protobuf_file, read_special_token_mapping_file, swap_key_values,
+    SpecialTokenMap,
 };
 use crate::vocab::Vocab;
 use std::collections::HashMap;
@@ -44,6 +45,36 @@ pub struct SentencePieceVocab {
 
 const DEFAULT_UNK_TOKEN: &str = "<unk>";
 
+impl SentencePieceVocab {
+    fn default_special_token_map() -> SpecialTokenMap {
+        SpecialTokenMap {
+            unk_token: DEFAULT_UNK_TOKEN.to_string(),
+            pad_token: None,
+            bos_token: None,
+            sep_token: None,
+            cls_token: None,
+            eos_token: None,
+            mask_token: None,
+            additional_special_tokens: None,
+        }
+    }
+
+    /// Creates a vocabulary from the bytes of a SentencePiece protobuf file, allowing to embed
+    /// the model in a binary (e.g. using `include_bytes!`).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use rust_tokenizers::vocab::SentencePieceVocab;
+    ///
+    /// let bytes = std::fs::read("path/to/spiece.model").unwrap();
+    /// let vocab = SentencePieceVocab::from_protobuf_bytes(&bytes).unwrap();
+    /// ```
+    pub fn from_protobuf_bytes(bytes: &[u8]) -> Result<SentencePieceVocab, TokenizerError> {
+        let values = read_protobuf_bytes(bytes)?;
+        Self::from_values_and_special_token_map(values, Self::default_special_token_map())
+    }
+}
+
 impl Vocab for SentencePieceVocab {
     fn get_unknown_value(&self) -> &str {
         &self.special_token_map.unk_token
@@ -65,6 +96,10 @@ impl Vocab for SentencePieceVocab {
         &self.special_indices
     }
 
+    fn special_token_map(&self) -> &SpecialTokenMap {
+        &self.special_token_map
+    }
+
     fn values_mut(&mut self) -> &mut HashMap<String, i64> {
         &mut self.values
     }
@@ -84,16 +119,7 @@ impl Vocab for SentencePieceVocab {
     fn from_file<P: AsRef<Path>>(path: P) -> Result<SentencePieceVocab, TokenizerError> {
         let values = read_protobuf_file(path)?;
 
-        let special_token_map = SpecialTokenMap {
-            unk_token: DEFAULT_UNK_TOKEN.to_string(),
-            pad_token: None,
-            bos_token: None,
-            sep_token: None,
-            cls_token: None,
-            eos_token: None,
-            mask_token: None,
-            additional_special_tokens: None,
-        };
+        let special_token_map = Self::default_special_token_map();
         Self::from_values_and_special_token_map(values, special_token_map)
     }
 
@@ -13,9 +13,10 @@
 
 use crate::error::TokenizerError;
 use crate::vocab::base_vocab::{
-    open_protobuf_file, read_special_token_mapping_file, register_as_special_value,
-    swap_key_values, SpecialTokenMap,
+    open_protobuf_file, parse_protobuf_bytes, read_special_token_mapping_file,
+    register_as_special_value, required_special_token, swap_key_values, SpecialTokenMap,
 };
+use crate::vocab::sentencepiece_proto::sentencepiece_model::ModelProto;
 use crate::vocab::Vocab;
 use std::collections::HashMap;
 use std::path::Path;
@@ -101,48 +102,23 @@ impl XLMRobertaVocab {
             .as_deref()
             .unwrap_or(DEFAULT_MASK_TOKEN)
     }
-}
-
-impl Vocab for XLMRobertaVocab {
-    fn get_unknown_value(&self) -> &str {
-        &self.special_token_map.unk_token
-    }
-
-    fn values(&self) -> &HashMap<String, i64> {
-        &self.values
-    }
-
-    fn indices(&self) -> &HashMap<i64, String> {
-        &self.indices
-    }
-
-    fn special_values(&self) -> &HashMap<String, i64> {
-        &self.special_values
-    }
-
-    fn special_indices(&self) -> &HashMap<i64, String> {
-        &self.special_indices
-    }
-
-    fn values_mut(&mut self) -> &mut HashMap<String, i64> {
-        &mut self.values
-    }
 
-    fn indices_mut(&mut self) -> &mut HashMap<i64, String> {
-        &mut self.indices
-    }
-
-    fn special_values_mut(&mut self) -> &mut HashMap<String, i64> {
-        &mut self.special_values
+    /// Creates a vocabulary from the bytes of a SentencePiece protobuf file, allowing to embed
+    /// the model in a binary (e.g. using `include_bytes!`).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use rust_tokenizers::vocab::XLMRobertaVocab;
+    ///
+    /// let bytes = std::fs::read("path/to/sentencepiece.bpe.model").unwrap();
+    /// let vocab = XLMRobertaVocab::from_protobuf_bytes(&bytes).unwrap();
+    /// ```
+    pub fn from_protobuf_bytes(bytes: &[u8]) -> Result<XLMRobertaVocab, TokenizerError> {
+        let proto = parse_protobuf_bytes(bytes)?;
+        Self::from_proto(&proto)
     }
 
-    fn special_indices_mut(&mut self) -> &mut HashMap<i64, String> {
-        &mut self.special_indices
-    }
-
-    fn from_file<P: AsRef<Path>>(path: P) -> Result<XLMRobertaVocab, TokenizerError> {
-        let proto = open_protobuf_file(path)?;
-
+    fn from_proto(proto: &ModelProto) -> Result<XLMRobertaVocab, TokenizerError> {
         let special_token_map = SpecialTokenMap {
             unk_token: DEFAULT_UNK_TOKEN.to_string(),
             pad_token: Some(DEFAULT_PAD_TOKEN.to_string()),
@@ -215,6 +191,53 @@ impl Vocab for XLMRobertaVocab {
             special_indices,
         })
     }
+}
+
+impl Vocab for XLMRobertaVocab {
+    fn get_unknown_value(&self) -> &str {
+        &self.special_token_map.unk_token
+    }
+
+    fn values(&self) -> &HashMap<String, i64> {
+        &self.values
+    }
+
+    fn indices(&self) -> &HashMap<i64, String> {
+        &self.indices
+    }
+
+    fn special_values(&self) -> &HashMap<String, i64> {
+        &self.special_values
+    }
+
+    fn special_indices(&self) -> &HashMap<i64, String> {
+        &self.special_indices
+    }
+
+    fn special_token_map(&self) -> &SpecialTokenMap {
+        &self.special_token_map
+    }
+
+    fn values_mut(&mut self) -> &mut HashMap<String, i64> {
+        &mut self.values
+    }
+
+    fn indices_mut(&mut self) -> &mut HashMap<i64, String> {
+        &mut self.indices
+    }
+
+    fn special_values_mut(&mut self) -> &mut HashMap<String, i64> {
+        &mut self.special_values
+    }
+
+    fn special_indices_mut(&mut self) -> &mut HashMap<i64, String> {
+        &mut self.special_indices
+    }
+
+    fn from_file<P: AsRef<Path>>(path: P) -> Result<XLMRobertaVocab, TokenizerError> {
+        let proto = open_protobuf_file(path)?;
+        Self::from_proto(&proto)
+    }
 
     fn from_file_with_special_token_mapping<P: AsRef<Path>, S: AsRef<Path>>(
         path: P,
@@ -247,7 +270,7 @@ impl Vocab for XLMRobertaVocab {
             values.insert(piece.get_piece().to_owned(), values.len() as i64);
         }
         values.insert(
-            special_token_map.cls_token.as_ref().unwrap().clone(),
+            required_special_token(&special_token_map.cls_token, "cls_token")?.clone(),
             values.len() as i64,
         );
 
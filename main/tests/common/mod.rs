@@ -0,0 +1,135 @@
+//! Factories for the toy vocabularies, merges and SentencePiece models shared by the tests that
+//! build tokenizers from in-memory values.
+#![allow(dead_code)]
+
+use rust_tokenizers::vocab::{
+    BpePairVocab, SentencePieceBpeModel, SentencePieceModel, SpecialTokenMap, TrieNode, Vocab,
+};
+use std::collections::HashMap;
+
+/// Maps the entries to their position, keeping the first position of duplicated entries.
+pub fn string_map<'a>(entries: impl IntoIterator<Item = &'a str>) -> HashMap<String, i64> {
+    let mut values = HashMap::new();
+    for entry in entries {
+        let index = values.len() as i64;
+        values.entry(entry.to_string()).or_insert(index);
+    }
+    values
+}
+
+/// Special tokens map from the unknown token and the pad, bos, sep, cls, eos and mask tokens.
+pub fn special_tokens(unk_token: &str, other_tokens: [Option<&str>; 6]) -> SpecialTokenMap {
+    let [pad_token, bos_token, sep_token, cls_token, eos_token, mask_token] =
+        other_tokens.map(|token| token.map(str::to_string));
+    SpecialTokenMap {
+        unk_token: unk_token.to_string(),
+        pad_token,
+        bos_token,
+        sep_token,
+        cls_token,
+        eos_token,
+        mask_token,
+        additional_special_tokens: None,
+    }
+}
+
+/// `[CLS]`, `[SEP]` and `[MASK]` special tokens.
+pub fn bert_like_special_tokens(unk_token: &str, pad_token: &str) -> SpecialTokenMap {
+    special_tokens(
+        unk_token,
+        [
+            Some(pad_token),
+            Some("[CLS]"),
+            Some("[SEP]"),
+            Some("[CLS]"),
+            Some("[SEP]"),
+            Some("[MASK]"),
+        ],
+    )
+}
+
+/// `<unk>`, `<pad>`, `<s>`, `</s>` and `<mask>` special tokens.
+pub fn roberta_like_special_tokens() -> SpecialTokenMap {
+    special_tokens(
+        "<unk>",
+        [
+            Some("<pad>"),
+            Some("<s>"),
+            Some("</s>"),
+            Some("<s>"),
+            Some("</s>"),
+            Some("<mask>"),
+        ],
+    )
+}
+
+/// BPE merges ranked by their position.
+pub fn merges(pairs: &[(&str, &str)]) -> BpePairVocab {
+    BpePairVocab {
+        values: pairs
+            .iter()
+            .enumerate()
+            .map(|(rank, (left, right))| ((left.to_string(), right.to_string()), rank as i64))
+            .collect(),
+    }
+}
+
+/// Unigram model with the pieces and scores, indexed by their position.
+pub fn sentence_piece_model(pieces: &[(&str, f32)]) -> SentencePieceModel {
+    let mut root = TrieNode::new(String::new());
+    for (index, (piece, score)) in pieces.iter().enumerate() {
+        let mut node = &mut root;
+        for character in piece.chars() {
+            let text = format!("{}{}", node.text, character);
+            node = node
+                .children
+                .entry(character)
+                .or_insert_with(|| TrieNode::new(text));
+        }
+        node.end = true;
+        node.score = *score;
+        node.index = index as i64;
+    }
+    SentencePieceModel::new(root)
+}
+
+pub fn sentence_piece_values(pieces: &[(&str, f32)]) -> HashMap<String, i64> {
+    string_map(pieces.iter().map(|(piece, _)| *piece))
+}
+
+pub fn sentence_piece_vocab<V: Vocab>(
+    pieces: &[(&str, f32)],
+    special_token_map: SpecialTokenMap,
+) -> V {
+    V::from_values_and_special_token_map(sentence_piece_values(pieces), special_token_map).unwrap()
+}
+
+fn write_varint(mut value: usize, buffer: &mut Vec<u8>) {
+    while value >= 0x80 {
+        buffer.push((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    }
+    buffer.push(value as u8);
+}
+
+/// Encodes the pieces as a SentencePiece `ModelProto` (`pieces` field, with the `piece` and
+/// `score` of each entry).
+pub fn sentence_piece_protobuf(pieces: &[(&str, f32)]) -> Vec<u8> {
+    let mut model = vec![];
+    for (piece, score) in pieces.iter() {
+        let mut sentence_piece = vec![0x0a];
+        write_varint(piece.len(), &mut sentence_piece);
+        sentence_piece.extend_from_slice(piece.as_bytes());
+        sentence_piece.push(0x15);
+        sentence_piece.extend_from_slice(&score.to_le_bytes());
+
+        model.push(0x0a);
+        write_varint(sentence_piece.len(), &mut model);
+        model.extend(sentence_piece);
+    }
+    model
+}
+
+pub fn sentence_piece_bpe_model(pieces: &[(&str, f32)]) -> SentencePieceBpeModel {
+    SentencePieceBpeModel::from_protobuf_bytes(&sentence_piece_protobuf(pieces)).unwrap()
+}
@@ -0,0 +1,86 @@
+# Copyright 2019 Guillaume Becquin
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#     http://www.apache.org/licenses/LICENSE-2.0
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+
+"""Generates the expected outputs of the parity fixtures using the Python `transformers` tokenizers.
+
+Each fixture file defines a tokenizer `family` (used by `tests/test_parity.rs` to build the Rust
+tokenizer), the reference `model` name on the Hugging Face hub, the `files` required by the Rust
+tokenizer, tokenizer `options` and a list of `cases`. Only the `text` (and optional `text_pair`)
+of each case needs to be provided: the `token_ids` and `offsets` are (re)generated by this script,
+and the versions of `transformers` and `tokenizers` used are recorded as the fixture `reference`.
+Fixtures without a `reference` are rejected by the parity test.
+
+Usage (from the crate root, with the pinned versions of the reference libraries):
+    python -m pip install -r tests/fixtures/parity/requirements.txt
+    python tests/fixtures/parity/generate_fixtures.py tests/fixtures/parity/*.json
+"""
+
+import json
+import sys
+
+import tokenizers
+import transformers
+from transformers import AutoTokenizer
+
+OPTION_NAMES = {
+    'lower_case': 'do_lower_case',
+    'strip_accents': 'strip_accents',
+    'add_prefix_space': 'add_prefix_space',
+    'split_by_punct': 'split_by_punct',
+}
+
+
+def generate_cases(fixture):
+    options = {OPTION_NAMES[key]: value for key, value in fixture.get('options', {}).items()}
+    tokenizer = AutoTokenizer.from_pretrained(fixture['model'], use_fast=True, **options)
+    cases = []
+    for case in fixture['cases']:
+        encoding = tokenizer(case['text'],
+                             case.get('text_pair'),
+                             return_offsets_mapping=True,
+                             return_special_tokens_mask=True)
+        offsets = [None if is_special else list(offset)
+                   for offset, is_special in zip(encoding['offset_mapping'],
+                                                 encoding['special_tokens_mask'])]
+        generated_case = {'text': case['text']}
+        if case.get('text_pair') is not None:
+            generated_case['text_pair'] = case['text_pair']
+        generated_case['token_ids'] = encoding['input_ids']
+        generated_case['offsets'] = offsets
+        cases.append(generated_case)
+    return cases
+
+
+def write_fixture(path, fixture):
+    lines = ['{']
+    for key in ['family', 'model', 'files', 'options', 'reference']:
+        lines.append(f'  "{key}": {json.dumps(fixture.get(key, {}), ensure_ascii=False)},')
+    lines.append('  "cases": [')
+    cases = []
+    for case in fixture['cases']:
+        fields = [f'      "{key}": {json.dumps(value, ensure_ascii=False)}' for key, value in case.items()]
+        cases.append('    {\n' + ',\n'.join(fields) + '\n    }')
+    lines.append(',\n'.join(cases))
+    lines.append('  ]')
+    lines.append('}')
+    with open(path, 'w', encoding='utf-8') as f:
+        f.write('\n'.join(lines) + '\n')
+
+
+if __name__ == '__main__':
+    for fixture_path in sys.argv[1:]:
+        with open(fixture_path, encoding='utf-8') as f:
+            fixture = json.load(f)
+        fixture['cases'] = generate_cases(fixture)
+        fixture['reference'] = {'transformers': transformers.__version__,
+                                'tokenizers': tokenizers.__version__}
+        write_fixture(fixture_path, fixture)
+        print(f'{fixture_path}: {len(fixture["cases"])} cases generated')
@@ -40,6 +40,7 @@ fn test_albert_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![2, 48, 25, 21, 5717, 5123, 20, 44, 20, 2853, 1333, 3],
@@ -63,6 +64,7 @@ fn test_albert_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![2, 5712, 184, 48, 129, 164, 20, 2853, 1333, 13, 1, 13, 60, 3],
@@ -88,6 +90,7 @@ fn test_albert_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -124,6 +127,7 @@ fn test_albert_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -173,6 +177,7 @@ fn test_albert_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -219,6 +224,7 @@ fn test_albert_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
     ]
     .to_vec();
@@ -257,3 +263,31 @@ fn test_albert_tokenization() -> anyhow::Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_albert_nfkc_normalization() -> anyhow::Result<()> {
+    let vocab_path = download_file_to_cache(
+        "https://s3.amazonaws.com/models.huggingface.co/bert/albert-base-v2-spiece.model",
+    )?;
+
+    let normalizing_tokenizer = AlbertTokenizer::from_file(vocab_path.as_path(), true, true)?;
+    let mut raw_tokenizer = AlbertTokenizer::from_file(vocab_path.as_path(), true, true)?;
+    raw_tokenizer.set_nfkc_normalization(false);
+
+    let compatibility_text = "ｈｅｌｌｏ ﬁne";
+    let normalized_text = "hello fine";
+
+    assert_eq!(
+        normalizing_tokenizer.tokenize(compatibility_text),
+        normalizing_tokenizer.tokenize(normalized_text)
+    );
+    assert_ne!(
+        raw_tokenizer.tokenize(compatibility_text),
+        raw_tokenizer.tokenize(normalized_text)
+    );
+    assert_eq!(
+        raw_tokenizer.tokenize(normalized_text),
+        normalizing_tokenizer.tokenize(normalized_text)
+    );
+    Ok(())
+}
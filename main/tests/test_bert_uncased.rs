@@ -34,6 +34,7 @@ fn test_bert_tokenization() -> anyhow::Result<()> {
             token_offsets: vec![None, Some(Offset { begin: 0, end: 1 }), None],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -58,6 +59,7 @@ fn test_bert_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -82,6 +84,7 @@ fn test_bert_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -114,6 +117,7 @@ fn test_bert_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -150,6 +154,7 @@ fn test_bert_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -182,6 +187,7 @@ fn test_bert_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -214,6 +220,7 @@ fn test_bert_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
     ]
     .to_vec();
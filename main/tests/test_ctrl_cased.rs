@@ -36,6 +36,7 @@ fn test_ctrl_tokenization() -> anyhow::Result<()> {
             token_offsets: vec![Some(Offset { begin: 0, end: 1 })],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![93, 8, 5, 10165, 3870, 3, 22, 2169, 3479, 88010, 2388, 16431],
@@ -59,6 +60,7 @@ fn test_ctrl_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![192851, 162, 32, 57, 213, 113421, 13389, 246532, 415],
@@ -79,6 +81,7 @@ fn test_ctrl_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -111,6 +114,7 @@ fn test_ctrl_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -145,6 +149,7 @@ fn test_ctrl_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -177,6 +182,7 @@ fn test_ctrl_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -217,6 +223,7 @@ fn test_ctrl_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
     ]
     .to_vec();
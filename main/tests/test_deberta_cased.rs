@@ -36,6 +36,7 @@ fn test_deberta_tokenization() -> anyhow::Result<()> {
             token_offsets: vec![None, Some(Offset { begin: 0, end: 1 }), None],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -64,6 +65,7 @@ fn test_deberta_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -92,6 +94,7 @@ fn test_deberta_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -148,6 +151,7 @@ fn test_deberta_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -200,6 +204,7 @@ fn test_deberta_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
     ]
     .to_vec();
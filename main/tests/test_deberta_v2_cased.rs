@@ -37,6 +37,7 @@ fn test_deberta_v2_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -63,6 +64,7 @@ fn test_deberta_v2_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -88,6 +90,7 @@ fn test_deberta_v2_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -133,6 +136,7 @@ fn test_deberta_v2_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -176,6 +180,7 @@ fn test_deberta_v2_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
     ]
     .to_vec();
@@ -214,3 +219,37 @@ fn test_deberta_v2_tokenization() -> anyhow::Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_deberta_v2_split_by_punct() -> anyhow::Result<()> {
+    let vocab_path = download_file_to_cache(
+        "https://huggingface.co/microsoft/deberta-v3-base/resolve/main/spm.model",
+    )
+    .unwrap();
+
+    let deberta_v2_tokenizer =
+        DeBERTaV2Tokenizer::from_file(vocab_path.as_path(), false, false, false)?;
+    let mut split_tokenizer = DeBERTaV2Tokenizer::from_file(vocab_path, false, false, false)?;
+    split_tokenizer.set_split_by_punct(true);
+
+    // Each punctuation character and span between punctuation characters is tokenized independently
+    let words = [
+        "Wait", ".", ".", ".", "it", "'", "s", "(", "not", ")", "e", "-", "mail", "!",
+    ];
+    let expected_tokens = words
+        .iter()
+        .flat_map(|word| deberta_v2_tokenizer.tokenize(word))
+        .collect::<Vec<String>>();
+
+    let tokens = split_tokenizer.tokenize("Wait... it's (not) e-mail!");
+    assert_eq!(tokens, expected_tokens);
+    assert_eq!(
+        split_tokenizer.decode(
+            &split_tokenizer.convert_tokens_to_ids(&tokens),
+            false,
+            false
+        ),
+        " Wait . . . it ' s ( not ) e - mail !"
+    );
+    Ok(())
+}
@@ -0,0 +1,447 @@
+//! Behavior of the tokenizers for degenerate inputs and inserted special tokens.
+//!
+//! For every tokenizer that can be built from in-memory vocabularies, the following is checked:
+//! - empty and whitespace-only inputs are tokenized as an empty sequence,
+//! - these inputs are encoded as the special tokens of the tokenizer only,
+//! - a `max_len` up to the number of special tokens (e.g. `max_len == 0`) truncates all
+//!   input tokens and returns the special tokens only,
+//! - decoding an empty sequence returns an empty string,
+//! - special tokens inserted when encoding (e.g. CLS/SEP) have `None` offsets and empty
+//!   reference offsets, while the tokens of the input keep their offsets.
+//!
+//! Tokenizers based on a unigram model also score these inputs with a log-probability of 0.
+mod common;
+
+use common::{
+    bert_like_special_tokens, roberta_like_special_tokens, sentence_piece_model,
+    sentence_piece_vocab, special_tokens, string_map,
+};
+use rust_tokenizers::tokenizer::{
+    AlbertTokenizer, BaseTokenizer, BertTokenizer, CtrlTokenizer, DeBERTaTokenizer,
+    DeBERTaV2Tokenizer, Gpt2Tokenizer, MBart50Tokenizer, MarianTokenizer, OpenAiGptTokenizer,
+    PegasusTokenizer, ProphetNetTokenizer, RobertaTokenizer, SentencePieceTokenizer, T5Tokenizer,
+    Tokenizer, TruncationStrategy, UnigramTokenizer, XLMRobertaTokenizer, XLNetTokenizer,
+};
+use rust_tokenizers::vocab::{
+    AlbertVocab, BaseVocab, BertVocab, BpePairVocab, DeBERTaV2Vocab, DeBERTaVocab, Gpt2Vocab,
+    MBart50Vocab, MarianVocab, OpenAiGptVocab, PegasusVocab, ProphetNetVocab, RobertaVocab,
+    SentencePieceVocab, T5Vocab, Vocab, XLMRobertaVocab, XLNetVocab,
+};
+use std::collections::HashMap;
+
+const DEGENERATE_TEXTS: [&str; 5] = ["", " ", "   ", " \t\n\r ", "\u{3000}\u{a0}"];
+
+const WORD_PIECE_VOCAB: [&str; 9] = [
+    "[UNK]", "[PAD]", "[CLS]", "[SEP]", "[MASK]", "[X_SEP]", "hello", "world", "!",
+];
+
+const BPE_VOCAB: [&str; 10] = [
+    "<unk>", "<s>", "</s>", "<pad>", "<mask>", "[CLS]", "[SEP]", "[MASK]", "[PAD]", "[UNK]",
+];
+
+const SENTENCE_PIECE_VOCAB: [(&str, f32); 14] = [
+    ("<unk>", 0.0),
+    ("<pad>", 0.0),
+    ("<s>", 0.0),
+    ("</s>", 0.0),
+    ("<sep>", 0.0),
+    ("<cls>", 0.0),
+    ("<mask>", 0.0),
+    ("[CLS]", 0.0),
+    ("[SEP]", 0.0),
+    ("[MASK]", 0.0),
+    ("[PAD]", 0.0),
+    ("[UNK]", 0.0),
+    ("▁", -2.0),
+    ("▁hello", -3.0),
+];
+
+fn check_degenerate_inputs<T: Vocab, U: Tokenizer<T>>(tokenizer: &U) {
+    let special_tokens_only = tokenizer
+        .encode("", None, 128, &TruncationStrategy::LongestFirst, 0)
+        .token_ids;
+    let special_tokens_only_pair = tokenizer
+        .encode("", Some(""), 128, &TruncationStrategy::LongestFirst, 0)
+        .token_ids;
+
+    for text in DEGENERATE_TEXTS.iter() {
+        let tokens = tokenizer.tokenize_with_offsets(text);
+        assert!(tokens.tokens.is_empty(), "{:?}: {:?}", text, tokens.tokens);
+        assert!(tokens.offsets.is_empty());
+        assert!(tokens.reference_offsets.is_empty());
+        assert!(tokens.masks.is_empty());
+
+        for max_len in [0, 1, 128].iter() {
+            for truncation_strategy in [
+                TruncationStrategy::LongestFirst,
+                TruncationStrategy::OnlyFirst,
+            ]
+            .iter()
+            {
+                let encoded = tokenizer
+                    .try_encode(text, None, *max_len, truncation_strategy, 0)
+                    .unwrap();
+                assert_eq!(encoded.token_ids, special_tokens_only);
+                assert_eq!(encoded.num_truncated_tokens, 0);
+            }
+            let encoded = tokenizer
+                .try_encode(
+                    text,
+                    Some(text),
+                    *max_len,
+                    &TruncationStrategy::LongestFirst,
+                    0,
+                )
+                .unwrap();
+            assert_eq!(encoded.token_ids, special_tokens_only_pair);
+        }
+    }
+
+    for max_len in 0..=special_tokens_only.len() {
+        let encoded = tokenizer
+            .try_encode(
+                "hello world!",
+                None,
+                max_len,
+                &TruncationStrategy::LongestFirst,
+                0,
+            )
+            .unwrap();
+        assert_eq!(encoded.token_ids, special_tokens_only);
+        assert!(encoded.num_truncated_tokens > 0);
+        assert_eq!(
+            encoded.token_ids.len(),
+            encoded.token_offsets.len(),
+            "inconsistent offsets for max_len {}",
+            max_len
+        );
+    }
+    for max_len in 0..=special_tokens_only_pair.len() {
+        let encoded = tokenizer
+            .try_encode(
+                "hello world!",
+                Some("hello"),
+                max_len,
+                &TruncationStrategy::LongestFirst,
+                1,
+            )
+            .unwrap();
+        assert_eq!(encoded.token_ids, special_tokens_only_pair);
+    }
+
+    assert_eq!(tokenizer.decode(&[], false, false), "");
+    assert_eq!(tokenizer.decode(&[], true, true), "");
+    assert!(tokenizer.decode_to_vec(&[], false).is_empty());
+
+    check_special_token_offsets(tokenizer);
+}
+
+fn check_special_token_offsets<T: Vocab, U: Tokenizer<T>>(tokenizer: &U) {
+    for (text_1, text_2) in [("hello world!", None), ("hello", Some("world!"))].iter() {
+        let encoded = tokenizer
+            .try_encode(text_1, *text_2, 128, &TruncationStrategy::LongestFirst, 0)
+            .unwrap();
+        let mut input_offsets = tokenizer.tokenize_with_offsets(text_1).offsets;
+        if let Some(text_2) = text_2 {
+            input_offsets.extend(tokenizer.tokenize_with_offsets(text_2).offsets);
+        }
+
+        assert_eq!(encoded.token_offsets.len(), encoded.token_ids.len());
+        assert_eq!(encoded.reference_offsets.len(), encoded.token_ids.len());
+        for (offset, reference_offsets) in encoded
+            .token_offsets
+            .iter()
+            .zip(encoded.reference_offsets.iter())
+        {
+            assert_eq!(offset.is_none(), reference_offsets.is_empty());
+        }
+        assert!(input_offsets.iter().all(Option::is_some), "{:?}", text_1);
+        assert_eq!(
+            encoded
+                .token_offsets
+                .iter()
+                .filter(|offset| offset.is_some())
+                .collect::<Vec<_>>(),
+            input_offsets.iter().collect::<Vec<_>>(),
+            "{:?}",
+            text_1
+        );
+        for (offset, special_token) in encoded
+            .token_offsets
+            .iter()
+            .zip(encoded.special_tokens_mask.iter())
+        {
+            if offset.is_none() {
+                assert_eq!(*special_token, 1);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_base_tokenizer_degenerate_inputs() {
+    let vocab = BaseVocab::from_values_and_special_token_map(
+        string_map(WORD_PIECE_VOCAB),
+        special_tokens("[UNK]", [None; 6]),
+    )
+    .unwrap();
+    check_degenerate_inputs(&BaseTokenizer::from_existing_vocab(vocab, true, true));
+}
+
+#[test]
+fn test_bert_tokenizer_degenerate_inputs() {
+    let vocab = BertVocab::from_values_and_special_token_map(
+        string_map(WORD_PIECE_VOCAB),
+        special_tokens(
+            "[UNK]",
+            [
+                Some("[PAD]"),
+                None,
+                Some("[SEP]"),
+                Some("[CLS]"),
+                None,
+                Some("[MASK]"),
+            ],
+        ),
+    )
+    .unwrap();
+    check_degenerate_inputs(&BertTokenizer::from_existing_vocab(vocab, true, true));
+}
+
+#[test]
+fn test_prophetnet_tokenizer_degenerate_inputs() {
+    let vocab = ProphetNetVocab::from_values_and_special_token_map(
+        string_map(WORD_PIECE_VOCAB),
+        special_tokens(
+            "[UNK]",
+            [
+                Some("[PAD]"),
+                None,
+                Some("[SEP]"),
+                Some("[CLS]"),
+                None,
+                Some("[MASK]"),
+            ],
+        ),
+    )
+    .unwrap();
+    check_degenerate_inputs(&ProphetNetTokenizer::from_existing_vocab(vocab, true, true));
+}
+
+#[test]
+fn test_byte_level_bpe_tokenizers_degenerate_inputs() {
+    let special_token_map = special_tokens(
+        "<unk>",
+        [
+            Some("<pad>"),
+            Some("<s>"),
+            Some("</s>"),
+            Some("<s>"),
+            Some("</s>"),
+            Some("<mask>"),
+        ],
+    );
+    let gpt2_vocab = Gpt2Vocab::from_values_and_special_token_map(
+        string_map(BPE_VOCAB),
+        special_token_map.clone(),
+    )
+    .unwrap();
+    check_degenerate_inputs(&Gpt2Tokenizer::from_existing_vocab_and_merges(
+        gpt2_vocab,
+        BpePairVocab {
+            values: HashMap::new(),
+        },
+        false,
+        false,
+    ));
+
+    for add_prefix_space in [false, true].iter() {
+        let roberta_vocab = RobertaVocab::from_values_and_special_token_map(
+            string_map(BPE_VOCAB),
+            special_token_map.clone(),
+        )
+        .unwrap();
+        check_degenerate_inputs(&RobertaTokenizer::from_existing_vocab_and_merges(
+            roberta_vocab,
+            BpePairVocab {
+                values: HashMap::new(),
+            },
+            false,
+            *add_prefix_space,
+        ));
+    }
+
+    let deberta_vocab = DeBERTaVocab::from_values_and_special_token_map(
+        string_map(BPE_VOCAB),
+        special_tokens(
+            "[UNK]",
+            [
+                Some("[PAD]"),
+                Some("[CLS]"),
+                Some("[SEP]"),
+                Some("[CLS]"),
+                Some("[SEP]"),
+                Some("[MASK]"),
+            ],
+        ),
+    )
+    .unwrap();
+    check_degenerate_inputs(&DeBERTaTokenizer::from_existing_vocab_and_merges(
+        deberta_vocab,
+        BpePairVocab {
+            values: HashMap::new(),
+        },
+        false,
+    ));
+}
+
+#[test]
+fn test_bpe_tokenizers_degenerate_inputs() {
+    let openai_gpt_vocab = OpenAiGptVocab::from_values_and_special_token_map(
+        string_map(BPE_VOCAB),
+        special_tokens("<unk>", [None; 6]),
+    )
+    .unwrap();
+    check_degenerate_inputs(&OpenAiGptTokenizer::from_existing_vocab_and_merges(
+        openai_gpt_vocab,
+        BpePairVocab {
+            values: HashMap::new(),
+        },
+        true,
+    ));
+
+    let ctrl_vocab = OpenAiGptVocab::from_values_and_special_token_map(
+        string_map(BPE_VOCAB),
+        special_tokens("<unk>", [None; 6]),
+    )
+    .unwrap();
+    check_degenerate_inputs(&CtrlTokenizer::from_existing_vocab_and_merges(
+        ctrl_vocab,
+        BpePairVocab {
+            values: HashMap::new(),
+        },
+        false,
+    ));
+}
+
+#[test]
+fn test_sentence_piece_tokenizers_degenerate_inputs() {
+    let vocab: AlbertVocab = sentence_piece_vocab(
+        &SENTENCE_PIECE_VOCAB,
+        bert_like_special_tokens("<unk>", "<pad>"),
+    );
+    check_degenerate_inputs(&AlbertTokenizer::from_existing_vocab_and_model(
+        vocab,
+        sentence_piece_model(&SENTENCE_PIECE_VOCAB),
+        true,
+        true,
+    ));
+
+    let vocab: XLNetVocab = sentence_piece_vocab(
+        &SENTENCE_PIECE_VOCAB,
+        special_tokens(
+            "<unk>",
+            [
+                Some("<pad>"),
+                Some("<s>"),
+                Some("<sep>"),
+                Some("<cls>"),
+                Some("</s>"),
+                Some("<mask>"),
+            ],
+        ),
+    );
+    check_degenerate_inputs(&XLNetTokenizer::from_existing_vocab_and_model(
+        vocab,
+        sentence_piece_model(&SENTENCE_PIECE_VOCAB),
+        false,
+        false,
+    ));
+
+    let vocab: T5Vocab = sentence_piece_vocab(
+        &SENTENCE_PIECE_VOCAB,
+        special_tokens(
+            "<unk>",
+            [Some("<pad>"), None, None, None, Some("</s>"), None],
+        ),
+    );
+    check_degenerate_inputs(&T5Tokenizer::from_existing_vocab_and_model(
+        vocab,
+        sentence_piece_model(&SENTENCE_PIECE_VOCAB),
+        false,
+    ));
+
+    let vocab: SentencePieceVocab =
+        sentence_piece_vocab(&SENTENCE_PIECE_VOCAB, special_tokens("<unk>", [None; 6]));
+    check_degenerate_inputs(&SentencePieceTokenizer::from_existing_vocab_and_model(
+        vocab,
+        sentence_piece_model(&SENTENCE_PIECE_VOCAB),
+        false,
+    ));
+
+    let vocab: XLMRobertaVocab =
+        sentence_piece_vocab(&SENTENCE_PIECE_VOCAB, roberta_like_special_tokens());
+    check_degenerate_inputs(&XLMRobertaTokenizer::from_existing_vocab_and_model(
+        vocab,
+        sentence_piece_model(&SENTENCE_PIECE_VOCAB),
+        false,
+    ));
+
+    let vocab: MarianVocab =
+        sentence_piece_vocab(&SENTENCE_PIECE_VOCAB, roberta_like_special_tokens());
+    check_degenerate_inputs(&MarianTokenizer::from_existing_vocab_and_model(
+        vocab,
+        sentence_piece_model(&SENTENCE_PIECE_VOCAB),
+        false,
+    ));
+
+    let vocab: PegasusVocab =
+        sentence_piece_vocab(&SENTENCE_PIECE_VOCAB, roberta_like_special_tokens());
+    check_degenerate_inputs(&PegasusTokenizer::from_existing_vocab_and_model(
+        vocab,
+        sentence_piece_model(&SENTENCE_PIECE_VOCAB),
+        false,
+    ));
+
+    let vocab: MBart50Vocab =
+        sentence_piece_vocab(&SENTENCE_PIECE_VOCAB, roberta_like_special_tokens());
+    check_degenerate_inputs(&MBart50Tokenizer::from_existing_vocab_and_model(
+        vocab,
+        sentence_piece_model(&SENTENCE_PIECE_VOCAB),
+        false,
+    ));
+
+    let vocab: DeBERTaV2Vocab = sentence_piece_vocab(
+        &SENTENCE_PIECE_VOCAB,
+        bert_like_special_tokens("<unk>", "<pad>"),
+    );
+    for add_prefix_space in [false, true].iter() {
+        check_degenerate_inputs(&DeBERTaV2Tokenizer::from_existing_vocab_and_model(
+            vocab.clone(),
+            sentence_piece_model(&SENTENCE_PIECE_VOCAB),
+            false,
+            false,
+            *add_prefix_space,
+        ));
+    }
+}
+
+#[test]
+fn test_unigram_tokenizer_scores() {
+    let vocab: SentencePieceVocab =
+        sentence_piece_vocab(&SENTENCE_PIECE_VOCAB, special_tokens("<unk>", [None; 6]));
+    let tokenizer = SentencePieceTokenizer::from_existing_vocab_and_model(
+        vocab,
+        sentence_piece_model(&SENTENCE_PIECE_VOCAB),
+        false,
+    );
+
+    for text in DEGENERATE_TEXTS.iter() {
+        assert_eq!(tokenizer.score_text(text), 0.0, "{:?}", text);
+    }
+    assert_eq!(tokenizer.score_text("hello hello"), -6.0);
+    assert_eq!(
+        tokenizer.score_tokens(&tokenizer.tokenize("hello x")),
+        -18.0
+    );
+}
@@ -20,7 +20,7 @@ fn test_gpt2_tokenization() -> anyhow::Result<()> {
     let vocab = Gpt2Vocab::from_file(vocab_path.as_path())?;
     let merges = BpePairVocab::from_file(merges_path.as_path())?;
 
-    let gpt2_tokenizer = Gpt2Tokenizer::from_existing_vocab_and_merges(vocab, merges, false);
+    let gpt2_tokenizer = Gpt2Tokenizer::from_existing_vocab_and_merges(vocab, merges, false, false);
 
     let original_strings = [
         "…",
@@ -42,6 +42,7 @@ fn test_gpt2_tokenization() -> anyhow::Result<()> {
             token_offsets: vec![Some(Offset { begin: 0, end: 1 })],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -68,6 +69,7 @@ fn test_gpt2_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -94,6 +96,7 @@ fn test_gpt2_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -148,6 +151,7 @@ fn test_gpt2_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -209,6 +213,7 @@ fn test_gpt2_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -276,6 +281,7 @@ fn test_gpt2_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -344,6 +350,7 @@ fn test_gpt2_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
     ]
     .to_vec();
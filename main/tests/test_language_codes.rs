@@ -0,0 +1,158 @@
+use rust_tokenizers::error::TokenizerError;
+use rust_tokenizers::tokenizer::{MBart50Tokenizer, OffsetUnit, Tokenizer, TruncationStrategy};
+use rust_tokenizers::vocab::{MBart50Vocab, SentencePieceModel, SpecialTokenMap, TrieNode, Vocab};
+use rust_tokenizers::Mask;
+
+const SENTENCE_PIECE_VOCAB: [(&str, f32); 8] = [
+    ("<unk>", 0.0),
+    ("<pad>", 0.0),
+    ("<s>", 0.0),
+    ("</s>", 0.0),
+    ("<mask>", 0.0),
+    (">>fr<<", 0.0),
+    ("▁", -2.0),
+    ("▁hello", -3.0),
+];
+
+fn mbart50_tokenizer() -> MBart50Tokenizer {
+    let mut root = TrieNode::new(String::new());
+    for (index, (piece, score)) in SENTENCE_PIECE_VOCAB.iter().enumerate() {
+        let mut node = &mut root;
+        for character in piece.chars() {
+            let text = format!("{}{}", node.text, character);
+            node = node
+                .children
+                .entry(character)
+                .or_insert_with(|| TrieNode::new(text));
+        }
+        node.end = true;
+        node.score = *score;
+        node.index = index as i64;
+    }
+    let values = SENTENCE_PIECE_VOCAB
+        .iter()
+        .enumerate()
+        .map(|(index, (piece, _))| (piece.to_string(), index as i64))
+        .collect();
+    let special_token_map = SpecialTokenMap {
+        unk_token: "<unk>".to_string(),
+        pad_token: Some("<pad>".to_string()),
+        bos_token: Some("<s>".to_string()),
+        sep_token: Some("</s>".to_string()),
+        cls_token: Some("<s>".to_string()),
+        eos_token: Some("</s>".to_string()),
+        mask_token: Some("<mask>".to_string()),
+        additional_special_tokens: None,
+    };
+    let vocab = MBart50Vocab::from_values_and_special_token_map(values, special_token_map).unwrap();
+    MBart50Tokenizer::from_existing_vocab_and_model(vocab, SentencePieceModel::new(root), false)
+}
+
+#[test]
+fn test_set_language_codes() {
+    //        Given
+    let mut tokenizer = mbart50_tokenizer();
+
+    //        When & Then
+    assert_eq!(tokenizer.src_lang(), None);
+    tokenizer.set_src_lang(">>fr<<").unwrap();
+    tokenizer.set_tgt_lang(">>de<<").unwrap();
+    assert_eq!(tokenizer.src_lang(), Some(">>fr<<"));
+    assert_eq!(tokenizer.tgt_lang(), Some(">>de<<"));
+
+    match tokenizer.set_src_lang("fr") {
+        Err(TokenizerError::LanguageCodeNotFound { code, supported }) => {
+            assert_eq!(code, "fr");
+            assert_eq!(supported.len(), 52);
+            assert!(supported.contains(&">>fr<<".to_string()));
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+    assert!(tokenizer.set_tgt_lang(">>xx<<").is_err());
+    assert_eq!(tokenizer.src_lang(), Some(">>fr<<"));
+    assert_eq!(tokenizer.tgt_lang(), Some(">>de<<"));
+}
+
+#[test]
+fn test_source_language_code_prefix() {
+    //        Given
+    let mut tokenizer = mbart50_tokenizer();
+    tokenizer.set_src_lang(">>fr<<").unwrap();
+
+    //        When
+    let prefixed = tokenizer.tokenize_with_offsets("hello");
+    let already_prefixed = tokenizer.tokenize_with_offsets(">>fr<< hello");
+    let encoded = tokenizer
+        .try_encode("hello", None, 128, &TruncationStrategy::LongestFirst, 0)
+        .unwrap();
+
+    //        Then
+    assert_eq!(prefixed.tokens, vec![">>fr<<", "▁hello"]);
+    assert_eq!(prefixed.masks, vec![Mask::Special, Mask::None]);
+    assert_eq!(already_prefixed.tokens, vec![">>fr<<", "▁hello"]);
+    assert_eq!(encoded.token_ids, vec![5, 7, 3]);
+    assert_eq!(encoded.token_offsets[0], None);
+}
+
+#[test]
+fn test_language_prefixed_input_validation() {
+    //        Given
+    let tokenizer = mbart50_tokenizer();
+
+    //        When & Then
+    assert!(tokenizer.validate_language_prefix(">>fr<< hello").is_ok());
+    assert!(tokenizer.validate_language_prefix("hello").is_ok());
+    assert!(tokenizer.validate_language_prefix(">>xx<< hello").is_err());
+    assert!(matches!(
+        tokenizer.try_encode(
+            ">>xx<< hello",
+            None,
+            128,
+            &TruncationStrategy::LongestFirst,
+            0
+        ),
+        Err(TokenizerError::LanguageCodeNotFound { .. })
+    ));
+    assert!(matches!(
+        tokenizer.try_encode(
+            "hello",
+            Some(">>xx<< hello"),
+            128,
+            &TruncationStrategy::LongestFirst,
+            0
+        ),
+        Err(TokenizerError::LanguageCodeNotFound { .. })
+    ));
+    assert!(matches!(
+        tokenizer.encode_plain(
+            "hello",
+            Some(">>xx<< hello"),
+            128,
+            &TruncationStrategy::LongestFirst,
+            0
+        ),
+        Err(TokenizerError::LanguageCodeNotFound { .. })
+    ));
+    for offset_unit in [OffsetUnit::Char, OffsetUnit::Utf16] {
+        assert!(matches!(
+            tokenizer.encode_with_offset_unit(
+                ">>xx<< hello",
+                None,
+                128,
+                &TruncationStrategy::LongestFirst,
+                0,
+                offset_unit
+            ),
+            Err(TokenizerError::LanguageCodeNotFound { .. })
+        ));
+    }
+    assert!(tokenizer
+        .try_encode(
+            ">>fr<< hello",
+            None,
+            128,
+            &TruncationStrategy::LongestFirst,
+            0
+        )
+        .is_ok());
+}
@@ -0,0 +1,680 @@
+//! Property-based tests for the offsets returned by the tokenizers.
+//!
+//! For every tokenizer family (using small in-memory vocabularies), the following invariants are
+//! checked on arbitrary inputs:
+//! - offsets and reference offsets are within the input bounds,
+//! - offsets are monotonically non-decreasing and do not overlap, except for tokens generated
+//!   from a single character (byte-level pieces or characters expanded by the normalization),
+//! - slicing the input with the offsets reproduces the token surface, modulo normalization
+//!   (case, accents, compatibility decomposition, whitespace and characters removed by the
+//!   tokenizer clean-up) and the tokenizer-specific sub-word markers.
+//!
+//! Unknown tokens and tokens made only of whitespace markers (such as the `▁` prefix added by
+//! SentencePiece models, which points to the first character of the word that follows) are not
+//! checked for overlaps and surface.
+//!
+//! The Reformer tokenizer merges consecutive unknown pieces into the first one without extending
+//! its offset: a character expanded by the normalization into an unknown and a known piece (for
+//! example the `ﬅ` ligature following an unknown character) would leave the known piece pointing
+//! to the whole character. Its inputs are therefore restricted to characters that are not
+//! expanded by the compatibility decomposition.
+//!
+//! All the tokenizers of the crate are covered. The tokenizers that can only be loaded from files
+//! (SentencePiece BPE, NLLB and Reformer) are built from an in-memory SentencePiece protobuf.
+mod common;
+
+use common::{
+    bert_like_special_tokens, merges, roberta_like_special_tokens, sentence_piece_bpe_model,
+    sentence_piece_model, sentence_piece_protobuf, sentence_piece_values, sentence_piece_vocab,
+    special_tokens, string_map,
+};
+use proptest::prelude::*;
+use rust_tokenizers::tokenizer::{
+    AlbertTokenizer, BaseTokenizer, BertTokenizer, CtrlTokenizer, DeBERTaTokenizer,
+    DeBERTaV2Tokenizer, FNetTokenizer, Gpt2Tokenizer, M2M100Tokenizer, MBart50Tokenizer,
+    MarianTokenizer, NLLBTokenizer, OpenAiGptTokenizer, PegasusTokenizer, ProphetNetTokenizer,
+    ReformerTokenizer, RobertaTokenizer, SentencePieceBpeTokenizer, SentencePieceTokenizer,
+    T5Tokenizer, Tokenizer, XLMRobertaTokenizer, XLNetTokenizer,
+};
+use rust_tokenizers::vocab::{
+    AlbertVocab, BaseVocab, BertVocab, DeBERTaV2Vocab, DeBERTaVocab, FNetVocab, Gpt2Vocab,
+    M2M100Vocab, MBart50Vocab, MarianVocab, OpenAiGptVocab, PegasusVocab, ProphetNetVocab,
+    RobertaVocab, SentencePieceVocab, T5Vocab, Vocab, XLMRobertaVocab, XLNetVocab,
+};
+use rust_tokenizers::Offset;
+use std::collections::HashMap;
+use std::fs;
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+/// Sub-word markers used by the tokenizer, removed from the tokens before comparison
+#[derive(Clone, Copy)]
+enum Surface {
+    WordPiece,
+    Bpe,
+    ContinuationBpe,
+    ByteLevelBpe,
+    SentencePiece,
+}
+
+const WORD_PIECE_VOCAB: [&str; 18] = [
+    "[UNK]", "[PAD]", "[CLS]", "[SEP]", "[MASK]", "hello", "world", "!", "中", "华", "人", "una",
+    "##ffa", "##ble", "a", "##a", "e", "##e",
+];
+
+const BERT_SPECIAL_TOKENS: [Option<&str>; 6] = [
+    Some("[PAD]"),
+    None,
+    Some("[SEP]"),
+    Some("[CLS]"),
+    None,
+    Some("[MASK]"),
+];
+
+const BYTE_LEVEL_BPE_VOCAB: [&str; 13] = [
+    "<unk>", "<s>", "</s>", "<pad>", "<mask>", "t", "h", "n", "the", "Ġthe", "Ġ", "Ġear", "th",
+];
+
+const BYTE_LEVEL_BPE_MERGES: [(&str, &str); 10] = [
+    ("Ġ", "t"),
+    ("Ġ", "n"),
+    ("e", "e"),
+    ("Ġt", "he"),
+    ("h", "e"),
+    ("t", "h"),
+    ("t", "he"),
+    ("Ġ", "e"),
+    ("Ġe", "a"),
+    ("Ġea", "r"),
+];
+
+const SENTENCE_PIECE_VOCAB: [(&str, f32); 24] = [
+    ("<unk>", 0.0),
+    ("<pad>", 0.0),
+    ("<s>", 0.0),
+    ("</s>", 0.0),
+    ("<sep>", 0.0),
+    ("<cls>", 0.0),
+    ("<mask>", 0.0),
+    ("[CLS]", 0.0),
+    ("[SEP]", 0.0),
+    ("[MASK]", 0.0),
+    ("▁", -2.0),
+    ("▁the", -3.0),
+    ("▁t", -4.0),
+    ("he", -4.5),
+    ("h", -5.0),
+    ("e", -5.0),
+    ("t", -5.0),
+    ("a", -5.0),
+    ("▁ear", -6.0),
+    ("th", -6.5),
+    ("é", -7.0),
+    ("中", -7.0),
+    ("!", -7.0),
+    ("▁!", -7.5),
+];
+
+const NLLB_LANGUAGE_CODES: [&str; 2] = ["eng_Latn", "fra_Latn"];
+
+/// NLLB tokenizer built from vocabulary, model and special tokens files (the NLLB tokenizer can not
+/// be built from in-memory values, and its default special tokens include all language codes)
+fn nllb_tokenizer() -> NLLBTokenizer {
+    let mut values = sentence_piece_values(&SENTENCE_PIECE_VOCAB);
+    for language_code in NLLB_LANGUAGE_CODES {
+        values.insert(language_code.to_string(), values.len() as i64);
+    }
+    let vocab_file = tempfile::NamedTempFile::new().unwrap();
+    fs::write(
+        vocab_file.path(),
+        serde_json::json!({ "model": { "vocab": values } }).to_string(),
+    )
+    .unwrap();
+    let model_file = tempfile::NamedTempFile::new().unwrap();
+    fs::write(
+        model_file.path(),
+        sentence_piece_protobuf(&SENTENCE_PIECE_VOCAB),
+    )
+    .unwrap();
+    let special_tokens_file = tempfile::NamedTempFile::new().unwrap();
+    fs::write(
+        special_tokens_file.path(),
+        serde_json::json!({
+            "unk_token": "<unk>",
+            "pad_token": "<pad>",
+            "bos_token": "<s>",
+            "sep_token": "</s>",
+            "cls_token": null,
+            "eos_token": "</s>",
+            "mask_token": { "content": "<mask>", "lstrip": true },
+            "additional_special_tokens": NLLB_LANGUAGE_CODES,
+        })
+        .to_string(),
+    )
+    .unwrap();
+    NLLBTokenizer::from_files_with_special_token_map(
+        vocab_file.path(),
+        model_file.path(),
+        special_tokens_file.path(),
+    )
+    .unwrap()
+}
+
+/// Inverse of the GPT2 byte to unicode mapping, used to recover the bytes of byte-level tokens
+fn unicode_to_bytes() -> HashMap<char, u8> {
+    let mut printable_bytes: Vec<u8> = (b'!'..=b'~').collect();
+    printable_bytes.extend(0xA1..=0xAC);
+    printable_bytes.extend(0xAE..=0xFF);
+    let mut mapping: HashMap<char, u8> = printable_bytes
+        .iter()
+        .map(|&byte| (byte as char, byte))
+        .collect();
+    let mut next_char = 256u32;
+    for byte in 0..=255u8 {
+        if !printable_bytes.contains(&byte) {
+            mapping.insert(char::from_u32(next_char).unwrap(), byte);
+            next_char += 1;
+        }
+    }
+    mapping
+}
+
+/// Normalized form used to compare token surfaces with the input slices
+fn canonical(text: &str, lower_case: bool) -> String {
+    let normalized = text
+        .nfkd()
+        .filter(|character| !is_combining_mark(*character))
+        .filter(|character| !character.is_whitespace() && *character != '▁')
+        // SentencePiece tokenizers replace ``/'' by a double quote, pointing to the first quote
+        .map(|character| match character {
+            '`' | '\'' => '"',
+            _ => character,
+        })
+        .collect::<String>();
+    if lower_case {
+        normalized.to_lowercase().nfkd().collect()
+    } else {
+        normalized
+    }
+}
+
+fn surface(tokens: &[String], surface: Surface) -> String {
+    match surface {
+        Surface::WordPiece => tokens
+            .iter()
+            .map(|token| token.strip_prefix("##").unwrap_or(token))
+            .collect(),
+        Surface::Bpe => tokens
+            .iter()
+            .map(|token| token.strip_suffix("</w>").unwrap_or(token))
+            .collect(),
+        Surface::ContinuationBpe => tokens
+            .iter()
+            .map(|token| token.strip_suffix("@@").unwrap_or(token))
+            .collect(),
+        Surface::ByteLevelBpe => {
+            let unicode_to_bytes = unicode_to_bytes();
+            let bytes = tokens
+                .iter()
+                .flat_map(|token| token.chars())
+                .map(|character| unicode_to_bytes[&character])
+                .collect::<Vec<u8>>();
+            String::from_utf8_lossy(&bytes).into_owned()
+        }
+        Surface::SentencePiece => tokens.concat(),
+    }
+}
+
+fn slice(text: &str, offset: &Offset) -> String {
+    text.chars()
+        .skip(offset.begin as usize)
+        .take((offset.end - offset.begin) as usize)
+        .collect()
+}
+
+fn check_tokenizer<T: Vocab, U: Tokenizer<T>>(
+    tokenizer: &U,
+    text: &str,
+    surface_type: Surface,
+    lower_case: bool,
+) -> Result<(), TestCaseError> {
+    let tokens = tokenizer.tokenize_with_offsets(text);
+    let char_count = text.chars().count() as u32;
+    prop_assert_eq!(tokens.tokens.len(), tokens.offsets.len());
+    prop_assert_eq!(tokens.tokens.len(), tokens.reference_offsets.len());
+    prop_assert_eq!(tokens.tokens.len(), tokens.masks.len());
+
+    for reference_offsets in &tokens.reference_offsets {
+        for &position in reference_offsets {
+            prop_assert!(position < char_count.max(1));
+        }
+    }
+
+    // Characters removed by the tokenizer clean-up (e.g. control or format characters) are
+    // ignored when comparing the input slices with the token surfaces.
+    let is_removed = |character: char| {
+        let tokens = tokenizer.tokenize(&character.to_string());
+        canonical(&surface(&tokens, surface_type), lower_case).is_empty()
+    };
+
+    let unknown_id = tokenizer
+        .vocab()
+        .token_to_id(tokenizer.vocab().get_unknown_value());
+    // Tokens with overlapping offsets are grouped and compared with the input as a whole: this
+    // happens when several tokens are generated from a single character (byte-level pieces or
+    // characters expanded by the normalization, for example ligatures).
+    let mut groups: Vec<(Offset, Vec<String>)> = vec![];
+    for (token, offset) in tokens.tokens.iter().zip(tokens.offsets.iter()) {
+        if let Some(offset) = offset {
+            prop_assert!(offset.begin <= offset.end, "invalid offset {:?}", offset);
+            prop_assert!(
+                offset.end <= char_count,
+                "offset {:?} out of bounds",
+                offset
+            );
+            let token_surface = surface(std::slice::from_ref(token), surface_type);
+            if canonical(&token_surface, lower_case).is_empty() {
+                continue;
+            }
+            match groups.last_mut() {
+                Some((group_offset, group_tokens))
+                    if offset.begin < group_offset.end || offset == group_offset =>
+                {
+                    prop_assert!(
+                        offset.begin >= group_offset.begin,
+                        "offset {:?} before previous offset {:?}",
+                        offset,
+                        group_offset
+                    );
+                    group_offset.end = group_offset.end.max(offset.end);
+                    group_tokens.push(token.clone());
+                }
+                _ => groups.push((*offset, vec![token.clone()])),
+            }
+        }
+    }
+
+    for (offset, group_tokens) in groups {
+        if tokenizer
+            .convert_tokens_to_ids(&group_tokens)
+            .contains(&unknown_id)
+        {
+            continue;
+        }
+        let text_slice = slice(text, &offset)
+            .chars()
+            .filter(|character| !is_removed(*character))
+            .collect::<String>();
+        prop_assert_eq!(
+            canonical(&surface(&group_tokens, surface_type), lower_case),
+            canonical(&text_slice, lower_case),
+            "tokens {:?} do not match offset {:?}",
+            &group_tokens,
+            offset
+        );
+    }
+    Ok(())
+}
+
+/// Texts mixing characters from the toy vocabularies with whitespace, punctuation, accents,
+/// combining marks, CJK characters, emojis and control characters
+fn text_strategy() -> impl Strategy<Value = String> {
+    prop_oneof![
+        "[the arthunafbléÉ!?.,中华人 \t\n\u{301}\u{200d}\u{a0}\u{3000}✿😀\u{0}\u{fffd}]{0,32}",
+        any::<String>(),
+    ]
+}
+
+/// Texts from `text_strategy` without the characters expanded by the compatibility decomposition
+fn non_expanding_text_strategy() -> impl Strategy<Value = String> {
+    text_strategy().prop_map(|text| {
+        text.chars()
+            .filter(|character| character.to_string().nfkc().count() <= 1)
+            .collect()
+    })
+}
+
+proptest! {
+    #[test]
+    fn base_tokenizer_offsets(text in text_strategy(), lower_case: bool, strip_accents: bool) {
+        let vocab = BaseVocab::from_values_and_special_token_map(
+            string_map(WORD_PIECE_VOCAB),
+            special_tokens("[UNK]", BERT_SPECIAL_TOKENS),
+        )
+        .unwrap();
+        let tokenizer = BaseTokenizer::from_existing_vocab(vocab, lower_case, strip_accents);
+        check_tokenizer(&tokenizer, &text, Surface::WordPiece, lower_case)?;
+    }
+
+    #[test]
+    fn bert_tokenizer_offsets(text in text_strategy(), lower_case: bool, strip_accents: bool) {
+        let vocab = BertVocab::from_values_and_special_token_map(
+            string_map(WORD_PIECE_VOCAB),
+            special_tokens("[UNK]", BERT_SPECIAL_TOKENS),
+        )
+        .unwrap();
+        let tokenizer = BertTokenizer::from_existing_vocab(vocab, lower_case, strip_accents);
+        check_tokenizer(&tokenizer, &text, Surface::WordPiece, lower_case)?;
+    }
+
+    #[test]
+    fn gpt2_tokenizer_offsets(
+        text in text_strategy(),
+        lower_case: bool,
+        add_prefix_space: bool
+    ) {
+        let vocab = Gpt2Vocab::from_values_and_special_token_map(
+            string_map(BYTE_LEVEL_BPE_VOCAB),
+            special_tokens("<unk>", [None, Some("<s>"), None, None, Some("</s>"), None]),
+        )
+        .unwrap();
+        let tokenizer = Gpt2Tokenizer::from_existing_vocab_and_merges(
+            vocab,
+            merges(&BYTE_LEVEL_BPE_MERGES),
+            lower_case,
+            add_prefix_space,
+        );
+        check_tokenizer(&tokenizer, &text, Surface::ByteLevelBpe, lower_case)?;
+    }
+
+    #[test]
+    fn roberta_tokenizer_offsets(
+        text in text_strategy(),
+        lower_case: bool,
+        add_prefix_space: bool
+    ) {
+        let vocab = RobertaVocab::from_values_and_special_token_map(
+            string_map(BYTE_LEVEL_BPE_VOCAB),
+            special_tokens(
+                "<unk>",
+                [
+                    Some("<pad>"),
+                    Some("<s>"),
+                    Some("</s>"),
+                    Some("<s>"),
+                    Some("</s>"),
+                    Some("<mask>"),
+                ],
+            ),
+        )
+        .unwrap();
+        let tokenizer = RobertaTokenizer::from_existing_vocab_and_merges(
+            vocab,
+            merges(&BYTE_LEVEL_BPE_MERGES),
+            lower_case,
+            add_prefix_space,
+        );
+        check_tokenizer(&tokenizer, &text, Surface::ByteLevelBpe, lower_case)?;
+    }
+
+    #[test]
+    fn openai_gpt_tokenizer_offsets(text in text_strategy(), lower_case: bool) {
+        let vocab = OpenAiGptVocab::from_values_and_special_token_map(
+            string_map([
+                "<unk>", "t", "h", "a</w>", "n", "the", "o</w>", "the</w>", "rth</w>", "ea",
+            ]),
+            special_tokens("<unk>", [None; 6]),
+        )
+        .unwrap();
+        let tokenizer = OpenAiGptTokenizer::from_existing_vocab_and_merges(
+            vocab,
+            merges(&[
+                ("r", "th</w>"),
+                ("t", "he</w>"),
+                ("h", "e"),
+                ("t", "h</w>"),
+                ("t", "h"),
+                ("th", "e</w>"),
+                ("e", "a"),
+            ]),
+            lower_case,
+        );
+        check_tokenizer(&tokenizer, &text, Surface::Bpe, lower_case)?;
+    }
+
+    #[test]
+    fn albert_tokenizer_offsets(
+        text in text_strategy(),
+        lower_case: bool,
+        strip_accents: bool,
+        nfkc_normalization: bool
+    ) {
+        let vocab: AlbertVocab = sentence_piece_vocab(&SENTENCE_PIECE_VOCAB, special_tokens(
+            "<unk>",
+            [
+                Some("<pad>"),
+                Some("[CLS]"),
+                Some("[SEP]"),
+                Some("[CLS]"),
+                Some("[SEP]"),
+                Some("[MASK]"),
+            ],
+        ));
+        let mut tokenizer = AlbertTokenizer::from_existing_vocab_and_model(
+            vocab,
+            sentence_piece_model(&SENTENCE_PIECE_VOCAB),
+            lower_case,
+            strip_accents,
+        );
+        tokenizer.set_nfkc_normalization(nfkc_normalization);
+        check_tokenizer(&tokenizer, &text, Surface::SentencePiece, lower_case)?;
+    }
+
+    #[test]
+    fn xlnet_tokenizer_offsets(
+        text in text_strategy(),
+        lower_case: bool,
+        strip_accents: bool,
+        nfkc_normalization: bool
+    ) {
+        let vocab: XLNetVocab = sentence_piece_vocab(&SENTENCE_PIECE_VOCAB, special_tokens(
+            "<unk>",
+            [
+                Some("<pad>"),
+                Some("<s>"),
+                Some("<sep>"),
+                Some("<cls>"),
+                Some("</s>"),
+                Some("<mask>"),
+            ],
+        ));
+        let mut tokenizer = XLNetTokenizer::from_existing_vocab_and_model(
+            vocab,
+            sentence_piece_model(&SENTENCE_PIECE_VOCAB),
+            lower_case,
+            strip_accents,
+        );
+        tokenizer.set_nfkc_normalization(nfkc_normalization);
+        check_tokenizer(&tokenizer, &text, Surface::SentencePiece, lower_case)?;
+    }
+
+    #[test]
+    fn t5_tokenizer_offsets(text in text_strategy(), lower_case: bool) {
+        let vocab: T5Vocab = sentence_piece_vocab(&SENTENCE_PIECE_VOCAB, special_tokens(
+            "<unk>",
+            [Some("<pad>"), None, None, None, Some("</s>"), None],
+        ));
+        let tokenizer = T5Tokenizer::from_existing_vocab_and_model(
+            vocab,
+            sentence_piece_model(&SENTENCE_PIECE_VOCAB),
+            lower_case,
+        );
+        check_tokenizer(&tokenizer, &text, Surface::SentencePiece, lower_case)?;
+    }
+
+    #[test]
+    fn sentence_piece_tokenizer_offsets(text in text_strategy(), lower_case: bool) {
+        let vocab: SentencePieceVocab = sentence_piece_vocab(&SENTENCE_PIECE_VOCAB, special_tokens("<unk>", [None; 6]));
+        let tokenizer = SentencePieceTokenizer::from_existing_vocab_and_model(
+            vocab,
+            sentence_piece_model(&SENTENCE_PIECE_VOCAB),
+            lower_case,
+        );
+        check_tokenizer(&tokenizer, &text, Surface::SentencePiece, lower_case)?;
+    }
+
+    #[test]
+    fn prophetnet_tokenizer_offsets(
+        text in text_strategy(),
+        lower_case: bool,
+        strip_accents: bool
+    ) {
+        let vocab = ProphetNetVocab::from_values_and_special_token_map(
+            string_map(WORD_PIECE_VOCAB),
+            special_tokens("[UNK]", BERT_SPECIAL_TOKENS),
+        )
+        .unwrap();
+        let tokenizer = ProphetNetTokenizer::from_existing_vocab(vocab, lower_case, strip_accents);
+        check_tokenizer(&tokenizer, &text, Surface::WordPiece, lower_case)?;
+    }
+
+    #[test]
+    fn deberta_tokenizer_offsets(text in text_strategy(), lower_case: bool) {
+        let vocab = DeBERTaVocab::from_values_and_special_token_map(
+            string_map(BYTE_LEVEL_BPE_VOCAB),
+            special_tokens("<unk>", [None, Some("<s>"), Some("</s>"), Some("<s>"), None, None]),
+        )
+        .unwrap();
+        let tokenizer = DeBERTaTokenizer::from_existing_vocab_and_merges(
+            vocab,
+            merges(&BYTE_LEVEL_BPE_MERGES),
+            lower_case,
+        );
+        check_tokenizer(&tokenizer, &text, Surface::ByteLevelBpe, lower_case)?;
+    }
+
+    #[test]
+    fn ctrl_tokenizer_offsets(text in text_strategy(), lower_case: bool) {
+        let vocab = OpenAiGptVocab::from_values_and_special_token_map(
+            string_map(["<unk>", "t@@", "h@@", "a", "n", "th@@", "the", "e@@", "ea@@", "rth"]),
+            special_tokens("<unk>", [None; 6]),
+        )
+        .unwrap();
+        let tokenizer = CtrlTokenizer::from_existing_vocab_and_merges(
+            vocab,
+            merges(&[
+                ("r", "th</w>"),
+                ("t", "he</w>"),
+                ("h", "e"),
+                ("t", "h</w>"),
+                ("t", "h"),
+                ("th", "e</w>"),
+                ("e", "a"),
+            ]),
+            lower_case,
+        );
+        check_tokenizer(&tokenizer, &text, Surface::ContinuationBpe, lower_case)?;
+    }
+
+    #[test]
+    fn deberta_v2_tokenizer_offsets(
+        text in text_strategy(),
+        lower_case: bool,
+        strip_accents: bool,
+        add_prefix_space: bool
+    ) {
+        let vocab: DeBERTaV2Vocab = sentence_piece_vocab(&SENTENCE_PIECE_VOCAB, bert_like_special_tokens("<unk>", "<pad>"));
+        let tokenizer = DeBERTaV2Tokenizer::from_existing_vocab_and_model(
+            vocab,
+            sentence_piece_model(&SENTENCE_PIECE_VOCAB),
+            lower_case,
+            strip_accents,
+            add_prefix_space,
+        );
+        check_tokenizer(&tokenizer, &text, Surface::SentencePiece, lower_case)?;
+    }
+
+    #[test]
+    fn xlm_roberta_tokenizer_offsets(text in text_strategy(), lower_case: bool) {
+        let vocab: XLMRobertaVocab = sentence_piece_vocab(&SENTENCE_PIECE_VOCAB, roberta_like_special_tokens());
+        let tokenizer = XLMRobertaTokenizer::from_existing_vocab_and_model(
+            vocab,
+            sentence_piece_model(&SENTENCE_PIECE_VOCAB),
+            lower_case,
+        );
+        check_tokenizer(&tokenizer, &text, Surface::SentencePiece, lower_case)?;
+    }
+
+    #[test]
+    fn marian_tokenizer_offsets(text in text_strategy(), lower_case: bool) {
+        let vocab: MarianVocab = sentence_piece_vocab(&SENTENCE_PIECE_VOCAB, roberta_like_special_tokens());
+        let tokenizer = MarianTokenizer::from_existing_vocab_and_model(
+            vocab,
+            sentence_piece_model(&SENTENCE_PIECE_VOCAB),
+            lower_case,
+        );
+        check_tokenizer(&tokenizer, &text, Surface::SentencePiece, lower_case)?;
+    }
+
+    #[test]
+    fn pegasus_tokenizer_offsets(text in text_strategy(), lower_case: bool) {
+        let vocab: PegasusVocab = sentence_piece_vocab(&SENTENCE_PIECE_VOCAB, roberta_like_special_tokens());
+        let tokenizer = PegasusTokenizer::from_existing_vocab_and_model(
+            vocab,
+            sentence_piece_model(&SENTENCE_PIECE_VOCAB),
+            lower_case,
+        );
+        check_tokenizer(&tokenizer, &text, Surface::SentencePiece, lower_case)?;
+    }
+
+    #[test]
+    fn mbart50_tokenizer_offsets(text in text_strategy(), lower_case: bool) {
+        let vocab: MBart50Vocab = sentence_piece_vocab(&SENTENCE_PIECE_VOCAB, roberta_like_special_tokens());
+        let tokenizer = MBart50Tokenizer::from_existing_vocab_and_model(
+            vocab,
+            sentence_piece_model(&SENTENCE_PIECE_VOCAB),
+            lower_case,
+        );
+        check_tokenizer(&tokenizer, &text, Surface::SentencePiece, lower_case)?;
+    }
+
+    #[test]
+    fn sentence_piece_bpe_tokenizer_offsets(text in text_strategy(), lower_case: bool) {
+        let tokenizer =
+            SentencePieceBpeTokenizer::from_protobuf_bytes(&sentence_piece_protobuf(&SENTENCE_PIECE_VOCAB), lower_case)
+                .unwrap();
+        check_tokenizer(&tokenizer, &text, Surface::SentencePiece, lower_case)?;
+    }
+
+    #[test]
+    fn fnet_tokenizer_offsets(
+        text in text_strategy(),
+        lower_case: bool,
+        strip_accents: bool,
+        nfkc_normalization: bool
+    ) {
+        let vocab: FNetVocab = sentence_piece_vocab(&SENTENCE_PIECE_VOCAB, bert_like_special_tokens("<unk>", "<pad>"));
+        let mut tokenizer = FNetTokenizer::from_existing_vocab_and_model(
+            vocab,
+            sentence_piece_bpe_model(&SENTENCE_PIECE_VOCAB),
+            lower_case,
+            strip_accents,
+        );
+        tokenizer.set_nfkc_normalization(nfkc_normalization);
+        check_tokenizer(&tokenizer, &text, Surface::SentencePiece, lower_case)?;
+    }
+
+    #[test]
+    fn m2m100_tokenizer_offsets(text in text_strategy(), lower_case: bool) {
+        let vocab = M2M100Vocab::from_values_and_special_token_map(
+            sentence_piece_values(&SENTENCE_PIECE_VOCAB),
+            special_tokens("<unk>", [Some("<pad>"), Some("<s>"), Some("</s>"), None, Some("</s>"), None]),
+        )
+        .unwrap();
+        let tokenizer =
+            M2M100Tokenizer::from_existing_vocab_and_model(vocab, sentence_piece_bpe_model(&SENTENCE_PIECE_VOCAB), lower_case);
+        check_tokenizer(&tokenizer, &text, Surface::SentencePiece, lower_case)?;
+    }
+
+    #[test]
+    fn nllb_tokenizer_offsets(text in text_strategy()) {
+        check_tokenizer(&nllb_tokenizer(), &text, Surface::SentencePiece, false)?;
+    }
+
+    #[test]
+    fn reformer_tokenizer_offsets(text in non_expanding_text_strategy(), lower_case: bool) {
+        let tokenizer =
+            ReformerTokenizer::from_protobuf_bytes(&sentence_piece_protobuf(&SENTENCE_PIECE_VOCAB), lower_case).unwrap();
+        check_tokenizer(&tokenizer, &text, Surface::SentencePiece, lower_case)?;
+    }
+}
@@ -38,6 +38,7 @@ fn test_openai_gpt_tokenization() -> anyhow::Result<()> {
             token_offsets: vec![Some(Offset { begin: 0, end: 1 })],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![616, 544, 246, 12273, 5958, 485, 580, 571, 2987, 4780],
@@ -59,6 +60,7 @@ fn test_openai_gpt_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![3039, 718, 616, 812, 727, 571, 2987, 4780, 0, 257],
@@ -80,6 +82,7 @@ fn test_openai_gpt_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -109,6 +112,7 @@ fn test_openai_gpt_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -140,6 +144,7 @@ fn test_openai_gpt_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -169,6 +174,7 @@ fn test_openai_gpt_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -198,6 +204,7 @@ fn test_openai_gpt_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
     ]
     .to_vec();
@@ -0,0 +1,246 @@
+//! Parity validation against the reference Python tokenizers.
+//!
+//! Each file in `tests/fixtures/parity` describes a tokenizer (family, resources and options) and
+//! a list of input texts with the token ids and offsets expected from the `transformers`
+//! tokenizers. The expected values are generated from the reference implementation (and never from
+//! the output of this crate), with the versions of `transformers` and `tokenizers` pinned in
+//! `tests/fixtures/parity/requirements.txt`. From the crate root:
+//! ```text
+//! python -m pip install -r tests/fixtures/parity/requirements.txt
+//! python tests/fixtures/parity/generate_fixtures.py tests/fixtures/parity/*.json
+//! ```
+//! The versions used are recorded in the `reference` field of each fixture, and fixtures without
+//! generated expectations fail the test. All mismatches of a fixture are reported at once to ease
+//! the investigation of a drift.
+//!
+//! The committed fixtures only contain the inputs: the test is ignored until their expectations
+//! are generated, and can be run explicitly with:
+//! ```text
+//! cargo test --test test_parity -- --ignored
+//! ```
+mod test_utils;
+use rust_tokenizers::tokenizer::{
+    AlbertTokenizer, BertTokenizer, DeBERTaTokenizer, DeBERTaV2Tokenizer, Gpt2Tokenizer,
+    PrefixSpaceTokenizer, RobertaTokenizer, Tokenizer, TruncationStrategy, XLMRobertaTokenizer,
+    XLNetTokenizer,
+};
+use rust_tokenizers::vocab::Vocab;
+use rust_tokenizers::{Offset, TokenizedInput};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use test_utils::download_file_to_cache;
+
+const MAX_LEN: usize = 512;
+
+#[derive(Debug, Deserialize)]
+struct ParityFixture {
+    family: String,
+    model: String,
+    files: HashMap<String, String>,
+    #[serde(default)]
+    options: ParityOptions,
+    reference: Option<ParityReference>,
+    cases: Vec<ParityCase>,
+}
+
+/// Versions of the Python libraries used to generate the expected values of a fixture
+#[derive(Debug, Deserialize)]
+struct ParityReference {
+    transformers: String,
+    tokenizers: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct ParityOptions {
+    lower_case: bool,
+    strip_accents: bool,
+    add_prefix_space: bool,
+    split_by_punct: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct ParityCase {
+    text: String,
+    text_pair: Option<String>,
+    #[serde(default)]
+    token_ids: Vec<i64>,
+    offsets: Option<Vec<Option<[u32; 2]>>>,
+}
+
+type Encoder = Box<dyn Fn(&str, Option<&str>) -> TokenizedInput>;
+
+impl ParityFixture {
+    fn from_file(path: &Path) -> anyhow::Result<ParityFixture> {
+        let contents = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&contents)?)
+    }
+
+    fn resource(&self, name: &str) -> anyhow::Result<PathBuf> {
+        let url = self
+            .files
+            .get(name)
+            .ok_or_else(|| anyhow::anyhow!("missing `{}` file for model {}", name, self.model))?;
+        Ok(download_file_to_cache(url)?)
+    }
+
+    fn encoder(&self) -> anyhow::Result<Encoder> {
+        let options = &self.options;
+        Ok(match self.family.as_str() {
+            "bert" => boxed_encoder(BertTokenizer::from_file(
+                self.resource("vocab")?,
+                options.lower_case,
+                options.strip_accents,
+            )?),
+            "gpt2" => boxed_encoder(Gpt2Tokenizer::from_file(
+                self.resource("vocab")?,
+                self.resource("merges")?,
+                options.lower_case,
+                options.add_prefix_space,
+            )?),
+            "roberta" => boxed_encoder(RobertaTokenizer::from_file(
+                self.resource("vocab")?,
+                self.resource("merges")?,
+                options.lower_case,
+                options.add_prefix_space,
+            )?),
+            "deberta" => {
+                let mut tokenizer = DeBERTaTokenizer::from_file(
+                    self.resource("vocab")?,
+                    self.resource("merges")?,
+                    options.lower_case,
+                )?;
+                tokenizer.set_add_prefix_space(options.add_prefix_space);
+                boxed_encoder(tokenizer)
+            }
+            "deberta_v2" => {
+                let mut tokenizer = DeBERTaV2Tokenizer::from_file(
+                    self.resource("vocab")?,
+                    options.lower_case,
+                    options.strip_accents,
+                    options.add_prefix_space,
+                )?;
+                tokenizer.set_split_by_punct(options.split_by_punct);
+                boxed_encoder(tokenizer)
+            }
+            "albert" => boxed_encoder(AlbertTokenizer::from_file(
+                self.resource("vocab")?,
+                options.lower_case,
+                options.strip_accents,
+            )?),
+            "xlnet" => boxed_encoder(XLNetTokenizer::from_file(
+                self.resource("vocab")?,
+                options.lower_case,
+                options.strip_accents,
+            )?),
+            "xlm_roberta" => boxed_encoder(XLMRobertaTokenizer::from_file(
+                self.resource("vocab")?,
+                options.lower_case,
+            )?),
+            family => anyhow::bail!("unsupported tokenizer family in parity fixture: {}", family),
+        })
+    }
+}
+
+fn boxed_encoder<T, V>(tokenizer: T) -> Encoder
+where
+    T: Tokenizer<V> + 'static,
+    V: Vocab,
+{
+    Box::new(move |text, text_pair| {
+        tokenizer.encode(
+            text,
+            text_pair,
+            MAX_LEN,
+            &TruncationStrategy::LongestFirst,
+            0,
+        )
+    })
+}
+
+fn check_case(encoder: &Encoder, case: &ParityCase) -> Option<String> {
+    let output = encoder(&case.text, case.text_pair.as_deref());
+    let mut mismatches = vec![];
+    if output.token_ids != case.token_ids {
+        mismatches.push(format!(
+            "  token ids:\n    expected {:?}\n    got      {:?}",
+            case.token_ids, output.token_ids
+        ));
+    }
+    if let Some(offsets) = &case.offsets {
+        let expected_offsets: Vec<Option<Offset>> = offsets
+            .iter()
+            .map(|offset| offset.map(|[begin, end]| Offset::new(begin, end)))
+            .collect();
+        if output.token_offsets != expected_offsets {
+            mismatches.push(format!(
+                "  offsets:\n    expected {:?}\n    got      {:?}",
+                expected_offsets, output.token_offsets
+            ));
+        }
+    }
+    if mismatches.is_empty() {
+        None
+    } else {
+        Some(format!("{:?}\n{}", case.text, mismatches.join("\n")))
+    }
+}
+
+#[test]
+#[ignore = "the parity fixtures have not been generated from the reference tokenizers yet"]
+fn test_parity_fixtures() -> anyhow::Result<()> {
+    let fixtures_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/parity");
+    let mut fixture_paths = fs::read_dir(fixtures_dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    fixture_paths.retain(|path| {
+        path.extension()
+            .is_some_and(|extension| extension == "json")
+    });
+    fixture_paths.sort();
+    assert!(!fixture_paths.is_empty());
+
+    let mut failures = vec![];
+    for fixture_path in fixture_paths {
+        let fixture = ParityFixture::from_file(&fixture_path)?;
+        let reference = match &fixture.reference {
+            Some(reference) => reference,
+            None => {
+                failures.push(format!(
+                    "{} ({}): expected values not generated from the reference tokenizers, run \
+                     `python tests/fixtures/parity/generate_fixtures.py {}`",
+                    fixture_path.display(),
+                    fixture.model,
+                    fixture_path.display()
+                ));
+                continue;
+            }
+        };
+        let encoder = fixture.encoder()?;
+        let fixture_failures: Vec<String> = fixture
+            .cases
+            .iter()
+            .filter_map(|case| check_case(&encoder, case))
+            .collect();
+        if !fixture_failures.is_empty() {
+            failures.push(format!(
+                "{} ({}, transformers {}, tokenizers {}, {}/{} cases failed):\n{}",
+                fixture_path.display(),
+                fixture.model,
+                reference.transformers,
+                reference.tokenizers,
+                fixture_failures.len(),
+                fixture.cases.len(),
+                fixture_failures.join("\n")
+            ));
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "parity check failed for:\n{}",
+        failures.join("\n\n")
+    );
+    Ok(())
+}
@@ -3,6 +3,7 @@ mod test_utils;
 use rust_tokenizers::tokenizer::{
     MultiThreadedTokenizer, PegasusTokenizer, Tokenizer, TruncationStrategy,
 };
+use rust_tokenizers::vocab::Vocab;
 use rust_tokenizers::{Offset, TokenizedInput};
 use test_utils::download_file_to_cache;
 
@@ -33,6 +34,7 @@ fn test_pegasus_tokenization() -> anyhow::Result<()> {
             token_offsets: vec![Some(Offset { begin: 0, end: 1 }), None],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -58,6 +60,7 @@ fn test_pegasus_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -83,6 +86,7 @@ fn test_pegasus_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -123,6 +127,7 @@ fn test_pegasus_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -177,6 +182,7 @@ fn test_pegasus_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -228,6 +234,7 @@ fn test_pegasus_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
     ]
     .to_vec();
@@ -266,3 +273,53 @@ fn test_pegasus_tokenization() -> anyhow::Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_pegasus_gap_sentence_example() -> anyhow::Result<()> {
+    let vocab_path = download_file_to_cache(
+        "https://cdn.huggingface.co/google/pegasus-cnn_dailymail/spiece.model",
+    )?;
+
+    let pegasus_tokenizer = PegasusTokenizer::from_file(vocab_path, false)?;
+    let vocab = Tokenizer::vocab(&pegasus_tokenizer);
+    let sentence_mask_id = vocab.token_to_id(vocab.get_sentence_mask_value());
+
+    let sentences = [
+        "This is a sample sentence.",
+        "The weather is nice.",
+        "This sentence is a sample.",
+    ];
+    assert_eq!(
+        PegasusTokenizer::select_principal_sentences(&sentences, 2),
+        vec![0, 2]
+    );
+
+    let example = pegasus_tokenizer.build_gap_sentence_example(&sentences, &[2, 0, 2], 128, 64)?;
+    let expected_target = pegasus_tokenizer.encode(
+        "This is a sample sentence. This sentence is a sample.",
+        None,
+        64,
+        &TruncationStrategy::LongestFirst,
+        0,
+    );
+    let num_input_tokens = example.input.token_ids.len();
+
+    assert_eq!(example.gap_sentence_indices, vec![0, 2]);
+    assert_eq!(example.input.token_ids[0], sentence_mask_id);
+    assert_eq!(
+        example.input.token_ids[num_input_tokens - 2],
+        sentence_mask_id
+    );
+    assert_eq!(example.input.token_ids[num_input_tokens - 1], 1);
+    assert_eq!(example.input.special_tokens_mask[0], 1);
+    assert_eq!(example.input.token_offsets[0], None);
+    assert_eq!(
+        example.input.token_offsets[1].map(|offset| offset.begin),
+        Some(27)
+    );
+    assert_eq!(example.target.token_ids, expected_target.token_ids);
+    assert!(pegasus_tokenizer
+        .build_gap_sentence_example(&sentences, &[3], 128, 64)
+        .is_err());
+    Ok(())
+}
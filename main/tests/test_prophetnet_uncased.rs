@@ -34,6 +34,7 @@ fn test_prophetnet_tokenization() -> anyhow::Result<()> {
             token_offsets: vec![Some(Offset { begin: 0, end: 1 }), None],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![2023, 2003, 1037, 7099, 6251, 2000, 2022, 19204, 3550, 102],
@@ -55,6 +56,7 @@ fn test_prophetnet_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![6603, 2129, 2023, 2097, 2131, 19204, 3550, 100, 1029, 102],
@@ -76,6 +78,7 @@ fn test_prophetnet_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -107,6 +110,7 @@ fn test_prophetnet_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -142,6 +146,7 @@ fn test_prophetnet_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -173,6 +178,7 @@ fn test_prophetnet_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -204,6 +210,7 @@ fn test_prophetnet_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
     ]
     .to_vec();
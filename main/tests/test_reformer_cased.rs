@@ -35,6 +35,7 @@ fn test_reformer_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -75,6 +76,7 @@ fn test_reformer_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -114,6 +116,7 @@ fn test_reformer_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -159,6 +162,7 @@ fn test_reformer_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -216,6 +220,7 @@ fn test_reformer_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -261,6 +266,7 @@ fn test_reformer_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -306,6 +312,7 @@ fn test_reformer_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
     ]
     .to_vec();
@@ -340,3 +347,44 @@ fn test_reformer_tokenization() -> anyhow::Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_reformer_bucket_length_encoding() -> anyhow::Result<()> {
+    let vocab_path = download_file_to_cache(
+        "https://cdn.huggingface.co/google/reformer-crime-and-punishment/spiece.model",
+    )
+    .unwrap();
+
+    let mut tokenizer: ReformerTokenizer = ReformerTokenizer::from_file(vocab_path, false)?;
+    assert!(tokenizer
+        .encode_to_bucket_length("Hello", None, 128, &TruncationStrategy::LongestFirst, 0)
+        .is_err());
+
+    tokenizer.set_attention_chunk_lengths(4, 6)?;
+    assert_eq!(tokenizer.bucket_length(), Some(12));
+    assert!(tokenizer.target_length(22, 8).is_err());
+
+    let padded = tokenizer.encode_to_bucket_length(
+        "This is a sample sentence to be tokénized",
+        None,
+        128,
+        &TruncationStrategy::LongestFirst,
+        0,
+    )?;
+    let truncated = tokenizer.encode_to_bucket_length(
+        "This is a sample sentence to be tokénized",
+        None,
+        20,
+        &TruncationStrategy::LongestFirst,
+        0,
+    )?;
+
+    assert_eq!(tokenizer.target_length(22, 128)?, 24);
+    assert_eq!(padded.token_ids.len(), 24);
+    assert_eq!(padded.token_ids[20..], [299, 19, 0, 0]);
+    assert_eq!(padded.special_tokens_mask[20..], [0, 0, 1, 1]);
+    assert_eq!(padded.token_offsets[22..], [None, None]);
+    assert_eq!(truncated.token_ids.len(), 12);
+    assert_eq!(truncated.num_truncated_tokens, 10);
+    Ok(())
+}
@@ -39,6 +39,7 @@ fn test_sentence_piece_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![122, 27, 24, 4561, 3833, 22, 39, 22, 267, 0, 180, 1227],
@@ -62,6 +63,7 @@ fn test_sentence_piece_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![14748, 56, 160, 52, 53, 133, 17366, 1227, 17, 0, 17, 82],
@@ -85,6 +87,7 @@ fn test_sentence_piece_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -126,6 +129,7 @@ fn test_sentence_piece_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -180,6 +184,7 @@ fn test_sentence_piece_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
         TokenizedInput {
             token_ids: vec![
@@ -231,6 +236,7 @@ fn test_sentence_piece_tokenization() -> anyhow::Result<()> {
             ],
             reference_offsets: vec![],
             mask: vec![],
+            tokens: None,
         },
     ]
     .to_vec();
@@ -0,0 +1,687 @@
+//! Golden-file snapshots of the tokenizer outputs.
+//!
+//! Every tokenizer family is built from small in-memory vocabularies and models, and encodes the
+//! inputs of `tests/fixtures/snapshots/corpus.txt` (one input per line, with an optional pair
+//! separated by a tab). The token ids, tokens, offsets and decoded text are compared with the
+//! fixture committed for the family in `tests/fixtures/snapshots/<family>.json`, so that changes
+//! to the shared tokenization utilities cannot silently modify the output of a tokenizer.
+//!
+//! When a change of output is intended, the fixtures are regenerated with:
+//! ```text
+//! UPDATE_SNAPSHOTS=1 cargo test --test test_snapshots
+//! ```
+//! and the resulting diff should be reviewed before being committed.
+mod common;
+
+use common::{
+    bert_like_special_tokens, merges, roberta_like_special_tokens, sentence_piece_bpe_model,
+    sentence_piece_model, sentence_piece_protobuf, sentence_piece_values, sentence_piece_vocab,
+    special_tokens, string_map,
+};
+use rust_tokenizers::tokenizer::{
+    AlbertTokenizer, BaseTokenizer, BertTokenizer, CtrlTokenizer, DeBERTaTokenizer,
+    DeBERTaV2Tokenizer, FNetTokenizer, Gpt2Tokenizer, M2M100Tokenizer, MBart50Tokenizer,
+    MarianTokenizer, NLLBTokenizer, OpenAiGptTokenizer, PegasusTokenizer, ProphetNetTokenizer,
+    ReformerTokenizer, RobertaTokenizer, SentencePieceBpeTokenizer, SentencePieceTokenizer,
+    T5Tokenizer, Tokenizer, TruncationStrategy, XLMRobertaTokenizer, XLNetTokenizer,
+};
+use rust_tokenizers::vocab::{
+    AlbertVocab, BaseVocab, BertVocab, DeBERTaV2Vocab, DeBERTaVocab, FNetVocab, Gpt2Vocab,
+    M2M100Vocab, MBart50Vocab, MarianVocab, OpenAiGptVocab, PegasusVocab, ProphetNetVocab,
+    RobertaVocab, SentencePieceVocab, SpecialTokenMap, T5Vocab, Vocab, XLMRobertaVocab, XLNetVocab,
+};
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const MAX_LEN: usize = 128;
+
+const WORD_PIECE_VOCAB: [&str; 63] = [
+    "[UNK]", "[PAD]", "[CLS]", "[SEP]", "[MASK]", "[X_SEP]", "hello", "world", "!", ",", ".", "'",
+    ":", "the", "cat", "sat", "on", "mat", "it", "fell", "as", "##leep", "un", "##aff", "##able",
+    "don", "t", "stop", "12", "##3", "45", "##6", "cafe", "caf", "##e", "de", "##ja", "vu", "na",
+    "##ive", "fa", "##cade", "中", "华", "人", "民", "共", "和", "国", "and", "日", "本", "emoji",
+    "symbols", "#", "@", "$", "upper", "lower", "mi", "##xed", "leading", "spaces",
+];
+
+const BYTE_LEVEL_MERGES: [(&str, &str); 25] = [
+    ("Ġ", "h"),
+    ("e", "l"),
+    ("Ġh", "el"),
+    ("l", "o"),
+    ("Ġhel", "lo"),
+    ("H", "el"),
+    ("Hel", "lo"),
+    ("Ġ", "w"),
+    ("o", "r"),
+    ("Ġw", "or"),
+    ("l", "d"),
+    ("Ġwor", "ld"),
+    ("Ġ", "t"),
+    ("h", "e"),
+    ("Ġt", "he"),
+    ("a", "t"),
+    ("Ġ", "c"),
+    ("Ġc", "at"),
+    ("Ġ", "s"),
+    ("Ġs", "at"),
+    ("Ġ", "m"),
+    ("Ġm", "at"),
+    ("Ġ", "a"),
+    ("n", "d"),
+    ("Ġa", "nd"),
+];
+
+const BPE_MERGES: [(&str, &str); 12] = [
+    ("h", "e"),
+    ("l", "l"),
+    ("he", "ll"),
+    ("hell", "o</w>"),
+    ("w", "o"),
+    ("wo", "r"),
+    ("l", "d</w>"),
+    ("wor", "ld</w>"),
+    ("t", "h"),
+    ("th", "e</w>"),
+    ("a", "t</w>"),
+    ("c", "at</w>"),
+];
+
+const SENTENCE_PIECE_VOCAB: [(&str, f32); 111] = [
+    ("<unk>", 0.0),
+    ("<pad>", 0.0),
+    ("<s>", 0.0),
+    ("</s>", 0.0),
+    ("<sep>", 0.0),
+    ("<cls>", 0.0),
+    ("<mask>", 0.0),
+    ("[CLS]", 0.0),
+    ("[SEP]", 0.0),
+    ("[MASK]", 0.0),
+    ("[PAD]", 0.0),
+    ("[UNK]", 0.0),
+    ("▁", -2.0),
+    ("a", -6.0),
+    ("b", -6.0),
+    ("c", -6.0),
+    ("d", -6.0),
+    ("e", -6.0),
+    ("f", -6.0),
+    ("g", -6.0),
+    ("h", -6.0),
+    ("i", -6.0),
+    ("j", -6.0),
+    ("k", -6.0),
+    ("l", -6.0),
+    ("m", -6.0),
+    ("n", -6.0),
+    ("o", -6.0),
+    ("p", -6.0),
+    ("r", -6.0),
+    ("s", -6.0),
+    ("t", -6.0),
+    ("u", -6.0),
+    ("v", -6.0),
+    ("w", -6.0),
+    ("x", -6.0),
+    ("y", -6.0),
+    ("C", -6.0),
+    ("D", -6.0),
+    ("E", -6.0),
+    ("H", -6.0),
+    ("I", -6.0),
+    ("M", -6.0),
+    ("P", -6.0),
+    ("R", -6.0),
+    ("T", -6.0),
+    ("U", -6.0),
+    ("W", -6.0),
+    ("X", -6.0),
+    ("1", -6.0),
+    ("2", -6.0),
+    ("3", -6.0),
+    ("4", -6.0),
+    ("5", -6.0),
+    ("6", -6.0),
+    ("!", -6.0),
+    (",", -6.0),
+    (".", -6.0),
+    ("'", -6.0),
+    (":", -6.0),
+    ("é", -6.0),
+    ("à", -6.0),
+    ("ç", -6.0),
+    ("ï", -6.0),
+    ("中", -6.0),
+    ("国", -6.0),
+    ("▁h", -4.0),
+    ("▁he", -4.0),
+    ("▁hel", -4.0),
+    ("▁hell", -4.0),
+    ("▁w", -4.0),
+    ("▁wo", -4.0),
+    ("▁wor", -4.0),
+    ("▁worl", -4.0),
+    ("▁t", -4.0),
+    ("▁th", -4.0),
+    ("▁c", -4.0),
+    ("▁ca", -4.0),
+    ("▁s", -4.0),
+    ("▁sa", -4.0),
+    ("▁m", -4.0),
+    ("▁ma", -4.0),
+    ("ll", -4.0),
+    ("lo", -4.0),
+    ("or", -4.0),
+    ("ld", -4.0),
+    ("at", -4.0),
+    ("he", -4.0),
+    ("in", -4.0),
+    ("er", -4.0),
+    ("on", -4.0),
+    ("an", -4.0),
+    ("es", -4.0),
+    ("▁hello", -2.0),
+    ("▁Hello", -2.0),
+    ("▁world", -2.0),
+    ("▁World", -2.0),
+    ("▁the", -2.0),
+    ("▁The", -2.0),
+    ("▁cat", -2.0),
+    ("▁sat", -2.0),
+    ("▁on", -2.0),
+    ("▁mat", -2.0),
+    ("▁and", -2.0),
+    ("▁It", -2.0),
+    ("▁fell", -2.0),
+    ("▁asleep", -2.0),
+    ("▁don", -2.0),
+    ("▁stop", -2.0),
+    ("▁Café", -2.0),
+    ("▁vu", -2.0),
+];
+
+const LANGUAGE_CODES: [&str; 2] = ["eng_Latn", "fra_Latn"];
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct SnapshotCase {
+    text: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    text_pair: Option<String>,
+    token_ids: Vec<i64>,
+    tokens: Vec<String>,
+    offsets: Vec<Option<[u32; 2]>>,
+    decoded: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Snapshot {
+    family: String,
+    cases: Vec<SnapshotCase>,
+}
+
+impl Snapshot {
+    /// Serializes the snapshot with one case per line to keep the fixtures diff-friendly.
+    fn to_json(family: &str, cases: &[SnapshotCase]) -> String {
+        let cases = cases
+            .iter()
+            .map(|case| format!("    {}", serde_json::to_string(case).unwrap()))
+            .collect::<Vec<_>>()
+            .join(",\n");
+        format!(
+            "{{\n  \"family\": {},\n  \"cases\": [\n{}\n  ]\n}}\n",
+            serde_json::to_string(family).unwrap(),
+            cases
+        )
+    }
+}
+
+fn snapshots_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/snapshots")
+}
+
+fn corpus() -> Vec<(String, Option<String>)> {
+    fs::read_to_string(snapshots_dir().join("corpus.txt"))
+        .unwrap()
+        .lines()
+        .map(|line| match line.split_once('\t') {
+            Some((text, text_pair)) => (text.to_string(), Some(text_pair.to_string())),
+            None => (line.to_string(), None),
+        })
+        .collect()
+}
+
+fn snapshot_cases<T: Vocab, U: Tokenizer<T>>(tokenizer: &U) -> Vec<SnapshotCase> {
+    corpus()
+        .into_iter()
+        .map(|(text, text_pair)| {
+            let encoded = tokenizer.encode(
+                &text,
+                text_pair.as_deref(),
+                MAX_LEN,
+                &TruncationStrategy::LongestFirst,
+                0,
+            );
+            SnapshotCase {
+                tokens: tokenizer.decode_to_vec(&encoded.token_ids, false),
+                offsets: encoded
+                    .token_offsets
+                    .iter()
+                    .map(|offset| offset.map(|offset| [offset.begin, offset.end]))
+                    .collect(),
+                decoded: tokenizer.decode(&encoded.token_ids, true, true),
+                token_ids: encoded.token_ids,
+                text,
+                text_pair,
+            }
+        })
+        .collect()
+}
+
+fn check_snapshot<T: Vocab, U: Tokenizer<T>>(family: &str, tokenizer: &U) {
+    let path = snapshots_dir().join(format!("{family}.json"));
+    let cases = snapshot_cases(tokenizer);
+    if env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        fs::write(&path, Snapshot::to_json(family, &cases)).unwrap();
+        return;
+    }
+
+    let contents = fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!(
+            "missing snapshot {}, run the tests with UPDATE_SNAPSHOTS=1 to create it",
+            path.display()
+        )
+    });
+    let snapshot: Snapshot = serde_json::from_str(&contents).unwrap();
+    assert_eq!(snapshot.family, family);
+    assert_eq!(
+        snapshot.cases.len(),
+        cases.len(),
+        "{}: the snapshot does not match the corpus, run the tests with UPDATE_SNAPSHOTS=1",
+        family
+    );
+    let mismatches: Vec<String> = snapshot
+        .cases
+        .iter()
+        .zip(cases.iter())
+        .filter(|(expected, output)| expected != output)
+        .map(|(expected, output)| {
+            format!(
+                "{:?}\n    expected {:?}\n    got      {:?}",
+                output.text, expected, output
+            )
+        })
+        .collect();
+    assert!(
+        mismatches.is_empty(),
+        "{} ({}/{} cases changed, run the tests with UPDATE_SNAPSHOTS=1 if intended):\n{}",
+        family,
+        mismatches.len(),
+        cases.len(),
+        mismatches.join("\n")
+    );
+}
+
+/// Mapping from bytes to the printable characters used by the byte-level BPE vocabularies.
+fn byte_level_characters() -> Vec<char> {
+    let mut characters = vec![' '; 256];
+    let mut next_unprintable = 256;
+    for (byte, character) in characters.iter_mut().enumerate() {
+        let printable = matches!(byte, 0x21..=0x7e | 0xa1..=0xac | 0xae..=0xff);
+        let code_point = if printable {
+            byte as u32
+        } else {
+            next_unprintable += 1;
+            next_unprintable - 1
+        };
+        *character = char::from_u32(code_point).unwrap();
+    }
+    characters
+}
+
+fn byte_level_vocab<V: Vocab>(special_token_map: SpecialTokenMap) -> V {
+    let characters: Vec<String> = byte_level_characters()
+        .into_iter()
+        .map(String::from)
+        .collect();
+    let merged: Vec<String> = BYTE_LEVEL_MERGES
+        .iter()
+        .map(|(left, right)| format!("{left}{right}"))
+        .collect();
+    let special_tokens = [
+        "<unk>", "<s>", "</s>", "<pad>", "<mask>", "[UNK]", "[CLS]", "[SEP]", "[PAD]", "[MASK]",
+    ];
+    let values = string_map(
+        characters
+            .iter()
+            .chain(merged.iter())
+            .map(String::as_str)
+            .chain(special_tokens),
+    );
+    V::from_values_and_special_token_map(values, special_token_map).unwrap()
+}
+
+/// Vocabulary shared by the OpenAI GPT (`</w>` end-of-word marker) and CTRL (`@@` continuation
+/// marker) tokenizers.
+fn bpe_vocab(special_token_map: SpecialTokenMap) -> OpenAiGptVocab {
+    let mut entries = vec!["<unk>".to_string()];
+    let pieces = (b'!'..=b'~').map(|byte| (byte as char).to_string()).chain(
+        BPE_MERGES
+            .iter()
+            .map(|(left, right)| format!("{left}{right}")),
+    );
+    for piece in pieces {
+        let word = piece.trim_end_matches("</w>");
+        entries.extend([
+            piece.clone(),
+            format!("{word}</w>"),
+            format!("{word}@@"),
+            word.to_string(),
+        ]);
+    }
+    let values = string_map(entries.iter().map(String::as_str));
+    OpenAiGptVocab::from_values_and_special_token_map(values, special_token_map).unwrap()
+}
+
+#[test]
+fn test_word_piece_tokenizers_snapshots() {
+    let vocab = BaseVocab::from_values_and_special_token_map(
+        string_map(WORD_PIECE_VOCAB),
+        special_tokens("[UNK]", [None; 6]),
+    )
+    .unwrap();
+    check_snapshot(
+        "base",
+        &BaseTokenizer::from_existing_vocab(vocab, true, true),
+    );
+
+    let vocab = BertVocab::from_values_and_special_token_map(
+        string_map(WORD_PIECE_VOCAB),
+        bert_like_special_tokens("[UNK]", "[PAD]"),
+    )
+    .unwrap();
+    check_snapshot(
+        "bert",
+        &BertTokenizer::from_existing_vocab(vocab, true, true),
+    );
+
+    let vocab = ProphetNetVocab::from_values_and_special_token_map(
+        string_map(WORD_PIECE_VOCAB),
+        bert_like_special_tokens("[UNK]", "[PAD]"),
+    )
+    .unwrap();
+    check_snapshot(
+        "prophetnet",
+        &ProphetNetTokenizer::from_existing_vocab(vocab, true, true),
+    );
+}
+
+#[test]
+fn test_byte_level_bpe_tokenizers_snapshots() {
+    let vocab: Gpt2Vocab = byte_level_vocab(roberta_like_special_tokens());
+    check_snapshot(
+        "gpt2",
+        &Gpt2Tokenizer::from_existing_vocab_and_merges(
+            vocab,
+            merges(&BYTE_LEVEL_MERGES),
+            false,
+            false,
+        ),
+    );
+
+    let vocab: RobertaVocab = byte_level_vocab(roberta_like_special_tokens());
+    check_snapshot(
+        "roberta",
+        &RobertaTokenizer::from_existing_vocab_and_merges(
+            vocab,
+            merges(&BYTE_LEVEL_MERGES),
+            false,
+            true,
+        ),
+    );
+
+    let vocab: DeBERTaVocab = byte_level_vocab(bert_like_special_tokens("[UNK]", "[PAD]"));
+    check_snapshot(
+        "deberta",
+        &DeBERTaTokenizer::from_existing_vocab_and_merges(vocab, merges(&BYTE_LEVEL_MERGES), false),
+    );
+}
+
+#[test]
+fn test_bpe_tokenizers_snapshots() {
+    check_snapshot(
+        "openai_gpt",
+        &OpenAiGptTokenizer::from_existing_vocab_and_merges(
+            bpe_vocab(special_tokens("<unk>", [None; 6])),
+            merges(&BPE_MERGES),
+            true,
+        ),
+    );
+
+    check_snapshot(
+        "ctrl",
+        &CtrlTokenizer::from_existing_vocab_and_merges(
+            bpe_vocab(special_tokens("<unk>", [None; 6])),
+            merges(&BPE_MERGES),
+            false,
+        ),
+    );
+}
+
+#[test]
+fn test_sentence_piece_tokenizers_snapshots() {
+    let vocab: AlbertVocab = sentence_piece_vocab(
+        &SENTENCE_PIECE_VOCAB,
+        bert_like_special_tokens("<unk>", "<pad>"),
+    );
+    check_snapshot(
+        "albert",
+        &AlbertTokenizer::from_existing_vocab_and_model(
+            vocab,
+            sentence_piece_model(&SENTENCE_PIECE_VOCAB),
+            true,
+            true,
+        ),
+    );
+
+    let vocab: XLNetVocab = sentence_piece_vocab(
+        &SENTENCE_PIECE_VOCAB,
+        special_tokens(
+            "<unk>",
+            [
+                Some("<pad>"),
+                Some("<s>"),
+                Some("<sep>"),
+                Some("<cls>"),
+                Some("</s>"),
+                Some("<mask>"),
+            ],
+        ),
+    );
+    let mut xlnet_tokenizer = XLNetTokenizer::from_existing_vocab_and_model(
+        vocab,
+        sentence_piece_model(&SENTENCE_PIECE_VOCAB),
+        false,
+        false,
+    );
+    xlnet_tokenizer.set_nfkc_normalization(false);
+    check_snapshot("xlnet", &xlnet_tokenizer);
+
+    let vocab: T5Vocab = sentence_piece_vocab(
+        &SENTENCE_PIECE_VOCAB,
+        special_tokens(
+            "<unk>",
+            [Some("<pad>"), None, None, None, Some("</s>"), None],
+        ),
+    );
+    check_snapshot(
+        "t5",
+        &T5Tokenizer::from_existing_vocab_and_model(
+            vocab,
+            sentence_piece_model(&SENTENCE_PIECE_VOCAB),
+            false,
+        ),
+    );
+
+    let vocab: SentencePieceVocab =
+        sentence_piece_vocab(&SENTENCE_PIECE_VOCAB, special_tokens("<unk>", [None; 6]));
+    check_snapshot(
+        "sentence_piece",
+        &SentencePieceTokenizer::from_existing_vocab_and_model(
+            vocab,
+            sentence_piece_model(&SENTENCE_PIECE_VOCAB),
+            false,
+        ),
+    );
+
+    let vocab: XLMRobertaVocab =
+        sentence_piece_vocab(&SENTENCE_PIECE_VOCAB, roberta_like_special_tokens());
+    check_snapshot(
+        "xlm_roberta",
+        &XLMRobertaTokenizer::from_existing_vocab_and_model(
+            vocab,
+            sentence_piece_model(&SENTENCE_PIECE_VOCAB),
+            false,
+        ),
+    );
+
+    let vocab: MarianVocab =
+        sentence_piece_vocab(&SENTENCE_PIECE_VOCAB, roberta_like_special_tokens());
+    check_snapshot(
+        "marian",
+        &MarianTokenizer::from_existing_vocab_and_model(
+            vocab,
+            sentence_piece_model(&SENTENCE_PIECE_VOCAB),
+            false,
+        ),
+    );
+
+    let vocab: PegasusVocab =
+        sentence_piece_vocab(&SENTENCE_PIECE_VOCAB, roberta_like_special_tokens());
+    check_snapshot(
+        "pegasus",
+        &PegasusTokenizer::from_existing_vocab_and_model(
+            vocab,
+            sentence_piece_model(&SENTENCE_PIECE_VOCAB),
+            false,
+        ),
+    );
+
+    let vocab: MBart50Vocab =
+        sentence_piece_vocab(&SENTENCE_PIECE_VOCAB, roberta_like_special_tokens());
+    check_snapshot(
+        "mbart50",
+        &MBart50Tokenizer::from_existing_vocab_and_model(
+            vocab,
+            sentence_piece_model(&SENTENCE_PIECE_VOCAB),
+            false,
+        ),
+    );
+
+    let vocab: DeBERTaV2Vocab = sentence_piece_vocab(
+        &SENTENCE_PIECE_VOCAB,
+        bert_like_special_tokens("<unk>", "<pad>"),
+    );
+    check_snapshot(
+        "deberta_v2",
+        &DeBERTaV2Tokenizer::from_existing_vocab_and_model(
+            vocab,
+            sentence_piece_model(&SENTENCE_PIECE_VOCAB),
+            false,
+            false,
+            true,
+        ),
+    );
+}
+
+#[test]
+fn test_sentence_piece_bpe_tokenizers_snapshots() -> anyhow::Result<()> {
+    check_snapshot(
+        "sentence_piece_bpe",
+        &SentencePieceBpeTokenizer::from_protobuf_bytes(
+            &sentence_piece_protobuf(&SENTENCE_PIECE_VOCAB),
+            false,
+        )?,
+    );
+
+    let vocab = M2M100Vocab::from_values_and_special_token_map(
+        sentence_piece_values(&SENTENCE_PIECE_VOCAB),
+        special_tokens(
+            "<unk>",
+            [
+                Some("<pad>"),
+                Some("<s>"),
+                Some("</s>"),
+                None,
+                Some("</s>"),
+                None,
+            ],
+        ),
+    )?;
+    check_snapshot(
+        "m2m100",
+        &M2M100Tokenizer::from_existing_vocab_and_model(
+            vocab,
+            sentence_piece_bpe_model(&SENTENCE_PIECE_VOCAB),
+            false,
+        ),
+    );
+
+    let vocab: FNetVocab = sentence_piece_vocab(
+        &SENTENCE_PIECE_VOCAB,
+        bert_like_special_tokens("<unk>", "<pad>"),
+    );
+    let mut fnet_tokenizer = FNetTokenizer::from_existing_vocab_and_model(
+        vocab,
+        sentence_piece_bpe_model(&SENTENCE_PIECE_VOCAB),
+        false,
+        false,
+    );
+    fnet_tokenizer.set_nfkc_normalization(false);
+    check_snapshot("fnet", &fnet_tokenizer);
+
+    let model_file = tempfile::NamedTempFile::new()?;
+    fs::write(
+        model_file.path(),
+        sentence_piece_protobuf(&SENTENCE_PIECE_VOCAB),
+    )?;
+    check_snapshot(
+        "reformer",
+        &ReformerTokenizer::from_file(model_file.path(), false)?,
+    );
+
+    let mut nllb_values = sentence_piece_values(&SENTENCE_PIECE_VOCAB);
+    for language_code in LANGUAGE_CODES {
+        nllb_values.insert(language_code.to_string(), nllb_values.len() as i64);
+    }
+    let vocab_file = tempfile::NamedTempFile::new()?;
+    fs::write(
+        vocab_file.path(),
+        serde_json::json!({ "model": { "vocab": nllb_values } }).to_string(),
+    )?;
+    let special_tokens_file = tempfile::NamedTempFile::new()?;
+    fs::write(
+        special_tokens_file.path(),
+        serde_json::json!({
+            "unk_token": "<unk>",
+            "pad_token": "<pad>",
+            "bos_token": "<s>",
+            "sep_token": "</s>",
+            "cls_token": null,
+            "eos_token": "</s>",
+            "mask_token": { "content": "<mask>", "lstrip": true },
+            "additional_special_tokens": LANGUAGE_CODES,
+        })
+        .to_string(),
+    )?;
+    check_snapshot(
+        "nllb",
+        &NLLBTokenizer::from_files_with_special_token_map(
+            vocab_file.path(),
+            model_file.path(),
+            special_tokens_file.path(),
+        )?,
+    );
+    Ok(())
+}
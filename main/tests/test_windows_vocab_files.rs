@@ -0,0 +1,133 @@
+//! Vocabulary files saved on Windows start with a UTF-8 byte order mark, use `\r\n` line endings
+//! and may carry trailing whitespace. These tests check that none of these artifacts leak into
+//! the tokens read from the fixtures in `tests/fixtures/windows`.
+
+use rust_tokenizers::vocab::{
+    BaseVocab, BertVocab, BpePairVocab, Gpt2Vocab, RobertaVocab, SpecialTokenMap, Vocab,
+};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+fn fixture(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join("windows")
+        .join(name)
+}
+
+fn assert_clean_tokens(values: &HashMap<String, i64>) {
+    for token in values.keys() {
+        assert!(!token.is_empty(), "empty token in vocabulary");
+        assert!(
+            !token.contains('\r') && !token.contains('\u{feff}'),
+            "token {:?} contains a carriage return or byte order mark",
+            token
+        );
+        assert_eq!(token.trim(), token, "token {:?} is not trimmed", token);
+    }
+}
+
+#[test]
+fn test_bert_vocab_from_windows_file() -> anyhow::Result<()> {
+    //        Given
+    let path = fixture("vocab.txt");
+
+    //        When
+    let vocab = BertVocab::from_file(&path)?;
+    let mapped_vocab =
+        BertVocab::from_file_with_special_token_mapping(&path, fixture("special_tokens_map.json"))?;
+
+    //        Then
+    for vocab in [&vocab, &mapped_vocab].iter() {
+        assert_clean_tokens(vocab.values());
+        assert_eq!(vocab.values().len(), 8);
+        assert_eq!(vocab.token_to_id("[PAD]"), 0);
+        assert_eq!(vocab.token_to_id("[MASK]"), 4);
+        assert_eq!(vocab.token_to_id("hello"), 5);
+        assert_eq!(vocab.token_to_id("world"), 6);
+        assert_eq!(vocab.token_to_id("!"), 7);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_token_list_from_windows_file() -> anyhow::Result<()> {
+    //        Given
+    let path = fixture("vocab.txt");
+
+    //        When
+    let vocab = BaseVocab::from_token_list_file(path, "[UNK]")?;
+
+    //        Then
+    assert_clean_tokens(vocab.values());
+    assert_eq!(vocab.values().len(), 8);
+    assert_eq!(vocab.token_to_id("[PAD]"), 0);
+    assert_eq!(vocab.token_to_id("hello"), 5);
+    assert_eq!(vocab.token_to_id("world"), 6);
+    Ok(())
+}
+
+#[test]
+fn test_bpe_merges_from_windows_file() -> anyhow::Result<()> {
+    //        Given
+    let path = fixture("merges.txt");
+
+    //        When
+    let merges = BpePairVocab::from_file(path)?;
+
+    //        Then
+    let expected = vec![("h", "e"), ("l", "l"), ("he", "ll"), ("o", "w")];
+    assert_eq!(merges.values.len(), expected.len());
+    for (index, (first, second)) in expected.into_iter().enumerate() {
+        assert_eq!(
+            merges.values.get(&(first.to_string(), second.to_string())),
+            Some(&(index as i64))
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_json_vocab_from_windows_file() -> anyhow::Result<()> {
+    //        Given
+    let path = fixture("vocab.json");
+
+    //        When
+    let vocab = Gpt2Vocab::from_file(path)?;
+
+    //        Then
+    assert_clean_tokens(vocab.values());
+    assert_eq!(vocab.values().len(), 3);
+    assert_eq!(vocab.token_to_id("<|endoftext|>"), 0);
+    assert_eq!(vocab.token_to_id("hello"), 1);
+    assert_eq!(vocab.token_to_id("world"), 2);
+    Ok(())
+}
+
+#[test]
+fn test_fairseq_dict_from_windows_file() -> anyhow::Result<()> {
+    //        Given
+    let path = fixture("dict.txt");
+    let special_token_map = SpecialTokenMap {
+        unk_token: "<unk>".to_string(),
+        pad_token: Some("<pad>".to_string()),
+        bos_token: Some("<s>".to_string()),
+        sep_token: Some("</s>".to_string()),
+        cls_token: Some("<s>".to_string()),
+        eos_token: Some("</s>".to_string()),
+        mask_token: None,
+        additional_special_tokens: None,
+    };
+
+    //        When
+    let vocab = RobertaVocab::from_fairseq_dict_file(path, special_token_map)?;
+
+    //        Then
+    assert_clean_tokens(vocab.values());
+    assert_eq!(vocab.values().len(), 7);
+    assert_eq!(vocab.token_to_id("hello"), 4);
+    assert_eq!(vocab.token_to_id("world"), 5);
+    assert_eq!(vocab.token_to_id("!"), 6);
+    Ok(())
+}
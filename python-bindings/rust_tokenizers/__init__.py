@@ -1,10 +1,11 @@
 from .rust_tokenizers import PyBertTokenizer, PyCtrlTokenizer, PyGpt2Tokenizer, PyRobertaTokenizer, \
     PyOpenAiGptTokenizer, PySentencePieceTokenizer, PySentencePieceBpeTokenizer, PyAlbertTokenizer, PyT5Tokenizer, \
     PyXLMRobertaTokenizer, PyXLNetTokenizer, PyReformerTokenizer, PyProphetNetTokenizer, PyPegasusTokenizer, \
-    PyMBart50Tokenizer, PyM2M100Tokenizer, PyFNetTokenizer, PyDeBertaTokenizer, PyDeBertaV2Tokenizer, PyNLLBTokenizer
+    PyMBart50Tokenizer, PyM2M100Tokenizer, PyFNetTokenizer, PyDeBertaTokenizer, PyDeBertaV2Tokenizer, PyNLLBTokenizer, \
+    set_num_threads, get_num_threads
 
 __all__ = ["PyBertTokenizer", "PyCtrlTokenizer", "PyGpt2Tokenizer", "PyRobertaTokenizer",
            "PyOpenAiGptTokenizer", "PySentencePieceTokenizer", "PySentencePieceBpeTokenizer", "PyAlbertTokenizer",
            "PyT5Tokenizer", "PyXLMRobertaTokenizer", "PyXLNetTokenizer", "PyReformerTokenizer", "PyProphetNetTokenizer",
            "PyPegasusTokenizer", "PyMBart50Tokenizer", "PyM2M100Tokenizer", "PyFNetTokenizer", "PyDeBertaTokenizer",
-           "PyDeBertaV2Tokenizer", "PyNLLBTokenizer"]
+           "PyDeBertaV2Tokenizer", "PyNLLBTokenizer", "set_num_threads", "get_num_threads"]
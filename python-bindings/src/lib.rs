@@ -1,5 +1,8 @@
 use pyo3::exceptions;
 use pyo3::prelude::*;
+use pyo3::wrap_pyfunction;
+use rayon::{ThreadPool, ThreadPoolBuilder};
+use std::sync::{Arc, Mutex};
 
 extern crate rust_tokenizers as rust_tokenizers_base;
 
@@ -16,6 +19,49 @@ use rust_tokenizers_base::vocab::{
     RobertaVocab, SentencePieceVocab, T5Vocab, Vocab, XLMRobertaVocab, XLNetVocab,
 };
 
+/// Thread pool running the batch methods of the tokenizers (`tokenize_list`, `encode_list` and
+/// `encode_pair_list`). `None` uses the rayon global pool (one thread per core by default).
+static THREAD_POOL: Mutex<Option<Arc<ThreadPool>>> = Mutex::new(None);
+
+fn run_in_thread_pool<R, F>(op: F) -> R
+where
+    R: Send,
+    F: FnOnce() -> R + Send,
+{
+    let thread_pool = THREAD_POOL.lock().unwrap().clone();
+    match thread_pool {
+        Some(thread_pool) => thread_pool.install(op),
+        None => op(),
+    }
+}
+
+/// Sets the number of threads used by the batch methods of all tokenizers, for example to avoid
+/// oversubscribing the cores when the module is used by several worker processes. A value of 0
+/// restores the default (rayon global pool).
+#[pyfunction]
+fn set_num_threads(num_threads: usize) -> PyResult<()> {
+    let thread_pool = if num_threads == 0 {
+        None
+    } else {
+        let thread_pool = ThreadPoolBuilder::new()
+            .num_threads(num_threads)
+            .build()
+            .map_err(|e| exceptions::PyRuntimeError::new_err(e.to_string()))?;
+        Some(Arc::new(thread_pool))
+    };
+    *THREAD_POOL.lock().unwrap() = thread_pool;
+    Ok(())
+}
+
+/// Returns the number of threads used by the batch methods of the tokenizers
+#[pyfunction]
+fn get_num_threads() -> usize {
+    match THREAD_POOL.lock().unwrap().as_ref() {
+        Some(thread_pool) => thread_pool.current_num_threads(),
+        None => rayon::current_num_threads(),
+    }
+}
+
 #[pyclass]
 #[derive(Debug, PartialEq, PartialOrd, Clone)]
 pub struct PyTokenizedInput {
@@ -58,9 +104,10 @@ trait PyTokenizer<T: Tokenizer<U>, U: Vocab> {
         };
         match truncation_strategy {
             Ok(truncation_strategy) => {
-                let tokenized_input =
-                    self.tokenizer()
-                        .encode(text, None, max_len, &truncation_strategy, stride);
+                let tokenized_input = self
+                    .tokenizer()
+                    .try_encode(text, None, max_len, &truncation_strategy, stride)
+                    .map_err(|e| exceptions::PyValueError::new_err(e.to_string()))?;
                 Ok(PyTokenizedInput {
                     token_ids: tokenized_input.token_ids,
                     segment_ids: tokenized_input.segment_ids,
@@ -90,13 +137,10 @@ trait PyTokenizer<T: Tokenizer<U>, U: Vocab> {
         };
         match truncation_strategy {
             Ok(truncation_strategy) => {
-                let tokenized_input = self.tokenizer().encode(
-                    text_a,
-                    Some(text_b),
-                    max_len,
-                    &truncation_strategy,
-                    stride,
-                );
+                let tokenized_input = self
+                    .tokenizer()
+                    .try_encode(text_a, Some(text_b), max_len, &truncation_strategy, stride)
+                    .map_err(|e| exceptions::PyValueError::new_err(e.to_string()))?;
                 Ok(PyTokenizedInput {
                     token_ids: tokenized_input.token_ids,
                     segment_ids: tokenized_input.segment_ids,
@@ -125,12 +169,10 @@ trait PyTokenizer<T: Tokenizer<U>, U: Vocab> {
         };
         match truncation_strategy {
             Ok(truncation_strategy) => {
-                let tokenized_inputs = self.tokenizer().encode_list(
-                    text_list.as_slice(),
-                    max_len,
-                    &truncation_strategy,
-                    stride,
-                );
+                let tokenized_inputs = self
+                    .tokenizer()
+                    .try_encode_list(text_list.as_slice(), max_len, &truncation_strategy, stride)
+                    .map_err(|e| exceptions::PyValueError::new_err(e.to_string()))?;
                 Ok(tokenized_inputs
                     .into_iter()
                     .map(|tokenized_input| PyTokenizedInput {
@@ -162,12 +204,15 @@ trait PyTokenizer<T: Tokenizer<U>, U: Vocab> {
         };
         match truncation_strategy {
             Ok(truncation_strategy) => {
-                let tokenized_inputs = self.tokenizer().encode_pair_list(
-                    text_list.as_slice(),
-                    max_len,
-                    &truncation_strategy,
-                    stride,
-                );
+                let tokenized_inputs = self
+                    .tokenizer()
+                    .try_encode_pair_list(
+                        text_list.as_slice(),
+                        max_len,
+                        &truncation_strategy,
+                        stride,
+                    )
+                    .map_err(|e| exceptions::PyValueError::new_err(e.to_string()))?;
                 Ok(tokenized_inputs
                     .into_iter()
                     .map(|tokenized_input| PyTokenizedInput {
@@ -189,10 +234,10 @@ where
     Self: PyTokenizer<T, U>,
 {
     fn tokenize_list(&self, text_list: Vec<&str>) -> PyResult<Vec<Vec<String>>> {
-        Ok(MultiThreadedTokenizer::tokenize_list(
-            self.tokenizer(),
-            text_list.as_slice(),
-        ))
+        let tokenizer = self.tokenizer();
+        Ok(run_in_thread_pool(|| {
+            MultiThreadedTokenizer::tokenize_list(tokenizer, text_list.as_slice())
+        }))
     }
 
     fn encode_list(
@@ -211,13 +256,17 @@ where
         };
         match truncation_strategy {
             Ok(truncation_strategy) => {
-                let tokenized_inputs = MultiThreadedTokenizer::encode_list(
-                    self.tokenizer(),
-                    &text_list,
-                    max_len,
-                    &truncation_strategy,
-                    stride,
-                );
+                let tokenizer = self.tokenizer();
+                let tokenized_inputs = run_in_thread_pool(|| {
+                    MultiThreadedTokenizer::try_encode_list(
+                        tokenizer,
+                        &text_list,
+                        max_len,
+                        &truncation_strategy,
+                        stride,
+                    )
+                })
+                .map_err(|e| exceptions::PyValueError::new_err(e.to_string()))?;
                 Ok(tokenized_inputs
                     .into_iter()
                     .map(|tokenized_input| PyTokenizedInput {
@@ -249,13 +298,17 @@ where
         };
         match truncation_strategy {
             Ok(truncation_strategy) => {
-                let tokenized_inputs = MultiThreadedTokenizer::encode_pair_list(
-                    self.tokenizer(),
-                    &text_list,
-                    max_len,
-                    &truncation_strategy,
-                    stride,
-                );
+                let tokenizer = self.tokenizer();
+                let tokenized_inputs = run_in_thread_pool(|| {
+                    MultiThreadedTokenizer::try_encode_pair_list(
+                        tokenizer,
+                        &text_list,
+                        max_len,
+                        &truncation_strategy,
+                        stride,
+                    )
+                })
+                .map_err(|e| exceptions::PyValueError::new_err(e.to_string()))?;
                 Ok(tokenized_inputs
                     .into_iter()
                     .map(|tokenized_input| PyTokenizedInput {
@@ -490,12 +543,19 @@ impl PyMultiThreadTokenizer<Gpt2Tokenizer, Gpt2Vocab> for PyGpt2Tokenizer {}
 #[pymethods]
 impl PyGpt2Tokenizer {
     #[new]
-    fn new(vocab_path: String, merges_path: String, do_lower_case: bool) -> Self {
+    #[pyo3(signature = (vocab_path, merges_path, do_lower_case, add_prefix_space=false))]
+    fn new(
+        vocab_path: String,
+        merges_path: String,
+        do_lower_case: bool,
+        add_prefix_space: bool,
+    ) -> Self {
         PyGpt2Tokenizer {
             tokenizer: Gpt2Tokenizer::from_file(
                 vocab_path.as_str(),
                 merges_path.as_str(),
                 do_lower_case,
+                add_prefix_space,
             )
             .unwrap(),
         }
@@ -902,11 +962,17 @@ impl PyMultiThreadTokenizer<AlbertTokenizer, AlbertVocab> for PyAlbertTokenizer
 #[pymethods]
 impl PyAlbertTokenizer {
     #[new]
-    fn new(path: String, do_lower_case: bool, strip_accents: bool) -> Self {
-        PyAlbertTokenizer {
-            tokenizer: AlbertTokenizer::from_file(path.as_str(), do_lower_case, strip_accents)
-                .unwrap(),
-        }
+    #[pyo3(signature = (path, do_lower_case, strip_accents, nfkc_normalization=true))]
+    fn new(
+        path: String,
+        do_lower_case: bool,
+        strip_accents: bool,
+        nfkc_normalization: bool,
+    ) -> Self {
+        let mut tokenizer =
+            AlbertTokenizer::from_file(path.as_str(), do_lower_case, strip_accents).unwrap();
+        tokenizer.set_nfkc_normalization(nfkc_normalization);
+        PyAlbertTokenizer { tokenizer }
     }
 
     fn tokenize(&self, text: &str) -> PyResult<Vec<String>> {
@@ -1002,11 +1068,17 @@ impl PyMultiThreadTokenizer<XLNetTokenizer, XLNetVocab> for PyXLNetTokenizer {}
 #[pymethods]
 impl PyXLNetTokenizer {
     #[new]
-    fn new(path: String, do_lower_case: bool, strip_accents: bool) -> Self {
-        PyXLNetTokenizer {
-            tokenizer: XLNetTokenizer::from_file(path.as_str(), do_lower_case, strip_accents)
-                .unwrap(),
-        }
+    #[pyo3(signature = (path, do_lower_case, strip_accents, nfkc_normalization=true))]
+    fn new(
+        path: String,
+        do_lower_case: bool,
+        strip_accents: bool,
+        nfkc_normalization: bool,
+    ) -> Self {
+        let mut tokenizer =
+            XLNetTokenizer::from_file(path.as_str(), do_lower_case, strip_accents).unwrap();
+        tokenizer.set_nfkc_normalization(nfkc_normalization);
+        PyXLNetTokenizer { tokenizer }
     }
 
     fn tokenize(&self, text: &str) -> PyResult<Vec<String>> {
@@ -1899,11 +1971,17 @@ impl PyMultiThreadTokenizer<FNetTokenizer, FNetVocab> for PyFNetTokenizer {}
 #[pymethods]
 impl PyFNetTokenizer {
     #[new]
-    fn new(vocab_path: String, do_lower_case: bool, strip_accents: bool) -> Self {
-        PyFNetTokenizer {
-            tokenizer: FNetTokenizer::from_file(vocab_path.as_str(), do_lower_case, strip_accents)
-                .unwrap(),
-        }
+    #[pyo3(signature = (vocab_path, do_lower_case, strip_accents, nfkc_normalization=true))]
+    fn new(
+        vocab_path: String,
+        do_lower_case: bool,
+        strip_accents: bool,
+        nfkc_normalization: bool,
+    ) -> Self {
+        let mut tokenizer =
+            FNetTokenizer::from_file(vocab_path.as_str(), do_lower_case, strip_accents).unwrap();
+        tokenizer.set_nfkc_normalization(nfkc_normalization);
+        PyFNetTokenizer { tokenizer }
     }
 
     fn tokenize(&self, text: &str) -> PyResult<Vec<String>> {
@@ -2299,6 +2377,8 @@ impl PyNLLBTokenizer {
 
 #[pymodule]
 fn rust_tokenizers(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(set_num_threads, m)?)?;
+    m.add_function(wrap_pyfunction!(get_num_threads, m)?)?;
     m.add_class::<PyBertTokenizer>()?;
     m.add_class::<PyCtrlTokenizer>()?;
     m.add_class::<PyGpt2Tokenizer>()?;
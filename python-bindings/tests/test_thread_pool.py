@@ -0,0 +1,84 @@
+# Copyright 2019 Guillaume Becquin
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#     http://www.apache.org/licenses/LICENSE-2.0
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+
+import tempfile
+from pathlib import Path
+
+import pytest
+
+from rust_tokenizers import PyBertTokenizer, set_num_threads, get_num_threads
+
+VOCAB = ['[UNK]', '[PAD]', '[CLS]', '[SEP]', '[MASK]', 'hello', 'world', '!', 'the', 'earth', '##s', ',']
+
+TEXTS = ['Hello world!', 'the earths, hello', 'Hello, the world', 'unknown words !'] * 64
+
+
+class TestThreadPool:
+    def setup_class(self):
+        self.test_dir = Path(tempfile.mkdtemp())
+        (self.test_dir / 'vocab.txt').write_text('\n'.join(VOCAB), encoding='utf-8')
+        self.rust_tokenizer = PyBertTokenizer(str(self.test_dir / 'vocab.txt'),
+                                              do_lower_case=True,
+                                              strip_accents=True)
+        self.default_num_threads = get_num_threads()
+
+    def teardown_method(self):
+        set_num_threads(0)
+
+    def test_num_threads_round_trip(self):
+        # Given
+        num_threads = 3
+
+        # When
+        set_num_threads(num_threads)
+
+        # Then
+        assert get_num_threads() == num_threads
+
+    def test_num_threads_reset_to_default(self):
+        # Given
+        set_num_threads(3)
+
+        # When
+        set_num_threads(0)
+
+        # Then
+        assert get_num_threads() == self.default_num_threads
+
+    @pytest.mark.parametrize('num_threads', [1, 2, 4])
+    def test_parallel_encode(self, num_threads):
+        # Given
+        expected = [self.rust_tokenizer.encode(text, max_len=16, truncation_strategy='longest_first', stride=0)
+                    for text in TEXTS]
+        set_num_threads(num_threads)
+
+        # When
+        output = self.rust_tokenizer.encode_list(TEXTS, max_len=16, truncation_strategy='longest_first', stride=0)
+
+        # Then
+        assert get_num_threads() == num_threads
+        assert len(output) == len(expected)
+        for encoded, expected_encoded in zip(output, expected):
+            assert encoded.token_ids == expected_encoded.token_ids
+            assert encoded.segment_ids == expected_encoded.segment_ids
+            assert encoded.special_tokens_mask == expected_encoded.special_tokens_mask
+
+    @pytest.mark.parametrize('num_threads', [1, 2, 4])
+    def test_parallel_tokenize(self, num_threads):
+        # Given
+        expected = [self.rust_tokenizer.tokenize(text) for text in TEXTS]
+        set_num_threads(num_threads)
+
+        # When
+        output = self.rust_tokenizer.tokenize_list(TEXTS)
+
+        # Then
+        assert output == expected